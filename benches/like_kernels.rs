@@ -0,0 +1,26 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use arrow2::array::*;
+use arrow2::compute::like::like_utf8_scalar;
+use arrow2::util::bench_util::create_string_array;
+
+fn bench_like(array: &Utf8Array<i32>, pattern: &str) {
+    criterion::black_box(like_utf8_scalar(array, pattern).unwrap());
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    for size_log2 in (10..=20).step_by(2) {
+        let size = 2usize.pow(size_log2);
+        let array = create_string_array::<i32>(size, 10, 0.1, 42);
+
+        for pattern in ["abc%", "%abc", "abc", "a%b_c"] {
+            c.bench_function(
+                &format!("like_utf8_scalar 2^{} \"{}\"", size_log2, pattern),
+                |b| b.iter(|| bench_like(&array, pattern)),
+            );
+        }
+    }
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
@@ -97,6 +97,21 @@ fn add_benchmark(c: &mut Criterion) {
     c.bench_function("take str null values null indices 1024", |b| {
         b.iter(|| bench_take(&values, &indices))
     });
+
+    // multiple columns taken with the same indices
+    let size = 2usize.pow(16);
+    let int = create_primitive_array::<i32>(size, 0.2);
+    let float = create_primitive_array::<f64>(size, 0.2);
+    let boolean = create_boolean_array(size, 0.2, 0.5);
+    let string = create_string_array::<i32>(size, 4, 0.2, 42);
+    let arrays: Vec<&dyn Array> = vec![&int, &float, &boolean, &string];
+    let indices = create_random_index(size, 0.1);
+    c.bench_function("take 4 columns 2^16", |b| {
+        b.iter(|| arrays.iter().for_each(|array| bench_take(*array, &indices)))
+    });
+    c.bench_function("take_arrays 4 columns 2^16", |b| {
+        b.iter(|| criterion::black_box(take::take_arrays(&arrays, &indices).unwrap()))
+    });
 }
 
 criterion_group!(benches, add_benchmark);
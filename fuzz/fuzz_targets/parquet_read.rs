@@ -0,0 +1,18 @@
+#![no_main]
+use std::io::Cursor;
+
+use arrow2::io::parquet::read::RecordReader;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let reader = match RecordReader::try_new(Cursor::new(data), None, None, None, None) {
+        Ok(reader) => reader,
+        Err(_) => return,
+    };
+    // malformed files must error, never panic
+    for batch in reader {
+        if batch.is_err() {
+            return;
+        }
+    }
+});
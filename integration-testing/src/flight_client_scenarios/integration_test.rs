@@ -262,6 +262,7 @@ async fn receive_batch_flight_data(
             dictionaries,
             &mut reader,
             0,
+            read::ValidationMode::Full,
         )
         .expect("Error reading dictionary");
 
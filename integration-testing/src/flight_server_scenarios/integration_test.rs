@@ -292,6 +292,7 @@ async fn record_batch_from_message(
         ArrowSchema::MetadataVersion::V5,
         &mut reader,
         0,
+        ipc::read::ValidationMode::Full,
     );
 
     arrow_batch_result
@@ -310,8 +311,15 @@ async fn dictionary_from_message(
 
     let mut reader = std::io::Cursor::new(data_body);
 
-    let dictionary_batch_result =
-        ipc::read::read_dictionary(ipc_batch, &schema_ref, true, dictionaries, &mut reader, 0);
+    let dictionary_batch_result = ipc::read::read_dictionary(
+        ipc_batch,
+        &schema_ref,
+        true,
+        dictionaries,
+        &mut reader,
+        0,
+        ipc::read::ValidationMode::Full,
+    );
     dictionary_batch_result
         .map_err(|e| Status::internal(format!("Could not convert to Dictionary: {:?}", e)))
 }
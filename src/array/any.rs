@@ -0,0 +1,283 @@
+use crate::datatypes::{IntegerType, PhysicalType, PrimitiveType};
+use crate::types::{days_ms, months_days_ns, NativeType};
+
+use super::*;
+
+/// Downcasting helpers, that avoid `array.as_any().downcast_ref::<...>()`.
+/// These are also available on [`ArrayRef`] and `Box<dyn Array>` via [`std::ops::Deref`].
+/// Each returns [`None`] iff the array is not of the requested concrete type.
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow2::array::{ArrayRef, Int32Array, Utf8Array};
+///
+/// let array: ArrayRef = Arc::new(Int32Array::from_slice(&[1, 2]));
+/// assert_eq!(array.as_primitive::<i32>().unwrap().value(1), 2);
+/// assert!(array.as_primitive::<i64>().is_none());
+/// assert!(array.as_utf8::<i32>().is_none());
+/// ```
+impl dyn Array {
+    /// Downcasts this array to a [`NullArray`].
+    pub fn as_null(&self) -> Option<&NullArray> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Downcasts this array to a [`BooleanArray`].
+    pub fn as_boolean(&self) -> Option<&BooleanArray> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Downcasts this array to a [`PrimitiveArray<T>`].
+    pub fn as_primitive<T: NativeType>(&self) -> Option<&PrimitiveArray<T>> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Downcasts this array to a [`BinaryArray<O>`].
+    pub fn as_binary<O: Offset>(&self) -> Option<&BinaryArray<O>> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Downcasts this array to a [`FixedSizeBinaryArray`].
+    pub fn as_fixed_size_binary(&self) -> Option<&FixedSizeBinaryArray> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Downcasts this array to a [`Utf8Array<O>`].
+    pub fn as_utf8<O: Offset>(&self) -> Option<&Utf8Array<O>> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Downcasts this array to a [`ListArray<O>`].
+    pub fn as_list<O: Offset>(&self) -> Option<&ListArray<O>> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Downcasts this array to a [`FixedSizeListArray`].
+    pub fn as_fixed_size_list(&self) -> Option<&FixedSizeListArray> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Downcasts this array to a [`StructArray`].
+    pub fn as_struct(&self) -> Option<&StructArray> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Downcasts this array to a [`UnionArray`].
+    pub fn as_union(&self) -> Option<&UnionArray> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Downcasts this array to a [`MapArray`].
+    pub fn as_map(&self) -> Option<&MapArray> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Downcasts this array to a [`DictionaryArray<K>`].
+    pub fn as_dictionary<K: DictionaryKey>(&self) -> Option<&DictionaryArray<K>> {
+        self.as_any().downcast_ref()
+    }
+}
+
+/// A view of a [`PrimitiveArray`], with one variant per [`PrimitiveType`].
+#[derive(Debug, Clone, Copy)]
+pub enum AnyPrimitiveArray<'a> {
+    /// A [`PrimitiveArray<i8>`]
+    Int8(&'a PrimitiveArray<i8>),
+    /// A [`PrimitiveArray<i16>`]
+    Int16(&'a PrimitiveArray<i16>),
+    /// A [`PrimitiveArray<i32>`]
+    Int32(&'a PrimitiveArray<i32>),
+    /// A [`PrimitiveArray<i64>`]
+    Int64(&'a PrimitiveArray<i64>),
+    /// A [`PrimitiveArray<i128>`]
+    Int128(&'a PrimitiveArray<i128>),
+    /// A [`PrimitiveArray<u8>`]
+    UInt8(&'a PrimitiveArray<u8>),
+    /// A [`PrimitiveArray<u16>`]
+    UInt16(&'a PrimitiveArray<u16>),
+    /// A [`PrimitiveArray<u32>`]
+    UInt32(&'a PrimitiveArray<u32>),
+    /// A [`PrimitiveArray<u64>`]
+    UInt64(&'a PrimitiveArray<u64>),
+    /// A [`PrimitiveArray<f32>`]
+    Float32(&'a PrimitiveArray<f32>),
+    /// A [`PrimitiveArray<f64>`]
+    Float64(&'a PrimitiveArray<f64>),
+    /// A [`PrimitiveArray<days_ms>`]
+    DaysMs(&'a PrimitiveArray<days_ms>),
+    /// A [`PrimitiveArray<months_days_ns>`]
+    MonthDayNano(&'a PrimitiveArray<months_days_ns>),
+}
+
+/// A view of a [`DictionaryArray`], with one variant per [`IntegerType`] of its keys.
+#[derive(Debug, Clone, Copy)]
+pub enum AnyDictionaryArray<'a> {
+    /// A [`DictionaryArray<i8>`]
+    Int8(&'a DictionaryArray<i8>),
+    /// A [`DictionaryArray<i16>`]
+    Int16(&'a DictionaryArray<i16>),
+    /// A [`DictionaryArray<i32>`]
+    Int32(&'a DictionaryArray<i32>),
+    /// A [`DictionaryArray<i64>`]
+    Int64(&'a DictionaryArray<i64>),
+    /// A [`DictionaryArray<u8>`]
+    UInt8(&'a DictionaryArray<u8>),
+    /// A [`DictionaryArray<u16>`]
+    UInt16(&'a DictionaryArray<u16>),
+    /// A [`DictionaryArray<u32>`]
+    UInt32(&'a DictionaryArray<u32>),
+    /// A [`DictionaryArray<u64>`]
+    UInt64(&'a DictionaryArray<u64>),
+}
+
+/// An exhaustive view of an [`Array`] as its concrete type, with one variant per [`PhysicalType`].
+/// This allows dispatching over arrays with a single `match`.
+/// # Example
+/// ```
+/// use arrow2::array::{AnyArray, AnyPrimitiveArray, Array, Int32Array};
+///
+/// fn sum(array: &dyn Array) -> Option<i64> {
+///     match AnyArray::from(array) {
+///         AnyArray::Primitive(AnyPrimitiveArray::Int32(array)) => {
+///             Some(array.iter().flatten().map(|x| *x as i64).sum())
+///         }
+///         AnyArray::Primitive(AnyPrimitiveArray::Int64(array)) => {
+///             Some(array.iter().flatten().sum())
+///         }
+///         _ => None,
+///     }
+/// }
+/// assert_eq!(sum(&Int32Array::from(&[Some(1), None, Some(2)])), Some(3));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub enum AnyArray<'a> {
+    /// A [`NullArray`]
+    Null(&'a NullArray),
+    /// A [`BooleanArray`]
+    Boolean(&'a BooleanArray),
+    /// A [`PrimitiveArray`]
+    Primitive(AnyPrimitiveArray<'a>),
+    /// A [`BinaryArray<i32>`]
+    Binary(&'a BinaryArray<i32>),
+    /// A [`FixedSizeBinaryArray`]
+    FixedSizeBinary(&'a FixedSizeBinaryArray),
+    /// A [`BinaryArray<i64>`]
+    LargeBinary(&'a BinaryArray<i64>),
+    /// A [`Utf8Array<i32>`]
+    Utf8(&'a Utf8Array<i32>),
+    /// A [`Utf8Array<i64>`]
+    LargeUtf8(&'a Utf8Array<i64>),
+    /// A [`ListArray<i32>`]
+    List(&'a ListArray<i32>),
+    /// A [`FixedSizeListArray`]
+    FixedSizeList(&'a FixedSizeListArray),
+    /// A [`ListArray<i64>`]
+    LargeList(&'a ListArray<i64>),
+    /// A [`StructArray`]
+    Struct(&'a StructArray),
+    /// A [`UnionArray`]
+    Union(&'a UnionArray),
+    /// A [`MapArray`]
+    Map(&'a MapArray),
+    /// A [`DictionaryArray`]
+    Dictionary(AnyDictionaryArray<'a>),
+}
+
+macro_rules! downcast {
+    ($array:expr) => {
+        $array.as_any().downcast_ref().unwrap()
+    };
+}
+
+impl<'a> From<&'a dyn Array> for AnyArray<'a> {
+    /// # Panics
+    /// Panics iff the concrete type of `array` is inconsistent with its [`Array::data_type`].
+    fn from(array: &'a dyn Array) -> Self {
+        match array.data_type().to_physical_type() {
+            PhysicalType::Null => AnyArray::Null(downcast!(array)),
+            PhysicalType::Boolean => AnyArray::Boolean(downcast!(array)),
+            PhysicalType::Primitive(primitive) => AnyArray::Primitive(match primitive {
+                PrimitiveType::Int8 => AnyPrimitiveArray::Int8(downcast!(array)),
+                PrimitiveType::Int16 => AnyPrimitiveArray::Int16(downcast!(array)),
+                PrimitiveType::Int32 => AnyPrimitiveArray::Int32(downcast!(array)),
+                PrimitiveType::Int64 => AnyPrimitiveArray::Int64(downcast!(array)),
+                PrimitiveType::Int128 => AnyPrimitiveArray::Int128(downcast!(array)),
+                PrimitiveType::UInt8 => AnyPrimitiveArray::UInt8(downcast!(array)),
+                PrimitiveType::UInt16 => AnyPrimitiveArray::UInt16(downcast!(array)),
+                PrimitiveType::UInt32 => AnyPrimitiveArray::UInt32(downcast!(array)),
+                PrimitiveType::UInt64 => AnyPrimitiveArray::UInt64(downcast!(array)),
+                PrimitiveType::Float32 => AnyPrimitiveArray::Float32(downcast!(array)),
+                PrimitiveType::Float64 => AnyPrimitiveArray::Float64(downcast!(array)),
+                PrimitiveType::DaysMs => AnyPrimitiveArray::DaysMs(downcast!(array)),
+                PrimitiveType::MonthDayNano => AnyPrimitiveArray::MonthDayNano(downcast!(array)),
+            }),
+            PhysicalType::Binary => AnyArray::Binary(downcast!(array)),
+            PhysicalType::FixedSizeBinary => AnyArray::FixedSizeBinary(downcast!(array)),
+            PhysicalType::LargeBinary => AnyArray::LargeBinary(downcast!(array)),
+            PhysicalType::Utf8 => AnyArray::Utf8(downcast!(array)),
+            PhysicalType::LargeUtf8 => AnyArray::LargeUtf8(downcast!(array)),
+            PhysicalType::List => AnyArray::List(downcast!(array)),
+            PhysicalType::FixedSizeList => AnyArray::FixedSizeList(downcast!(array)),
+            PhysicalType::LargeList => AnyArray::LargeList(downcast!(array)),
+            PhysicalType::Struct => AnyArray::Struct(downcast!(array)),
+            PhysicalType::Union => AnyArray::Union(downcast!(array)),
+            PhysicalType::Map => AnyArray::Map(downcast!(array)),
+            PhysicalType::Dictionary(key_type) => AnyArray::Dictionary(match key_type {
+                IntegerType::Int8 => AnyDictionaryArray::Int8(downcast!(array)),
+                IntegerType::Int16 => AnyDictionaryArray::Int16(downcast!(array)),
+                IntegerType::Int32 => AnyDictionaryArray::Int32(downcast!(array)),
+                IntegerType::Int64 => AnyDictionaryArray::Int64(downcast!(array)),
+                IntegerType::UInt8 => AnyDictionaryArray::UInt8(downcast!(array)),
+                IntegerType::UInt16 => AnyDictionaryArray::UInt16(downcast!(array)),
+                IntegerType::UInt32 => AnyDictionaryArray::UInt32(downcast!(array)),
+                IntegerType::UInt64 => AnyDictionaryArray::UInt64(downcast!(array)),
+            }),
+        }
+    }
+}
+
+impl<'a> AnyArray<'a> {
+    /// Returns this array as a trait object.
+    pub fn as_array(&self) -> &'a dyn Array {
+        match *self {
+            AnyArray::Null(array) => array,
+            AnyArray::Boolean(array) => array,
+            AnyArray::Primitive(array) => match array {
+                AnyPrimitiveArray::Int8(array) => array,
+                AnyPrimitiveArray::Int16(array) => array,
+                AnyPrimitiveArray::Int32(array) => array,
+                AnyPrimitiveArray::Int64(array) => array,
+                AnyPrimitiveArray::Int128(array) => array,
+                AnyPrimitiveArray::UInt8(array) => array,
+                AnyPrimitiveArray::UInt16(array) => array,
+                AnyPrimitiveArray::UInt32(array) => array,
+                AnyPrimitiveArray::UInt64(array) => array,
+                AnyPrimitiveArray::Float32(array) => array,
+                AnyPrimitiveArray::Float64(array) => array,
+                AnyPrimitiveArray::DaysMs(array) => array,
+                AnyPrimitiveArray::MonthDayNano(array) => array,
+            },
+            AnyArray::Binary(array) => array,
+            AnyArray::FixedSizeBinary(array) => array,
+            AnyArray::LargeBinary(array) => array,
+            AnyArray::Utf8(array) => array,
+            AnyArray::LargeUtf8(array) => array,
+            AnyArray::List(array) => array,
+            AnyArray::FixedSizeList(array) => array,
+            AnyArray::LargeList(array) => array,
+            AnyArray::Struct(array) => array,
+            AnyArray::Union(array) => array,
+            AnyArray::Map(array) => array,
+            AnyArray::Dictionary(array) => match array {
+                AnyDictionaryArray::Int8(array) => array,
+                AnyDictionaryArray::Int16(array) => array,
+                AnyDictionaryArray::Int32(array) => array,
+                AnyDictionaryArray::Int64(array) => array,
+                AnyDictionaryArray::UInt8(array) => array,
+                AnyDictionaryArray::UInt16(array) => array,
+                AnyDictionaryArray::UInt32(array) => array,
+                AnyDictionaryArray::UInt64(array) => array,
+            },
+        }
+    }
+}
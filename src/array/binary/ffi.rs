@@ -1,5 +1,5 @@
 use crate::{
-    array::{FromFfi, Offset, ToFfi},
+    array::{bitmap_ptr, buffer_ptr, ffi_offset, FromFfi, Offset, ToFfi},
     bitmap::align,
     ffi,
 };
@@ -11,23 +11,14 @@ use super::BinaryArray;
 unsafe impl<O: Offset> ToFfi for BinaryArray<O> {
     fn buffers(&self) -> Vec<Option<std::ptr::NonNull<u8>>> {
         vec![
-            self.validity.as_ref().map(|x| x.as_ptr()),
-            Some(self.offsets.as_ptr().cast::<u8>()),
-            Some(self.values.as_ptr().cast::<u8>()),
+            self.validity.as_ref().map(bitmap_ptr),
+            Some(buffer_ptr(&self.offsets, self.offset().unwrap())),
+            Some(buffer_ptr(&self.values, 0)),
         ]
     }
 
     fn offset(&self) -> Option<usize> {
-        let offset = self.offsets.offset();
-        if let Some(bitmap) = self.validity.as_ref() {
-            if bitmap.offset() == offset {
-                Some(offset)
-            } else {
-                None
-            }
-        } else {
-            Some(offset)
-        }
+        ffi_offset(self.offsets.offset(), self.validity.as_ref())
     }
 
     fn to_ffi_aligned(&self) -> Self {
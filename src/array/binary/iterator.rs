@@ -1,5 +1,5 @@
 use crate::{
-    array::Offset,
+    array::{Offset, OffsetsValuesIter, OffsetsWindows},
     bitmap::utils::{zip_validity, ZipValidity},
     trusted_len::TrustedLen,
 };
@@ -63,6 +63,18 @@ impl<'a, O: Offset> BinaryArray<O> {
     pub fn values_iter(&'a self) -> BinaryValueIter<'a, O> {
         BinaryValueIter::new(self)
     }
+
+    /// Returns an iterator of the `(start, end)` positions of each value in
+    /// [`BinaryArray::values`], irrespectively of their validity.
+    pub fn offsets_windows(&'a self) -> OffsetsWindows<'a, O> {
+        OffsetsWindows::new(self.offsets.as_slice())
+    }
+
+    /// Returns an iterator of each value as bytes, irrespectively of their validity.
+    pub fn values_bytes_iter(&'a self) -> OffsetsValuesIter<'a, O> {
+        // soundness: the offsets of a `BinaryArray` are in bounds of its values
+        unsafe { OffsetsValuesIter::new_unchecked(self.offsets.as_slice(), self.values.as_slice()) }
+    }
 }
 
 unsafe impl<O: Offset> TrustedLen for BinaryValueIter<'_, O> {}
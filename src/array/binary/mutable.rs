@@ -383,6 +383,48 @@ impl<O: Offset> MutableBinaryArray<O> {
         }
     }
 
+    /// Extends the [`MutableBinaryArray`] from a slice of values.
+    /// # Panic
+    /// This operation panics iff the length of all values (in bytes) exceeds `O` maximum value.
+    pub fn extend_from_slice<T: AsRef<[u8]>>(&mut self, items: &[T]) {
+        self.extend_trusted_len_values(items.iter())
+    }
+
+    /// Extends the [`MutableBinaryArray`] with `additional` copies of `value`.
+    /// # Panic
+    /// This operation panics iff the length of all values (in bytes) exceeds `O` maximum value.
+    pub fn extend_constant<T: AsRef<[u8]>>(&mut self, additional: usize, value: Option<T>) {
+        if let Some(value) = value {
+            let bytes = value.as_ref();
+            let last = self.values.len();
+            // check the last offset upfront so that we do not need to check on every item
+            O::from_usize(last + bytes.len() * additional)
+                .ok_or(ArrowError::Overflow)
+                .unwrap();
+
+            self.values.reserve(bytes.len() * additional);
+            self.offsets.reserve(additional);
+            for i in 1..=additional {
+                self.values.extend_from_slice(bytes);
+                self.offsets
+                    .push(O::from_usize(last + bytes.len() * i).unwrap());
+            }
+            if let Some(validity) = &mut self.validity {
+                validity.extend_constant(additional, true)
+            }
+        } else {
+            if let Some(validity) = &mut self.validity {
+                validity.extend_constant(additional, false)
+            } else if additional > 0 {
+                let mut validity = MutableBitmap::with_capacity(self.offsets.capacity());
+                validity.extend_constant(self.len(), true);
+                validity.extend_constant(additional, false);
+                self.validity = Some(validity)
+            }
+            self.offsets.extend_constant(additional, self.last_offset());
+        }
+    }
+
     /// Creates a new [`MutableBinaryArray`] from a [`Iterator`] of `&[u8]`.
     pub fn from_iter_values<T: AsRef<[u8]>, I: Iterator<Item = T>>(iterator: I) -> Self {
         let (offsets, values) = values_iter(iterator);
@@ -1,5 +1,5 @@
 use crate::{
-    array::{FromFfi, ToFfi},
+    array::{bitmap_ptr, FromFfi, ToFfi},
     bitmap::align,
     ffi,
 };
@@ -11,21 +11,17 @@ use super::BooleanArray;
 unsafe impl ToFfi for BooleanArray {
     fn buffers(&self) -> Vec<Option<std::ptr::NonNull<u8>>> {
         vec![
-            self.validity.as_ref().map(|x| x.as_ptr()),
-            Some(self.values.as_ptr()),
+            self.validity.as_ref().map(bitmap_ptr),
+            Some(bitmap_ptr(&self.values)),
         ]
     }
 
     fn offset(&self) -> Option<usize> {
-        let offset = self.values.offset();
-        if let Some(bitmap) = self.validity.as_ref() {
-            if bitmap.offset() == offset {
-                Some(offset)
-            } else {
-                None
-            }
-        } else {
-            Some(offset)
+        // both bitmaps are exported from the byte where they start
+        let offset = self.values.offset() % 8;
+        match self.validity.as_ref() {
+            Some(bitmap) if bitmap.offset() % 8 != offset => None,
+            _ => Some(offset),
         }
     }
 
@@ -169,6 +169,34 @@ impl MutableBooleanArray {
         }
     }
 
+    /// Extends the [`MutableBooleanArray`] from a slice of values.
+    pub fn extend_from_slice(&mut self, items: &[bool]) {
+        self.extend_trusted_len_values(items.iter().copied())
+    }
+
+    /// Extends the [`MutableBooleanArray`] with `additional` copies of `value`.
+    /// # Implementation
+    /// This does not iterate over individual items; the values and validity
+    /// are extended in bulk.
+    pub fn extend_constant(&mut self, additional: usize, value: Option<bool>) {
+        if let Some(value) = value {
+            self.values.extend_constant(additional, value);
+            if let Some(validity) = &mut self.validity {
+                validity.extend_constant(additional, true)
+            }
+        } else {
+            if let Some(validity) = &mut self.validity {
+                validity.extend_constant(additional, false)
+            } else if additional > 0 {
+                let mut validity = MutableBitmap::with_capacity(self.values.capacity());
+                validity.extend_constant(self.len(), true);
+                validity.extend_constant(additional, false);
+                self.validity = Some(validity)
+            }
+            self.values.extend_constant(additional, false);
+        }
+    }
+
     fn init_validity(&mut self) {
         let mut validity = MutableBitmap::with_capacity(self.values.capacity());
         validity.extend_constant(self.len(), true);
@@ -31,9 +31,10 @@ impl<K: DictionaryKey, A: ffi::ArrowArrayRef> FromFfi<A> for DictionaryArray<K>
         let values = unsafe { array.buffer::<K>(1) }?;
 
         let keys = PrimitiveArray::<K>::from_data(K::DATA_TYPE, values, validity);
+        let ordered = Self::get_is_ordered(array.field().data_type());
         let values = array.dictionary()?.unwrap();
         let values = ffi::try_from(values)?.into();
 
-        Ok(DictionaryArray::<K>::from_data(keys, values))
+        Ok(DictionaryArray::<K>::from_data(keys, values).with_ordered(ordered))
     }
 }
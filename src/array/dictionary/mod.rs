@@ -1,8 +1,10 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::{
     bitmap::Bitmap,
     datatypes::{DataType, IntegerType},
+    error::{ArrowError, Result},
     scalar::{new_scalar, Scalar},
     types::NativeType,
 };
@@ -14,7 +16,7 @@ pub use iterator::*;
 pub use mutable::*;
 
 use super::display::get_value_display;
-use super::{display_fmt, new_empty_array, primitive::PrimitiveArray, Array};
+use super::{display_fmt, new_empty_array, primitive::PrimitiveArray, Array, Utf8Array};
 use crate::scalar::NullScalar;
 
 /// Trait denoting [`NativeType`]s that can be used as keys of a dictionary.
@@ -63,6 +65,7 @@ impl<K: DictionaryKey> DictionaryArray<K> {
         let values = Self::get_child(&data_type);
         let values = new_empty_array(values.clone()).into();
         Self::from_data(PrimitiveArray::<K>::new_empty(K::DATA_TYPE), values)
+            .with_ordered(Self::get_is_ordered(&data_type))
     }
 
     /// Returns an [`DictionaryArray`] whose all elements are null
@@ -73,11 +76,13 @@ impl<K: DictionaryKey> DictionaryArray<K> {
             PrimitiveArray::<K>::new_null(K::DATA_TYPE, length),
             new_empty_array(values.clone()).into(),
         )
+        .with_ordered(Self::get_is_ordered(&data_type))
     }
 
     /// The canonical method to create a new [`DictionaryArray`].
     pub fn from_data(keys: PrimitiveArray<K>, values: Arc<dyn Array>) -> Self {
-        let data_type = DataType::Dictionary(K::KEY_TYPE, Box::new(values.data_type().clone()));
+        let data_type =
+            DataType::Dictionary(K::KEY_TYPE, Box::new(values.data_type().clone()), false);
 
         Self {
             data_type,
@@ -86,6 +91,60 @@ impl<K: DictionaryKey> DictionaryArray<K> {
         }
     }
 
+    /// Creates a new [`DictionaryArray`] of strings whose categories (values) are `categories`,
+    /// in this order.
+    /// When `ordered` is true, the order of `categories` is the order of the array, and is used
+    /// by comparisons and sorts instead of the order of the strings themselves.
+    /// # Errors
+    /// Errors iff
+    /// * `categories` contains duplicates
+    /// * an item of `iter` is not in `categories`
+    /// * the number of categories does not fit in `K`
+    pub fn try_from_strings<C, P, I>(iter: I, categories: &[C], ordered: bool) -> Result<Self>
+    where
+        C: AsRef<str>,
+        P: AsRef<str>,
+        I: IntoIterator<Item = Option<P>>,
+    {
+        let mut codes = HashMap::with_capacity(categories.len());
+        for (i, category) in categories.iter().enumerate() {
+            let code = K::from_usize(i).ok_or(ArrowError::Overflow)?;
+            if codes.insert(category.as_ref(), code).is_some() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The category \"{}\" is not unique",
+                    category.as_ref()
+                )));
+            }
+        }
+
+        let keys = iter
+            .into_iter()
+            .map(|item| {
+                item.map(|item| {
+                    codes.get(item.as_ref()).copied().ok_or_else(|| {
+                        ArrowError::InvalidArgumentError(format!(
+                            "The value \"{}\" is not a category",
+                            item.as_ref()
+                        ))
+                    })
+                })
+                .transpose()
+            })
+            .collect::<Result<PrimitiveArray<K>>>()?;
+
+        let values = Utf8Array::<i32>::from_slice(categories);
+        Ok(Self::from_data(keys, Arc::new(values)).with_ordered(ordered))
+    }
+
+    /// Returns a new [`DictionaryArray`] whose categories are (or not) ordered.
+    /// See [`DictionaryArray::is_ordered`].
+    pub fn with_ordered(mut self, ordered: bool) -> Self {
+        if let DataType::Dictionary(_, _, is_ordered) = &mut self.data_type {
+            *is_ordered = ordered;
+        }
+        self
+    }
+
     /// Creates a new [`DictionaryArray`] by slicing the existing [`DictionaryArray`].
     /// # Panics
     /// iff `offset + length > self.len()`.
@@ -148,6 +207,30 @@ impl<K: DictionaryKey> DictionaryArray<K> {
         &self.values
     }
 
+    /// Returns the codes of the [`DictionaryArray`], i.e. the position of each item in
+    /// [`DictionaryArray::categories`]. Equivalent to `self.keys()`.
+    #[inline]
+    pub fn codes(&self) -> &PrimitiveArray<K> {
+        &self.keys
+    }
+
+    /// Returns the categories of the [`DictionaryArray`]. Equivalent to `self.values()`.
+    #[inline]
+    pub fn categories(&self) -> &Arc<dyn Array> {
+        &self.values
+    }
+
+    /// Returns whether the categories of this [`DictionaryArray`] are ordered.
+    /// When ordered, comparisons and sorts use the order of the categories
+    /// (i.e. the codes) instead of the order of the values.
+    ///
+    /// This is the `is_ordered` of its [`DataType::Dictionary`], and is thus written to and
+    /// read from IPC and exported and imported via FFI with the array's data type.
+    #[inline]
+    pub fn is_ordered(&self) -> bool {
+        Self::get_is_ordered(&self.data_type)
+    }
+
     /// Returns the value of the [`DictionaryArray`] at position `i`.
     #[inline]
     pub fn value(&self, index: usize) -> Box<dyn Scalar> {
@@ -163,11 +246,18 @@ impl<K: DictionaryKey> DictionaryArray<K> {
 impl<K: DictionaryKey> DictionaryArray<K> {
     pub(crate) fn get_child(data_type: &DataType) -> &DataType {
         match data_type {
-            DataType::Dictionary(_, values) => values.as_ref(),
+            DataType::Dictionary(_, values, _) => values.as_ref(),
             DataType::Extension(_, inner, _) => Self::get_child(inner),
             _ => panic!("DictionaryArray must be initialized with DataType::Dictionary"),
         }
     }
+
+    pub(crate) fn get_is_ordered(data_type: &DataType) -> bool {
+        matches!(
+            data_type.to_logical_type(),
+            DataType::Dictionary(_, _, true)
+        )
+    }
 }
 
 impl<K: DictionaryKey> Array for DictionaryArray<K> {
@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
+use crate::bitmap::Bitmap;
+use crate::buffer::Buffer;
 use crate::datatypes::PhysicalType;
+use crate::types::NativeType;
 use crate::{array::*, ffi};
 
 use crate::error::Result;
@@ -18,13 +21,50 @@ pub(crate) unsafe trait ToFfi {
         vec![]
     }
 
-    /// The offset
+    /// The offset to export, or `None` if the buffers cannot share an offset without copying.
     fn offset(&self) -> Option<usize>;
 
-    /// return a partial clone of self with an offset.
+    /// return a partial clone of self whose buffers share an offset.
+    /// This is only called when [`ToFfi::offset`] is `None`.
     fn to_ffi_aligned(&self) -> Self;
 }
 
+/// Returns the offset under which a buffer whose first item is at `offset` can be exported
+/// alongside `validity` without copying, or `None` if the validity must be re-aligned.
+/// # Implementation
+/// The C data interface declares a single offset for all buffers. Since buffers are exported
+/// from the item (for values) and byte (for bitmaps) where they start, only the bit offset
+/// within the first byte of `validity` needs to be shared.
+#[inline]
+pub(crate) fn ffi_offset(offset: usize, validity: Option<&Bitmap>) -> Option<usize> {
+    let bit_offset = validity.map(|x| x.offset() % 8).unwrap_or(0);
+    if offset >= bit_offset {
+        Some(bit_offset)
+    } else {
+        None
+    }
+}
+
+/// Returns the pointer to the byte containing the first bit of `bitmap`.
+#[inline]
+pub(crate) fn bitmap_ptr(bitmap: &Bitmap) -> std::ptr::NonNull<u8> {
+    // Safety: the byte is within the region of the bitmap
+    unsafe { std::ptr::NonNull::new_unchecked(bitmap.as_ptr().as_ptr().add(bitmap.offset() / 8)) }
+}
+
+/// Returns the pointer to the item `ffi_offset` items before the first item of `buffer`.
+/// # Panics
+/// This function panics iff `ffi_offset > buffer.offset()`.
+#[inline]
+pub(crate) fn buffer_ptr<T: NativeType>(
+    buffer: &Buffer<T>,
+    ffi_offset: usize,
+) -> std::ptr::NonNull<u8> {
+    let offset = buffer.offset().checked_sub(ffi_offset).unwrap();
+    // Safety: the item is within the region of the buffer
+    unsafe { std::ptr::NonNull::new_unchecked(buffer.as_ptr().as_ptr().add(offset) as *mut u8) }
+}
+
 /// Trait describing how a struct imports into itself from the
 /// [C data interface](https://arrow.apache.org/docs/format/CDataInterface.html) (FFI).
 pub(crate) trait FromFfi<T: ffi::ArrowArrayRef>: Sized {
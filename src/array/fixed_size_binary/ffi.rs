@@ -1,5 +1,5 @@
 use crate::{
-    array::{FromFfi, ToFfi},
+    array::{bitmap_ptr, buffer_ptr, ffi_offset, FromFfi, ToFfi},
     bitmap::align,
     error::Result,
     ffi,
@@ -10,22 +10,13 @@ use super::FixedSizeBinaryArray;
 unsafe impl ToFfi for FixedSizeBinaryArray {
     fn buffers(&self) -> Vec<Option<std::ptr::NonNull<u8>>> {
         vec![
-            self.validity.as_ref().map(|x| x.as_ptr()),
-            Some(self.values.as_ptr().cast::<u8>()),
+            self.validity.as_ref().map(bitmap_ptr),
+            Some(buffer_ptr(&self.values, self.offset().unwrap() * self.size)),
         ]
     }
 
     fn offset(&self) -> Option<usize> {
-        let offset = self.values.offset() / self.size as usize;
-        if let Some(bitmap) = self.validity.as_ref() {
-            if bitmap.offset() == offset {
-                Some(offset)
-            } else {
-                None
-            }
-        } else {
-            Some(offset)
-        }
+        ffi_offset(self.values.offset() / self.size, self.validity.as_ref())
     }
 
     fn to_ffi_aligned(&self) -> Self {
@@ -34,12 +34,19 @@ impl From<MutableFixedSizeBinaryArray> for FixedSizeBinaryArray {
 
 impl MutableFixedSizeBinaryArray {
     /// Canonical method to create a new [`MutableFixedSizeBinaryArray`].
+    /// # Panics
+    /// Panics iff the size of `data_type` is zero, the len of `values` is not a multiple of it,
+    /// or the len of `validity` does not match.
     pub fn from_data(
         data_type: DataType,
         values: MutableBuffer<u8>,
         validity: Option<MutableBitmap>,
     ) -> Self {
         let size = FixedSizeBinaryArray::get_size(&data_type);
+        assert!(
+            size > 0,
+            "The size of a MutableFixedSizeBinaryArray must be positive"
+        );
         assert_eq!(
             values.len() % size,
             0,
@@ -61,11 +68,15 @@ impl MutableFixedSizeBinaryArray {
     }
 
     /// Creates a new empty [`MutableFixedSizeBinaryArray`].
+    /// # Panics
+    /// Panics iff `size` is zero.
     pub fn new(size: usize) -> Self {
         Self::with_capacity(size, 0)
     }
 
     /// Creates a new [`MutableFixedSizeBinaryArray`] with capacity for `capacity` entries.
+    /// # Panics
+    /// Panics iff `size` is zero.
     pub fn with_capacity(size: usize, capacity: usize) -> Self {
         Self::from_data(
             DataType::FixedSizeBinary(size),
@@ -113,6 +124,68 @@ impl MutableFixedSizeBinaryArray {
         self.try_push(value).unwrap()
     }
 
+    /// tries to extend [`MutableFixedSizeBinaryArray`] with `additional` copies of `value`.
+    /// # Error
+    /// Errors iff the size of `value` is not equal to its own size.
+    pub fn try_extend_constant<P: AsRef<[u8]>>(
+        &mut self,
+        additional: usize,
+        value: Option<P>,
+    ) -> Result<()> {
+        match value {
+            Some(bytes) => {
+                let bytes = bytes.as_ref();
+                if self.size != bytes.len() {
+                    return Err(ArrowError::InvalidArgumentError(
+                        "FixedSizeBinaryArray requires every item to be of its length".to_string(),
+                    ));
+                }
+                self.values.reserve(additional * self.size);
+                (0..additional).for_each(|_| self.values.extend_from_slice(bytes));
+
+                if let Some(validity) = &mut self.validity {
+                    validity.extend_constant(additional, true)
+                }
+            }
+            None => {
+                if let Some(validity) = &mut self.validity {
+                    validity.extend_constant(additional, false)
+                } else if additional > 0 {
+                    let mut validity = MutableBitmap::new();
+                    validity.extend_constant(self.len(), true);
+                    validity.extend_constant(additional, false);
+                    self.validity = Some(validity)
+                }
+                self.values.extend_constant(additional * self.size, 0);
+            }
+        }
+        Ok(())
+    }
+
+    /// extends [`MutableFixedSizeBinaryArray`] with `additional` copies of `value`.
+    /// # Panics
+    /// Panics iff the size of `value` is not equal to its own size.
+    #[inline]
+    pub fn extend_constant<P: AsRef<[u8]>>(&mut self, additional: usize, value: Option<P>) {
+        self.try_extend_constant(additional, value).unwrap()
+    }
+
+    /// extends [`MutableFixedSizeBinaryArray`] from a slice of bytes containing
+    /// consecutive (non-null) items of its size.
+    /// # Panics
+    /// Panics iff the length of `items` is not a multiple of its own size.
+    pub fn extend_from_slice(&mut self, items: &[u8]) {
+        assert_eq!(
+            items.len() % self.size,
+            0,
+            "The len of items must be a multiple of size"
+        );
+        self.values.extend_from_slice(items);
+        if let Some(validity) = &mut self.validity {
+            validity.extend_constant(items.len() / self.size, true)
+        }
+    }
+
     /// Creates a new [`MutableFixedSizeBinaryArray`] from an iterator of values.
     /// # Errors
     /// Errors iff the size of any of the `value` is not equal to its own size.
@@ -3,16 +3,18 @@ use std::sync::Arc;
 use super::FixedSizeListArray;
 use crate::{
     array::{
+        bitmap_ptr,
         ffi::{FromFfi, ToFfi},
         Array,
     },
+    bitmap::align,
     error::Result,
     ffi,
 };
 
 unsafe impl ToFfi for FixedSizeListArray {
     fn buffers(&self) -> Vec<Option<std::ptr::NonNull<u8>>> {
-        vec![self.validity.as_ref().map(|x| x.as_ptr())]
+        vec![self.validity.as_ref().map(bitmap_ptr)]
     }
 
     fn children(&self) -> Vec<Arc<dyn Array>> {
@@ -20,16 +22,17 @@ unsafe impl ToFfi for FixedSizeListArray {
     }
 
     fn offset(&self) -> Option<usize> {
-        Some(
-            self.validity
-                .as_ref()
-                .map(|bitmap| bitmap.offset())
-                .unwrap_or_default(),
-        )
+        // the children are already sliced, so the validity must be exported without an offset
+        match self.validity.as_ref() {
+            Some(bitmap) if bitmap.offset() % 8 != 0 => None,
+            _ => Some(0),
+        }
     }
 
     fn to_ffi_aligned(&self) -> Self {
-        self.clone()
+        let mut array = self.clone();
+        array.validity = self.validity.as_ref().map(|bitmap| align(bitmap, 0));
+        array
     }
 }
 
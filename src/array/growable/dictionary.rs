@@ -16,12 +16,15 @@ use super::{
 /// # Implementation
 /// This growable does not perform collision checks and instead concatenates
 /// the values of each [`DictionaryArray`] one after the other.
+/// When all arrays are ordered and have equal categories, the categories are instead
+/// shared and the result is ordered (see [`DictionaryArray::is_ordered`]).
 pub struct GrowableDictionary<'a, K: DictionaryKey> {
     keys_values: Vec<&'a [K]>,
     key_values: MutableBuffer<K>,
     key_validity: MutableBitmap,
     offsets: Vec<usize>,
     values: Arc<dyn Array>,
+    ordered: bool,
     extend_null_bits: Vec<ExtendNullBits<'a>>,
 }
 
@@ -67,11 +70,22 @@ impl<'a, T: DictionaryKey> GrowableDictionary<'a, T> {
             .map(|array| array.values().as_ref())
             .collect::<Vec<_>>();
 
-        let (values, offsets) = concatenate_values(&arrays_keys, &arrays_values, capacity);
+        // the codes of ordered arrays are only comparable when their categories are equal
+        let ordered = arrays.iter().all(|array| array.is_ordered())
+            && arrays_values
+                .iter()
+                .all(|values| *values == arrays_values[0]);
+
+        let (values, offsets) = if ordered {
+            (arrays[0].values().clone(), vec![0; arrays.len()])
+        } else {
+            concatenate_values(&arrays_keys, &arrays_values, capacity)
+        };
 
         Self {
             offsets,
             values,
+            ordered,
             keys_values,
             key_values: MutableBuffer::with_capacity(capacity),
             key_validity: MutableBitmap::with_capacity(capacity),
@@ -86,7 +100,7 @@ impl<'a, T: DictionaryKey> GrowableDictionary<'a, T> {
 
         let keys = PrimitiveArray::<T>::from_data(T::DATA_TYPE, values.into(), validity.into());
 
-        DictionaryArray::<T>::from_data(keys, self.values.clone())
+        DictionaryArray::<T>::from_data(keys, self.values.clone()).with_ordered(self.ordered)
     }
 }
 
@@ -131,6 +145,6 @@ impl<'a, T: DictionaryKey> From<GrowableDictionary<'a, T>> for DictionaryArray<T
             val.key_validity.into(),
         );
 
-        DictionaryArray::<T>::from_data(keys, val.values)
+        DictionaryArray::<T>::from_data(keys, val.values).with_ordered(val.ordered)
     }
 }
@@ -2,14 +2,14 @@ use std::sync::Arc;
 
 use crate::{array::FromFfi, bitmap::align, error::Result, ffi};
 
-use super::super::{ffi::ToFfi, specification::Offset, Array};
+use super::super::{bitmap_ptr, buffer_ptr, ffi::ToFfi, ffi_offset, specification::Offset, Array};
 use super::ListArray;
 
 unsafe impl<O: Offset> ToFfi for ListArray<O> {
     fn buffers(&self) -> Vec<Option<std::ptr::NonNull<u8>>> {
         vec![
-            self.validity.as_ref().map(|x| x.as_ptr()),
-            Some(self.offsets.as_ptr().cast::<u8>()),
+            self.validity.as_ref().map(bitmap_ptr),
+            Some(buffer_ptr(&self.offsets, self.offset().unwrap())),
         ]
     }
 
@@ -18,16 +18,7 @@ unsafe impl<O: Offset> ToFfi for ListArray<O> {
     }
 
     fn offset(&self) -> Option<usize> {
-        let offset = self.offsets.offset();
-        if let Some(bitmap) = self.validity.as_ref() {
-            if bitmap.offset() == offset {
-                Some(offset)
-            } else {
-                None
-            }
-        } else {
-            Some(offset)
-        }
+        ffi_offset(self.offsets.offset(), self.validity.as_ref())
     }
 
     fn to_ffi_aligned(&self) -> Self {
@@ -60,6 +60,30 @@ impl<O: Offset> ListArray<O> {
     ) -> Self {
         check_offsets(&offsets, values.len());
 
+        // Safety: the offsets were checked above
+        unsafe { Self::from_data_unchecked(data_type, offsets, values, validity) }
+    }
+
+    /// The same as [`ListArray::from_data`] but only checks the last offset against `values`.
+    /// # Safety
+    /// `offsets` MUST be monotonically increasing
+    /// # Panics
+    /// This function panics iff:
+    /// * The `data_type`'s physical type is not consistent with the offset `O`.
+    /// * `offsets` is empty or its last element is larger than `values.len()`.
+    /// * The validity is not `None` and its length is different from `offsets.len() - 1`.
+    pub unsafe fn from_data_unchecked(
+        data_type: DataType,
+        offsets: Buffer<O>,
+        values: Arc<dyn Array>,
+        validity: Option<Bitmap>,
+    ) -> Self {
+        let last = offsets
+            .as_slice()
+            .last()
+            .expect("The length of the offset buffer must be larger than 1");
+        assert!(last.to_usize() <= values.len());
+
         if let Some(ref validity) = validity {
             assert_eq!(offsets.len() - 1, validity.len());
         }
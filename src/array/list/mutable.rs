@@ -67,6 +67,8 @@ where
     I: IntoIterator<Item = Option<T>>,
 {
     fn try_extend<II: IntoIterator<Item = Option<I>>>(&mut self, iter: II) -> Result<()> {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
         for items in iter {
             self.try_push(items)?;
         }
@@ -74,6 +76,34 @@ where
     }
 }
 
+impl<O: Offset> ListArray<O> {
+    /// Creates a new [`ListArray`] from an iterator of optional iterators of optional
+    /// values, whose values are collected into `M`.
+    ///
+    /// The lower bound of the iterator's size hint is used to pre-allocate the offsets.
+    /// # Example
+    /// ```
+    /// use arrow2::array::{ListArray, MutablePrimitiveArray};
+    ///
+    /// let data = vec![Some(vec![Some(1i32), None]), None, Some(vec![])];
+    /// let array = ListArray::<i32>::try_from_iter::<MutablePrimitiveArray<i32>, _, _, _>(data).unwrap();
+    /// assert_eq!(array.len(), 3);
+    /// assert_eq!(array.offsets().as_slice(), &[0, 2, 2, 2]);
+    /// ```
+    /// # Errors
+    /// Errors iff the values cannot be extended into `M` or the offsets overflow `O`.
+    pub fn try_from_iter<M, II, I, T>(iter: II) -> Result<Self>
+    where
+        M: MutableArray + Default + TryExtend<Option<T>>,
+        II: IntoIterator<Item = Option<I>>,
+        I: IntoIterator<Item = Option<T>>,
+    {
+        let mut array = MutableListArray::<O, M>::new();
+        array.try_extend(iter)?;
+        Ok(array.into())
+    }
+}
+
 impl<O, M, I, T> TryPush<Option<I>> for MutableListArray<O, M>
 where
     O: Offset,
@@ -140,6 +170,92 @@ impl<O: Offset, M: MutableArray> MutableListArray<O, M> {
         Ok(())
     }
 
+    /// Extends this array with lists whose lengths are `lengths` and whose values are
+    /// taken, in order, from `values`. A `None` length is a null list.
+    ///
+    /// Unlike [`TryExtend::try_extend`], the values are extended in bulk and the offsets
+    /// are computed from the lengths, which is faster when the lengths are known upfront.
+    /// # Example
+    /// ```
+    /// use arrow2::array::{ListArray, MutableListArray, MutablePrimitiveArray};
+    ///
+    /// let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    /// array
+    ///     .try_extend_from_lengths([Some(1), Some(2), Some(3)], [Some(2), None, Some(1)])
+    ///     .unwrap();
+    /// let array: ListArray<i32> = array.into();
+    /// assert_eq!(array.offsets().as_slice(), &[0, 2, 2, 3]);
+    /// ```
+    /// # Errors
+    /// Errors iff the offsets overflow `O`, the values cannot be extended into `M`,
+    /// or the sum of the lengths differs from the number of values.
+    /// On error, no list is added, but the values may have been extended.
+    pub fn try_extend_from_lengths<T, I, L>(&mut self, values: I, lengths: L) -> Result<()>
+    where
+        M: TryExtend<Option<T>>,
+        I: IntoIterator<Item = Option<T>>,
+        L: IntoIterator<Item = Option<usize>>,
+    {
+        let lengths = lengths.into_iter();
+        self.reserve(lengths.size_hint().0);
+
+        let length = self.offsets.len() - 1;
+        let has_validity = self.validity.is_some();
+        let result = self.extend_from_lengths(values, lengths);
+        if result.is_err() {
+            // roll back the lists, since their offsets may be inconsistent with the values
+            self.offsets.truncate(length + 1);
+            self.validity = if has_validity {
+                self.validity
+                    .take()
+                    .map(|validity| validity.iter().take(length).collect())
+            } else {
+                None
+            };
+        }
+        result
+    }
+
+    fn extend_from_lengths<T, I, L>(&mut self, values: I, lengths: L) -> Result<()>
+    where
+        M: TryExtend<Option<T>>,
+        I: IntoIterator<Item = Option<T>>,
+        L: Iterator<Item = Option<usize>>,
+    {
+        let start = self.values.len();
+        let mut last = start;
+        for length in lengths {
+            if let Some(length) = length {
+                last = last.checked_add(length).ok_or(ArrowError::Overflow)?;
+                self.offsets
+                    .push(O::from_usize(last).ok_or(ArrowError::Overflow)?);
+                if let Some(validity) = &mut self.validity {
+                    validity.push(true)
+                }
+            } else {
+                self.push_null();
+            }
+        }
+
+        self.values.try_extend(values)?;
+        if self.values.len() != last {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The sum of the lengths ({}) must equal the number of values ({})",
+                last - start,
+                self.values.len() - start
+            )));
+        }
+        Ok(())
+    }
+
+    /// Reserves `additional` lists.
+    pub fn reserve(&mut self, additional: usize) {
+        self.offsets.reserve(additional);
+        if let Some(validity) = &mut self.validity {
+            validity.reserve(additional)
+        }
+    }
+
     #[inline]
     fn push_null(&mut self) {
         self.offsets.push(self.last_offset());
@@ -64,6 +64,30 @@ impl MapArray {
     ) -> Self {
         check_offsets(&offsets, field.len());
 
+        // Safety: the offsets were checked above
+        unsafe { Self::from_data_unchecked(data_type, offsets, field, validity) }
+    }
+
+    /// The same as [`MapArray::from_data`] but only checks the last offset against `field`.
+    /// # Safety
+    /// `offsets` MUST be monotonically increasing
+    /// # Panics
+    /// This function panics iff:
+    /// * The `data_type`'s physical type is not consistent with [`MapArray`],
+    /// * `offsets` is empty or its last element is larger than `field.len()`.
+    /// * The validity is not `None` and its length is different from `offsets.len() - 1`.
+    pub unsafe fn from_data_unchecked(
+        data_type: DataType,
+        offsets: Buffer<i32>,
+        field: Arc<dyn Array>,
+        validity: Option<Bitmap>,
+    ) -> Self {
+        let last = offsets
+            .as_slice()
+            .last()
+            .expect("The length of the offset buffer must be larger than 1");
+        assert!(last.to_usize() <= field.len());
+
         if let Some(ref validity) = validity {
             assert_eq!(offsets.len() - 1, validity.len());
         }
@@ -20,7 +20,7 @@
 use std::any::Any;
 use std::fmt::Display;
 
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 use crate::types::{days_ms, months_days_ns};
 use crate::{
     bitmap::{Bitmap, MutableBitmap},
@@ -301,6 +301,136 @@ pub fn new_null_array(data_type: DataType, length: usize) -> Box<dyn Array> {
     }
 }
 
+macro_rules! full_utf8 {
+    ($scalar:expr, $length:expr, $type:ty) => {{
+        let scalar = $scalar
+            .as_any()
+            .downcast_ref::<crate::scalar::Utf8Scalar<$type>>()
+            .unwrap();
+        let mut array = MutableUtf8Array::<$type>::with_capacities(
+            $length,
+            scalar.value().map(|x| x.len()).unwrap_or(0) * $length,
+        );
+        array.extend_constant($length, scalar.value());
+        let array: Utf8Array<$type> = array.into();
+        Box::new(array)
+    }};
+}
+
+macro_rules! full_binary {
+    ($scalar:expr, $length:expr, $type:ty) => {{
+        let scalar = $scalar
+            .as_any()
+            .downcast_ref::<crate::scalar::BinaryScalar<$type>>()
+            .unwrap();
+        let mut array = MutableBinaryArray::<$type>::with_capacity($length);
+        array.extend_constant($length, scalar.value());
+        let array: BinaryArray<$type> = array.into();
+        Box::new(array)
+    }};
+}
+
+macro_rules! full_list {
+    ($scalar:expr, $length:expr, $type:ty) => {{
+        let scalar = $scalar
+            .as_any()
+            .downcast_ref::<crate::scalar::ListScalar<$type>>()
+            .unwrap();
+        let values = scalar.values().as_ref();
+        let size = values.len();
+        let offsets = (0..=$length)
+            .map(|i| <$type as crate::types::Index>::from_usize(i * size).unwrap())
+            .collect::<crate::buffer::Buffer<$type>>();
+
+        let mut growable = growable::make_growable(&[values], false, size * $length);
+        (0..$length).for_each(|_| growable.extend(0, 0, size));
+        Box::new(ListArray::<$type>::from_data(
+            scalar.data_type().clone(),
+            offsets,
+            growable.as_arc(),
+            None,
+        ))
+    }};
+}
+
+/// Creates a new [`Array`] of `length` whose every slot equals `scalar`.
+/// The resulting array has the [`DataType`] of the scalar.
+/// # Implementation
+/// Each buffer is allocated once and filled in bulk. When `scalar` is null,
+/// this is equivalent to [`new_null_array`].
+/// # Errors
+/// This function errors iff the physical type of `scalar` has no [`Scalar`](crate::scalar::Scalar)
+/// implementation in this crate (`FixedSizeList`, `Union` and `Map`).
+pub fn new_full_array(scalar: &dyn crate::scalar::Scalar, length: usize) -> Result<Box<dyn Array>> {
+    use crate::datatypes::PhysicalType::*;
+    use crate::scalar::*;
+    let data_type = scalar.data_type().clone();
+    if !scalar.is_valid() {
+        return Ok(new_null_array(data_type, length));
+    }
+    Ok(match data_type.to_physical_type() {
+        Null => Box::new(NullArray::new_null(data_type, length)),
+        Boolean => {
+            let scalar = scalar.as_any().downcast_ref::<BooleanScalar>().unwrap();
+            let mut values = MutableBitmap::with_capacity(length);
+            values.extend_constant(length, scalar.value().unwrap());
+            Box::new(BooleanArray::from_data(data_type, values.into(), None))
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let scalar = scalar.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
+            let mut values = MutablePrimitiveArray::<$T>::with_capacity_from(length, data_type);
+            values.extend_constant(length, scalar.value());
+            Box::new(PrimitiveArray::<$T>::from(values))
+        }),
+        Utf8 => full_utf8!(scalar, length, i32),
+        LargeUtf8 => full_utf8!(scalar, length, i64),
+        Binary => full_binary!(scalar, length, i32),
+        LargeBinary => full_binary!(scalar, length, i64),
+        List => full_list!(scalar, length, i32),
+        LargeList => full_list!(scalar, length, i64),
+        Struct => {
+            let scalar = scalar.as_any().downcast_ref::<StructScalar>().unwrap();
+            let values = scalar
+                .values()
+                .iter()
+                .map(|x| new_full_array(x.as_ref(), length).map(|x| x.into()))
+                .collect::<Result<_>>()?;
+            Box::new(StructArray::from_data(data_type, values, None))
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let scalar = scalar
+                .as_any()
+                .downcast_ref::<DictionaryScalar<$T>>()
+                .unwrap();
+            let values = new_full_array(scalar.value().unwrap().as_ref(), 1)?;
+            let keys = PrimitiveArray::<$T>::from_data(
+                <$T as crate::types::NaturalDataType>::DATA_TYPE,
+                vec![<$T>::default(); length].into(),
+                None,
+            );
+            Box::new(DictionaryArray::<$T>::from_data(keys, values.into()))
+        }),
+        FixedSizeBinary => {
+            let scalar = scalar
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryScalar>()
+                .unwrap();
+            let values = scalar.value().unwrap().repeat(length);
+            Box::new(FixedSizeBinaryArray::from_data(
+                data_type,
+                values.into(),
+                None,
+            ))
+        }
+        FixedSizeList | Union | Map => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Full arrays of type {:?}",
+                data_type
+            )))
+        }
+    })
+}
+
 macro_rules! clone_dyn {
     ($array:expr, $ty:ty) => {{
         let f = |x: &$ty| Box::new(x.clone());
@@ -339,6 +469,7 @@ pub fn clone(array: &dyn Array) -> Box<dyn Array> {
     }
 }
 
+mod any;
 mod binary;
 mod boolean;
 mod dictionary;
@@ -348,6 +479,7 @@ mod fixed_size_list;
 mod list;
 mod map;
 mod null;
+mod offsets;
 mod primitive;
 mod specification;
 mod struct_;
@@ -359,6 +491,7 @@ mod ffi;
 pub mod growable;
 pub mod ord;
 
+pub use any::{AnyArray, AnyDictionaryArray, AnyPrimitiveArray};
 pub use display::get_display;
 pub use equal::equal;
 
@@ -370,6 +503,7 @@ pub use fixed_size_list::{FixedSizeListArray, MutableFixedSizeListArray};
 pub use list::{ListArray, MutableListArray};
 pub use map::MapArray;
 pub use null::NullArray;
+pub use offsets::{OffsetsValuesIter, OffsetsWindows};
 pub use primitive::*;
 pub use specification::Offset;
 pub use struct_::StructArray;
@@ -379,6 +513,7 @@ pub use utf8::{MutableUtf8Array, Utf8Array, Utf8ValuesIter};
 pub(crate) use self::ffi::offset_buffers_children_dictionary;
 pub(crate) use self::ffi::FromFfi;
 pub(crate) use self::ffi::ToFfi;
+pub(crate) use self::ffi::{bitmap_ptr, buffer_ptr, ffi_offset};
 
 /// A trait describing the ability of a struct to create itself from a iterator.
 /// This is similar to [`Extend`], but accepted the creation to error.
@@ -0,0 +1,144 @@
+use crate::trusted_len::TrustedLen;
+
+use super::Offset;
+
+/// Iterator of the `(start, end)` positions of the values of an array with offsets
+/// (e.g. [`Utf8Array`](super::Utf8Array) and [`BinaryArray`](super::BinaryArray)) in its values,
+/// i.e. of the windows of two consecutive offsets.
+///
+/// Unlike `value(i)`, this iterator performs no bounds checks, which makes it suitable for
+/// kernels that operate on the offsets or the values directly (e.g. tokenizers and parsers).
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+///
+/// let array = Utf8Array::<i32>::from_slice(&["a", "bc", ""]);
+/// let windows = array.offsets_windows().collect::<Vec<_>>();
+/// assert_eq!(windows, vec![(0, 1), (1, 3), (3, 3)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct OffsetsWindows<'a, O: Offset> {
+    offsets: &'a [O],
+    index: usize,
+    end: usize,
+}
+
+impl<'a, O: Offset> OffsetsWindows<'a, O> {
+    /// Creates a new [`OffsetsWindows`].
+    /// # Panic
+    /// This function panics iff `offsets` is empty.
+    pub fn new(offsets: &'a [O]) -> Self {
+        assert!(!offsets.is_empty());
+        Self {
+            offsets,
+            index: 0,
+            end: offsets.len() - 1,
+        }
+    }
+
+    /// Returns the `(start, end)` of the value at `index`.
+    /// # Safety
+    /// `index + 1` must be smaller than the number of offsets.
+    #[inline]
+    unsafe fn window_unchecked(&self, index: usize) -> (usize, usize) {
+        let start = self.offsets.get_unchecked(index).to_usize();
+        let end = self.offsets.get_unchecked(index + 1).to_usize();
+        (start, end)
+    }
+}
+
+impl<'a, O: Offset> Iterator for OffsetsWindows<'a, O> {
+    type Item = (usize, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.end {
+            return None;
+        }
+        let old = self.index;
+        self.index += 1;
+        // soundness: `old < self.end = self.offsets.len() - 1`
+        Some(unsafe { self.window_unchecked(old) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.end - self.index, Some(self.end - self.index))
+    }
+}
+
+impl<'a, O: Offset> DoubleEndedIterator for OffsetsWindows<'a, O> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index == self.end {
+            None
+        } else {
+            self.end -= 1;
+            // soundness: `self.end < self.offsets.len() - 1`
+            Some(unsafe { self.window_unchecked(self.end) })
+        }
+    }
+}
+
+impl<'a, O: Offset> ExactSizeIterator for OffsetsWindows<'a, O> {}
+
+unsafe impl<O: Offset> TrustedLen for OffsetsWindows<'_, O> {}
+
+/// Iterator of the values of an array with offsets (e.g. [`Utf8Array`](super::Utf8Array) and
+/// [`BinaryArray`](super::BinaryArray)) as byte slices, without bounds checks.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+///
+/// let array = Utf8Array::<i32>::from_slice(&["a", "bc", ""]);
+/// let values = array.values_bytes_iter().collect::<Vec<_>>();
+/// assert_eq!(values, vec![b"a".as_ref(), b"bc", b""]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct OffsetsValuesIter<'a, O: Offset> {
+    windows: OffsetsWindows<'a, O>,
+    values: &'a [u8],
+}
+
+impl<'a, O: Offset> OffsetsValuesIter<'a, O> {
+    /// Creates a new [`OffsetsValuesIter`].
+    /// # Safety
+    /// `offsets` must be monotonically increasing and its last offset must be smaller or equal
+    /// to the length of `values`, which is the case for the offsets and values of every
+    /// [`Utf8Array`](super::Utf8Array) and [`BinaryArray`](super::BinaryArray).
+    pub unsafe fn new_unchecked(offsets: &'a [O], values: &'a [u8]) -> Self {
+        Self {
+            windows: OffsetsWindows::new(offsets),
+            values,
+        }
+    }
+}
+
+impl<'a, O: Offset> Iterator for OffsetsValuesIter<'a, O> {
+    type Item = &'a [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, end) = self.windows.next()?;
+        // soundness: invariant of the struct
+        Some(unsafe { self.values.get_unchecked(start..end) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.windows.size_hint()
+    }
+}
+
+impl<'a, O: Offset> DoubleEndedIterator for OffsetsValuesIter<'a, O> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (start, end) = self.windows.next_back()?;
+        // soundness: invariant of the struct
+        Some(unsafe { self.values.get_unchecked(start..end) })
+    }
+}
+
+impl<'a, O: Offset> ExactSizeIterator for OffsetsValuesIter<'a, O> {}
+
+unsafe impl<O: Offset> TrustedLen for OffsetsValuesIter<'_, O> {}
@@ -1,6 +1,7 @@
 //! Contains functions and function factories to order values within arrays.
 
 use std::cmp::Ordering;
+use std::convert::TryInto;
 
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
@@ -47,6 +48,47 @@ where
     l.cmp(r)
 }
 
+macro_rules! cmp_be_words {
+    ($name:ident, $word:ty) => {
+        #[inline]
+        fn $name(l: &[u8], r: &[u8]) -> Ordering {
+            const SIZE: usize = std::mem::size_of::<$word>();
+            // the bytes that both sides have as whole words
+            let words = l.len().min(r.len()) / SIZE * SIZE;
+            l[..words]
+                .chunks_exact(SIZE)
+                .zip(r[..words].chunks_exact(SIZE))
+                .map(|(l, r)| {
+                    let l = <$word>::from_be_bytes(l.try_into().unwrap());
+                    let r = <$word>::from_be_bytes(r.try_into().unwrap());
+                    l.cmp(&r)
+                })
+                .find(|ordering| *ordering != Ordering::Equal)
+                .unwrap_or_else(|| l[words..].cmp(&r[words..]))
+        }
+    };
+}
+
+cmp_be_words!(cmp_be_u32, u32);
+cmp_be_words!(cmp_be_u64, u64);
+cmp_be_words!(cmp_be_u128, u128);
+
+/// Returns the lexicographical order of byte strings of `size` bytes, such as the values of
+/// a [`FixedSizeBinaryArray`].
+///
+/// When `size` is a multiple of 4, the byte strings are compared as big-endian words
+/// (of up to 128 bits), whose order is the lexicographical order of their bytes.
+/// The returned function is correct for byte strings of any length, and is fastest when
+/// both have `size` bytes.
+pub fn fixed_size_cmp(size: usize) -> fn(&[u8], &[u8]) -> Ordering {
+    match size {
+        size if size % 16 == 0 => cmp_be_u128,
+        size if size % 8 == 0 => cmp_be_u64,
+        size if size % 4 == 0 => cmp_be_u32,
+        _ => |l: &[u8], r: &[u8]| l.cmp(r),
+    }
+}
+
 fn compare_primitives<T: NativeType + Ord>(left: &dyn Array, right: &dyn Array) -> DynComparator {
     let left = left
         .as_any()
@@ -131,6 +173,21 @@ fn compare_binary<O: Offset>(left: &dyn Array, right: &dyn Array) -> DynComparat
     Box::new(move |i, j| left.value(i).cmp(right.value(j)))
 }
 
+fn compare_fixed_size_binary(left: &dyn Array, right: &dyn Array) -> DynComparator {
+    let left = left
+        .as_any()
+        .downcast_ref::<FixedSizeBinaryArray>()
+        .unwrap()
+        .clone();
+    let right = right
+        .as_any()
+        .downcast_ref::<FixedSizeBinaryArray>()
+        .unwrap()
+        .clone();
+    let cmp = fixed_size_cmp(left.size());
+    Box::new(move |i, j| cmp(left.value(i), right.value(j)))
+}
+
 fn compare_dict<K>(left: &DictionaryArray<K>, right: &DictionaryArray<K>) -> Result<DynComparator>
 where
     K: DictionaryKey,
@@ -138,6 +195,21 @@ where
     let left_keys = left.keys().values().clone();
     let right_keys = right.keys().values().clone();
 
+    if left.is_ordered() && right.is_ordered() {
+        // ordered categories are compared by their position in the categories
+        if left.categories().as_ref() != right.categories().as_ref() {
+            return Err(ArrowError::InvalidArgumentError(
+                "Ordered dictionaries can only be compared when their categories are equal"
+                    .to_string(),
+            ));
+        }
+        return Ok(Box::new(move |i: usize, j: usize| {
+            let key_left = left_keys[i].to_usize().unwrap();
+            let key_right = right_keys[j].to_usize().unwrap();
+            key_left.cmp(&key_right)
+        }));
+    }
+
     let comparator = build_compare(left.values().as_ref(), right.values().as_ref())?;
 
     Ok(Box::new(move |i: usize, j: usize| {
@@ -215,7 +287,10 @@ pub fn build_compare(left: &dyn Array, right: &dyn Array) -> Result<DynComparato
         (LargeUtf8, LargeUtf8) => compare_string::<i64>(left, right),
         (Binary, Binary) => compare_binary::<i32>(left, right),
         (LargeBinary, LargeBinary) => compare_binary::<i64>(left, right),
-        (Dictionary(key_type_lhs, _), Dictionary(key_type_rhs, _)) => {
+        (FixedSizeBinary(lhs), FixedSizeBinary(rhs)) if lhs == rhs => {
+            compare_fixed_size_binary(left, right)
+        }
+        (Dictionary(key_type_lhs, _, _), Dictionary(key_type_rhs, _, _)) => {
             match (key_type_lhs, key_type_rhs) {
                 (IntegerType::UInt8, IntegerType::UInt8) => dyn_dict!(u8, left, right),
                 (IntegerType::UInt16, IntegerType::UInt16) => dyn_dict!(u16, left, right),
@@ -202,6 +202,29 @@ impl<T: NativeType> MutablePrimitiveArray<T> {
         self.update_all_valid();
     }
 
+    /// Extends the [`MutablePrimitiveArray`] with `additional` copies of `value`.
+    /// # Implementation
+    /// This does not iterate over individual items; the values and validity
+    /// are extended in bulk.
+    pub fn extend_constant(&mut self, additional: usize, value: Option<T>) {
+        if let Some(value) = value {
+            self.values.extend_constant(additional, value);
+            if let Some(validity) = &mut self.validity {
+                validity.extend_constant(additional, true)
+            }
+        } else {
+            if let Some(validity) = &mut self.validity {
+                validity.extend_constant(additional, false)
+            } else if additional > 0 {
+                let mut validity = MutableBitmap::with_capacity(self.values.capacity());
+                validity.extend_constant(self.len(), true);
+                validity.extend_constant(additional, false);
+                self.validity = Some(validity)
+            }
+            self.values.extend_constant(additional, T::default());
+        }
+    }
+
     fn update_all_valid(&mut self) {
         // get len before mutable borrow
         let len = self.len();
@@ -1,12 +1,12 @@
 use std::sync::Arc;
 
-use super::super::{ffi::ToFfi, Array, FromFfi};
+use super::super::{bitmap_ptr, ffi::ToFfi, Array, FromFfi};
 use super::StructArray;
-use crate::{error::Result, ffi};
+use crate::{bitmap::align, error::Result, ffi};
 
 unsafe impl ToFfi for StructArray {
     fn buffers(&self) -> Vec<Option<std::ptr::NonNull<u8>>> {
-        vec![self.validity.as_ref().map(|x| x.as_ptr())]
+        vec![self.validity.as_ref().map(bitmap_ptr)]
     }
 
     fn children(&self) -> Vec<Arc<dyn Array>> {
@@ -14,16 +14,17 @@ unsafe impl ToFfi for StructArray {
     }
 
     fn offset(&self) -> Option<usize> {
-        Some(
-            self.validity
-                .as_ref()
-                .map(|bitmap| bitmap.offset())
-                .unwrap_or_default(),
-        )
+        // the children are already sliced, so the validity must be exported without an offset
+        match self.validity.as_ref() {
+            Some(bitmap) if bitmap.offset() % 8 != 0 => None,
+            _ => Some(0),
+        }
     }
 
     fn to_ffi_aligned(&self) -> Self {
-        self.clone()
+        let mut array = self.clone();
+        array.validity = self.validity.as_ref().map(|bitmap| align(bitmap, 0));
+        array
     }
 }
 
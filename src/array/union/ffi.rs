@@ -1,6 +1,10 @@
 use std::sync::Arc;
 
-use crate::{array::FromFfi, error::Result, ffi};
+use crate::{
+    array::{buffer_ptr, FromFfi},
+    error::Result,
+    ffi,
+};
 
 use super::super::{ffi::ToFfi, Array};
 use super::UnionArray;
@@ -9,11 +13,11 @@ unsafe impl ToFfi for UnionArray {
     fn buffers(&self) -> Vec<Option<std::ptr::NonNull<u8>>> {
         if let Some(offsets) = &self.offsets {
             vec![
-                Some(self.types.as_ptr().cast::<u8>()),
-                Some(offsets.as_ptr().cast::<u8>()),
+                Some(buffer_ptr(&self.types, self.offset)),
+                Some(buffer_ptr(offsets, self.offset)),
             ]
         } else {
-            vec![Some(self.types.as_ptr().cast::<u8>())]
+            vec![Some(buffer_ptr(&self.types, self.offset))]
         }
     }
 
@@ -22,7 +26,8 @@ unsafe impl ToFfi for UnionArray {
     }
 
     fn offset(&self) -> Option<usize> {
-        Some(self.types.offset())
+        // the slots of sparse unions are offset on their (unsliced) fields
+        Some(self.offset)
     }
 
     fn to_ffi_aligned(&self) -> Self {
@@ -36,7 +41,8 @@ impl<A: ffi::ArrowArrayRef> FromFfi<A> for UnionArray {
         let data_type = field.data_type().clone();
         let fields = Self::get_fields(field.data_type());
 
-        let mut types = unsafe { array.buffer::<i8>(0) }?;
+        // the buffers are already offset
+        let types = unsafe { array.buffer::<i8>(0) }?;
         let offsets = if Self::is_sparse(&data_type) {
             None
         } else {
@@ -45,17 +51,20 @@ impl<A: ffi::ArrowArrayRef> FromFfi<A> for UnionArray {
 
         let length = array.array().len();
         let offset = array.array().offset();
+        let is_sparse = offsets.is_none();
         let fields = (0..fields.len())
             .map(|index| {
                 let child = array.child(index)?;
-                Ok(ffi::try_from(child)?.into())
+                let child = ffi::try_from(child)?;
+                // the slots of sparse unions are offset on their fields
+                Ok(if is_sparse && offset > 0 {
+                    child.slice(offset, length).into()
+                } else {
+                    child.into()
+                })
             })
             .collect::<Result<Vec<Arc<dyn Array>>>>()?;
 
-        if offset > 0 {
-            types = types.slice(offset, length);
-        };
-
         Ok(Self::from_data(data_type, types, fields, offsets))
     }
 }
@@ -31,6 +31,7 @@ pub struct UnionArray {
     fields: Vec<Arc<dyn Array>>,
     offsets: Option<Buffer<i32>>,
     data_type: DataType,
+    // the offset of the slots of sparse unions on their fields
     offset: usize,
 }
 
@@ -141,7 +142,7 @@ impl UnionArray {
             fields: self.fields.clone(),
             fields_hash: self.fields_hash.clone(),
             types: self.types.clone().slice(offset, length),
-            offsets: self.offsets.clone(),
+            offsets: self.offsets.clone().map(|x| x.slice(offset, length)),
             offset: self.offset + offset,
         }
     }
@@ -158,7 +159,10 @@ impl UnionArray {
             fields: self.fields.clone(),
             fields_hash: self.fields_hash.clone(),
             types: self.types.clone().slice_unchecked(offset, length),
-            offsets: self.offsets.clone(),
+            offsets: self
+                .offsets
+                .clone()
+                .map(|x| x.slice_unchecked(offset, length)),
             offset: self.offset + offset,
         }
     }
@@ -199,7 +203,7 @@ impl UnionArray {
         self.offsets()
             .as_ref()
             .map(|x| x[index] as usize)
-            .unwrap_or(index)
+            .unwrap_or(index + self.offset)
     }
 
     /// Returns the index and slot of the field to select from `self.fields`.
@@ -221,6 +225,73 @@ impl UnionArray {
         let index = self.field_slot(index);
         new_scalar(field.as_ref(), index)
     }
+
+    /// Returns the slices of the fields referenced by the slots of this [`UnionArray`],
+    /// in the order of [`Self::fields`].
+    ///
+    /// For sparse unions, each slice has the length of this array. For dense unions, each slice
+    /// spans from the smallest to the largest offset of the slots of its field, and is empty
+    /// when no slot selects the field.
+    /// # Implementation
+    /// This operation is `O(F)` for sparse unions and `O(N + F)` for dense unions, where `F` is
+    /// the number of fields and `N` the length of the array.
+    pub fn child_slices(&self) -> Vec<Box<dyn Array>> {
+        match &self.offsets {
+            None => self
+                .fields
+                .iter()
+                .map(|field| field.slice(self.offset, self.len()))
+                .collect(),
+            Some(_) => self
+                .child_ranges()
+                .into_iter()
+                .zip(self.fields.iter())
+                .map(|(range, field)| {
+                    let (start, end) = range.unwrap_or((0, 0));
+                    field.slice(start, end - start)
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns a new [`UnionArray`] with the same values as this one whose fields are
+    /// [`Self::child_slices`]. The offsets of dense unions are recomputed relative to
+    /// their new fields.
+    ///
+    /// This is useful to release the parts of the fields not referenced by a sliced
+    /// [`UnionArray`], e.g. before sending it to another process.
+    pub fn with_child_slices(&self) -> Self {
+        let offsets = self.offsets.as_ref().map(|_| {
+            let starts = self
+                .child_ranges()
+                .into_iter()
+                .map(|range| range.map(|(start, _)| start).unwrap_or_default())
+                .collect::<Vec<_>>();
+            (0..self.len())
+                .map(|index| {
+                    let (field, slot) = self.index(index);
+                    (slot - starts[field]) as i32
+                })
+                .collect::<Buffer<i32>>()
+        });
+        let fields = self.child_slices().into_iter().map(|x| x.into()).collect();
+        Self::from_data(self.data_type.clone(), self.types.clone(), fields, offsets)
+    }
+
+    /// Returns, for each field, the range `[start, end)` of its slots referenced by this
+    /// array, or `None` if no slot references it.
+    fn child_ranges(&self) -> Vec<Option<(usize, usize)>> {
+        let mut ranges = vec![None; self.fields.len()];
+        (0..self.len()).for_each(|index| {
+            let (field, slot) = self.index(index);
+            let range: &mut Option<(usize, usize)> = &mut ranges[field];
+            *range = Some(match *range {
+                Some((start, end)) => (start.min(slot), end.max(slot + 1)),
+                None => (slot, slot + 1),
+            });
+        });
+        ranges
+    }
 }
 
 impl Array for UnionArray {
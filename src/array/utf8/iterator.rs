@@ -1,5 +1,8 @@
 use crate::bitmap::utils::{zip_validity, ZipValidity};
-use crate::{array::Offset, trusted_len::TrustedLen};
+use crate::{
+    array::{Offset, OffsetsValuesIter, OffsetsWindows},
+    trusted_len::TrustedLen,
+};
 
 use super::Utf8Array;
 
@@ -75,6 +78,18 @@ impl<'a, O: Offset> Utf8Array<O> {
     pub fn values_iter(&'a self) -> Utf8ValuesIter<'a, O> {
         Utf8ValuesIter::new(self)
     }
+
+    /// Returns an iterator of the `(start, end)` positions of each value in
+    /// [`Utf8Array::values`], irrespectively of their validity.
+    pub fn offsets_windows(&'a self) -> OffsetsWindows<'a, O> {
+        OffsetsWindows::new(self.offsets.as_slice())
+    }
+
+    /// Returns an iterator of each value as bytes, irrespectively of their validity.
+    pub fn values_bytes_iter(&'a self) -> OffsetsValuesIter<'a, O> {
+        // soundness: the offsets of a `Utf8Array` are in bounds of its values
+        unsafe { OffsetsValuesIter::new_unchecked(self.offsets.as_slice(), self.values.as_slice()) }
+    }
 }
 
 unsafe impl<O: Offset> TrustedLen for Utf8ValuesIter<'_, O> {}
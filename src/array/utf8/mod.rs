@@ -88,6 +88,17 @@ impl<O: Offset> Utf8Array<O> {
         }
     }
 
+    /// Deconstructs the [`Utf8Array`] into its individual components.
+    pub fn into_data(self) -> (DataType, Buffer<O>, Buffer<u8>, Option<Bitmap>) {
+        let Self {
+            data_type,
+            offsets,
+            values,
+            validity,
+        } = self;
+        (data_type, offsets, values, validity)
+    }
+
     /// Returns the default [`DataType`], `DataType::Utf8` or `DataType::LargeUtf8`
     pub fn default_data_type() -> DataType {
         if O::is_large() {
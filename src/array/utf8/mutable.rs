@@ -329,6 +329,48 @@ impl<O: Offset> MutableUtf8Array<O> {
         }
     }
 
+    /// Extends the [`MutableUtf8Array`] from a slice of values.
+    /// # Panic
+    /// This operation panics iff the length of all values (in bytes) exceeds `O` maximum value.
+    pub fn extend_from_slice<T: AsRef<str>>(&mut self, items: &[T]) {
+        self.extend_trusted_len_values(items.iter())
+    }
+
+    /// Extends the [`MutableUtf8Array`] with `additional` copies of `value`.
+    /// # Panic
+    /// This operation panics iff the length of all values (in bytes) exceeds `O` maximum value.
+    pub fn extend_constant<T: AsRef<str>>(&mut self, additional: usize, value: Option<T>) {
+        if let Some(value) = value {
+            let bytes = value.as_ref().as_bytes();
+            let last = self.values.len();
+            // check the last offset upfront so that we do not need to check on every item
+            O::from_usize(last + bytes.len() * additional)
+                .ok_or(ArrowError::Overflow)
+                .unwrap();
+
+            self.values.reserve(bytes.len() * additional);
+            self.offsets.reserve(additional);
+            for i in 1..=additional {
+                self.values.extend_from_slice(bytes);
+                self.offsets
+                    .push(O::from_usize(last + bytes.len() * i).unwrap());
+            }
+            if let Some(validity) = &mut self.validity {
+                validity.extend_constant(additional, true)
+            }
+        } else {
+            if let Some(validity) = &mut self.validity {
+                validity.extend_constant(additional, false)
+            } else if additional > 0 {
+                let mut validity = MutableBitmap::with_capacity(self.offsets.capacity());
+                validity.extend_constant(self.len(), true);
+                validity.extend_constant(additional, false);
+                self.validity = Some(validity)
+            }
+            self.offsets.extend_constant(additional, self.last_offset());
+        }
+    }
+
     /// Creates a [`MutableUtf8Array`] from an iterator of trusted length.
     /// # Safety
     /// The iterator must be [`TrustedLen`](https://doc.rust-lang.org/std/iter/trait.TrustedLen.html).
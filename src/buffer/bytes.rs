@@ -84,6 +84,18 @@ impl<T: NativeType> Bytes<T> {
     pub fn ptr(&self) -> NonNull<T> {
         self.ptr
     }
+
+    /// Returns a mutable slice of this region iff it was allocated natively.
+    #[inline]
+    pub fn get_mut(&mut self) -> Option<&mut [T]> {
+        match self.deallocation {
+            // Safety: the region is owned by `self` and valid for `len` elements
+            Deallocation::Native(_) => {
+                Some(unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) })
+            }
+            Deallocation::Foreign(_) => None,
+        }
+    }
 }
 
 impl<T: NativeType> Drop for Bytes<T> {
@@ -115,6 +115,17 @@ impl<T: NativeType> Buffer<T> {
         self
     }
 
+    /// Returns a mutable reference to the values of this [`Buffer`] iff its memory region
+    /// is neither shared with other buffers (e.g. via [`Clone`]) nor allocated by a foreign allocator.
+    #[inline]
+    pub fn get_mut(&mut self) -> Option<&mut [T]> {
+        let offset = self.offset;
+        let length = self.length;
+        Arc::get_mut(&mut self.data)
+            .and_then(|bytes| bytes.get_mut())
+            .map(|values| &mut values[offset..offset + length])
+    }
+
     /// Returns a pointer to the start of this buffer.
     #[inline]
     pub(crate) fn as_ptr(&self) -> std::ptr::NonNull<T> {
@@ -1,3 +1,7 @@
+use std::cmp::Ordering;
+
+use multiversion::multiversion;
+
 use crate::bitmap::utils::{BitChunkIterExact, BitChunksExact};
 use crate::datatypes::{DataType, IntervalUnit};
 use crate::error::{ArrowError, Result};
@@ -5,7 +9,10 @@ use crate::scalar::*;
 use crate::types::simd::*;
 use crate::types::NativeType;
 use crate::{
-    array::{Array, BinaryArray, BooleanArray, Offset, PrimitiveArray, Utf8Array},
+    array::{
+        ord::fixed_size_cmp, Array, BinaryArray, BooleanArray, FixedSizeBinaryArray, Offset,
+        PrimitiveArray, Utf8Array,
+    },
     bitmap::Bitmap,
 };
 
@@ -66,6 +73,16 @@ fn min_max_binary<O: Offset, F: Fn(&[u8], &[u8]) -> bool>(
     Some(n)
 }
 
+/// Helper to perform min/max of fixed size binarys: the extreme is replaced by every value
+/// whose order relative to it is `ordering`.
+fn min_max_fixed_size_binary(array: &FixedSizeBinaryArray, ordering: Ordering) -> Option<&[u8]> {
+    let cmp = fixed_size_cmp(array.size());
+    array
+        .iter()
+        .flatten()
+        .reduce(|n, item| if cmp(item, n) == ordering { item } else { n })
+}
+
 /// Helper macro to perform min/max of strings
 fn min_max_string<O: Offset, F: Fn(&str, &str) -> bool>(
     array: &Utf8Array<O>,
@@ -102,6 +119,10 @@ fn min_max_string<O: Offset, F: Fn(&str, &str) -> bool>(
     Some(n)
 }
 
+#[multiversion]
+#[cfg_attr(feature = "simd_avx512", clone(target = "x86_64+avx512f"))]
+#[clone(target = "x86_64+avx+avx2")]
+#[clone(target = "aarch64+neon")]
 fn nonnull_min_primitive<T>(values: &[T]) -> T
 where
     T: NativeType + Simd,
@@ -121,6 +142,10 @@ where
     reduced.min_element()
 }
 
+#[multiversion]
+#[cfg_attr(feature = "simd_avx512", clone(target = "x86_64+avx512f"))]
+#[clone(target = "x86_64+avx+avx2")]
+#[clone(target = "aarch64+neon")]
 fn null_min_primitive_impl<T, I>(values: &[T], mut validity_masks: I) -> T
 where
     T: NativeType + Simd,
@@ -181,6 +206,10 @@ where
     }
 }
 
+#[multiversion]
+#[cfg_attr(feature = "simd_avx512", clone(target = "x86_64+avx512f"))]
+#[clone(target = "x86_64+avx+avx2")]
+#[clone(target = "aarch64+neon")]
 fn nonnull_max_primitive<T>(values: &[T]) -> T
 where
     T: NativeType + Simd,
@@ -200,6 +229,10 @@ where
     reduced.max_element()
 }
 
+#[multiversion]
+#[cfg_attr(feature = "simd_avx512", clone(target = "x86_64+avx512f"))]
+#[clone(target = "x86_64+avx+avx2")]
+#[clone(target = "aarch64+neon")]
 fn null_max_primitive_impl<T, I>(values: &[T], mut validity_masks: I) -> T
 where
     T: NativeType + Simd,
@@ -280,6 +313,16 @@ pub fn min_binary<O: Offset>(array: &BinaryArray<O>) -> Option<&[u8]> {
     min_max_binary(array, |a, b| a > b)
 }
 
+/// Returns the maximum value in the fixed size binary array, according to the natural order.
+pub fn max_fixed_size_binary(array: &FixedSizeBinaryArray) -> Option<&[u8]> {
+    min_max_fixed_size_binary(array, Ordering::Greater)
+}
+
+/// Returns the minimum value in the fixed size binary array, according to the natural order.
+pub fn min_fixed_size_binary(array: &FixedSizeBinaryArray) -> Option<&[u8]> {
+    min_max_fixed_size_binary(array, Ordering::Less)
+}
+
 /// Returns the maximum value in the string array, according to the natural order.
 pub fn max_string<O: Offset>(array: &Utf8Array<O>) -> Option<&str> {
     min_max_string(array, |a, b| a < b)
@@ -360,6 +403,19 @@ macro_rules! dyn_generic {
     }};
 }
 
+macro_rules! dyn_fixed_size_binary {
+    ($array:expr, $f:ident) => {{
+        let array = $array
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .unwrap();
+        Box::new(FixedSizeBinaryScalar::new(
+            array.data_type().clone(),
+            $f(array),
+        ))
+    }};
+}
+
 /// Returns the maximum of [`Array`]. The scalar is null when all elements are null.
 /// # Error
 /// Errors iff the type does not support this operation.
@@ -392,6 +448,7 @@ pub fn max(array: &dyn Array) -> Result<Box<dyn Scalar>> {
         DataType::LargeBinary => {
             dyn_generic!(BinaryArray<i64>, BinaryScalar<i64>, array, max_binary)
         }
+        DataType::FixedSizeBinary(_) => dyn_fixed_size_binary!(array, max_fixed_size_binary),
         _ => {
             return Err(ArrowError::InvalidArgumentError(format!(
                 "The `max` operator does not support type `{}`",
@@ -433,6 +490,7 @@ pub fn min(array: &dyn Array) -> Result<Box<dyn Scalar>> {
         DataType::LargeBinary => {
             dyn_generic!(BinaryArray<i64>, BinaryScalar<i64>, array, min_binary)
         }
+        DataType::FixedSizeBinary(_) => dyn_fixed_size_binary!(array, min_fixed_size_binary),
         _ => {
             return Err(ArrowError::InvalidArgumentError(format!(
                 "The `max` operator does not support type `{}`",
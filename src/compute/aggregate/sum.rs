@@ -21,7 +21,9 @@ pub trait Sum<T> {
 }
 
 #[multiversion]
-#[clone(target = "x86_64+avx")]
+#[cfg_attr(feature = "simd_avx512", clone(target = "x86_64+avx512f"))]
+#[clone(target = "x86_64+avx+avx2")]
+#[clone(target = "aarch64+neon")]
 fn nonnull_sum<T>(values: &[T]) -> T
 where
     T: NativeType + Simd + Add<Output = T> + std::iter::Sum<T>,
@@ -40,7 +42,9 @@ where
 /// # Panics
 /// iff `values.len() != bitmap.len()` or the operation overflows.
 #[multiversion]
-#[clone(target = "x86_64+avx")]
+#[cfg_attr(feature = "simd_avx512", clone(target = "x86_64+avx512f"))]
+#[clone(target = "x86_64+avx+avx2")]
+#[clone(target = "aarch64+neon")]
 fn null_sum_impl<T, I>(values: &[T], mut validity_masks: I) -> T
 where
     T: NativeType + Simd,
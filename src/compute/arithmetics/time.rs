@@ -15,7 +15,7 @@ use num_traits::AsPrimitive;
 
 use crate::{
     array::{Array, PrimitiveArray},
-    compute::arity::{binary, unary},
+    compute::arity::{binary, binary_checked, unary},
     datatypes::{DataType, TimeUnit},
     error::{ArrowError, Result},
     scalar::{PrimitiveScalar, Scalar},
@@ -23,6 +23,8 @@ use crate::{
     types::{months_days_ns, NativeType},
 };
 
+pub use crate::temporal_conversions::EndOfMonth;
+
 /// Creates the scale required to add or subtract a Duration to a time array
 /// (Timestamp, Time, or Date). The resulting scale always multiplies the rhs
 /// number (Duration) so it can be added to the lhs number (time array).
@@ -432,3 +434,138 @@ pub fn add_interval_scalar(
         )),
     }
 }
+
+/// Adds an interval to a [`DataType::Timestamp`] or [`DataType::Date64`], adding months to the
+/// calendar date following `end_of_month` (e.g. January 31st + 1 month is February 28th when
+/// clamped). Months are added first, then days and then nanoseconds.
+///
+/// Contrarily to [`add_interval`], values that overflow are null.
+/// # Example
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::arithmetics::time::{checked_add_interval, EndOfMonth};
+/// use arrow2::datatypes::{DataType, TimeUnit};
+/// use arrow2::types::months_days_ns;
+///
+/// // 2021-01-31 and 2021-03-31
+/// let dates = PrimitiveArray::from_slice([1612051200000i64, 1617148800000]).to(DataType::Date64);
+/// let interval = PrimitiveArray::from_slice([months_days_ns::new(1, 0, 0); 2]);
+///
+/// let result = checked_add_interval(&dates, &interval, EndOfMonth::Clamp).unwrap();
+/// // 2021-02-28 and 2021-04-30
+/// let expected = PrimitiveArray::from_slice([1614470400000i64, 1619740800000]).to(DataType::Date64);
+/// assert_eq!(result, expected);
+/// ```
+/// # Errors
+/// Errors iff `temporal` is not a timestamp nor a date64, or its timezone cannot be parsed.
+pub fn checked_add_interval(
+    temporal: &PrimitiveArray<i64>,
+    interval: &PrimitiveArray<months_days_ns>,
+    end_of_month: EndOfMonth,
+) -> Result<PrimitiveArray<i64>> {
+    let data_type = temporal.data_type().clone();
+    match temporal.data_type().to_logical_type() {
+        DataType::Timestamp(time_unit, Some(timezone_str)) => {
+            let time_unit = *time_unit;
+            let timezone = temporal_conversions::parse_offset(timezone_str);
+            match timezone {
+                Ok(timezone) => Ok(binary_checked(
+                    temporal,
+                    interval,
+                    data_type,
+                    |timestamp, interval| {
+                        temporal_conversions::checked_add_interval(
+                            timestamp,
+                            time_unit,
+                            interval,
+                            end_of_month,
+                            &timezone,
+                        )
+                    },
+                )),
+                #[cfg(feature = "chrono-tz")]
+                Err(_) => {
+                    let timezone = temporal_conversions::parse_offset_tz(timezone_str)?;
+                    Ok(binary_checked(
+                        temporal,
+                        interval,
+                        data_type,
+                        |timestamp, interval| {
+                            temporal_conversions::checked_add_interval(
+                                timestamp,
+                                time_unit,
+                                interval,
+                                end_of_month,
+                                &timezone,
+                            )
+                        },
+                    ))
+                }
+                #[cfg(not(feature = "chrono-tz"))]
+                _ => Err(ArrowError::InvalidArgumentError(format!(
+                    "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+                    timezone_str
+                ))),
+            }
+        }
+        DataType::Timestamp(time_unit, None) => {
+            let time_unit = *time_unit;
+            Ok(binary_checked(
+                temporal,
+                interval,
+                data_type,
+                |timestamp, interval| {
+                    temporal_conversions::checked_add_naive_interval(
+                        timestamp,
+                        time_unit,
+                        interval,
+                        end_of_month,
+                    )
+                },
+            ))
+        }
+        DataType::Date64 => Ok(binary_checked(
+            temporal,
+            interval,
+            data_type,
+            |date, interval| {
+                temporal_conversions::checked_add_naive_interval(
+                    date,
+                    TimeUnit::Millisecond,
+                    interval,
+                    end_of_month,
+                )
+            },
+        )),
+        _ => Err(ArrowError::InvalidArgumentError(
+            "checked_add_interval is only supported for `DataType::Timestamp` and `DataType::Date64`"
+                .to_string(),
+        )),
+    }
+}
+
+/// Adds an interval to a [`DataType::Date32`], following `end_of_month`.
+/// The nanoseconds of the interval are added and the result is rounded down to days.
+///
+/// See [`checked_add_interval`] for how the interval is added. Values that overflow are null.
+/// # Errors
+/// Errors iff `date` is not a date32.
+pub fn checked_add_interval_date32(
+    date: &PrimitiveArray<i32>,
+    interval: &PrimitiveArray<months_days_ns>,
+    end_of_month: EndOfMonth,
+) -> Result<PrimitiveArray<i32>> {
+    if date.data_type().to_logical_type() != &DataType::Date32 {
+        return Err(ArrowError::InvalidArgumentError(
+            "checked_add_interval_date32 is only supported for `DataType::Date32`".to_string(),
+        ));
+    }
+    Ok(binary_checked(
+        date,
+        interval,
+        date.data_type().clone(),
+        |date, interval| {
+            temporal_conversions::checked_add_date32_interval(date, interval, end_of_month)
+        },
+    ))
+}
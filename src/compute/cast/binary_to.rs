@@ -3,7 +3,7 @@ use std::convert::TryFrom;
 use crate::error::{ArrowError, Result};
 use crate::{array::*, buffer::Buffer, datatypes::DataType, types::NativeType};
 
-use super::CastOptions;
+use super::{dictionary_to::try_extend_capped, CastOptions};
 
 /// Conversion of binary
 pub fn binary_to_large_binary(from: &BinaryArray<i32>, to_data_type: DataType) -> BinaryArray<i64> {
@@ -90,7 +90,12 @@ pub fn binary_to_dictionary<O: Offset, K: DictionaryKey>(
 
 pub(super) fn binary_to_dictionary_dyn<O: Offset, K: DictionaryKey>(
     from: &dyn Array,
-) -> Result<Box<dyn Array>> {
-    let values = from.as_any().downcast_ref().unwrap();
-    binary_to_dictionary::<O, K>(values).map(|x| Box::new(x) as Box<dyn Array>)
+    max_size: Option<usize>,
+) -> Result<Option<Box<dyn Array>>> {
+    let values = from.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    let mut array = MutableDictionaryArray::<K, MutableBinaryArray<O>>::new();
+    if !try_extend_capped(&mut array, values.iter(), max_size)? {
+        return Ok(None);
+    }
+    Ok(Some(Box::new(DictionaryArray::<K>::from(array))))
 }
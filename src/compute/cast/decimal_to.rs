@@ -0,0 +1,135 @@
+use num_traits::{AsPrimitive, Float};
+
+use crate::{
+    array::*,
+    compute::arity::{unary, unary_checked},
+    datatypes::DataType,
+    error::{ArrowError, Result},
+    types::NativeType,
+};
+
+/// The rounding of the values that do not fit the scale of a decimal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Rounds to the nearest value, and ties away from zero (e.g. `2.5 -> 3`, `-2.5 -> -3`).
+    HalfAwayFromZero,
+    /// Rounds to the nearest value, and ties to the nearest even value (e.g. `2.5 -> 2`, `3.5 -> 4`).
+    HalfEven,
+    /// Rounds towards zero, i.e. truncates.
+    TowardZero,
+    /// Rounds towards negative infinity.
+    Floor,
+    /// Rounds towards positive infinity.
+    Ceil,
+}
+
+impl RoundingMode {
+    #[inline]
+    fn round(self, value: f64) -> f64 {
+        match self {
+            RoundingMode::HalfAwayFromZero => value.round(),
+            RoundingMode::HalfEven => {
+                if (value - value.trunc()).abs() == 0.5 {
+                    2.0 * (value / 2.0).round()
+                } else {
+                    value.round()
+                }
+            }
+            RoundingMode::TowardZero => value.trunc(),
+            RoundingMode::Floor => value.floor(),
+            RoundingMode::Ceil => value.ceil(),
+        }
+    }
+}
+
+fn get_precision_scale(data_type: &DataType) -> Result<(usize, usize)> {
+    match data_type.to_logical_type() {
+        DataType::Decimal(precision, scale) => Ok((*precision, *scale)),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "A decimal data type was expected, got {:?}",
+            data_type
+        ))),
+    }
+}
+
+/// Returns a [`PrimitiveArray`] of floats with the values of a [`DataType::Decimal`] array.
+///
+/// Each value is converted to the float closest to its integer representation, and then
+/// divided by `10^scale`. Therefore, values whose integer representation exceeds
+/// the float's mantissa (2^53 for `f64`) lose precision, and the result may differ
+/// from the closest float to the decimal by one unit in the last place.
+/// # Example
+/// ```
+/// use arrow2::array::{Float64Array, Int128Array};
+/// use arrow2::compute::cast::decimal_to_float;
+/// use arrow2::datatypes::DataType;
+///
+/// let array = Int128Array::from([Some(12345), None, Some(-5)]).to(DataType::Decimal(5, 2));
+/// let result = decimal_to_float::<f64>(&array).unwrap();
+/// assert_eq!(result, Float64Array::from([Some(123.45), None, Some(-0.05)]));
+/// ```
+/// # Errors
+/// Errors iff `from` is not a [`DataType::Decimal`].
+pub fn decimal_to_float<T>(from: &PrimitiveArray<i128>) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + Float,
+    i128: AsPrimitive<T>,
+{
+    let (_, scale) = get_precision_scale(from.data_type())?;
+    let divisor = T::powi(10i128.as_(), scale as i32);
+
+    Ok(unary(from, |x| x.as_() / divisor, T::DATA_TYPE))
+}
+
+/// Returns a [`PrimitiveArray`] of [`DataType::Decimal`] of `precision` and `scale`
+/// with the values of a [`PrimitiveArray`] of floats.
+///
+/// Each value is multiplied by `10^scale` and rounded according to `rounding`. Note that
+/// rounding applies to the float resulting from the multiplication, e.g. `1.005 * 100` is
+/// `100.49999999999999` and rounds to `1.00` with [`RoundingMode::HalfAwayFromZero`].
+/// Values that are not finite, or that do not fit in `precision`, are null.
+/// # Example
+/// ```
+/// use arrow2::array::{Float64Array, Int128Array};
+/// use arrow2::compute::cast::{float_to_decimal, RoundingMode};
+/// use arrow2::datatypes::DataType;
+///
+/// let array = Float64Array::from([Some(1.125), None, Some(f64::NAN), Some(1000.0)]);
+/// let result = float_to_decimal(&array, 5, 2, RoundingMode::HalfEven).unwrap();
+/// let expected = Int128Array::from([Some(112), None, None, None]).to(DataType::Decimal(5, 2));
+/// assert_eq!(result, expected);
+/// ```
+/// # Errors
+/// Errors iff `precision` is not between 1 and 38 or `scale` is larger than `precision`.
+pub fn float_to_decimal<T>(
+    from: &PrimitiveArray<T>,
+    precision: usize,
+    scale: usize,
+    rounding: RoundingMode,
+) -> Result<PrimitiveArray<i128>>
+where
+    T: NativeType + Float + AsPrimitive<f64>,
+{
+    if precision == 0 || precision > 38 || scale > precision {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Decimal({}, {}) is not a valid decimal data type",
+            precision, scale
+        )));
+    }
+    let multiplier = 10f64.powi(scale as i32);
+    let max = 10f64.powi(precision as i32);
+
+    Ok(unary_checked(
+        from,
+        |x| {
+            let value = rounding.round(x.as_() * multiplier);
+            // not finite values fail this check
+            if value.abs() < max {
+                Some(value as i128)
+            } else {
+                None
+            }
+        },
+        DataType::Decimal(precision, scale),
+    ))
+}
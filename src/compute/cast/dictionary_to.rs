@@ -1,6 +1,11 @@
 use super::{primitive_as_primitive, primitive_to_primitive, CastOptions};
+use std::hash::Hash;
+
 use crate::{
-    array::{Array, DictionaryArray, DictionaryKey, PrimitiveArray},
+    array::{
+        Array, DictionaryArray, DictionaryKey, MutableArray, MutableDictionaryArray,
+        PrimitiveArray, TryExtend,
+    },
     compute::{cast::cast, take::take},
     datatypes::DataType,
     error::{ArrowError, Result},
@@ -15,9 +20,10 @@ macro_rules! key_cast {
         if cast_keys.null_count() > $keys.null_count() {
             return Err(ArrowError::Overflow);
         }
-        Ok(Box::new(DictionaryArray::<$to_type>::from_data(
-            cast_keys, $values,
-        )))
+        Ok(Box::new(
+            DictionaryArray::<$to_type>::from_data(cast_keys, $values)
+                .with_ordered($array.is_ordered()),
+        ))
     }};
 }
 
@@ -33,7 +39,7 @@ pub fn dictionary_to_dictionary_values<K: DictionaryKey>(
     let values = from.values();
 
     let values = cast(values.as_ref(), values_type, CastOptions::default())?.into();
-    Ok(DictionaryArray::from_data(keys.clone(), values))
+    Ok(DictionaryArray::from_data(keys.clone(), values).with_ordered(from.is_ordered()))
 }
 
 /// Similar to dictionary_to_dictionary_values, but overflowing cast is wrapped
@@ -50,10 +56,11 @@ pub fn wrapping_dictionary_to_dictionary_values<K: DictionaryKey>(
         CastOptions {
             wrapped: true,
             partial: false,
+            max_dictionary_size: None,
         },
     )?
     .into();
-    Ok(DictionaryArray::from_data(keys.clone(), values))
+    Ok(DictionaryArray::from_data(keys.clone(), values).with_ordered(from.is_ordered()))
 }
 
 /// Casts a [`DictionaryArray`] to a new [`DictionaryArray`] backed by a
@@ -76,7 +83,7 @@ where
     if casted_keys.null_count() > keys.null_count() {
         Err(ArrowError::Overflow)
     } else {
-        Ok(DictionaryArray::from_data(casted_keys, values.clone()))
+        Ok(DictionaryArray::from_data(casted_keys, values.clone()).with_ordered(from.is_ordered()))
     }
 }
 
@@ -96,7 +103,7 @@ where
     if casted_keys.null_count() > keys.null_count() {
         Err(ArrowError::Overflow)
     } else {
-        Ok(DictionaryArray::from_data(casted_keys, values.clone()))
+        Ok(DictionaryArray::from_data(casted_keys, values.clone()).with_ordered(from.is_ordered()))
     }
 }
 
@@ -110,7 +117,7 @@ pub(super) fn dictionary_cast_dyn<K: DictionaryKey>(
     let values = array.values();
 
     match to_type {
-        DataType::Dictionary(to_keys_type, to_values_type) => {
+        DataType::Dictionary(to_keys_type, to_values_type, _) => {
             let values = cast(values.as_ref(), to_values_type, options)?.into();
 
             // create the appropriate array type
@@ -155,3 +162,32 @@ where
     // unwrap: The dictionary guarantees that the keys are not out-of-bounds.
     take(from.values().as_ref(), &indices).unwrap()
 }
+
+/// Extends `array` with `iter`, stopping as soon as its dictionary has more than `max_size`
+/// values. Returns whether all items were added, i.e. whether `max_size` was not exceeded.
+pub(super) fn try_extend_capped<K, M, T, I>(
+    array: &mut MutableDictionaryArray<K, M>,
+    iter: I,
+    max_size: Option<usize>,
+) -> Result<bool>
+where
+    K: DictionaryKey,
+    M: MutableArray + TryExtend<Option<T>>,
+    T: Hash,
+    I: Iterator<Item = Option<T>>,
+{
+    let max_size = match max_size {
+        Some(max_size) => max_size,
+        None => {
+            array.try_extend(iter)?;
+            return Ok(true);
+        }
+    };
+    for value in iter {
+        array.try_extend(std::iter::once(value))?;
+        if array.values().len() > max_size {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
@@ -9,12 +9,14 @@ use crate::{
 
 mod binary_to;
 mod boolean_to;
+mod decimal_to;
 mod dictionary_to;
 mod primitive_to;
 mod utf8_to;
 
 pub use binary_to::*;
 pub use boolean_to::*;
+pub use decimal_to::*;
 pub use dictionary_to::*;
 pub use primitive_to::*;
 pub use utf8_to::*;
@@ -29,6 +31,11 @@ pub struct CastOptions {
     /// default to false
     /// whether to cast to an integer at the best-effort
     pub partial: bool,
+    /// default to `None`
+    /// the maximum number of distinct values when casting to a [`DataType::Dictionary`].
+    /// When exceeded, the cast falls back to a plain array of the dictionary's value type,
+    /// so that high cardinality columns do not build large, ineffective dictionaries.
+    pub max_dictionary_size: Option<usize>,
 }
 
 impl CastOptions {
@@ -39,15 +46,6 @@ impl CastOptions {
     }
 }
 
-/// Returns true if this type is numeric: (UInt*, Unit*, or Float*).
-fn is_numeric(t: &DataType) -> bool {
-    use DataType::*;
-    matches!(
-        t,
-        UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64
-    )
-}
-
 macro_rules! primitive_dyn {
     ($from:expr, $expr:tt) => {{
         let from = $from.as_any().downcast_ref().unwrap();
@@ -95,7 +93,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             | Float64
             | Date64
             | List(_)
-            | Dictionary(_, _),
+            | Dictionary(_, _, _),
         )
         | (
             Boolean
@@ -113,7 +111,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             | Float64
             | Date64
             | List(_)
-            | Dictionary(_, _),
+            | Dictionary(_, _, _),
             Null,
         ) => true,
         (Struct(_), _) => false,
@@ -127,15 +125,19 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (List(list_from), LargeList(list_to)) if list_from == list_to => true,
         (LargeList(list_from), List(list_to)) if list_from == list_to => true,
         (_, List(list_to)) => can_cast_types(from_type, list_to.data_type()),
-        (Dictionary(_, from_value_type), Dictionary(_, to_value_type)) => {
+        (Dictionary(_, from_value_type, _), Dictionary(_, to_value_type, _)) => {
             can_cast_types(from_value_type, to_value_type)
         }
-        (Dictionary(_, value_type), _) => can_cast_types(value_type, to_type),
-        (_, Dictionary(_, value_type)) => can_cast_types(from_type, value_type),
+        (Dictionary(_, value_type, _), _) => can_cast_types(value_type, to_type),
+        (_, Dictionary(_, value_type, _)) => can_cast_types(from_type, value_type),
 
-        (_, Boolean) => is_numeric(from_type),
+        // `cast` does not implement these, even though they are numeric
+        (Float16 | Decimal(_, _) | Extension(_, _, _), _)
+        | (_, Float16 | Decimal(_, _) | Extension(_, _, _)) => false,
+
+        (_, Boolean) => from_type.is_numeric(),
         (Boolean, _) => {
-            is_numeric(to_type)
+            to_type.is_numeric()
                 || to_type == &Utf8
                 || to_type == &LargeUtf8
                 || to_type == &Binary
@@ -144,23 +146,29 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
 
         (Utf8, Date32) => true,
         (Utf8, Date64) => true,
+        (Utf8, Interval(_) | Duration(_)) => true,
         (Utf8, Timestamp(TimeUnit::Nanosecond, _)) => true,
         (Utf8, LargeUtf8) => true,
-        (Utf8, _) => is_numeric(to_type),
+        (Utf8, Binary) => true,
+        (Utf8, _) => to_type.is_numeric(),
         (LargeUtf8, Date32) => true,
         (LargeUtf8, Date64) => true,
+        (LargeUtf8, Interval(_) | Duration(_)) => true,
         (LargeUtf8, Timestamp(TimeUnit::Nanosecond, _)) => true,
         (LargeUtf8, Utf8) => true,
-        (LargeUtf8, _) => is_numeric(to_type),
+        (LargeUtf8, LargeBinary) => true,
+        (LargeUtf8, _) => to_type.is_numeric(),
         (Timestamp(_, _), Utf8) => true,
         (Timestamp(_, _), LargeUtf8) => true,
-        (_, Utf8) => is_numeric(from_type) || from_type == &Binary,
-        (_, LargeUtf8) => is_numeric(from_type) || from_type == &Binary,
+        (_, Utf8) => from_type.is_numeric() || from_type == &Binary,
+        (_, LargeUtf8) => {
+            from_type.is_numeric() || from_type == &Binary || from_type == &LargeBinary
+        }
 
-        (Binary, _) => is_numeric(to_type) || to_type == &LargeBinary,
-        (LargeBinary, _) => is_numeric(to_type) || to_type == &Binary,
-        (_, Binary) => is_numeric(from_type),
-        (_, LargeBinary) => is_numeric(from_type),
+        (Binary, _) => to_type.is_numeric() || to_type == &LargeBinary,
+        (LargeBinary, _) => to_type.is_numeric() || to_type == &Binary,
+        (_, Binary) => from_type.is_numeric(),
+        (_, LargeBinary) => from_type.is_numeric(),
 
         // start numeric casts
         (UInt8, UInt16) => true,
@@ -358,11 +366,16 @@ fn cast_large_to_list(array: &ListArray<i64>, to_type: &DataType) -> ListArray<i
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
 /// * Temporal to/from backing primitive: zero-copy with data type change
+/// * Utf8 to Binary (and their large variants): zero-copy with data type change
+/// * Utf8 to Interval and Duration: SQL interval literals (e.g. `1 year 2 months 3 days` or
+///   `02:30:00`) are parsed; strings that can't be parsed or represented in the type return null
+/// * To Dictionary: values are cast to the dictionary's value type and dictionary-encoded,
+///   or left as is when there are more than `options.max_dictionary_size` distinct values
 /// Unsupported Casts
 /// * To or from `StructArray`
 /// * List to primitive
 /// * Utf8 to boolean
-/// * Interval and duration
+/// * Interval and duration, except from Utf8
 pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Result<Box<dyn Array>> {
     use DataType::*;
     let from_type = array.data_type();
@@ -391,7 +404,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             | Float64
             | Date64
             | List(_)
-            | Dictionary(_, _),
+            | Dictionary(_, _, _),
         )
         | (
             Boolean
@@ -409,7 +422,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             | Float64
             | Date64
             | List(_)
-            | Dictionary(_, _),
+            | Dictionary(_, _, _),
             Null,
         ) => Ok(new_null_array(to_type.clone(), array.len())),
         (Struct(_), _) => Err(ArrowError::NotYetImplemented(
@@ -449,10 +462,10 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Ok(Box::new(list_array))
         }
 
-        (Dictionary(index_type, _), _) => match_integer_type!(index_type, |$T| {
+        (Dictionary(index_type, _, _), _) => match_integer_type!(index_type, |$T| {
             dictionary_cast_dyn::<$T>(array, to_type, options)
         }),
-        (_, Dictionary(index_type, value_type)) => match_integer_type!(index_type, |$T| {
+        (_, Dictionary(index_type, value_type, _)) => match_integer_type!(index_type, |$T| {
             cast_to_dictionary::<$T>(array, value_type, options)
         }),
         (_, Boolean) => match from_type {
@@ -505,6 +518,11 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Float64 => utf8_to_primitive_dyn::<i32, f64>(array, to_type, options),
             Date32 => utf8_to_date32_dyn::<i32>(array),
             Date64 => utf8_to_date64_dyn::<i32>(array),
+            Interval(_) | Duration(_) => utf8_to_interval_dyn::<i32>(array, to_type),
+            Binary => Ok(Box::new(utf8_to_binary::<i32>(
+                array.as_any().downcast_ref().unwrap(),
+                to_type.clone(),
+            ))),
             LargeUtf8 => Ok(Box::new(utf8_to_large_utf8(
                 array.as_any().downcast_ref().unwrap(),
             ))),
@@ -530,8 +548,13 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Float64 => utf8_to_primitive_dyn::<i64, f64>(array, to_type, options),
             Date32 => utf8_to_date32_dyn::<i64>(array),
             Date64 => utf8_to_date64_dyn::<i64>(array),
+            Interval(_) | Duration(_) => utf8_to_interval_dyn::<i64>(array, to_type),
             Utf8 => utf8_large_to_utf8(array.as_any().downcast_ref().unwrap())
                 .map(|x| Box::new(x) as Box<dyn Array>),
+            LargeBinary => Ok(Box::new(utf8_to_binary::<i64>(
+                array.as_any().downcast_ref().unwrap(),
+                to_type.clone(),
+            ))),
             Timestamp(TimeUnit::Nanosecond, None) => utf8_to_naive_timestamp_ns_dyn::<i64>(array),
             Timestamp(TimeUnit::Nanosecond, Some(tz)) => {
                 utf8_to_timestamp_ns_dyn::<i64>(array, tz.clone())
@@ -600,6 +623,17 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
                 let array = Utf8Array::<i64>::from_trusted_len_iter(iter);
                 Ok(Box::new(array))
             }
+            LargeBinary => {
+                let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+
+                // perf todo: the offsets are equal; we can speed-up this
+                let iter = array
+                    .iter()
+                    .map(|x| x.and_then(|x| simdutf8::basic::from_utf8(x).ok()));
+
+                let array = Utf8Array::<i64>::from_trusted_len_iter(iter);
+                Ok(Box::new(array))
+            }
             Timestamp(from_unit, Some(tz)) => {
                 let from = array.as_any().downcast_ref().unwrap();
                 Ok(Box::new(timestamp_to_utf8::<i64>(from, *from_unit, tz)?))
@@ -856,29 +890,36 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
 /// type K and value (dictionary) type value_type
 ///
 /// K is the key type
+///
+/// When the number of distinct values exceeds `options.max_dictionary_size`, the array is
+/// returned cast to `dict_value_type` instead.
 fn cast_to_dictionary<K: DictionaryKey>(
     array: &dyn Array,
     dict_value_type: &DataType,
     options: CastOptions,
 ) -> Result<Box<dyn Array>> {
     let array = cast(array, dict_value_type, options)?;
-    let array = array.as_ref();
-    match *dict_value_type {
-        DataType::Int8 => primitive_to_dictionary_dyn::<i8, K>(array),
-        DataType::Int16 => primitive_to_dictionary_dyn::<i16, K>(array),
-        DataType::Int32 => primitive_to_dictionary_dyn::<i32, K>(array),
-        DataType::Int64 => primitive_to_dictionary_dyn::<i64, K>(array),
-        DataType::UInt8 => primitive_to_dictionary_dyn::<u8, K>(array),
-        DataType::UInt16 => primitive_to_dictionary_dyn::<u16, K>(array),
-        DataType::UInt32 => primitive_to_dictionary_dyn::<u32, K>(array),
-        DataType::UInt64 => primitive_to_dictionary_dyn::<u64, K>(array),
-        DataType::Utf8 => utf8_to_dictionary_dyn::<i32, K>(array),
-        DataType::LargeUtf8 => utf8_to_dictionary_dyn::<i64, K>(array),
-        DataType::Binary => binary_to_dictionary_dyn::<i32, K>(array),
-        DataType::LargeBinary => binary_to_dictionary_dyn::<i64, K>(array),
-        _ => Err(ArrowError::NotYetImplemented(format!(
-            "Unsupported output type for dictionary packing: {:?}",
-            dict_value_type
-        ))),
-    }
+    let max_size = options.max_dictionary_size;
+    let dictionary = {
+        let array = array.as_ref();
+        match *dict_value_type {
+            DataType::Int8 => primitive_to_dictionary_dyn::<i8, K>(array, max_size),
+            DataType::Int16 => primitive_to_dictionary_dyn::<i16, K>(array, max_size),
+            DataType::Int32 => primitive_to_dictionary_dyn::<i32, K>(array, max_size),
+            DataType::Int64 => primitive_to_dictionary_dyn::<i64, K>(array, max_size),
+            DataType::UInt8 => primitive_to_dictionary_dyn::<u8, K>(array, max_size),
+            DataType::UInt16 => primitive_to_dictionary_dyn::<u16, K>(array, max_size),
+            DataType::UInt32 => primitive_to_dictionary_dyn::<u32, K>(array, max_size),
+            DataType::UInt64 => primitive_to_dictionary_dyn::<u64, K>(array, max_size),
+            DataType::Utf8 => utf8_to_dictionary_dyn::<i32, K>(array, max_size),
+            DataType::LargeUtf8 => utf8_to_dictionary_dyn::<i64, K>(array, max_size),
+            DataType::Binary => binary_to_dictionary_dyn::<i32, K>(array, max_size),
+            DataType::LargeBinary => binary_to_dictionary_dyn::<i64, K>(array, max_size),
+            _ => Err(ArrowError::NotYetImplemented(format!(
+                "Unsupported output type for dictionary packing: {:?}",
+                dict_value_type
+            ))),
+        }
+    }?;
+    Ok(dictionary.unwrap_or(array))
 }
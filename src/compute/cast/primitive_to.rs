@@ -1,7 +1,7 @@
 use std::hash::Hash;
 
 use crate::buffer::MutableBuffer;
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 use crate::{
     array::*,
     bitmap::Bitmap,
@@ -11,7 +11,7 @@ use crate::{
     types::NativeType,
 };
 
-use super::CastOptions;
+use super::{dictionary_to::try_extend_capped, CastOptions};
 
 /// Returns a [`BinaryArray`] where every element is the binary representation of the number.
 pub fn primitive_to_binary<T: NativeType + lexical_core::ToLexical, O: Offset>(
@@ -198,9 +198,17 @@ where
 
 pub(super) fn primitive_to_dictionary_dyn<T: NativeType + Eq + Hash, K: DictionaryKey>(
     from: &dyn Array,
-) -> Result<Box<dyn Array>> {
-    let from = from.as_any().downcast_ref().unwrap();
-    primitive_to_dictionary::<T, K>(from).map(|x| Box::new(x) as Box<dyn Array>)
+    max_size: Option<usize>,
+) -> Result<Option<Box<dyn Array>>> {
+    let from = from.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let iter = from.iter().map(|x| x.copied());
+    let mut array = MutableDictionaryArray::<K, _>::from(MutablePrimitiveArray::<T>::from(
+        from.data_type().clone(),
+    ));
+    if !try_extend_capped(&mut array, iter, max_size)? {
+        return Ok(None);
+    }
+    Ok(Some(Box::new(DictionaryArray::<K>::from(array))))
 }
 
 /// Cast [`PrimitiveArray`] to [`DictionaryArray`]. Also known as packing.
@@ -219,16 +227,6 @@ pub fn primitive_to_dictionary<T: NativeType + Eq + Hash, K: DictionaryKey>(
     Ok(array.into())
 }
 
-/// Get the time unit as a multiple of a second
-const fn time_unit_multiple(unit: TimeUnit) -> i64 {
-    match unit {
-        TimeUnit::Second => 1,
-        TimeUnit::Millisecond => MILLISECONDS,
-        TimeUnit::Microsecond => MICROSECONDS,
-        TimeUnit::Nanosecond => NANOSECONDS,
-    }
-}
-
 /// Conversion of dates
 pub fn date32_to_date64(from: &PrimitiveArray<i32>) -> PrimitiveArray<i64> {
     unary(from, |x| x as i64 * MILLISECONDS_IN_DAY, DataType::Date64)
@@ -239,6 +237,55 @@ pub fn date64_to_date32(from: &PrimitiveArray<i64>) -> PrimitiveArray<i32> {
     unary(from, |x| (x / MILLISECONDS_IN_DAY) as i32, DataType::Date32)
 }
 
+/// How [`normalize_date64`] handles [`DataType::Date64`] values that are not at midnight
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Date64Policy {
+    /// The values are floored to the midnight of their day
+    Floor,
+    /// [`normalize_date64`] errors
+    Error,
+}
+
+/// Validates that the values of a [`DataType::Date64`] array are at midnight, i.e. multiples of
+/// [`MILLISECONDS_IN_DAY`] as required by the Arrow specification, handling the values that are
+/// not according to `policy`.
+/// # Errors
+/// This function errors iff `policy` is [`Date64Policy::Error`] and a non-null value is not at
+/// midnight.
+/// # Example
+/// ```
+/// use arrow2::array::Int64Array;
+/// use arrow2::compute::cast::{normalize_date64, Date64Policy};
+/// use arrow2::datatypes::DataType;
+///
+/// let array = Int64Array::from_slice(&[0, 86_400_001]).to(DataType::Date64);
+///
+/// let result = normalize_date64(&array, Date64Policy::Floor).unwrap();
+/// assert_eq!(result, Int64Array::from_slice(&[0, 86_400_000]).to(DataType::Date64));
+/// assert!(normalize_date64(&array, Date64Policy::Error).is_err());
+/// ```
+pub fn normalize_date64(
+    from: &PrimitiveArray<i64>,
+    policy: Date64Policy,
+) -> Result<PrimitiveArray<i64>> {
+    match policy {
+        Date64Policy::Floor => Ok(floor_date64_array(from)),
+        Date64Policy::Error => {
+            if let Some(value) = from
+                .iter()
+                .flatten()
+                .find(|x| **x % MILLISECONDS_IN_DAY != 0)
+            {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Date64 value {} is not at midnight (a multiple of {})",
+                    value, MILLISECONDS_IN_DAY
+                )));
+            }
+            Ok(from.clone())
+        }
+    }
+}
+
 /// Conversion of times
 pub fn time32s_to_time32ms(from: &PrimitiveArray<i32>) -> PrimitiveArray<i32> {
     unary(from, |x| x * 1000, DataType::Time32(TimeUnit::Millisecond))
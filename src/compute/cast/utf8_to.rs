@@ -2,16 +2,22 @@ use std::convert::TryFrom;
 
 use chrono::Datelike;
 
-use crate::{array::*, buffer::Buffer, datatypes::DataType, types::NativeType};
+use crate::{
+    array::*,
+    buffer::Buffer,
+    datatypes::{DataType, IntervalUnit},
+    types::{months_days_ns, NativeType},
+};
 use crate::{
     error::{ArrowError, Result},
     temporal_conversions::{
-        utf8_to_naive_timestamp_ns as utf8_to_naive_timestamp_ns_,
+        months_days_ns_to_days_ms, months_days_ns_to_duration, months_days_ns_to_months,
+        parse_interval, utf8_to_naive_timestamp_ns as utf8_to_naive_timestamp_ns_,
         utf8_to_timestamp_ns as utf8_to_timestamp_ns_, EPOCH_DAYS_FROM_CE,
     },
 };
 
-use super::CastOptions;
+use super::{dictionary_to::try_extend_capped, CastOptions};
 
 const RFC3339: &str = "%Y-%m-%dT%H:%M:%S%.f%:z";
 
@@ -92,11 +98,70 @@ pub(super) fn utf8_to_date64_dyn<O: Offset>(from: &dyn Array) -> Result<Box<dyn
     Ok(Box::new(utf8_to_date64::<O>(from)))
 }
 
-pub(super) fn utf8_to_dictionary_dyn<O: Offset, K: DictionaryKey>(
+fn utf8_to_interval_impl<O: Offset, T: NativeType, F: Fn(months_days_ns) -> Option<T>>(
+    from: &Utf8Array<O>,
+    to_type: &DataType,
+    op: F,
+) -> PrimitiveArray<T> {
+    let iter = from
+        .iter()
+        .map(|x| x.and_then(|x| parse_interval(x).ok().and_then(&op)));
+    PrimitiveArray::<T>::from_trusted_len_iter(iter).to(to_type.clone())
+}
+
+/// Casts a [`Utf8Array`] of SQL interval literals (e.g. `1 year 2 months` or `02:30:00`) to an
+/// array of `to_type`, an `Interval` or a `Duration`, making any uncastable value a Null.
+/// Use [`crate::temporal_conversions::utf8_to_interval`] to error on uncastable values instead.
+/// # Errors
+/// This function errors iff `to_type` is neither an `Interval` nor a `Duration`.
+pub fn utf8_to_interval_lossy<O: Offset>(
+    from: &Utf8Array<O>,
+    to_type: &DataType,
+) -> Result<Box<dyn Array>> {
+    Ok(match to_type.to_logical_type() {
+        DataType::Interval(IntervalUnit::YearMonth) => Box::new(utf8_to_interval_impl(
+            from,
+            to_type,
+            months_days_ns_to_months,
+        )),
+        DataType::Interval(IntervalUnit::DayTime) => Box::new(utf8_to_interval_impl(
+            from,
+            to_type,
+            months_days_ns_to_days_ms,
+        )),
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            Box::new(utf8_to_interval_impl(from, to_type, Some))
+        }
+        DataType::Duration(time_unit) => Box::new(utf8_to_interval_impl(from, to_type, |x| {
+            months_days_ns_to_duration(x, *time_unit)
+        })),
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Utf8 can only be cast to Interval or Duration, not to {:?}",
+                to_type
+            )))
+        }
+    })
+}
+
+pub(super) fn utf8_to_interval_dyn<O: Offset>(
     from: &dyn Array,
+    to_type: &DataType,
 ) -> Result<Box<dyn Array>> {
-    let values = from.as_any().downcast_ref().unwrap();
-    utf8_to_dictionary::<O, K>(values).map(|x| Box::new(x) as Box<dyn Array>)
+    let from = from.as_any().downcast_ref().unwrap();
+    utf8_to_interval_lossy::<O>(from, to_type)
+}
+
+pub(super) fn utf8_to_dictionary_dyn<O: Offset, K: DictionaryKey>(
+    from: &dyn Array,
+    max_size: Option<usize>,
+) -> Result<Option<Box<dyn Array>>> {
+    let values = from.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    let mut array = MutableDictionaryArray::<K, MutableUtf8Array<O>>::new();
+    if !try_extend_capped(&mut array, values.iter(), max_size)? {
+        return Ok(None);
+    }
+    Ok(Some(Box::new(DictionaryArray::<K>::from(array))))
 }
 
 /// Cast [`Utf8Array`] to [`DictionaryArray`], also known as packing.
@@ -166,3 +231,13 @@ pub fn utf8_large_to_utf8(from: &Utf8Array<i64>) -> Result<Utf8Array<i32>> {
         Utf8Array::<i32>::from_data_unchecked(data_type, offsets, values, from.validity().cloned())
     })
 }
+
+/// Conversion of utf8 to binary, which is zero-copy as both have the same layout
+pub fn utf8_to_binary<O: Offset>(from: &Utf8Array<O>, to_data_type: DataType) -> BinaryArray<O> {
+    BinaryArray::<O>::from_data(
+        to_data_type,
+        from.offsets().clone(),
+        from.values().clone(),
+        from.validity().cloned(),
+    )
+}
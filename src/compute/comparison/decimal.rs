@@ -0,0 +1,337 @@
+//! Comparison functions between [`DataType::Decimal`] arrays and integers or floats.
+//!
+//! Contrarily to casting the decimal array to `f64`, these comparisons are exact:
+//! e.g. `0.1` as a decimal is not equal to `0.1_f64` (which is `0.1000000000000000055...`),
+//! and a decimal `12345678901234567.89` is greater than `12345678901234567_i64`.
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+
+use crate::{
+    array::{Array, BooleanArray, PrimitiveArray},
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    types::NativeType,
+};
+
+use super::super::utils::{check_same_len, combine_validities};
+use super::primitive;
+
+/// A number represented in the scale of a decimal array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Scaled {
+    /// The number is smaller than any decimal
+    Below,
+    /// The number is `floor` (in the scale of the decimal) plus a fraction, which is zero iff `exact`
+    Value { floor: i128, exact: bool },
+    /// The number is larger than any decimal
+    Above,
+    /// The number is not a number
+    NaN,
+}
+
+impl Scaled {
+    fn from_int(value: i128, scale: usize) -> Self {
+        match 10i128
+            .checked_pow(scale as u32)
+            .and_then(|x| x.checked_mul(value))
+        {
+            Some(floor) => Scaled::Value { floor, exact: true },
+            None if value < 0 => Scaled::Below,
+            None => Scaled::Above,
+        }
+    }
+
+    fn from_float(value: f64, scale: usize) -> Self {
+        if value.is_nan() {
+            return Scaled::NaN;
+        }
+        if value.is_infinite() {
+            return if value < 0.0 {
+                Scaled::Below
+            } else {
+                Scaled::Above
+            };
+        }
+        let out_of_range = if value < 0.0 {
+            Scaled::Below
+        } else {
+            Scaled::Above
+        };
+
+        // `|value| = mantissa * 2^exponent`
+        let bits = value.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i64;
+        let fraction = bits & ((1 << 52) - 1);
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), biased_exponent - 1075)
+        };
+
+        let (floor, exact) = match scale_mantissa(mantissa, exponent, scale) {
+            Some((floor, exact)) if floor <= i128::MAX as u128 => (floor as i128, exact),
+            _ => return out_of_range,
+        };
+        if value < 0.0 {
+            let floor = if exact { -floor } else { -floor - 1 };
+            Scaled::Value { floor, exact }
+        } else {
+            Scaled::Value { floor, exact }
+        }
+    }
+
+    /// Compares `lhs` (in the scale of the decimal) with this number.
+    /// Returns `None` iff this number is `NaN`.
+    fn cmp(&self, lhs: i128) -> Option<Ordering> {
+        match self {
+            Scaled::Below => Some(Ordering::Greater),
+            Scaled::Above => Some(Ordering::Less),
+            Scaled::NaN => None,
+            Scaled::Value { floor, exact } => Some(match lhs.cmp(floor) {
+                Ordering::Equal if !exact => Ordering::Less,
+                ordering => ordering,
+            }),
+        }
+    }
+}
+
+/// Returns `floor(mantissa * 2^exponent * 10^scale)` and whether it is exact,
+/// or `None` if it does not fit in a `u128`.
+fn scale_mantissa(mantissa: u64, exponent: i64, scale: usize) -> Option<(u128, bool)> {
+    if mantissa == 0 {
+        return Some((0, true));
+    }
+    // `mantissa * 2^exponent * 10^scale = mantissa * 5^scale * 2^(exponent + scale)`
+    let shift = exponent + scale as i64;
+
+    let product = u32::try_from(scale)
+        .ok()
+        .and_then(|scale| 5u128.checked_pow(scale))
+        .and_then(|power| power.checked_mul(mantissa as u128));
+    if let Some(product) = product {
+        return if shift >= 0 {
+            // the result must be smaller than 2^128
+            if shift >= product.leading_zeros() as i64 {
+                None
+            } else {
+                Some((product << shift, true))
+            }
+        } else if -shift >= 128 {
+            Some((0, false))
+        } else {
+            let shift = -shift as u32;
+            Some((product >> shift, product & ((1 << shift) - 1) == 0))
+        };
+    }
+
+    // the product does not fit in a `u128`: use little-endian limbs of 64 bits
+    let mut limbs = vec![mantissa];
+    let mut remaining = scale;
+    while remaining > 0 {
+        let step = remaining.min(27);
+        // 5^27 < 2^64
+        let factor = 5u128.pow(step as u32);
+        let mut carry = 0u128;
+        for limb in limbs.iter_mut() {
+            let x = *limb as u128 * factor + carry;
+            *limb = x as u64;
+            carry = x >> 64;
+        }
+        if carry > 0 {
+            limbs.push(carry as u64);
+        }
+        remaining -= step;
+    }
+    if shift >= 0 {
+        // the product is larger than 2^128 and thus so is the result
+        return None;
+    }
+    let shift = (-shift) as usize;
+    let (skipped, bits) = (shift / 64, shift % 64);
+    let exact = limbs.iter().take(skipped).all(|limb| *limb == 0)
+        && !matches!(limbs.get(skipped), Some(limb) if limb & ((1u64 << bits) - 1) != 0);
+    let mut result = 0u128;
+    for (i, limb) in limbs.iter().enumerate().skip(skipped) {
+        // the bits of this limb after shifting, at position `64 * (i - skipped) - bits`
+        let position = 64 * (i - skipped) as i64 - bits as i64;
+        let value = *limb as u128;
+        let value = if position < 0 {
+            value >> (-position)
+        } else if position >= 128 || (value << position) >> position != value {
+            return None;
+        } else {
+            value << position
+        };
+        result |= value;
+    }
+    Some((result, exact))
+}
+
+fn get_scale(data_type: &DataType) -> usize {
+    match data_type.to_logical_type() {
+        DataType::Decimal(_, scale) => *scale,
+        _ => panic!("Decimal comparisons require a DataType::Decimal array"),
+    }
+}
+
+fn constant(lhs: &PrimitiveArray<i128>, value: bool) -> BooleanArray {
+    let mut values = MutableBitmap::with_capacity(lhs.len());
+    values.extend_constant(lhs.len(), value);
+    BooleanArray::from_data(DataType::Boolean, values.into(), lhs.validity().cloned())
+}
+
+fn compare_scalar<F>(lhs: &PrimitiveArray<i128>, rhs: Scaled, op: F) -> BooleanArray
+where
+    F: Fn(Option<Ordering>) -> bool,
+{
+    // the result is constant unless `rhs` is a value
+    let (floor, exact) = match rhs {
+        Scaled::Value { floor, exact } => (floor, exact),
+        _ => return constant(lhs, op(rhs.cmp(0))),
+    };
+    // `lhs` only takes integer values in the scale of the decimal, so all comparisons
+    // can be written as comparisons against `floor`
+    let less = op(Some(Ordering::Less));
+    let equal = op(Some(Ordering::Equal));
+    let greater = op(Some(Ordering::Greater));
+    match (less, equal, greater) {
+        (false, _, false) if !exact => constant(lhs, false),
+        (true, _, true) if !exact => constant(lhs, true),
+        (false, true, false) => primitive::eq_scalar(lhs, floor),
+        (true, false, true) => primitive::neq_scalar(lhs, floor),
+        // x < floor + fraction <=> x <= floor when the fraction is positive
+        (true, false, false) if !exact => primitive::lt_eq_scalar(lhs, floor),
+        (true, false, false) => primitive::lt_scalar(lhs, floor),
+        (true, true, false) => primitive::lt_eq_scalar(lhs, floor),
+        (false, false, true) => primitive::gt_scalar(lhs, floor),
+        // x >= floor + fraction <=> x > floor when the fraction is positive
+        (false, true, true) if !exact => primitive::gt_scalar(lhs, floor),
+        (false, true, true) => primitive::gt_eq_scalar(lhs, floor),
+        (value, _, _) => constant(lhs, value),
+    }
+}
+
+fn compare<T, S, F>(
+    lhs: &PrimitiveArray<i128>,
+    rhs: &PrimitiveArray<T>,
+    to_scaled: S,
+    op: F,
+) -> BooleanArray
+where
+    T: NativeType,
+    S: Fn(T) -> Scaled,
+    F: Fn(Option<Ordering>) -> bool,
+{
+    check_same_len(lhs, rhs).unwrap();
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let values = lhs
+        .values()
+        .iter()
+        .zip(rhs.values().iter())
+        .map(|(lhs, rhs)| op(to_scaled(*rhs).cmp(*lhs)));
+    let values = MutableBitmap::from_trusted_len_iter(values);
+
+    BooleanArray::from_data(DataType::Boolean, values.into(), validity)
+}
+
+macro_rules! comparison {
+    ($op_name:literal, $int:ident, $int_scalar:ident, $float:ident, $float_scalar:ident, $op:ident) => {
+        #[doc = concat!("Perform `lhs ", $op_name, " rhs` between a decimal array and an integer array.")]
+        /// # Panics
+        /// Panics iff `lhs` is not a decimal array or the arrays have different lengths.
+        pub fn $int<T: NativeType + Into<i128>>(
+            lhs: &PrimitiveArray<i128>,
+            rhs: &PrimitiveArray<T>,
+        ) -> BooleanArray {
+            let scale = get_scale(lhs.data_type());
+            compare(lhs, rhs, |x| Scaled::from_int(x.into(), scale), $op)
+        }
+
+        #[doc = concat!("Perform `lhs ", $op_name, " rhs` between a decimal array and an integer.")]
+        /// # Panics
+        /// Panics iff `lhs` is not a decimal array.
+        pub fn $int_scalar(lhs: &PrimitiveArray<i128>, rhs: i128) -> BooleanArray {
+            let scale = get_scale(lhs.data_type());
+            compare_scalar(lhs, Scaled::from_int(rhs, scale), $op)
+        }
+
+        #[doc = concat!("Perform `lhs ", $op_name, " rhs` between a decimal array and a float array.")]
+        /// # Panics
+        /// Panics iff `lhs` is not a decimal array or the arrays have different lengths.
+        pub fn $float<T: NativeType + Into<f64>>(
+            lhs: &PrimitiveArray<i128>,
+            rhs: &PrimitiveArray<T>,
+        ) -> BooleanArray {
+            let scale = get_scale(lhs.data_type());
+            compare(lhs, rhs, |x| Scaled::from_float(x.into(), scale), $op)
+        }
+
+        #[doc = concat!("Perform `lhs ", $op_name, " rhs` between a decimal array and a float.")]
+        /// # Panics
+        /// Panics iff `lhs` is not a decimal array.
+        pub fn $float_scalar(lhs: &PrimitiveArray<i128>, rhs: f64) -> BooleanArray {
+            let scale = get_scale(lhs.data_type());
+            compare_scalar(lhs, Scaled::from_float(rhs, scale), $op)
+        }
+    };
+}
+
+fn is_eq(ordering: Option<Ordering>) -> bool {
+    ordering == Some(Ordering::Equal)
+}
+
+fn is_neq(ordering: Option<Ordering>) -> bool {
+    ordering != Some(Ordering::Equal)
+}
+
+fn is_lt(ordering: Option<Ordering>) -> bool {
+    ordering == Some(Ordering::Less)
+}
+
+fn is_lt_eq(ordering: Option<Ordering>) -> bool {
+    matches!(ordering, Some(Ordering::Less | Ordering::Equal))
+}
+
+fn is_gt(ordering: Option<Ordering>) -> bool {
+    ordering == Some(Ordering::Greater)
+}
+
+fn is_gt_eq(ordering: Option<Ordering>) -> bool {
+    matches!(ordering, Some(Ordering::Greater | Ordering::Equal))
+}
+
+comparison!(
+    "==",
+    eq_int,
+    eq_int_scalar,
+    eq_float,
+    eq_float_scalar,
+    is_eq
+);
+comparison!(
+    "!=",
+    neq_int,
+    neq_int_scalar,
+    neq_float,
+    neq_float_scalar,
+    is_neq
+);
+comparison!("<", lt_int, lt_int_scalar, lt_float, lt_float_scalar, is_lt);
+comparison!(
+    "<=",
+    lt_eq_int,
+    lt_eq_int_scalar,
+    lt_eq_float,
+    lt_eq_float_scalar,
+    is_lt_eq
+);
+comparison!(">", gt_int, gt_int_scalar, gt_float, gt_float_scalar, is_gt);
+comparison!(
+    ">=",
+    gt_eq_int,
+    gt_eq_int_scalar,
+    gt_eq_float,
+    gt_eq_float_scalar,
+    is_gt_eq
+);
@@ -0,0 +1,149 @@
+//! Comparison functions for [`FixedSizeBinaryArray`]
+use std::cmp::Ordering;
+
+use crate::{
+    array::{ord::fixed_size_cmp, BooleanArray, FixedSizeBinaryArray},
+    bitmap::Bitmap,
+    datatypes::DataType,
+};
+
+use super::super::utils::combine_validities;
+
+/// Evaluate `op(lhs, rhs)` for [`FixedSizeBinaryArray`]s using a specified
+/// comparison function.
+fn compare_op<F>(lhs: &FixedSizeBinaryArray, rhs: &FixedSizeBinaryArray, op: F) -> BooleanArray
+where
+    F: Fn(&[u8], &[u8]) -> bool,
+{
+    assert_eq!(lhs.len(), rhs.len());
+    assert_eq!(lhs.size(), rhs.size());
+
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let values = (0..lhs.len()).map(|i| op(lhs.value(i), rhs.value(i)));
+    // safety: `Range` reports its length correctly
+    let values = unsafe { Bitmap::from_trusted_len_iter_unchecked(values) };
+
+    BooleanArray::from_data(DataType::Boolean, values, validity)
+}
+
+/// Evaluate `op(lhs, rhs)` for [`FixedSizeBinaryArray`] and scalar using
+/// a specified comparison function.
+fn compare_op_scalar<F>(lhs: &FixedSizeBinaryArray, rhs: &[u8], op: F) -> BooleanArray
+where
+    F: Fn(&[u8], &[u8]) -> bool,
+{
+    assert_eq!(lhs.size(), rhs.len());
+
+    let validity = lhs.validity().cloned();
+
+    let values = (0..lhs.len()).map(|i| op(lhs.value(i), rhs));
+    // safety: `Range` reports its length correctly
+    let values = unsafe { Bitmap::from_trusted_len_iter_unchecked(values) };
+
+    BooleanArray::from_data(DataType::Boolean, values, validity)
+}
+
+// Evaluate whether the order of `lhs` and `rhs` is one of `orderings`
+fn compare_order(
+    lhs: &FixedSizeBinaryArray,
+    rhs: &FixedSizeBinaryArray,
+    orderings: &[Ordering],
+) -> BooleanArray {
+    let cmp = fixed_size_cmp(lhs.size());
+    compare_op(lhs, rhs, |a, b| orderings.contains(&cmp(a, b)))
+}
+
+// Evaluate whether the order of `lhs` and the scalar `rhs` is one of `orderings`
+fn compare_order_scalar(
+    lhs: &FixedSizeBinaryArray,
+    rhs: &[u8],
+    orderings: &[Ordering],
+) -> BooleanArray {
+    let cmp = fixed_size_cmp(lhs.size());
+    compare_op_scalar(lhs, rhs, |a, b| orderings.contains(&cmp(a, b)))
+}
+
+/// Perform `lhs == rhs` operation on [`FixedSizeBinaryArray`].
+/// # Panic
+/// iff the arrays do not have the same length or size.
+pub fn eq(lhs: &FixedSizeBinaryArray, rhs: &FixedSizeBinaryArray) -> BooleanArray {
+    compare_op(lhs, rhs, |a, b| a == b)
+}
+
+/// Perform `lhs == rhs` operation on [`FixedSizeBinaryArray`] and a scalar.
+/// # Panic
+/// iff the scalar does not have the size of the array.
+pub fn eq_scalar(lhs: &FixedSizeBinaryArray, rhs: &[u8]) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, |a, b| a == b)
+}
+
+/// Perform `lhs != rhs` operation on [`FixedSizeBinaryArray`].
+/// # Panic
+/// iff the arrays do not have the same length or size.
+pub fn neq(lhs: &FixedSizeBinaryArray, rhs: &FixedSizeBinaryArray) -> BooleanArray {
+    compare_op(lhs, rhs, |a, b| a != b)
+}
+
+/// Perform `lhs != rhs` operation on [`FixedSizeBinaryArray`] and a scalar.
+/// # Panic
+/// iff the scalar does not have the size of the array.
+pub fn neq_scalar(lhs: &FixedSizeBinaryArray, rhs: &[u8]) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, |a, b| a != b)
+}
+
+/// Perform `lhs < rhs` operation on [`FixedSizeBinaryArray`].
+/// # Panic
+/// iff the arrays do not have the same length or size.
+pub fn lt(lhs: &FixedSizeBinaryArray, rhs: &FixedSizeBinaryArray) -> BooleanArray {
+    compare_order(lhs, rhs, &[Ordering::Less])
+}
+
+/// Perform `lhs < rhs` operation on [`FixedSizeBinaryArray`] and a scalar.
+/// # Panic
+/// iff the scalar does not have the size of the array.
+pub fn lt_scalar(lhs: &FixedSizeBinaryArray, rhs: &[u8]) -> BooleanArray {
+    compare_order_scalar(lhs, rhs, &[Ordering::Less])
+}
+
+/// Perform `lhs <= rhs` operation on [`FixedSizeBinaryArray`].
+/// # Panic
+/// iff the arrays do not have the same length or size.
+pub fn lt_eq(lhs: &FixedSizeBinaryArray, rhs: &FixedSizeBinaryArray) -> BooleanArray {
+    compare_order(lhs, rhs, &[Ordering::Less, Ordering::Equal])
+}
+
+/// Perform `lhs <= rhs` operation on [`FixedSizeBinaryArray`] and a scalar.
+/// # Panic
+/// iff the scalar does not have the size of the array.
+pub fn lt_eq_scalar(lhs: &FixedSizeBinaryArray, rhs: &[u8]) -> BooleanArray {
+    compare_order_scalar(lhs, rhs, &[Ordering::Less, Ordering::Equal])
+}
+
+/// Perform `lhs > rhs` operation on [`FixedSizeBinaryArray`].
+/// # Panic
+/// iff the arrays do not have the same length or size.
+pub fn gt(lhs: &FixedSizeBinaryArray, rhs: &FixedSizeBinaryArray) -> BooleanArray {
+    compare_order(lhs, rhs, &[Ordering::Greater])
+}
+
+/// Perform `lhs > rhs` operation on [`FixedSizeBinaryArray`] and a scalar.
+/// # Panic
+/// iff the scalar does not have the size of the array.
+pub fn gt_scalar(lhs: &FixedSizeBinaryArray, rhs: &[u8]) -> BooleanArray {
+    compare_order_scalar(lhs, rhs, &[Ordering::Greater])
+}
+
+/// Perform `lhs >= rhs` operation on [`FixedSizeBinaryArray`].
+/// # Panic
+/// iff the arrays do not have the same length or size.
+pub fn gt_eq(lhs: &FixedSizeBinaryArray, rhs: &FixedSizeBinaryArray) -> BooleanArray {
+    compare_order(lhs, rhs, &[Ordering::Greater, Ordering::Equal])
+}
+
+/// Perform `lhs >= rhs` operation on [`FixedSizeBinaryArray`] and a scalar.
+/// # Panic
+/// iff the scalar does not have the size of the array.
+pub fn gt_eq_scalar(lhs: &FixedSizeBinaryArray, rhs: &[u8]) -> BooleanArray {
+    compare_order_scalar(lhs, rhs, &[Ordering::Greater, Ordering::Equal])
+}
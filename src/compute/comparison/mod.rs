@@ -50,6 +50,8 @@ use crate::scalar::*;
 
 pub mod binary;
 pub mod boolean;
+pub mod decimal;
+pub mod fixed_size_binary;
 pub mod primitive;
 pub mod utf8;
 
@@ -80,6 +82,8 @@ macro_rules! with_match_primitive_cmp {(
     }
 })}
 
+pub mod selection;
+
 macro_rules! compare {
     ($lhs:expr, $rhs:expr, $op:tt) => {{
         let lhs = $lhs;
@@ -121,6 +125,11 @@ macro_rules! compare {
                 let rhs = rhs.as_any().downcast_ref().unwrap();
                 binary::$op::<i64>(lhs, rhs)
             }
+            FixedSizeBinary => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                fixed_size_binary::$op(lhs, rhs)
+            }
             _ => todo!(
                 "Comparison between {:?} are not yet supported",
                 lhs.data_type()
@@ -240,6 +249,14 @@ macro_rules! compare_scalar {
                 let rhs = rhs.as_any().downcast_ref::<BinaryScalar<i64>>().unwrap();
                 binary::$op::<i64>(lhs, rhs.value().unwrap())
             }
+            FixedSizeBinary => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs
+                    .as_any()
+                    .downcast_ref::<FixedSizeBinaryScalar>()
+                    .unwrap();
+                fixed_size_binary::$op(lhs, rhs.value().unwrap())
+            }
             _ => todo!("Comparisons of {:?} are not yet supported", lhs.data_type()),
         }
     }};
@@ -362,5 +379,6 @@ fn can_compare(data_type: &DataType) -> bool {
             | DataType::Decimal(_, _)
             | DataType::Binary
             | DataType::LargeBinary
+            | DataType::FixedSizeBinary(_)
     )
 }
@@ -0,0 +1,452 @@
+//! Comparison kernels that only evaluate a selection of rows and write their result to a mask.
+//!
+//! Each kernel sets the position `i` of `mask` to whether row `i` is valid and satisfies
+//! the comparison, for every selected row `i`, and leaves the other positions untouched.
+//! A filter with multiple predicates can thus evaluate each predicate only on the rows
+//! that passed the previous ones.
+//!
+//! # Example
+//! ```
+//! use arrow2::array::{Int32Array, Utf8Array};
+//! use arrow2::bitmap::MutableBitmap;
+//! use arrow2::compute::comparison::selection::{gt_scalar, eq_scalar, selected_indices, Selection};
+//! use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
+//! use arrow2::datatypes::DataType;
+//!
+//! let a = Int32Array::from(&[Some(1), Some(5), None, Some(7)]);
+//! let b = Utf8Array::<i32>::from_slice(&["x", "y", "x", "x"]);
+//!
+//! // a > 2 AND b == "x"
+//! let mut mask = MutableBitmap::from_len_zeroed(a.len());
+//! gt_scalar(&a, &PrimitiveScalar::new(DataType::Int32, Some(2)), None, &mut mask);
+//! let indices = selected_indices(&mask);
+//! let selection = Selection::Indices(&indices);
+//! eq_scalar(&b, &Utf8Scalar::<i32>::new(Some("x")), Some(&selection), &mut mask);
+//!
+//! assert_eq!(mask.iter().collect::<Vec<_>>(), vec![false, false, false, true]);
+//! ```
+use std::cmp::Ordering;
+use std::ops::Range;
+
+use crate::array::*;
+use crate::bitmap::MutableBitmap;
+use crate::scalar::*;
+
+/// The rows evaluated by the kernels of this module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Selection<'a> {
+    /// The rows at these indices.
+    Indices(&'a [usize]),
+    /// The rows in these ranges.
+    Ranges(&'a [Range<usize>]),
+}
+
+/// Returns the indices of the set positions of `mask`, e.g. to select the rows
+/// that satisfied a previous predicate.
+pub fn selected_indices(mask: &MutableBitmap) -> Vec<usize> {
+    mask.iter()
+        .enumerate()
+        .filter_map(|(index, is_set)| if is_set { Some(index) } else { None })
+        .collect()
+}
+
+/// Sets every selected position `i` of `mask` to `f(i)`.
+fn apply<F: Fn(usize) -> bool>(len: usize, selection: &Selection, mask: &mut MutableBitmap, f: F) {
+    let mut set = |i: usize| {
+        assert!(i < len, "selected row {} is out of bounds", i);
+        mask.set(i, f(i))
+    };
+    match selection {
+        Selection::Indices(indices) => indices.iter().for_each(|&i| set(i)),
+        Selection::Ranges(ranges) => ranges.iter().flat_map(|r| r.clone()).for_each(set),
+    }
+}
+
+/// Overwrites `mask` with the valid and set positions of `result`.
+fn write_all(result: BooleanArray, mask: &mut MutableBitmap) {
+    let values = match result.validity() {
+        Some(validity) => result.values() & validity,
+        None => result.values().clone(),
+    };
+    mask.clear();
+    mask.extend_from_bitmap(&values);
+}
+
+macro_rules! compare_rows {
+    ($lhs:expr, $rhs:expr, $array:ty, $selection:expr, $mask:expr, $op:expr) => {{
+        let lhs = $lhs.as_any().downcast_ref::<$array>().unwrap();
+        let rhs = $rhs.as_any().downcast_ref::<$array>().unwrap();
+        apply(lhs.len(), $selection, $mask, |i| {
+            lhs.is_valid(i)
+                && rhs.is_valid(i)
+                && $op(PartialOrd::partial_cmp(&lhs.value(i), &rhs.value(i)))
+        })
+    }};
+}
+
+macro_rules! compare {
+    ($lhs:expr, $rhs:expr, $selection:expr, $mask:expr, $kernel:tt, $op:expr) => {{
+        let lhs = $lhs;
+        let rhs = $rhs;
+        let mask = $mask;
+        assert_eq!(
+            lhs.data_type().to_logical_type(),
+            rhs.data_type().to_logical_type()
+        );
+        assert_eq!(lhs.len(), rhs.len());
+        assert_eq!(lhs.len(), mask.len());
+
+        let selection = match $selection {
+            Some(selection) => selection,
+            None => return write_all(super::$kernel(lhs, rhs), mask),
+        };
+
+        use crate::datatypes::PhysicalType::*;
+        match lhs.data_type().to_physical_type() {
+            Boolean => compare_rows!(lhs, rhs, BooleanArray, selection, mask, $op),
+            Primitive(primitive) => with_match_primitive_cmp!(primitive, |$T| {
+                compare_rows!(lhs, rhs, PrimitiveArray<$T>, selection, mask, $op)
+            }),
+            Utf8 => compare_rows!(lhs, rhs, Utf8Array<i32>, selection, mask, $op),
+            LargeUtf8 => compare_rows!(lhs, rhs, Utf8Array<i64>, selection, mask, $op),
+            Binary => compare_rows!(lhs, rhs, BinaryArray<i32>, selection, mask, $op),
+            LargeBinary => compare_rows!(lhs, rhs, BinaryArray<i64>, selection, mask, $op),
+            FixedSizeBinary => {
+                compare_rows!(lhs, rhs, FixedSizeBinaryArray, selection, mask, $op)
+            }
+            _ => todo!(
+                "Comparison between {:?} are not yet supported",
+                lhs.data_type()
+            ),
+        }
+    }};
+}
+
+macro_rules! compare_rows_scalar {
+    ($lhs:expr, $rhs:expr, $array:ty, $scalar:ty, $selection:expr, $mask:expr, $op:expr) => {{
+        let lhs = $lhs.as_any().downcast_ref::<$array>().unwrap();
+        // validity checked by the caller
+        let rhs = $rhs
+            .as_any()
+            .downcast_ref::<$scalar>()
+            .unwrap()
+            .value()
+            .unwrap();
+        apply(lhs.len(), $selection, $mask, |i| {
+            lhs.is_valid(i) && $op(PartialOrd::partial_cmp(&lhs.value(i), &rhs))
+        })
+    }};
+}
+
+macro_rules! compare_scalar {
+    ($lhs:expr, $rhs:expr, $selection:expr, $mask:expr, $kernel:tt, $op:expr) => {{
+        let lhs = $lhs;
+        let rhs = $rhs;
+        let mask = $mask;
+        assert_eq!(
+            lhs.data_type().to_logical_type(),
+            rhs.data_type().to_logical_type()
+        );
+        assert_eq!(lhs.len(), mask.len());
+
+        let selection = match $selection {
+            Some(selection) => selection,
+            None => return write_all(super::$kernel(lhs, rhs), mask),
+        };
+        if !rhs.is_valid() {
+            return apply(lhs.len(), selection, mask, |_| false);
+        }
+
+        use crate::datatypes::PhysicalType::*;
+        match lhs.data_type().to_physical_type() {
+            Boolean => {
+                compare_rows_scalar!(lhs, rhs, BooleanArray, BooleanScalar, selection, mask, $op)
+            }
+            Primitive(primitive) => with_match_primitive_cmp!(primitive, |$T| {
+                compare_rows_scalar!(
+                    lhs,
+                    rhs,
+                    PrimitiveArray<$T>,
+                    PrimitiveScalar<$T>,
+                    selection,
+                    mask,
+                    $op
+                )
+            }),
+            Utf8 => compare_rows_scalar!(
+                lhs,
+                rhs,
+                Utf8Array<i32>,
+                Utf8Scalar<i32>,
+                selection,
+                mask,
+                $op
+            ),
+            LargeUtf8 => compare_rows_scalar!(
+                lhs,
+                rhs,
+                Utf8Array<i64>,
+                Utf8Scalar<i64>,
+                selection,
+                mask,
+                $op
+            ),
+            Binary => compare_rows_scalar!(
+                lhs,
+                rhs,
+                BinaryArray<i32>,
+                BinaryScalar<i32>,
+                selection,
+                mask,
+                $op
+            ),
+            LargeBinary => compare_rows_scalar!(
+                lhs,
+                rhs,
+                BinaryArray<i64>,
+                BinaryScalar<i64>,
+                selection,
+                mask,
+                $op
+            ),
+            FixedSizeBinary => compare_rows_scalar!(
+                lhs,
+                rhs,
+                FixedSizeBinaryArray,
+                FixedSizeBinaryScalar,
+                selection,
+                mask,
+                $op
+            ),
+            _ => todo!("Comparisons of {:?} are not yet supported", lhs.data_type()),
+        }
+    }};
+}
+
+#[inline]
+fn is_eq(ordering: Option<Ordering>) -> bool {
+    ordering == Some(Ordering::Equal)
+}
+
+#[inline]
+fn is_neq(ordering: Option<Ordering>) -> bool {
+    ordering != Some(Ordering::Equal)
+}
+
+#[inline]
+fn is_lt(ordering: Option<Ordering>) -> bool {
+    ordering == Some(Ordering::Less)
+}
+
+#[inline]
+fn is_lt_eq(ordering: Option<Ordering>) -> bool {
+    matches!(ordering, Some(Ordering::Less | Ordering::Equal))
+}
+
+#[inline]
+fn is_gt(ordering: Option<Ordering>) -> bool {
+    ordering == Some(Ordering::Greater)
+}
+
+#[inline]
+fn is_gt_eq(ordering: Option<Ordering>) -> bool {
+    matches!(ordering, Some(Ordering::Greater | Ordering::Equal))
+}
+
+/// `==` between two [`Array`]s on the rows of `selection` (or all rows), written to `mask`.
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays and `mask` do not have the same length
+/// * a selected row is out of bounds
+/// * the operation is not supported for the logical type
+pub fn eq(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+    selection: Option<&Selection>,
+    mask: &mut MutableBitmap,
+) {
+    compare!(lhs, rhs, selection, mask, eq, is_eq)
+}
+
+/// `!=` between two [`Array`]s on the rows of `selection` (or all rows), written to `mask`.
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays and `mask` do not have the same length
+/// * a selected row is out of bounds
+/// * the operation is not supported for the logical type
+pub fn neq(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+    selection: Option<&Selection>,
+    mask: &mut MutableBitmap,
+) {
+    compare!(lhs, rhs, selection, mask, neq, is_neq)
+}
+
+/// `<` between two [`Array`]s on the rows of `selection` (or all rows), written to `mask`.
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays and `mask` do not have the same length
+/// * a selected row is out of bounds
+/// * the operation is not supported for the logical type
+pub fn lt(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+    selection: Option<&Selection>,
+    mask: &mut MutableBitmap,
+) {
+    compare!(lhs, rhs, selection, mask, lt, is_lt)
+}
+
+/// `<=` between two [`Array`]s on the rows of `selection` (or all rows), written to `mask`.
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays and `mask` do not have the same length
+/// * a selected row is out of bounds
+/// * the operation is not supported for the logical type
+pub fn lt_eq(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+    selection: Option<&Selection>,
+    mask: &mut MutableBitmap,
+) {
+    compare!(lhs, rhs, selection, mask, lt_eq, is_lt_eq)
+}
+
+/// `>` between two [`Array`]s on the rows of `selection` (or all rows), written to `mask`.
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays and `mask` do not have the same length
+/// * a selected row is out of bounds
+/// * the operation is not supported for the logical type
+pub fn gt(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+    selection: Option<&Selection>,
+    mask: &mut MutableBitmap,
+) {
+    compare!(lhs, rhs, selection, mask, gt, is_gt)
+}
+
+/// `>=` between two [`Array`]s on the rows of `selection` (or all rows), written to `mask`.
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays and `mask` do not have the same length
+/// * a selected row is out of bounds
+/// * the operation is not supported for the logical type
+pub fn gt_eq(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+    selection: Option<&Selection>,
+    mask: &mut MutableBitmap,
+) {
+    compare!(lhs, rhs, selection, mask, gt_eq, is_gt_eq)
+}
+
+/// `==` between an [`Array`] and a [`Scalar`] on the rows of `selection` (or all rows),
+/// written to `mask`.
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the array and `mask` do not have the same length
+/// * a selected row is out of bounds
+/// * the operation is not supported for the logical type
+pub fn eq_scalar(
+    lhs: &dyn Array,
+    rhs: &dyn Scalar,
+    selection: Option<&Selection>,
+    mask: &mut MutableBitmap,
+) {
+    compare_scalar!(lhs, rhs, selection, mask, eq_scalar, is_eq)
+}
+
+/// `!=` between an [`Array`] and a [`Scalar`] on the rows of `selection` (or all rows),
+/// written to `mask`.
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the array and `mask` do not have the same length
+/// * a selected row is out of bounds
+/// * the operation is not supported for the logical type
+pub fn neq_scalar(
+    lhs: &dyn Array,
+    rhs: &dyn Scalar,
+    selection: Option<&Selection>,
+    mask: &mut MutableBitmap,
+) {
+    compare_scalar!(lhs, rhs, selection, mask, neq_scalar, is_neq)
+}
+
+/// `<` between an [`Array`] and a [`Scalar`] on the rows of `selection` (or all rows),
+/// written to `mask`.
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the array and `mask` do not have the same length
+/// * a selected row is out of bounds
+/// * the operation is not supported for the logical type
+pub fn lt_scalar(
+    lhs: &dyn Array,
+    rhs: &dyn Scalar,
+    selection: Option<&Selection>,
+    mask: &mut MutableBitmap,
+) {
+    compare_scalar!(lhs, rhs, selection, mask, lt_scalar, is_lt)
+}
+
+/// `<=` between an [`Array`] and a [`Scalar`] on the rows of `selection` (or all rows),
+/// written to `mask`.
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the array and `mask` do not have the same length
+/// * a selected row is out of bounds
+/// * the operation is not supported for the logical type
+pub fn lt_eq_scalar(
+    lhs: &dyn Array,
+    rhs: &dyn Scalar,
+    selection: Option<&Selection>,
+    mask: &mut MutableBitmap,
+) {
+    compare_scalar!(lhs, rhs, selection, mask, lt_eq_scalar, is_lt_eq)
+}
+
+/// `>` between an [`Array`] and a [`Scalar`] on the rows of `selection` (or all rows),
+/// written to `mask`.
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the array and `mask` do not have the same length
+/// * a selected row is out of bounds
+/// * the operation is not supported for the logical type
+pub fn gt_scalar(
+    lhs: &dyn Array,
+    rhs: &dyn Scalar,
+    selection: Option<&Selection>,
+    mask: &mut MutableBitmap,
+) {
+    compare_scalar!(lhs, rhs, selection, mask, gt_scalar, is_gt)
+}
+
+/// `>=` between an [`Array`] and a [`Scalar`] on the rows of `selection` (or all rows),
+/// written to `mask`.
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the array and `mask` do not have the same length
+/// * a selected row is out of bounds
+/// * the operation is not supported for the logical type
+pub fn gt_eq_scalar(
+    lhs: &dyn Array,
+    rhs: &dyn Scalar,
+    selection: Option<&Selection>,
+    mask: &mut MutableBitmap,
+) {
+    compare_scalar!(lhs, rhs, selection, mask, gt_eq_scalar, is_gt_eq)
+}
@@ -44,3 +44,32 @@ pub fn concatenate(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
 
     Ok(mutable.as_box())
 }
+
+/// Concatenates multiple [Array]s like [`concatenate`], also returning the offsets of each
+/// of them in the output.
+///
+/// The offsets have `arrays.len() + 1` entries starting at 0, so that the rows
+/// `offsets[i]..offsets[i + 1]` of the output are the rows of `arrays[i]`.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::concatenate::concatenate_with_offsets;
+///
+/// let (array, offsets) = concatenate_with_offsets(&[
+///     &Int32Array::from_slice([1, 2]),
+///     &Int32Array::from_slice([]),
+///     &Int32Array::from_slice([3]),
+/// ]).unwrap();
+/// assert_eq!(array.len(), 3);
+/// assert_eq!(offsets, vec![0, 2, 2, 3]);
+/// ```
+pub fn concatenate_with_offsets(arrays: &[&dyn Array]) -> Result<(Box<dyn Array>, Vec<usize>)> {
+    let array = concatenate(arrays)?;
+    let offsets = std::iter::once(0)
+        .chain(arrays.iter().scan(0, |offset, array| {
+            *offset += array.len();
+            Some(*offset)
+        }))
+        .collect();
+    Ok((array, offsets))
+}
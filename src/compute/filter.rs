@@ -1,14 +1,21 @@
 //! Contains operators to filter arrays such as [`filter`].
+use multiversion::multiversion;
+
 use crate::array::growable::{make_growable, Growable};
 use crate::bitmap::{utils::SlicesIterator, Bitmap, MutableBitmap};
 use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
 use crate::record_batch::RecordBatch;
-use crate::{array::*, types::NativeType};
-use crate::{buffer::MutableBuffer, error::Result};
+use crate::types::Index;
+use crate::{array::*, buffer::MutableBuffer, types::NativeType};
 
 /// Function that can filter arbitrary arrays
 pub type Filter<'a> = Box<dyn Fn(&dyn Array) -> Box<dyn Array> + 'a + Send + Sync>;
 
+#[multiversion]
+#[cfg_attr(feature = "simd_avx512", clone(target = "x86_64+avx512f"))]
+#[clone(target = "x86_64+avx+avx2")]
+#[clone(target = "aarch64+neon")]
 fn filter_nonnull_primitive<T: NativeType>(
     array: &PrimitiveArray<T>,
     mask: &Bitmap,
@@ -154,6 +161,56 @@ pub fn filter(array: &dyn Array, filter: &BooleanArray) -> Result<Box<dyn Array>
     }
 }
 
+/// Filters an [Array] like [`filter`], also returning the indices of `array` that were
+/// selected, i.e. the input row of each output row.
+///
+/// The indices are a non-nullable [`PrimitiveArray`] of the same shape as the one returned by
+/// [`sort_to_indices`](crate::compute::sort::sort_to_indices), so that the lineage of rows
+/// can be tracked (e.g. with [`take`](crate::compute::take::take)) through multiple kernels.
+/// # Example
+/// ```rust
+/// # use arrow2::array::{Int32Array, PrimitiveArray, BooleanArray, UInt32Array};
+/// # use arrow2::error::Result;
+/// # use arrow2::compute::filter::filter_with_indices;
+/// # fn main() -> Result<()> {
+/// let array = PrimitiveArray::from_slice([5, 6, 7, 8, 9]);
+/// let filter_array = BooleanArray::from(&[Some(true), Some(false), None, Some(true), Some(true)]);
+/// let (c, indices) = filter_with_indices::<u32>(&array, &filter_array)?;
+/// let c = c.as_any().downcast_ref::<Int32Array>().unwrap();
+/// assert_eq!(c, &PrimitiveArray::from_slice(vec![5, 8, 9]));
+/// assert_eq!(indices, UInt32Array::from_slice(&[0, 3, 4]));
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// Errors if `I` cannot represent the largest index of `array`, `array.len() - 1`.
+pub fn filter_with_indices<I: Index>(
+    array: &dyn Array,
+    filter: &BooleanArray,
+) -> Result<(Box<dyn Array>, PrimitiveArray<I>)> {
+    if I::from_usize(array.len().saturating_sub(1)).is_none() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Arrays of length {} cannot be indexed by indices of type {:?}",
+            array.len(),
+            I::DATA_TYPE
+        )));
+    }
+    let mask = match filter.validity() {
+        Some(validity) => filter.values() & validity,
+        None => filter.values().clone(),
+    };
+
+    let iter = SlicesIterator::new(&mask);
+    let mut indices = MutableBuffer::<I>::with_capacity(iter.slots());
+    iter.for_each(|(start, len)| {
+        indices.extend((start..start + len).map(|index| I::from_usize(index).unwrap()))
+    });
+    let indices = PrimitiveArray::<I>::from_data(I::DATA_TYPE, indices.into(), None);
+
+    let filter = BooleanArray::from_data(DataType::Boolean, mask, None);
+    Ok((self::filter(array, &filter)?, indices))
+}
+
 /// Returns a new [RecordBatch] with arrays containing only values matching the filter.
 /// WARNING: the nulls of `filter` are ignored and the value on its slot is considered.
 /// Therefore, it is considered undefined behavior to pass `filter` with null values.
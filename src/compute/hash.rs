@@ -3,7 +3,7 @@
 #![allow(missing_docs)]
 use ahash::{CallHasher, RandomState};
 use multiversion::multiversion;
-use std::hash::Hash;
+use std::hash::{BuildHasher, Hash, Hasher};
 
 macro_rules! new_state {
     () => {
@@ -62,6 +62,190 @@ pub fn hash_binary<O: Offset>(array: &BinaryArray<O>) -> PrimitiveArray<u64> {
     PrimitiveArray::<u64>::from_data(DataType::UInt64, values, array.validity().cloned())
 }
 
+/// A [`BuildHasher`] of [`SipHasher24`] keyed by two `u64`.
+///
+/// Together with [`hash_with`], its hashes are stable across versions of this crate
+/// and across platforms.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct StableState {
+    k0: u64,
+    k1: u64,
+}
+
+impl StableState {
+    /// Creates a new [`StableState`] with the key `(k0, k1)`.
+    pub fn with_keys(k0: u64, k1: u64) -> Self {
+        Self { k0, k1 }
+    }
+}
+
+impl BuildHasher for StableState {
+    type Hasher = SipHasher24;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        SipHasher24::new_with_keys(self.k0, self.k1)
+    }
+}
+
+/// A [`Hasher`] implementing [SipHash-2-4](https://www.aumasson.jp/siphash/siphash.pdf)
+/// with a 64-bit output.
+#[derive(Debug, Clone)]
+pub struct SipHasher24 {
+    v0: u64,
+    v1: u64,
+    v2: u64,
+    v3: u64,
+    // the bytes not yet compressed, in little endian
+    tail: u64,
+    tail_length: usize,
+    length: usize,
+}
+
+impl SipHasher24 {
+    /// Creates a new [`SipHasher24`] with the key `(k0, k1)`.
+    pub fn new_with_keys(k0: u64, k1: u64) -> Self {
+        Self {
+            v0: k0 ^ 0x736f6d6570736575,
+            v1: k1 ^ 0x646f72616e646f6d,
+            v2: k0 ^ 0x6c7967656e657261,
+            v3: k1 ^ 0x7465646279746573,
+            tail: 0,
+            tail_length: 0,
+            length: 0,
+        }
+    }
+
+    #[inline]
+    fn round(&mut self) {
+        self.v0 = self.v0.wrapping_add(self.v1);
+        self.v1 = self.v1.rotate_left(13) ^ self.v0;
+        self.v0 = self.v0.rotate_left(32);
+        self.v2 = self.v2.wrapping_add(self.v3);
+        self.v3 = self.v3.rotate_left(16) ^ self.v2;
+        self.v0 = self.v0.wrapping_add(self.v3);
+        self.v3 = self.v3.rotate_left(21) ^ self.v0;
+        self.v2 = self.v2.wrapping_add(self.v1);
+        self.v1 = self.v1.rotate_left(17) ^ self.v2;
+        self.v2 = self.v2.rotate_left(32);
+    }
+
+    #[inline]
+    fn compress(&mut self, m: u64) {
+        self.v3 ^= m;
+        self.round();
+        self.round();
+        self.v0 ^= m;
+    }
+}
+
+impl Hasher for SipHasher24 {
+    fn write(&mut self, mut bytes: &[u8]) {
+        self.length += bytes.len();
+
+        // complete the tail
+        if self.tail_length > 0 {
+            while self.tail_length < 8 {
+                match bytes.split_first() {
+                    Some((byte, remaining)) => {
+                        self.tail |= (*byte as u64) << (8 * self.tail_length);
+                        self.tail_length += 1;
+                        bytes = remaining;
+                    }
+                    None => return,
+                }
+            }
+            let tail = self.tail;
+            self.compress(tail);
+            self.tail = 0;
+            self.tail_length = 0;
+        }
+
+        let chunks = bytes.chunks_exact(8);
+        let remainder = chunks.remainder();
+        for chunk in chunks {
+            let mut m = [0u8; 8];
+            m.copy_from_slice(chunk);
+            self.compress(u64::from_le_bytes(m));
+        }
+        for (i, byte) in remainder.iter().enumerate() {
+            self.tail |= (*byte as u64) << (8 * i);
+        }
+        self.tail_length = remainder.len();
+    }
+
+    fn finish(&self) -> u64 {
+        let mut state = self.clone();
+        let b = ((self.length as u64 & 0xff) << 56) | self.tail;
+        state.compress(b);
+        state.v2 ^= 0xff;
+        state.round();
+        state.round();
+        state.round();
+        state.round();
+        state.v0 ^ state.v1 ^ state.v2 ^ state.v3
+    }
+}
+
+#[inline]
+fn hash_bytes<S: BuildHasher>(bytes: &[u8], state: &S) -> u64 {
+    let mut hasher = state.build_hasher();
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+#[inline]
+fn hash_binary_value<S: BuildHasher>(value: &[u8], state: &S) -> u64 {
+    let mut hasher = state.build_hasher();
+    hasher.write(&(value.len() as u64).to_le_bytes());
+    hasher.write(value);
+    hasher.finish()
+}
+
+/// Element-wise hash of a [`PrimitiveArray`] with the hashers built by `state`.
+/// Validity is preserved.
+pub fn hash_primitive_with<T: NativeType, S: BuildHasher>(
+    array: &PrimitiveArray<T>,
+    state: &S,
+) -> PrimitiveArray<u64> {
+    unary(
+        array,
+        |x| hash_bytes(x.to_le_bytes().as_ref(), state),
+        DataType::UInt64,
+    )
+}
+
+/// Element-wise hash of a [`BooleanArray`] with the hashers built by `state`.
+/// Validity is preserved.
+pub fn hash_boolean_with<S: BuildHasher>(array: &BooleanArray, state: &S) -> PrimitiveArray<u64> {
+    let iter = array.values_iter().map(|x| hash_bytes(&[x as u8], state));
+    let values = Buffer::from_trusted_len_iter(iter);
+    PrimitiveArray::<u64>::from_data(DataType::UInt64, values, array.validity().cloned())
+}
+
+/// Element-wise hash of a [`Utf8Array`] with the hashers built by `state`.
+/// Validity is preserved.
+pub fn hash_utf8_with<O: Offset, S: BuildHasher>(
+    array: &Utf8Array<O>,
+    state: &S,
+) -> PrimitiveArray<u64> {
+    let iter = array
+        .values_iter()
+        .map(|x| hash_binary_value(x.as_bytes(), state));
+    let values = Buffer::from_trusted_len_iter(iter);
+    PrimitiveArray::<u64>::from_data(DataType::UInt64, values, array.validity().cloned())
+}
+
+/// Element-wise hash of a [`BinaryArray`] with the hashers built by `state`.
+/// Validity is preserved.
+pub fn hash_binary_with<O: Offset, S: BuildHasher>(
+    array: &BinaryArray<O>,
+    state: &S,
+) -> PrimitiveArray<u64> {
+    let iter = array.values_iter().map(|x| hash_binary_value(x, state));
+    let values = Buffer::from_trusted_len_iter(iter);
+    PrimitiveArray::<u64>::from_data(DataType::UInt64, values, array.validity().cloned())
+}
+
 macro_rules! with_match_primitive_type {(
     $key_type:expr, | $_:tt $T:ident | $($body:tt)*
 ) => ({
@@ -114,6 +298,51 @@ pub fn hash(array: &dyn Array) -> Result<PrimitiveArray<u64>> {
     })
 }
 
+/// Returns the element-wise hash of an [`Array`] with the hashers built by `state`.
+/// Validity is preserved. Supports the same DataTypes as [`hash`].
+///
+/// The hashes of [`hash`] are an implementation detail: they may change across versions of
+/// this crate and of `ahash`, and across CPUs (e.g. with and without `aes` instructions).
+/// This function instead writes each value to a hasher as follows:
+/// * booleans as a single byte (`0` or `1`);
+/// * primitive values as their little-endian bytes;
+/// * binary and utf8 values as their length as a little-endian `u64`, followed by their bytes.
+///
+/// With a [`StableState`] (SipHash-2-4), the hashes are therefore identical across processes,
+/// platforms and versions of this crate, e.g. to partition data across nodes.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::hash::{hash_with, StableState};
+///
+/// let array = Int32Array::from_slice([1, 2, 1]);
+/// let state = StableState::with_keys(1, 2);
+/// let hashes = hash_with(&array, &state).unwrap();
+/// assert_eq!(hashes.value(0), hashes.value(2));
+/// assert_eq!(hashes, hash_with(&array, &StableState::with_keys(1, 2)).unwrap());
+/// ```
+/// # Errors
+/// This function errors whenever it does not support the specific `DataType`.
+pub fn hash_with<S: BuildHasher>(array: &dyn Array, state: &S) -> Result<PrimitiveArray<u64>> {
+    use PhysicalType::*;
+    Ok(match array.data_type().to_physical_type() {
+        Boolean => hash_boolean_with(array.as_any().downcast_ref().unwrap(), state),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            hash_primitive_with::<$T, _>(array.as_any().downcast_ref().unwrap(), state)
+        }),
+        Binary => hash_binary_with::<i32, _>(array.as_any().downcast_ref().unwrap(), state),
+        LargeBinary => hash_binary_with::<i64, _>(array.as_any().downcast_ref().unwrap(), state),
+        Utf8 => hash_utf8_with::<i32, _>(array.as_any().downcast_ref().unwrap(), state),
+        LargeUtf8 => hash_utf8_with::<i64, _>(array.as_any().downcast_ref().unwrap(), state),
+        t => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Hash not implemented for type {:?}",
+                t
+            )))
+        }
+    })
+}
+
 /// Checks if an array of type `datatype` can be used in [`hash`].
 ///
 /// # Examples
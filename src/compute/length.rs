@@ -45,6 +45,24 @@ where
     PrimitiveArray::<O>::from_data(data_type, values, array.validity().cloned())
 }
 
+fn unary_values_string<O, F>(array: &Utf8Array<O>, op: F) -> PrimitiveArray<O>
+where
+    O: Offset,
+    F: Fn(&str) -> O,
+{
+    let values = array.values_iter().map(op);
+
+    let values = Buffer::from_trusted_len_iter(values);
+
+    let data_type = if O::is_large() {
+        DataType::Int64
+    } else {
+        DataType::Int32
+    };
+
+    PrimitiveArray::<O>::from_data(data_type, values, array.validity().cloned())
+}
+
 /// Returns an array of integers with the number of bytes on each string of the array.
 pub fn length(array: &dyn Array) -> Result<Box<dyn Array>> {
     match array.data_type() {
@@ -63,7 +81,63 @@ pub fn length(array: &dyn Array) -> Result<Box<dyn Array>> {
     }
 }
 
-/// Checks if an array of type `datatype` can perform length operation
+/// Returns an array of integers with the number of characters (unicode scalar values)
+/// on each string of the array.
+pub fn char_length(array: &dyn Array) -> Result<Box<dyn Array>> {
+    match array.data_type() {
+        DataType::Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            Ok(Box::new(unary_values_string::<i32, _>(array, |x| {
+                x.chars().count() as i32
+            })))
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            Ok(Box::new(unary_values_string::<i64, _>(array, |x| {
+                x.chars().count() as i64
+            })))
+        }
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "char_length not supported for {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+fn bit_length_string<O: Offset>(array: &Utf8Array<O>) -> PrimitiveArray<i64> {
+    // widened to `i64` since 8 times the length of an `i32` offset may overflow `i32`
+    let values = array
+        .offsets()
+        .windows(2)
+        .map(|offset| (offset[1] - offset[0]).to_isize() as i64 * 8);
+
+    let values = Buffer::from_trusted_len_iter(values);
+
+    PrimitiveArray::<i64>::from_data(DataType::Int64, values, array.validity().cloned())
+}
+
+/// Returns an array of integers with the number of bits on each string of the array.
+/// The result is an `Int64` array for both `Utf8` and `LargeUtf8`, since the number of bits
+/// of a `Utf8` string may not fit in an `i32`.
+pub fn bit_length(array: &dyn Array) -> Result<Box<dyn Array>> {
+    match array.data_type() {
+        DataType::Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            Ok(Box::new(bit_length_string(array)))
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            Ok(Box::new(bit_length_string(array)))
+        }
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "bit_length not supported for {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Checks if an array of type `datatype` can perform length operations
+/// ([`length`], [`char_length`] and [`bit_length`])
 ///
 /// # Examples
 /// ```
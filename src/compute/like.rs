@@ -1,8 +1,11 @@
 //! Contains "like" operators such as [`like_utf8`] and [`like_utf8_scalar`].
+//!
+//! Patterns are analysed before being evaluated: patterns without wildcards (`abc`), with only
+//! a trailing `%` (`abc%`) or with only a leading `%` (`%abc`) are evaluated with byte
+//! comparisons; all other patterns are evaluated with a regex.
 use std::collections::HashMap;
 
 use regex::bytes::Regex as BytesRegex;
-use regex::Regex;
 
 use crate::{
     array::{BinaryArray, BooleanArray, Offset, Utf8Array},
@@ -10,11 +13,118 @@ use crate::{
     compute::utils::combine_validities,
     datatypes::DataType,
     error::{ArrowError, Result},
+    trusted_len::TrustedLen,
 };
 
-#[inline]
-fn is_like_pattern(c: char) -> bool {
-    c == '%' || c == '_'
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    /// `%`
+    Any,
+    /// `_`
+    One,
+    Literal(char),
+}
+
+/// A LIKE pattern, analysed so that its most common shapes are evaluated without a regex.
+#[derive(Debug)]
+enum Pattern {
+    /// A pattern without wildcards, e.g. `abc`
+    Eq(Vec<u8>),
+    /// A pattern whose only wildcards are trailing `%`, e.g. `abc%`
+    StartsWith(Vec<u8>),
+    /// A pattern whose only wildcards are leading `%`, e.g. `%abc`
+    EndsWith(Vec<u8>),
+    /// Any other pattern, e.g. `a_c` or `a%c`
+    Regex(BytesRegex),
+}
+
+impl Pattern {
+    /// Analyses `pattern`, where `%` and `_` are wildcards unless escaped with `\`.
+    fn try_new(pattern: &str) -> Result<Self> {
+        let mut tokens = Vec::with_capacity(pattern.len());
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            tokens.push(match c {
+                '\\' => Token::Literal(chars.next().unwrap_or('\\')),
+                '%' => Token::Any,
+                '_' => Token::One,
+                c => Token::Literal(c),
+            })
+        }
+
+        let start = tokens.iter().take_while(|t| **t == Token::Any).count();
+        let end = tokens.len()
+            - tokens[start..]
+                .iter()
+                .rev()
+                .take_while(|t| **t == Token::Any)
+                .count();
+        let literal = tokens[start..end]
+            .iter()
+            .map(|t| match t {
+                Token::Literal(c) => Some(*c),
+                _ => None,
+            })
+            .collect::<Option<String>>();
+
+        match (literal, start > 0, end < tokens.len()) {
+            (Some(literal), false, false) => return Ok(Self::Eq(literal.into_bytes())),
+            (Some(literal), false, true) => return Ok(Self::StartsWith(literal.into_bytes())),
+            (Some(literal), true, false) => return Ok(Self::EndsWith(literal.into_bytes())),
+            _ => {}
+        };
+
+        let mut re_pattern = String::with_capacity(pattern.len() + 6);
+        re_pattern.push_str("(?s)^");
+        for token in tokens {
+            match token {
+                Token::Any => re_pattern.push_str(".*"),
+                Token::One => re_pattern.push('.'),
+                Token::Literal(c) => {
+                    re_pattern.push_str(&regex::escape(c.encode_utf8(&mut [0; 4])))
+                }
+            }
+        }
+        re_pattern.push('$');
+        let re = BytesRegex::new(&re_pattern).map_err(|e| {
+            ArrowError::InvalidArgumentError(format!(
+                "Unable to build regex from LIKE pattern: {}",
+                e
+            ))
+        })?;
+        Ok(Self::Regex(re))
+    }
+
+    #[inline]
+    fn is_match(&self, value: &[u8]) -> bool {
+        match self {
+            Self::Eq(literal) => value == literal.as_slice(),
+            Self::StartsWith(literal) => value.starts_with(literal),
+            Self::EndsWith(literal) => value.ends_with(literal),
+            Self::Regex(re) => re.is_match(value),
+        }
+    }
+
+    /// Returns a [`Bitmap`] with `op` applied to whether each of `values` matches this pattern,
+    /// dispatching on the shape of the pattern once instead of for every value.
+    fn evaluate<'a, I, F>(&self, values: I, op: F) -> Bitmap
+    where
+        I: TrustedLen<Item = &'a [u8]>,
+        F: Fn(bool) -> bool,
+    {
+        match self {
+            Self::Eq(literal) => {
+                Bitmap::from_trusted_len_iter(values.map(|x| op(x == literal.as_slice())))
+            }
+            Self::StartsWith(literal) => {
+                Bitmap::from_trusted_len_iter(values.map(|x| op(x.starts_with(literal))))
+            }
+            Self::EndsWith(literal) => {
+                Bitmap::from_trusted_len_iter(values.map(|x| op(x.ends_with(literal))))
+            }
+            Self::Regex(re) => Bitmap::from_trusted_len_iter(values.map(|x| op(re.is_match(x)))),
+        }
+    }
 }
 
 #[inline]
@@ -40,17 +150,10 @@ fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
                     let pattern = if let Some(pattern) = map.get(pattern) {
                         pattern
                     } else {
-                        let re_pattern = pattern.replace("%", ".*").replace("_", ".");
-                        let re = Regex::new(&format!("^{}$", re_pattern)).map_err(|e| {
-                            ArrowError::InvalidArgumentError(format!(
-                                "Unable to build regex from LIKE pattern: {}",
-                                e
-                            ))
-                        })?;
-                        map.insert(pattern, re);
+                        map.insert(pattern, Pattern::try_new(pattern)?);
                         map.get(pattern).unwrap()
                     };
-                    Result::Ok(op(pattern.is_match(lhs)))
+                    Result::Ok(op(pattern.is_match(lhs.as_bytes())))
                 }
                 _ => Ok(false),
             }
@@ -66,6 +169,8 @@ fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 ///
+/// Wildcards preceded by `\` (e.g. `\%`) are matched literally.
+///
 /// # Error
 /// Errors iff:
 /// * the arrays have a different length
@@ -91,6 +196,8 @@ pub fn like_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<Bo
 ///
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
+///
+/// Wildcards preceded by `\` (e.g. `\%`) are matched literally.
 pub fn nlike_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
     a_like_utf8(lhs, rhs, |x| !x)
 }
@@ -102,26 +209,8 @@ fn a_like_utf8_scalar<O: Offset, F: Fn(bool) -> bool>(
 ) -> Result<BooleanArray> {
     let validity = lhs.validity();
 
-    let values = if !rhs.contains(is_like_pattern) {
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| x == rhs))
-    } else if rhs.ends_with('%') && !rhs[..rhs.len() - 1].contains(is_like_pattern) {
-        // fast path, can use starts_with
-        let starts_with = &rhs[..rhs.len() - 1];
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.starts_with(starts_with))))
-    } else if rhs.starts_with('%') && !rhs[1..].contains(is_like_pattern) {
-        // fast path, can use ends_with
-        let ends_with = &rhs[1..];
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.ends_with(ends_with))))
-    } else {
-        let re_pattern = rhs.replace("%", ".*").replace("_", ".");
-        let re = Regex::new(&format!("^{}$", re_pattern)).map_err(|e| {
-            ArrowError::InvalidArgumentError(format!(
-                "Unable to build regex from LIKE pattern: {}",
-                e
-            ))
-        })?;
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(re.is_match(x))))
-    };
+    let pattern = Pattern::try_new(rhs)?;
+    let values = pattern.evaluate(lhs.values_iter().map(|x| x.as_bytes()), op);
     Ok(BooleanArray::from_data(
         DataType::Boolean,
         values,
@@ -136,6 +225,8 @@ fn a_like_utf8_scalar<O: Offset, F: Fn(bool) -> bool>(
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 ///
+/// Wildcards preceded by `\` (e.g. `\%`) are matched literally.
+///
 /// # Error
 /// Errors iff:
 /// * the arrays have a different length
@@ -160,10 +251,21 @@ pub fn like_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<Bool
 ///
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
+///
+/// Wildcards preceded by `\` (e.g. `\%`) are matched literally.
 pub fn nlike_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
     a_like_utf8_scalar(lhs, rhs, |x| !x)
 }
 
+fn to_utf8(pattern: &[u8]) -> Result<&str> {
+    simdutf8::basic::from_utf8(pattern).map_err(|e| {
+        ArrowError::InvalidArgumentError(format!(
+            "Unable to convert the LIKE pattern to string: {}",
+            e
+        ))
+    })
+}
+
 #[inline]
 fn a_like_binary<O: Offset, F: Fn(bool) -> bool>(
     lhs: &BinaryArray<O>,
@@ -187,17 +289,8 @@ fn a_like_binary<O: Offset, F: Fn(bool) -> bool>(
                     let pattern = if let Some(pattern) = map.get(pattern) {
                         pattern
                     } else {
-                        let re_pattern = simdutf8::basic::from_utf8(pattern)
-                            .unwrap()
-                            .replace("%", ".*")
-                            .replace("_", ".");
-                        let re = BytesRegex::new(&format!("^{}$", re_pattern)).map_err(|e| {
-                            ArrowError::InvalidArgumentError(format!(
-                                "Unable to build regex from LIKE pattern: {}",
-                                e
-                            ))
-                        })?;
-                        map.insert(pattern, re);
+                        let analysed = Pattern::try_new(to_utf8(pattern)?)?;
+                        map.insert(pattern, analysed);
                         map.get(pattern).unwrap()
                     };
                     Result::Ok(op(pattern.is_match(lhs)))
@@ -216,6 +309,8 @@ fn a_like_binary<O: Offset, F: Fn(bool) -> bool>(
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 ///
+/// Wildcards preceded by `\` (e.g. `\%`) are matched literally.
+///
 /// # Error
 /// Errors iff:
 /// * the arrays have a different length
@@ -242,6 +337,8 @@ pub fn like_binary<O: Offset>(lhs: &BinaryArray<O>, rhs: &BinaryArray<O>) -> Res
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 ///
+/// Wildcards preceded by `\` (e.g. `\%`) are matched literally.
+///
 pub fn nlike_binary<O: Offset>(lhs: &BinaryArray<O>, rhs: &BinaryArray<O>) -> Result<BooleanArray> {
     a_like_binary(lhs, rhs, |x| !x)
 }
@@ -252,33 +349,9 @@ fn a_like_binary_scalar<O: Offset, F: Fn(bool) -> bool>(
     op: F,
 ) -> Result<BooleanArray> {
     let validity = lhs.validity();
-    let pattern = simdutf8::basic::from_utf8(rhs).map_err(|e| {
-        ArrowError::InvalidArgumentError(format!(
-            "Unable to convert the LIKE pattern to string: {}",
-            e
-        ))
-    })?;
-
-    let values = if !pattern.contains(is_like_pattern) {
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| x == rhs))
-    } else if pattern.ends_with('%') && !pattern[..pattern.len() - 1].contains(is_like_pattern) {
-        // fast path, can use starts_with
-        let starts_with = &rhs[..rhs.len() - 1];
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.starts_with(starts_with))))
-    } else if pattern.starts_with('%') && !pattern[1..].contains(is_like_pattern) {
-        // fast path, can use ends_with
-        let ends_with = &rhs[1..];
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.ends_with(ends_with))))
-    } else {
-        let re_pattern = pattern.replace("%", ".*").replace("_", ".");
-        let re = BytesRegex::new(&format!("^{}$", re_pattern)).map_err(|e| {
-            ArrowError::InvalidArgumentError(format!(
-                "Unable to build regex from LIKE pattern: {}",
-                e
-            ))
-        })?;
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(re.is_match(x))))
-    };
+
+    let pattern = Pattern::try_new(to_utf8(rhs)?)?;
+    let values = pattern.evaluate(lhs.values_iter(), op);
     Ok(BooleanArray::from_data(
         DataType::Boolean,
         values,
@@ -293,6 +366,8 @@ fn a_like_binary_scalar<O: Offset, F: Fn(bool) -> bool>(
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 ///
+/// Wildcards preceded by `\` (e.g. `\%`) are matched literally.
+///
 /// # Error
 /// Errors iff:
 /// * the arrays have a different length
@@ -318,6 +393,8 @@ pub fn like_binary_scalar<O: Offset>(lhs: &BinaryArray<O>, rhs: &[u8]) -> Result
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 ///
+/// Wildcards preceded by `\` (e.g. `\%`) are matched literally.
+///
 pub fn nlike_binary_scalar<O: Offset>(lhs: &BinaryArray<O>, rhs: &[u8]) -> Result<BooleanArray> {
     a_like_binary_scalar(lhs, rhs, |x| !x)
 }
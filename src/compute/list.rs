@@ -0,0 +1,99 @@
+//! Contains operators that act on the values of each element of a [`ListArray`],
+//! such as [`sort_values`] and [`list_unique`].
+use std::cmp::Ordering;
+
+use crate::array::{Array, ListArray, Offset, PrimitiveArray};
+use crate::compute::sort::{build_compare, SortOptions};
+use crate::compute::take::take;
+use crate::error::Result;
+
+/// Returns the indices of the values of `array` that sort each of its elements,
+/// and the offsets of the elements over those indices.
+fn sort_indices<O: Offset>(
+    array: &ListArray<O>,
+    options: SortOptions,
+    unique: bool,
+) -> Result<(PrimitiveArray<O>, Vec<O>)> {
+    let compare = build_compare(array.values().as_ref(), options)?;
+    let offsets = array.offsets().as_slice();
+
+    let first = offsets[0].to_usize();
+    let last = offsets[offsets.len() - 1].to_usize();
+    let mut indices = Vec::<O>::with_capacity(last - first);
+    let mut new_offsets = Vec::<O>::with_capacity(offsets.len());
+    new_offsets.push(O::zero());
+
+    let mut element = vec![];
+    for window in offsets.windows(2) {
+        element.clear();
+        element.extend(window[0].to_usize()..window[1].to_usize());
+        element.sort_unstable_by(|a, b| compare(*a, *b));
+        if unique {
+            element.dedup_by(|a, b| compare(*a, *b) == Ordering::Equal);
+        }
+        indices.extend(element.iter().map(|x| O::from_usize(*x).unwrap()));
+        new_offsets.push(O::from_usize(indices.len()).unwrap());
+    }
+
+    let indices = PrimitiveArray::<O>::from_data(O::DATA_TYPE, indices.into(), None);
+    Ok((indices, new_offsets))
+}
+
+fn apply<O: Offset>(
+    array: &ListArray<O>,
+    options: SortOptions,
+    unique: bool,
+) -> Result<ListArray<O>> {
+    let (indices, offsets) = sort_indices(array, options, unique)?;
+    let values = take(array.values().as_ref(), &indices)?;
+
+    Ok(ListArray::<O>::from_data(
+        array.data_type().clone(),
+        offsets.into(),
+        values.into(),
+        array.validity().cloned(),
+    ))
+}
+
+/// Sorts the values of each element of `array` independently, using [`SortOptions`].
+///
+/// The order of the elements, and which of them are null, is preserved.
+/// # Example
+/// ```rust
+/// use arrow2::array::{ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::list::sort_values;
+/// use arrow2::compute::sort::SortOptions;
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let data = vec![Some(vec![Some(3i32), None, Some(1)]), None, Some(vec![Some(2), Some(0)])];
+/// let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// array.try_extend(data)?;
+/// let array: ListArray<i32> = array.into();
+///
+/// let result = sort_values(&array, &SortOptions::default())?;
+///
+/// let expected = vec![Some(vec![None, Some(1i32), Some(3)]), None, Some(vec![Some(0), Some(2)])];
+/// let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// array.try_extend(expected)?;
+/// let expected: ListArray<i32> = array.into();
+/// assert_eq!(result, expected);
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// Errors iff the values of `array` have no natural order, as defined by
+/// [`build_compare`](crate::array::ord::build_compare).
+pub fn sort_values<O: Offset>(array: &ListArray<O>, options: &SortOptions) -> Result<ListArray<O>> {
+    apply(array, *options, false)
+}
+
+/// Returns a [`ListArray`] whose elements contain the distinct values of each element of `array`,
+/// in ascending order and with nulls first. Null values are considered equal to each other.
+///
+/// The order of the elements, and which of them are null, is preserved.
+/// # Errors
+/// Errors iff the values of `array` have no natural order, as defined by
+/// [`build_compare`](crate::array::ord::build_compare).
+pub fn list_unique<O: Offset>(array: &ListArray<O>) -> Result<ListArray<O>> {
+    apply(array, SortOptions::default(), true)
+}
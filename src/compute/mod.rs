@@ -11,6 +11,11 @@
 //! supported.
 //! Some dynamically-typed operators have an auxiliary function, `can_*`, that returns
 //! true if the operator can be applied to the particular `DataType`.
+//!
+//! Hot kernels (e.g. [`aggregate`], [`comparison`] and [`filter`] of primitive arrays) are
+//! compiled for multiple instruction sets (AVX2 on x86-64, NEON on aarch64, and AVX-512 on
+//! x86-64 with the feature `simd_avx512`) and the best one supported by the CPU is selected at
+//! runtime, so binaries built for a generic target still use them on capable machines.
 
 #[cfg(feature = "compute_aggregate")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_aggregate")))]
@@ -58,6 +63,9 @@ pub mod like;
 #[cfg(feature = "compute_limit")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_limit")))]
 pub mod limit;
+#[cfg(feature = "compute_list")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_list")))]
+pub mod list;
 #[cfg(feature = "compute_lower")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_lower")))]
 pub mod lower;
@@ -70,12 +78,18 @@ pub mod nullif;
 #[cfg(feature = "compute_partition")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_partition")))]
 pub mod partition;
+#[cfg(feature = "compute_rechunk")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_rechunk")))]
+pub mod rechunk;
 #[cfg(feature = "compute_regex_match")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_regex_match")))]
 pub mod regex_match;
 #[cfg(feature = "compute_sort")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_sort")))]
 pub mod sort;
+#[cfg(feature = "compute_statistics")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_statistics")))]
+pub mod statistics;
 #[cfg(feature = "compute_substring")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_substring")))]
 pub mod substring;
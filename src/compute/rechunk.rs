@@ -0,0 +1,157 @@
+//! Contains [`rechunk`], to split and coalesce a stream of [`RecordBatch`]es into
+//! batches with a target number of rows.
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use crate::array::Array;
+use crate::compute::concatenate::concatenate;
+use crate::error::Result;
+use crate::record_batch::RecordBatch;
+
+/// Returns an iterator of [`RecordBatch`]es with `target_rows` rows each (except possibly
+/// the last one), built from `batches`.
+///
+/// Batches larger than `target_rows` are split via (zero-copy) slices; batches smaller than
+/// `target_rows` are concatenated together. A batch is only copied when it needs to be
+/// concatenated with another one; a slice that already has `target_rows` rows is
+/// returned as is. Empty batches are skipped.
+///
+/// Errors from `batches` are forwarded as they appear; errors from concatenating batches
+/// with different schemas are returned in place of the batch that would have been built.
+/// # Panics
+/// iff `target_rows` is zero.
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::rechunk::rechunk;
+/// use arrow2::datatypes::{DataType, Field, Schema};
+/// use arrow2::error::Result;
+/// use arrow2::record_batch::RecordBatch;
+///
+/// # fn main() -> Result<()> {
+/// let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+/// let batch = |values: &[i32]| {
+///     RecordBatch::try_new(schema.clone(), vec![Arc::new(Int32Array::from_slice(values))])
+/// };
+/// let batches = vec![batch(&[1, 2, 3]), batch(&[4]), batch(&[5, 6, 7, 8, 9])];
+///
+/// let rows = rechunk(batches.into_iter(), 4)
+///     .map(|batch| batch.map(|batch| batch.num_rows()))
+///     .collect::<Result<Vec<_>>>()?;
+/// assert_eq!(rows, vec![4, 4, 1]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn rechunk<I>(batches: I, target_rows: usize) -> Rechunk<I>
+where
+    I: Iterator<Item = Result<RecordBatch>>,
+{
+    assert!(target_rows > 0, "rechunk requires a non-zero target_rows");
+    Rechunk {
+        iter: batches,
+        target_rows,
+        pending: VecDeque::new(),
+        pending_rows: 0,
+    }
+}
+
+/// Iterator returned by [`rechunk`].
+#[derive(Debug)]
+pub struct Rechunk<I>
+where
+    I: Iterator<Item = Result<RecordBatch>>,
+{
+    iter: I,
+    target_rows: usize,
+    pending: VecDeque<RecordBatch>,
+    pending_rows: usize,
+}
+
+/// Returns a zero-copy slice of `batch`.
+fn slice(batch: &RecordBatch, offset: usize, length: usize) -> Result<RecordBatch> {
+    let columns = batch
+        .columns()
+        .iter()
+        .map(|column| Arc::from(column.slice(offset, length)))
+        .collect();
+    RecordBatch::try_new(batch.schema().clone(), columns)
+}
+
+/// Concatenates `batches` column-wise into a single [`RecordBatch`].
+fn concatenate_batches(batches: &[RecordBatch]) -> Result<RecordBatch> {
+    let schema = batches[0].schema().clone();
+    let columns = (0..batches[0].num_columns())
+        .map(|i| {
+            let arrays = batches
+                .iter()
+                .map(|batch| batch.column(i).as_ref())
+                .collect::<Vec<&dyn Array>>();
+            concatenate(&arrays).map(Arc::from)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    RecordBatch::try_new(schema, columns)
+}
+
+impl<I> Rechunk<I>
+where
+    I: Iterator<Item = Result<RecordBatch>>,
+{
+    /// Removes up to `length` rows from the front of the pending batches, slicing the
+    /// last one if needed, and returns them as a single [`RecordBatch`].
+    fn take_front(&mut self, length: usize) -> Result<RecordBatch> {
+        let mut taken = vec![];
+        let mut remaining = length;
+        while remaining > 0 {
+            let batch = match self.pending.pop_front() {
+                Some(batch) => batch,
+                None => break,
+            };
+            let num_rows = batch.num_rows();
+            if num_rows > remaining {
+                let head = slice(&batch, 0, remaining)?;
+                let tail = slice(&batch, remaining, num_rows - remaining)?;
+                self.pending.push_front(tail);
+                taken.push(head);
+                remaining = 0;
+            } else {
+                taken.push(batch);
+                remaining -= num_rows;
+            }
+        }
+        self.pending_rows -= length - remaining;
+
+        if taken.len() == 1 {
+            Ok(taken.pop().unwrap())
+        } else {
+            concatenate_batches(&taken)
+        }
+    }
+}
+
+impl<I> Iterator for Rechunk<I>
+where
+    I: Iterator<Item = Result<RecordBatch>>,
+{
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pending_rows < self.target_rows {
+            match self.iter.next() {
+                Some(Ok(batch)) => {
+                    if batch.num_rows() > 0 {
+                        self.pending_rows += batch.num_rows();
+                        self.pending.push_back(batch);
+                    }
+                }
+                Some(Err(e)) => return Some(Err(e)),
+                None => break,
+            }
+        }
+        if self.pending_rows == 0 {
+            return None;
+        }
+        let length = self.pending_rows.min(self.target_rows);
+        Some(self.take_front(length))
+    }
+}
@@ -0,0 +1,18 @@
+use crate::array::{DictionaryArray, DictionaryKey, PrimitiveArray};
+use crate::types::Index;
+
+use super::common;
+use super::SortOptions;
+
+/// Sorts an ordered [`DictionaryArray`] by its codes, i.e. by the order of its categories.
+pub(super) fn indices_sorted_unstable_by_codes<I: Index, K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> PrimitiveArray<I> {
+    let codes = array.codes();
+
+    let get = |idx| unsafe { codes.value_unchecked(idx).to_usize().unwrap() };
+    let cmp = |lhs: &usize, rhs: &usize| lhs.cmp(rhs);
+    common::indices_sorted_unstable_by(array.validity(), get, cmp, array.len(), options, limit)
+}
@@ -146,6 +146,7 @@ pub fn lexsort_to_indices<I: Index>(
             "lexical sort columns have different row counts".to_string(),
         ));
     };
+    super::check_index_length::<I>(row_count)?;
 
     // map arrays to comparators
     let comparators = columns
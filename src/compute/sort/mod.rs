@@ -14,6 +14,8 @@ use crate::{
 mod binary;
 mod boolean;
 mod common;
+mod dictionary;
+mod fixed_size_binary;
 mod lex_sort;
 mod primitive;
 mod utf8;
@@ -71,6 +73,20 @@ pub fn sort(
     }
 }
 
+/// Sorts the [`Array`] like [`sort`], also returning the indices that sort `values`,
+/// i.e. the input row of each output row, as returned by [`sort_to_indices`].
+/// # Errors
+/// Errors if the [`DataType`] is not supported or if `I` cannot represent the largest index of `values`.
+pub fn sort_with_indices<I: Index>(
+    values: &dyn Array,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> Result<(Box<dyn Array>, PrimitiveArray<I>)> {
+    let indices = sort_to_indices::<I>(values, options, limit)?;
+    let sorted = take::take(values, &indices)?;
+    Ok((sorted, indices))
+}
+
 // partition indices into valid and null indices
 fn partition_validity<I: Index>(array: &dyn Array) -> (Vec<I>, Vec<I>) {
     let length = array.len();
@@ -94,12 +110,32 @@ macro_rules! dyn_sort_indices {
     }};
 }
 
+/// Errors iff arrays of `length` cannot be indexed by `I`, i.e. iff `I` cannot represent
+/// their largest index, `length - 1`.
+fn check_index_length<I: Index>(length: usize) -> Result<()> {
+    if I::from_usize(length.saturating_sub(1)).is_none() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Arrays of length {} cannot be sorted into indices of type {:?}; use a larger index type",
+            length,
+            I::DATA_TYPE
+        )));
+    }
+    Ok(())
+}
+
 /// Sort elements from `values` into a non-nullable [`PrimitiveArray`] of indices that sort `values`.
+///
+/// The type of the indices, `I`, can be any of `i32`, `i64`, `u32` and `u64`, and must be able
+/// to represent the largest index of `values`, `values.len() - 1` (e.g. `i64` or `u64` for arrays
+/// longer than `i32::MAX + 1`).
+/// # Errors
+/// Errors if the [`DataType`] is not supported or if `I` cannot represent the largest index of `values`.
 pub fn sort_to_indices<I: Index>(
     values: &dyn Array,
     options: &SortOptions,
     limit: Option<usize>,
 ) -> Result<PrimitiveArray<I>> {
+    check_index_length::<I>(values.len())?;
     match values.data_type() {
         DataType::Boolean => {
             let (v, n) = partition_validity(values);
@@ -152,6 +188,11 @@ pub fn sort_to_indices<I: Index>(
             options,
             limit,
         )),
+        DataType::FixedSizeBinary(_) => Ok(fixed_size_binary::indices_sorted_unstable_by::<I>(
+            values.as_any().downcast_ref().unwrap(),
+            options,
+            limit,
+        )),
         DataType::List(field) => {
             let (v, n) = partition_validity(values);
             match field.data_type() {
@@ -203,7 +244,10 @@ pub fn sort_to_indices<I: Index>(
                 ))),
             }
         }
-        DataType::Dictionary(key_type, value_type) => match value_type.as_ref() {
+        DataType::Dictionary(key_type, _, _) if is_ordered_dict(values, key_type) => {
+            Ok(sort_ordered_dict::<I>(values, key_type, options, limit))
+        }
+        DataType::Dictionary(key_type, value_type, _) => match value_type.as_ref() {
             DataType::Utf8 => Ok(sort_dict::<I, i32>(values, key_type, options, limit)),
             DataType::LargeUtf8 => Ok(sort_dict::<I, i64>(values, key_type, options, limit)),
             t => Err(ArrowError::NotYetImplemented(format!(
@@ -233,6 +277,31 @@ fn sort_dict<I: Index, O: Offset>(
     })
 }
 
+fn is_ordered_dict(values: &dyn Array, key_type: &IntegerType) -> bool {
+    match_integer_type!(key_type, |$T| {
+        values
+            .as_any()
+            .downcast_ref::<DictionaryArray<$T>>()
+            .unwrap()
+            .is_ordered()
+    })
+}
+
+fn sort_ordered_dict<I: Index>(
+    values: &dyn Array,
+    key_type: &IntegerType,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> PrimitiveArray<I> {
+    match_integer_type!(key_type, |$T| {
+        dictionary::indices_sorted_unstable_by_codes::<I, $T>(
+            values.as_any().downcast_ref().unwrap(),
+            options,
+            limit,
+        )
+    })
+}
+
 /// Checks if an array of type `datatype` can be sorted
 ///
 /// # Examples
@@ -269,7 +338,8 @@ pub fn can_sort(data_type: &DataType) -> bool {
         | DataType::Utf8
         | DataType::LargeUtf8
         | DataType::Binary
-        | DataType::LargeBinary => true,
+        | DataType::LargeBinary
+        | DataType::FixedSizeBinary(_) => true,
         DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
             matches!(
                 field.data_type(),
@@ -283,7 +353,7 @@ pub fn can_sort(data_type: &DataType) -> bool {
                     | DataType::UInt64
             )
         }
-        DataType::Dictionary(_, value_type) => {
+        DataType::Dictionary(_, value_type, _) => {
             matches!(*value_type.as_ref(), DataType::Utf8 | DataType::LargeUtf8)
         }
         _ => false,
@@ -0,0 +1,337 @@
+//! Contains [`Accumulator`], to compute the statistics of a column from its chunks.
+//!
+//! Writers (e.g. the parquet writer) use it to compute the statistics of the columns they
+//! write, so that statistics computed by other sinks over the same chunks are identical.
+use crate::{
+    array::*,
+    datatypes::{DataType, PhysicalType},
+    error::{ArrowError, Result},
+    scalar::*,
+    types::NativeType,
+};
+
+/// The minimum and maximum of the values accumulated, per physical type.
+/// Utf8, binary and fixed size binary values are ordered by their bytes.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Bounds {
+    /// The data type has no order, e.g. nested types
+    Unsupported,
+    Boolean(Option<(bool, bool)>),
+    Int8(Option<(i8, i8)>),
+    Int16(Option<(i16, i16)>),
+    Int32(Option<(i32, i32)>),
+    Int64(Option<(i64, i64)>),
+    Int128(Option<(i128, i128)>),
+    UInt8(Option<(u8, u8)>),
+    UInt16(Option<(u16, u16)>),
+    UInt32(Option<(u32, u32)>),
+    UInt64(Option<(u64, u64)>),
+    Float32(Option<(f32, f32)>),
+    Float64(Option<(f64, f64)>),
+    Binary(Option<(Vec<u8>, Vec<u8>)>),
+}
+
+impl Bounds {
+    fn new(data_type: &DataType) -> Self {
+        use DataType::*;
+        match data_type.to_logical_type() {
+            Boolean => Bounds::Boolean(None),
+            Int8 => Bounds::Int8(None),
+            Int16 => Bounds::Int16(None),
+            Int32 | Date32 | Time32(_) => Bounds::Int32(None),
+            Int64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_) => Bounds::Int64(None),
+            Decimal(_, _) => Bounds::Int128(None),
+            UInt8 => Bounds::UInt8(None),
+            UInt16 => Bounds::UInt16(None),
+            UInt32 => Bounds::UInt32(None),
+            UInt64 => Bounds::UInt64(None),
+            Float32 => Bounds::Float32(None),
+            Float64 => Bounds::Float64(None),
+            Utf8 | LargeUtf8 | Binary | LargeBinary | FixedSizeBinary(_) => Bounds::Binary(None),
+            // e.g. intervals and nested types have no natural order
+            _ => Bounds::Unsupported,
+        }
+    }
+}
+
+/// Extends `bounds` so that they include `min` and `max`.
+#[inline]
+fn extend<T: PartialOrd>(bounds: &mut Option<(T, T)>, min: T, max: T) {
+    match bounds {
+        Some((current_min, current_max)) => {
+            if min < *current_min {
+                *current_min = min;
+            }
+            if max > *current_max {
+                *current_max = max;
+            }
+        }
+        None => *bounds = Some((min, max)),
+    }
+}
+
+fn update_primitive<T: NativeType + PartialOrd>(bounds: &mut Option<(T, T)>, array: &dyn Array) {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    array
+        .iter()
+        .flatten()
+        // NaNs are not ordered and thus do not contribute to the bounds
+        .filter(|x| x.partial_cmp(x).is_some())
+        .for_each(|x| extend(bounds, *x, *x));
+}
+
+fn update_binary<'a, I: Iterator<Item = &'a [u8]>>(
+    bounds: &mut Option<(Vec<u8>, Vec<u8>)>,
+    mut values: I,
+) {
+    let first = if let Some(first) = values.next() {
+        first
+    } else {
+        return;
+    };
+    let (min, max) = values.fold((first, first), |(min, max), x| {
+        (if x < min { x } else { min }, if x > max { x } else { max })
+    });
+    match bounds {
+        Some((current_min, current_max)) => {
+            if min < current_min.as_slice() {
+                *current_min = min.to_vec();
+            }
+            if max > current_max.as_slice() {
+                *current_max = max.to_vec();
+            }
+        }
+        None => *bounds = Some((min.to_vec(), max.to_vec())),
+    }
+}
+
+fn update_bounds(bounds: &mut Bounds, array: &dyn Array) {
+    match bounds {
+        Bounds::Unsupported => {}
+        Bounds::Boolean(bounds) => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            array.iter().flatten().for_each(|x| extend(bounds, x, x))
+        }
+        Bounds::Int8(bounds) => update_primitive(bounds, array),
+        Bounds::Int16(bounds) => update_primitive(bounds, array),
+        Bounds::Int32(bounds) => update_primitive(bounds, array),
+        Bounds::Int64(bounds) => update_primitive(bounds, array),
+        Bounds::Int128(bounds) => update_primitive(bounds, array),
+        Bounds::UInt8(bounds) => update_primitive(bounds, array),
+        Bounds::UInt16(bounds) => update_primitive(bounds, array),
+        Bounds::UInt32(bounds) => update_primitive(bounds, array),
+        Bounds::UInt64(bounds) => update_primitive(bounds, array),
+        Bounds::Float32(bounds) => update_primitive(bounds, array),
+        Bounds::Float64(bounds) => update_primitive(bounds, array),
+        Bounds::Binary(bounds) => match array.data_type().to_physical_type() {
+            PhysicalType::Utf8 => {
+                let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+                update_binary(bounds, array.iter().flatten().map(|x| x.as_bytes()))
+            }
+            PhysicalType::LargeUtf8 => {
+                let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+                update_binary(bounds, array.iter().flatten().map(|x| x.as_bytes()))
+            }
+            PhysicalType::Binary => {
+                let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+                update_binary(bounds, array.iter().flatten())
+            }
+            PhysicalType::LargeBinary => {
+                let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+                update_binary(bounds, array.iter().flatten())
+            }
+            PhysicalType::FixedSizeBinary => {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<FixedSizeBinaryArray>()
+                    .unwrap();
+                update_binary(bounds, array.iter().flatten())
+            }
+            _ => unreachable!(),
+        },
+    }
+}
+
+fn merge_bounds(bounds: &mut Bounds, other: &Bounds) {
+    fn merge<T: PartialOrd + Clone>(bounds: &mut Option<(T, T)>, other: &Option<(T, T)>) {
+        if let Some((min, max)) = other {
+            extend(bounds, min.clone(), max.clone())
+        }
+    }
+    match (bounds, other) {
+        (Bounds::Boolean(lhs), Bounds::Boolean(rhs)) => merge(lhs, rhs),
+        (Bounds::Int8(lhs), Bounds::Int8(rhs)) => merge(lhs, rhs),
+        (Bounds::Int16(lhs), Bounds::Int16(rhs)) => merge(lhs, rhs),
+        (Bounds::Int32(lhs), Bounds::Int32(rhs)) => merge(lhs, rhs),
+        (Bounds::Int64(lhs), Bounds::Int64(rhs)) => merge(lhs, rhs),
+        (Bounds::Int128(lhs), Bounds::Int128(rhs)) => merge(lhs, rhs),
+        (Bounds::UInt8(lhs), Bounds::UInt8(rhs)) => merge(lhs, rhs),
+        (Bounds::UInt16(lhs), Bounds::UInt16(rhs)) => merge(lhs, rhs),
+        (Bounds::UInt32(lhs), Bounds::UInt32(rhs)) => merge(lhs, rhs),
+        (Bounds::UInt64(lhs), Bounds::UInt64(rhs)) => merge(lhs, rhs),
+        (Bounds::Float32(lhs), Bounds::Float32(rhs)) => merge(lhs, rhs),
+        (Bounds::Float64(lhs), Bounds::Float64(rhs)) => merge(lhs, rhs),
+        (Bounds::Binary(lhs), Bounds::Binary(rhs)) => merge(lhs, rhs),
+        // accumulators of the same data type have the same bounds
+        _ => {}
+    }
+}
+
+/// Accumulates the statistics of a column (its length, null count, minimum and maximum)
+/// from its chunks, without holding the chunks in memory.
+///
+/// Minimum and maximum follow the natural order of the values. Utf8 and binary values are
+/// ordered by their bytes, and NaNs are ignored. Types without a natural order
+/// (e.g. nested types and intervals) only accumulate their length and null count.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::statistics::Accumulator;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::scalar::{PrimitiveScalar, Scalar};
+///
+/// let mut accumulator = Accumulator::new(DataType::Int32);
+/// accumulator.update(&Int32Array::from([Some(2), None, Some(3)])).unwrap();
+/// accumulator.update(&Int32Array::from([Some(1), None])).unwrap();
+///
+/// assert_eq!(accumulator.len(), 5);
+/// assert_eq!(accumulator.null_count(), 2);
+/// let min = accumulator.min().unwrap();
+/// let min = min.as_any().downcast_ref::<PrimitiveScalar<i32>>().unwrap();
+/// assert_eq!(min.value(), Some(1));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Accumulator {
+    data_type: DataType,
+    length: usize,
+    null_count: usize,
+    bounds: Bounds,
+}
+
+impl Accumulator {
+    /// Returns a new empty [`Accumulator`] of arrays of `data_type`.
+    pub fn new(data_type: DataType) -> Self {
+        let bounds = Bounds::new(&data_type);
+        Self {
+            data_type,
+            length: 0,
+            null_count: 0,
+            bounds,
+        }
+    }
+
+    /// The [`DataType`] of the arrays accumulated.
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// The number of values accumulated, including nulls.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Whether no values were accumulated.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// The number of nulls accumulated.
+    pub fn null_count(&self) -> usize {
+        self.null_count
+    }
+
+    pub(crate) fn bounds(&self) -> &Bounds {
+        &self.bounds
+    }
+
+    /// Updates the statistics with the values of `array`.
+    /// # Errors
+    /// Errors iff the data type of `array` is not the data type of this [`Accumulator`].
+    pub fn update(&mut self, array: &dyn Array) -> Result<()> {
+        if array.data_type() != &self.data_type {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "An accumulator of {:?} cannot be updated with an array of {:?}",
+                self.data_type,
+                array.data_type()
+            )));
+        }
+        self.length += array.len();
+        self.null_count += array.null_count();
+        update_bounds(&mut self.bounds, array);
+        Ok(())
+    }
+
+    /// Updates the statistics with the statistics of `other`, e.g. of chunks
+    /// accumulated in parallel.
+    /// # Errors
+    /// Errors iff `other` does not have the data type of this [`Accumulator`].
+    pub fn merge(&mut self, other: &Accumulator) -> Result<()> {
+        if other.data_type != self.data_type {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "An accumulator of {:?} cannot be merged with an accumulator of {:?}",
+                self.data_type, other.data_type
+            )));
+        }
+        self.length += other.length;
+        self.null_count += other.null_count;
+        merge_bounds(&mut self.bounds, &other.bounds);
+        Ok(())
+    }
+
+    /// The minimum of the values accumulated. The scalar is null when no non-null values were
+    /// accumulated, and `None` when the data type has no natural order or no [`Scalar`]
+    /// (e.g. [`DataType::FixedSizeBinary`]).
+    pub fn min(&self) -> Option<Box<dyn Scalar>> {
+        self.to_scalar(true)
+    }
+
+    /// The maximum of the values accumulated. The scalar is null when no non-null values were
+    /// accumulated, and `None` when the data type has no natural order or no [`Scalar`]
+    /// (e.g. [`DataType::FixedSizeBinary`]).
+    pub fn max(&self) -> Option<Box<dyn Scalar>> {
+        self.to_scalar(false)
+    }
+
+    fn to_scalar(&self, is_min: bool) -> Option<Box<dyn Scalar>> {
+        fn select<T: Clone>(bounds: &Option<(T, T)>, is_min: bool) -> Option<T> {
+            bounds
+                .as_ref()
+                .map(|(min, max)| if is_min { min.clone() } else { max.clone() })
+        }
+        macro_rules! primitive {
+            ($bounds:expr) => {
+                Box::new(PrimitiveScalar::new(
+                    self.data_type.clone(),
+                    select($bounds, is_min),
+                ))
+            };
+        }
+        Some(match &self.bounds {
+            Bounds::Unsupported => return None,
+            Bounds::Boolean(bounds) => Box::new(BooleanScalar::new(select(bounds, is_min))),
+            Bounds::Int8(bounds) => primitive!(bounds),
+            Bounds::Int16(bounds) => primitive!(bounds),
+            Bounds::Int32(bounds) => primitive!(bounds),
+            Bounds::Int64(bounds) => primitive!(bounds),
+            Bounds::Int128(bounds) => primitive!(bounds),
+            Bounds::UInt8(bounds) => primitive!(bounds),
+            Bounds::UInt16(bounds) => primitive!(bounds),
+            Bounds::UInt32(bounds) => primitive!(bounds),
+            Bounds::UInt64(bounds) => primitive!(bounds),
+            Bounds::Float32(bounds) => primitive!(bounds),
+            Bounds::Float64(bounds) => primitive!(bounds),
+            Bounds::Binary(bounds) => {
+                let value = select(bounds, is_min);
+                // utf8 values were accumulated from utf8 arrays and are thus valid utf8
+                let utf8 = || value.clone().map(|x| String::from_utf8(x).unwrap());
+                match self.data_type.to_physical_type() {
+                    PhysicalType::Utf8 => Box::new(Utf8Scalar::<i32>::new(utf8())),
+                    PhysicalType::LargeUtf8 => Box::new(Utf8Scalar::<i64>::new(utf8())),
+                    PhysicalType::Binary => Box::new(BinaryScalar::<i32>::new(value)),
+                    PhysicalType::LargeBinary => Box::new(BinaryScalar::<i64>::new(value)),
+                    _ => return None,
+                }
+            }
+        })
+    }
+}
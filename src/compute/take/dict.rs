@@ -30,5 +30,5 @@ where
     I: Index,
 {
     let keys = take_primitive::<K, I>(values.keys(), indices);
-    DictionaryArray::<K>::from_data(keys, values.values().clone())
+    DictionaryArray::<K>::from_data(keys, values.values().clone()).with_ordered(values.is_ordered())
 }
@@ -0,0 +1,20 @@
+use crate::array::{Array, FixedSizeBinaryArray, MutableFixedSizeBinaryArray, PrimitiveArray};
+
+use super::Index;
+
+/// `take` implementation for [`FixedSizeBinaryArray`]
+pub fn take<I: Index>(
+    values: &FixedSizeBinaryArray,
+    indices: &PrimitiveArray<I>,
+) -> FixedSizeBinaryArray {
+    let mut taken = MutableFixedSizeBinaryArray::with_capacity(values.size(), indices.len());
+    for index in indices.iter() {
+        let value = index
+            .map(|index| index.to_usize())
+            .filter(|index| values.is_valid(*index))
+            .map(|index| values.value(index));
+        taken.push(value);
+    }
+    let taken: FixedSizeBinaryArray = taken.into();
+    taken.to(values.data_type().clone())
+}
@@ -18,15 +18,20 @@
 //! Defines take kernel for [`Array`]
 
 use crate::{
-    array::{new_empty_array, Array, NullArray, PrimitiveArray},
+    array::{
+        growable::{make_growable, Growable},
+        new_empty_array, Array, NullArray, PrimitiveArray,
+    },
     datatypes::DataType,
-    error::Result,
+    error::{ArrowError, Result},
+    record_batch::RecordBatch,
     types::Index,
 };
 
 mod binary;
 mod boolean;
 mod dict;
+mod fixed_size_binary;
 mod generic_binary;
 mod list;
 mod primitive;
@@ -70,6 +75,10 @@ pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result
             let values = values.as_any().downcast_ref().unwrap();
             Ok(Box::new(binary::take::<i64, _>(values, indices)))
         }
+        FixedSizeBinary => {
+            let values = values.as_any().downcast_ref().unwrap();
+            Ok(Box::new(fixed_size_binary::take::<O>(values, indices)))
+        }
         Dictionary(key_type) => {
             match_integer_type!(key_type, |$T| {
                 let values = values.as_any().downcast_ref().unwrap();
@@ -92,6 +101,234 @@ pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result
     }
 }
 
+/// Indices of a take operation, validated and decoded once so that they can be used to take
+/// multiple arrays of the same length (e.g. the columns of a [`RecordBatch`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TakeIndices {
+    // runs of consecutive indices `(Some(start), len)` and of null indices `(None, len)`
+    runs: Vec<(Option<usize>, usize)>,
+    len: usize,
+    has_nulls: bool,
+}
+
+impl TakeIndices {
+    /// Decodes `indices`, used to take from arrays of length `length`.
+    /// # Errors
+    /// Errors iff a non-null index is out of bounds, i.e. larger than or equal to `length`.
+    pub fn try_new<O: Index>(indices: &PrimitiveArray<O>, length: usize) -> Result<Self> {
+        Self::try_from_iter(indices.iter().map(|x| x.map(|x| x.to_usize())), length)
+    }
+
+    fn try_from_iter<I: Iterator<Item = Option<usize>>>(indices: I, length: usize) -> Result<Self> {
+        let mut runs: Vec<(Option<usize>, usize)> = vec![];
+        let mut len = 0;
+        let mut has_nulls = false;
+        for index in indices {
+            len += 1;
+            has_nulls |= index.is_none();
+            if let Some(index) = index {
+                if index >= length {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "Take index {} is out of bounds for arrays of length {}",
+                        index, length
+                    )));
+                }
+            }
+            match (runs.last_mut(), index) {
+                (Some((Some(start), len)), Some(index)) if *start + *len == index => *len += 1,
+                (Some((None, len)), None) => *len += 1,
+                _ => runs.push((index, 1)),
+            }
+        }
+        Ok(Self {
+            runs,
+            len,
+            has_nulls,
+        })
+    }
+
+    /// The number of indices, i.e. the length of the arrays resulting from taking them.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether there are no indices.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Whether any of the indices is null. Growables extended by [`TakeIndices::extend`]
+    /// must then be created with `use_validity = true`.
+    pub fn has_nulls(&self) -> bool {
+        self.has_nulls
+    }
+
+    /// Extends `growable` with the elements at these indices of its array at `index`,
+    /// e.g. to gather into pre-allocated [`Growable`]s, or from multiple batches into one.
+    /// # Panics
+    /// Panics iff the array at `index` is shorter than the length these indices were decoded for.
+    pub fn extend<'a>(&self, growable: &mut dyn Growable<'a>, index: usize) {
+        self.runs.iter().for_each(|(start, len)| match start {
+            Some(start) => growable.extend(index, *start, *len),
+            None => growable.extend_validity(*len),
+        })
+    }
+}
+
+/// Takes the elements at `indices` of each of `arrays`, validating `indices` once.
+///
+/// Arrays whose type is supported by [`take`] (see [`can_take`]) are taken with its kernels,
+/// and are thus taken as fast as by calling [`take`] on each of them; the other arrays are
+/// gathered via [`Growable`]s (see [`gather`]).
+/// # Errors
+/// Errors iff the arrays do not have the same length or an index is out of bounds.
+pub fn take_arrays<O: Index>(
+    arrays: &[&dyn Array],
+    indices: &PrimitiveArray<O>,
+) -> Result<Vec<Box<dyn Array>>> {
+    let length = arrays.first().map(|array| array.len()).unwrap_or(0);
+    if arrays.iter().any(|array| array.len() != length) {
+        return Err(ArrowError::InvalidArgumentError(
+            "Arrays taken with the same indices must have the same length".to_string(),
+        ));
+    }
+    check_bounds(indices, length)?;
+
+    arrays
+        .iter()
+        .map(|array| {
+            if can_take(array.data_type().to_logical_type()) {
+                take(*array, indices)
+            } else {
+                gather(*array, indices)
+            }
+        })
+        .collect()
+}
+
+/// Errors iff a non-null index of `indices` is larger than or equal to `length`.
+fn check_bounds<O: Index>(indices: &PrimitiveArray<O>, length: usize) -> Result<()> {
+    // comparing all values is vectorized; null slots are only inspected when that fails
+    let out_of_bounds = indices
+        .values()
+        .iter()
+        .fold(false, |acc, index| acc | (index.to_usize() >= length))
+        && indices
+            .iter()
+            .flatten()
+            .any(|index| index.to_usize() >= length);
+    if out_of_bounds {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "Take indices are out of bounds for arrays of length {}",
+            length
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+fn gather_indices(values: &dyn Array, indices: &TakeIndices) -> Box<dyn Array> {
+    let mut growable = make_growable(&[values], indices.has_nulls(), indices.len());
+    indices.extend(growable.as_mut(), 0);
+    growable.as_box()
+}
+
+/// Returns a new [`Array`] with the elements of `values` at `indices`, where null indices
+/// (e.g. the misses of the probe side of a join) are gathered as nulls.
+///
+/// Contrarily to [`take`], this is implemented via [`Growable`]s for all types and errors
+/// instead of panicking on out of bounds indices.
+/// # Errors
+/// Errors iff an index is out of bounds.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::take::gather;
+///
+/// let values = Utf8Array::<i32>::from_slice(&["a", "b", "c"]);
+/// let indices = Int32Array::from(&[Some(2), None, Some(0)]);
+///
+/// let result = gather(&values, &indices).unwrap();
+/// let expected = Utf8Array::<i32>::from(&[Some("c"), None, Some("a")]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn gather<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result<Box<dyn Array>> {
+    let indices = TakeIndices::try_new(indices, values.len())?;
+    Ok(gather_indices(values, &indices))
+}
+
+/// Returns a new [`Array`] of length `len` where the element at `positions[i]` is `values[i]`
+/// and all other elements are null. Elements of `values` whose position is null are dropped.
+///
+/// This is the inverse of [`gather`], e.g. to place the matches of a join at their rows in the output.
+/// # Errors
+/// Errors iff `values` and `positions` do not have the same length, or a position is out of bounds
+/// or repeated.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, UInt32Array};
+/// use arrow2::compute::take::scatter;
+///
+/// let values = Int32Array::from_slice(&[10, 20, 30]);
+/// let positions = UInt32Array::from(&[Some(3), Some(0), None]);
+///
+/// let result = scatter(&values, &positions, 4).unwrap();
+/// let expected = Int32Array::from(&[Some(20), None, None, Some(10)]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn scatter<O: Index>(
+    values: &dyn Array,
+    positions: &PrimitiveArray<O>,
+    len: usize,
+) -> Result<Box<dyn Array>> {
+    if values.len() != positions.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Scattered values and positions must have the same length".to_string(),
+        ));
+    }
+    let mut indices = vec![None; len];
+    for (index, position) in positions.iter().enumerate() {
+        if let Some(position) = position {
+            let position = position.to_usize();
+            let slot = indices.get_mut(position).ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "Scatter position {} is out of bounds for arrays of length {}",
+                    position, len
+                ))
+            })?;
+            if slot.is_some() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Scatter position {} is repeated",
+                    position
+                )));
+            }
+            *slot = Some(index);
+        }
+    }
+    let indices = TakeIndices::try_from_iter(indices.into_iter(), values.len())?;
+    Ok(gather_indices(values, &indices))
+}
+
+/// Returns a new [`RecordBatch`] with the rows of `batch` at `indices`, validating `indices`
+/// once for all columns (see [`take_arrays`]). Null indices are taken as nulls.
+/// # Errors
+/// Errors iff an index is out of bounds.
+pub fn take_record_batch<O: Index>(
+    batch: &RecordBatch,
+    indices: &PrimitiveArray<O>,
+) -> Result<RecordBatch> {
+    let arrays = batch
+        .columns()
+        .iter()
+        .map(|array| array.as_ref())
+        .collect::<Vec<_>>();
+    let columns = take_arrays(&arrays, indices)?
+        .into_iter()
+        .map(|array| array.into())
+        .collect();
+    RecordBatch::try_new(batch.schema().clone(), columns)
+}
+
 /// Checks if an array of type `datatype` can perform take operation
 ///
 /// # Examples
@@ -130,9 +367,10 @@ pub fn can_take(data_type: &DataType) -> bool {
             | DataType::LargeUtf8
             | DataType::Binary
             | DataType::LargeBinary
+            | DataType::FixedSizeBinary(_)
             | DataType::Struct(_)
             | DataType::List(_)
             | DataType::LargeList(_)
-            | DataType::Dictionary(_, _)
+            | DataType::Dictionary(_, _, _)
     )
 }
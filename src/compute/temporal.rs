@@ -20,6 +20,8 @@
 use chrono::{Datelike, Timelike};
 
 use crate::array::*;
+use crate::bitmap::Bitmap;
+use crate::buffer::Buffer;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::temporal_conversions::*;
@@ -27,6 +29,7 @@ use crate::types::NativeType;
 use crate::types::NaturalDataType;
 
 use super::arity::unary;
+use super::utils::{check_same_len, combine_validities};
 
 // Create and implement a trait that converts chrono's `Weekday`
 // type into `u32`
@@ -50,6 +53,26 @@ trait U32IsoWeek: Datelike {
 impl U32IsoWeek for chrono::NaiveDateTime {}
 impl<T: chrono::TimeZone> U32IsoWeek for chrono::DateTime<T> {}
 
+fn is_leap(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+// Create and implement a trait that returns the number of days
+// of the month of a date as `u32`
+trait U32DaysInMonth: Datelike {
+    fn u32_days_in_month(&self) -> u32 {
+        match self.month() {
+            2 if is_leap(self.year()) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+}
+
+impl U32DaysInMonth for chrono::NaiveDateTime {}
+impl<T: chrono::TimeZone> U32DaysInMonth for chrono::DateTime<T> {}
+
 // Macro to avoid repetition in functions, that apply
 // `chrono::Datelike` methods on Arrays
 macro_rules! date_like {
@@ -110,6 +133,227 @@ pub fn iso_week(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
     date_like!(u32_iso_week, array, DataType::UInt32)
 }
 
+/// Returns whether the years of a temporal array are leap years, as a [`BooleanArray`].
+/// Use [`can_is_leap_year`] to check if this operation is supported for the target [`DataType`].
+pub fn is_leap_year(array: &dyn Array) -> Result<BooleanArray> {
+    let years = year(array)?;
+    let values = Bitmap::from_trusted_len_iter(years.values().iter().map(|x| is_leap(*x)));
+    Ok(BooleanArray::from_data(
+        DataType::Boolean,
+        values,
+        years.validity().cloned(),
+    ))
+}
+
+/// Extracts the number of days of the month of a temporal array as [`PrimitiveArray<u32>`].
+/// Value ranges from 28 to 31.
+/// Use [`can_days_in_month`] to check if this operation is supported for the target [`DataType`].
+pub fn days_in_month(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    date_like!(u32_days_in_month, array, DataType::UInt32)
+}
+
+/// The language of the names returned by [`weekday_name`] and [`month_name`].
+/// More languages may be added in minor releases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Locale {
+    /// English, e.g. `Monday` and `January`.
+    English,
+    /// French, e.g. `lundi` and `janvier`.
+    French,
+    /// German, e.g. `Montag` and `Januar`.
+    German,
+    /// Italian, e.g. `lunedì` and `gennaio`.
+    Italian,
+    /// Portuguese, e.g. `segunda-feira` and `janeiro`.
+    Portuguese,
+    /// Spanish, e.g. `lunes` and `enero`.
+    Spanish,
+}
+
+// English, the language of the names `chrono` formats; written by hand because
+// `#[default]` needs a newer toolchain than the one we support
+#[allow(clippy::derivable_impls)]
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::English
+    }
+}
+
+impl Locale {
+    /// The names of the weekdays, starting on Monday.
+    fn weekdays(self) -> &'static [&'static str; 7] {
+        match self {
+            Locale::English => &[
+                "Monday",
+                "Tuesday",
+                "Wednesday",
+                "Thursday",
+                "Friday",
+                "Saturday",
+                "Sunday",
+            ],
+            Locale::French => &[
+                "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+            ],
+            Locale::German => &[
+                "Montag",
+                "Dienstag",
+                "Mittwoch",
+                "Donnerstag",
+                "Freitag",
+                "Samstag",
+                "Sonntag",
+            ],
+            Locale::Italian => &[
+                "lunedì",
+                "martedì",
+                "mercoledì",
+                "giovedì",
+                "venerdì",
+                "sabato",
+                "domenica",
+            ],
+            Locale::Portuguese => &[
+                "segunda-feira",
+                "terça-feira",
+                "quarta-feira",
+                "quinta-feira",
+                "sexta-feira",
+                "sábado",
+                "domingo",
+            ],
+            Locale::Spanish => &[
+                "lunes",
+                "martes",
+                "miércoles",
+                "jueves",
+                "viernes",
+                "sábado",
+                "domingo",
+            ],
+        }
+    }
+
+    /// The names of the months, starting on January.
+    fn months(self) -> &'static [&'static str; 12] {
+        match self {
+            Locale::English => &[
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ],
+            Locale::French => &[
+                "janvier",
+                "février",
+                "mars",
+                "avril",
+                "mai",
+                "juin",
+                "juillet",
+                "août",
+                "septembre",
+                "octobre",
+                "novembre",
+                "décembre",
+            ],
+            Locale::German => &[
+                "Januar",
+                "Februar",
+                "März",
+                "April",
+                "Mai",
+                "Juni",
+                "Juli",
+                "August",
+                "September",
+                "Oktober",
+                "November",
+                "Dezember",
+            ],
+            Locale::Italian => &[
+                "gennaio",
+                "febbraio",
+                "marzo",
+                "aprile",
+                "maggio",
+                "giugno",
+                "luglio",
+                "agosto",
+                "settembre",
+                "ottobre",
+                "novembre",
+                "dicembre",
+            ],
+            Locale::Portuguese => &[
+                "janeiro",
+                "fevereiro",
+                "março",
+                "abril",
+                "maio",
+                "junho",
+                "julho",
+                "agosto",
+                "setembro",
+                "outubro",
+                "novembro",
+                "dezembro",
+            ],
+            Locale::Spanish => &[
+                "enero",
+                "febrero",
+                "marzo",
+                "abril",
+                "mayo",
+                "junio",
+                "julio",
+                "agosto",
+                "septiembre",
+                "octubre",
+                "noviembre",
+                "diciembre",
+            ],
+        }
+    }
+}
+
+// maps values ranging from 1 to `names.len()` to their names
+fn to_names(array: &PrimitiveArray<u32>, names: &[&'static str]) -> Utf8Array<i32> {
+    Utf8Array::<i32>::from_trusted_len_iter(array.iter().map(|x| x.map(|x| names[*x as usize - 1])))
+}
+
+/// Returns the names of the weekdays of a temporal array in `locale`, as a [`Utf8Array<i32>`].
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::temporal::{weekday_name, Locale};
+/// use arrow2::datatypes::DataType;
+///
+/// // 2021-12-20 and 2021-12-25
+/// let array = Int32Array::from([Some(18981), None, Some(18986)]).to(DataType::Date32);
+/// let result = weekday_name(&array, Locale::French).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from([Some("lundi"), None, Some("samedi")]));
+/// ```
+/// Use [`can_weekday_name`] to check if this operation is supported for the target [`DataType`].
+pub fn weekday_name(array: &dyn Array, locale: Locale) -> Result<Utf8Array<i32>> {
+    Ok(to_names(&weekday(array)?, locale.weekdays()))
+}
+
+/// Returns the names of the months of a temporal array in `locale`, as a [`Utf8Array<i32>`].
+/// Use [`can_month_name`] to check if this operation is supported for the target [`DataType`].
+pub fn month_name(array: &dyn Array, locale: Locale) -> Result<Utf8Array<i32>> {
+    Ok(to_names(&month(array)?, locale.months()))
+}
+
 // Macro to avoid repetition in functions, that apply
 // `chrono::Timelike` methods on Arrays
 macro_rules! time_like {
@@ -320,6 +564,225 @@ where
     }
 }
 
+/// The unit in which [`date_diff`] expresses the difference between two temporal values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiffUnit {
+    /// Number of complete calendar years
+    Years,
+    /// Number of complete calendar months
+    Months,
+    /// Number of complete weeks of 7 calendar days
+    Weeks,
+    /// Number of complete calendar days
+    Days,
+    /// Number of complete hours
+    Hours,
+    /// Number of complete minutes
+    Minutes,
+    /// Number of complete seconds
+    Seconds,
+    /// Number of complete milliseconds
+    Milliseconds,
+    /// Number of complete microseconds
+    Microseconds,
+    /// Number of nanoseconds
+    Nanoseconds,
+}
+
+/// Number of complete months from `rhs` to `lhs`, using calendar rules:
+/// e.g. there is one month from 2021-01-15 to 2021-02-15 but none to 2021-02-14.
+fn months_between(lhs: chrono::NaiveDateTime, rhs: chrono::NaiveDateTime) -> i64 {
+    let months =
+        (lhs.year() as i64 - rhs.year() as i64) * 12 + lhs.month() as i64 - rhs.month() as i64;
+    let lhs_rest = (lhs.day(), lhs.time());
+    let rhs_rest = (rhs.day(), rhs.time());
+    if months > 0 && lhs_rest < rhs_rest {
+        months - 1
+    } else if months < 0 && lhs_rest > rhs_rest {
+        months + 1
+    } else {
+        months
+    }
+}
+
+/// Returns the difference `lhs - rhs` in `unit`, where each value is a pair of
+/// (UTC, local) datetimes. Calendar units (days and above) are computed from the local
+/// datetimes, so that e.g. a day across a daylight saving time transition counts as one day;
+/// the remaining units are computed from the elapsed (UTC) time.
+fn datetime_diff(
+    lhs: (chrono::NaiveDateTime, chrono::NaiveDateTime),
+    rhs: (chrono::NaiveDateTime, chrono::NaiveDateTime),
+    unit: DiffUnit,
+) -> Result<i64> {
+    let (lhs_utc, lhs_local) = lhs;
+    let (rhs_utc, rhs_local) = rhs;
+    let elapsed = lhs_utc - rhs_utc;
+    Ok(match unit {
+        DiffUnit::Years => months_between(lhs_local, rhs_local) / 12,
+        DiffUnit::Months => months_between(lhs_local, rhs_local),
+        DiffUnit::Weeks => (lhs_local - rhs_local).num_weeks(),
+        DiffUnit::Days => (lhs_local - rhs_local).num_days(),
+        DiffUnit::Hours => elapsed.num_hours(),
+        DiffUnit::Minutes => elapsed.num_minutes(),
+        DiffUnit::Seconds => elapsed.num_seconds(),
+        DiffUnit::Milliseconds => elapsed.num_milliseconds(),
+        DiffUnit::Microseconds => elapsed.num_microseconds().ok_or(ArrowError::Overflow)?,
+        DiffUnit::Nanoseconds => elapsed.num_nanoseconds().ok_or(ArrowError::Overflow)?,
+    })
+}
+
+fn diff_impl<F>(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveArray<i64>,
+    unit: DiffUnit,
+    to_datetimes: F,
+) -> Result<PrimitiveArray<i64>>
+where
+    F: Fn(i64) -> (chrono::NaiveDateTime, chrono::NaiveDateTime),
+{
+    check_same_len(lhs, rhs)?;
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let values = lhs
+        .values()
+        .iter()
+        .zip(rhs.values().iter())
+        .enumerate()
+        .map(|(i, (lhs, rhs))| {
+            // values in null slots are undefined and may not represent valid datetimes
+            if validity.as_ref().map(|x| x.get_bit(i)).unwrap_or(true) {
+                datetime_diff(to_datetimes(*lhs), to_datetimes(*rhs), unit)
+            } else {
+                Ok(0)
+            }
+        });
+    let values = Buffer::try_from_trusted_len_iter(values)?;
+
+    Ok(PrimitiveArray::<i64>::from_data(
+        DataType::Int64,
+        values,
+        validity,
+    ))
+}
+
+fn timestamp_diff_tz<T: chrono::TimeZone>(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone: T,
+    unit: DiffUnit,
+) -> Result<PrimitiveArray<i64>> {
+    diff_impl(lhs, rhs, unit, |x| {
+        let datetime = timestamp_to_naive_datetime(x, time_unit);
+        let local = timezone.from_utc_datetime(&datetime).naive_local();
+        (datetime, local)
+    })
+}
+
+#[cfg(feature = "chrono-tz")]
+fn timestamp_diff_chrono_tz(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone_str: &str,
+    unit: DiffUnit,
+) -> Result<PrimitiveArray<i64>> {
+    let timezone = parse_offset_tz(timezone_str)?;
+    timestamp_diff_tz(lhs, rhs, time_unit, timezone, unit)
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn timestamp_diff_chrono_tz(
+    _: &PrimitiveArray<i64>,
+    _: &PrimitiveArray<i64>,
+    _: TimeUnit,
+    timezone_str: &str,
+    _: DiffUnit,
+) -> Result<PrimitiveArray<i64>> {
+    Err(ArrowError::InvalidArgumentError(format!(
+        "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+        timezone_str
+    )))
+}
+
+/// Returns the difference `lhs - rhs` between two temporal arrays of the same [`DataType`]
+/// as a [`PrimitiveArray<i64>`] in `unit`, truncated towards zero.
+///
+/// Calendar units ([`DiffUnit::Years`], [`DiffUnit::Months`], [`DiffUnit::Weeks`] and
+/// [`DiffUnit::Days`]) follow calendar rules on the local datetime of each value, so that
+/// e.g. there are no complete months from 2021-01-31 to 2021-02-28 and a day across a
+/// daylight saving time transition counts as one day.
+/// Other units measure the elapsed time.
+/// Use [`can_date_diff`] to check if this operation is supported for the target [`DataType`].
+/// # Errors
+/// Errors iff the arrays have different lengths or types, the type is not supported,
+/// or the difference overflows.
+/// # Example
+/// ```
+/// use arrow2::array::{Int64Array, PrimitiveArray};
+/// use arrow2::compute::temporal::{date_diff, DiffUnit};
+/// use arrow2::datatypes::DataType;
+///
+/// // 2021-03-15 and 2021-01-20
+/// let lhs = PrimitiveArray::<i32>::from_slice([18701]).to(DataType::Date32);
+/// let rhs = PrimitiveArray::<i32>::from_slice([18647]).to(DataType::Date32);
+///
+/// assert_eq!(date_diff(&lhs, &rhs, DiffUnit::Days).unwrap(), Int64Array::from_slice([54]));
+/// assert_eq!(date_diff(&lhs, &rhs, DiffUnit::Months).unwrap(), Int64Array::from_slice([1]));
+/// ```
+pub fn date_diff(lhs: &dyn Array, rhs: &dyn Array, unit: DiffUnit) -> Result<PrimitiveArray<i64>> {
+    if lhs.data_type() != rhs.data_type() {
+        return Err(ArrowError::InvalidArgumentError(
+            "date_diff requires both arrays to have the same data type".to_string(),
+        ));
+    }
+    match lhs.data_type().to_logical_type() {
+        DataType::Date32 => {
+            let to_i64 = |array: &dyn Array| {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<i32>>()
+                    .unwrap();
+                unary(array, |x| x as i64, DataType::Int64)
+            };
+            diff_impl(&to_i64(lhs), &to_i64(rhs), unit, |x| {
+                let datetime = date32_to_datetime(x as i32);
+                (datetime, datetime)
+            })
+        }
+        DataType::Date64 => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            diff_impl(lhs, rhs, unit, |x| {
+                let datetime = date64_to_datetime(x);
+                (datetime, datetime)
+            })
+        }
+        DataType::Timestamp(time_unit, None) => {
+            let time_unit = *time_unit;
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            diff_impl(lhs, rhs, unit, |x| {
+                let datetime = timestamp_to_naive_datetime(x, time_unit);
+                (datetime, datetime)
+            })
+        }
+        DataType::Timestamp(time_unit, Some(timezone_str)) => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            if let Ok(timezone) = parse_offset(timezone_str) {
+                timestamp_diff_tz(lhs, rhs, *time_unit, timezone, unit)
+            } else {
+                timestamp_diff_chrono_tz(lhs, rhs, *time_unit, timezone_str, unit)
+            }
+        }
+        dt => Err(ArrowError::NotYetImplemented(format!(
+            "\"date_diff\" does not support type {:?}",
+            dt
+        ))),
+    }
+}
+
 /// Checks if an array of type `datatype` can perform year operation
 ///
 /// # Examples
@@ -354,6 +817,31 @@ pub fn can_iso_week(data_type: &DataType) -> bool {
     can_date(data_type)
 }
 
+/// Checks if an array of type `datatype` can perform is_leap_year operation
+pub fn can_is_leap_year(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `datatype` can perform days_in_month operation
+pub fn can_days_in_month(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `datatype` can perform weekday_name operation
+pub fn can_weekday_name(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `datatype` can perform month_name operation
+pub fn can_month_name(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if arrays of type `data_type` can perform [`date_diff`]
+pub fn can_date_diff(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
 fn can_date(data_type: &DataType) -> bool {
     matches!(
         data_type,
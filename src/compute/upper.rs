@@ -17,7 +17,7 @@
 
 //! Defines kernel to extract a upper case of a \[Large\]StringArray
 
-use super::utils::utf8_apply;
+use super::utils::{utf8_apply_ascii, utf8_apply_ascii_owned};
 use crate::array::*;
 use crate::{
     datatypes::DataType,
@@ -26,16 +26,19 @@ use crate::{
 
 /// Returns a new `Array` where each of each of the elements is upper-cased.
 /// this function errors when the passed array is not a \[Large\]String array.
+/// When all values are ASCII, they are upper-cased in a single pass over the values buffer.
 pub fn upper(array: &dyn Array) -> Result<Box<dyn Array>> {
     match array.data_type() {
-        DataType::LargeUtf8 => Ok(Box::new(utf8_apply(
+        DataType::LargeUtf8 => Ok(Box::new(utf8_apply_ascii(
+            u8::to_ascii_uppercase,
             str::to_uppercase,
             array
                 .as_any()
                 .downcast_ref::<Utf8Array<i64>>()
                 .expect("A large string is expected"),
         ))),
-        DataType::Utf8 => Ok(Box::new(utf8_apply(
+        DataType::Utf8 => Ok(Box::new(utf8_apply_ascii(
+            u8::to_ascii_uppercase,
             str::to_uppercase,
             array
                 .as_any()
@@ -49,6 +52,12 @@ pub fn upper(array: &dyn Array) -> Result<Box<dyn Array>> {
     }
 }
 
+/// Same as [`upper`], but consumes `array` and, when all its values are ASCII and its
+/// values buffer is not shared with other arrays, upper-cases them in place.
+pub fn upper_owned<O: Offset>(array: Utf8Array<O>) -> Utf8Array<O> {
+    utf8_apply_ascii_owned(u8::to_ascii_uppercase, str::to_uppercase, array)
+}
+
 /// Checks if an array of type `datatype` can perform upper operation
 ///
 /// # Examples
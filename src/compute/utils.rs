@@ -1,6 +1,7 @@
 use crate::{
     array::{Array, BooleanArray, Offset, Utf8Array},
     bitmap::Bitmap,
+    buffer::Buffer,
     datatypes::DataType,
     error::{ArrowError, Result},
 };
@@ -38,6 +39,75 @@ pub fn utf8_apply<O: Offset, F: Fn(&str) -> String>(f: F, array: &Utf8Array<O>)
     new.with_validity(array.validity().cloned())
 }
 
+/// Applies `ascii` to every byte of the values of `array` when all of them are ASCII,
+/// and `Fn(&str) -> String` to every value otherwise.
+/// `ascii` must map ASCII bytes to ASCII bytes.
+pub fn utf8_apply_ascii<O, A, F>(ascii: A, f: F, array: &Utf8Array<O>) -> Utf8Array<O>
+where
+    O: Offset,
+    A: Fn(&u8) -> u8,
+    F: Fn(&str) -> String,
+{
+    let offsets = array.offsets();
+    let start = offsets[0];
+    // the values of a sliced array are a subset of its buffer
+    let values = &array.values()[start.to_usize()..offsets[offsets.len() - 1].to_usize()];
+    if !values.is_ascii() {
+        return utf8_apply(f, array);
+    }
+
+    let values = Buffer::from_trusted_len_iter(values.iter().map(ascii));
+    let offsets = if start == O::default() {
+        offsets.clone()
+    } else {
+        Buffer::from_trusted_len_iter(offsets.iter().map(|x| *x - start))
+    };
+    // Safety: the offsets are monotonic and ASCII is valid utf8
+    unsafe {
+        Utf8Array::<O>::from_data_unchecked(
+            array.data_type().clone(),
+            offsets,
+            values,
+            array.validity().cloned(),
+        )
+    }
+}
+
+/// Same as [`utf8_apply_ascii`], but applies `ascii` in place when the values of `array`
+/// are not shared with other arrays.
+pub fn utf8_apply_ascii_owned<O, A, F>(ascii: A, f: F, array: Utf8Array<O>) -> Utf8Array<O>
+where
+    O: Offset,
+    A: Fn(&u8) -> u8,
+    F: Fn(&str) -> String,
+{
+    let (data_type, offsets, values, validity) = array.into_data();
+    // the values of a sliced array are a subset of its buffer
+    let start = offsets[0];
+    let end = offsets[offsets.len() - 1].to_usize();
+    let (offsets, mut values) = if start == O::default() && end == values.len() {
+        (offsets, values)
+    } else {
+        let offsets = Buffer::from_trusted_len_iter(offsets.iter().map(|x| *x - start));
+        let values = values.slice(start.to_usize(), end - start.to_usize());
+        (offsets, values)
+    };
+
+    if let Some(slice) = values.get_mut() {
+        if slice.is_ascii() {
+            slice.iter_mut().for_each(|x| *x = ascii(x));
+            // Safety: the offsets are unchanged and ASCII is valid utf8
+            return unsafe {
+                Utf8Array::<O>::from_data_unchecked(data_type, offsets, values, validity)
+            };
+        }
+    }
+    // Safety: the array is unchanged
+    let array =
+        unsafe { Utf8Array::<O>::from_data_unchecked(data_type, offsets, values, validity) };
+    utf8_apply_ascii(ascii, f, &array)
+}
+
 // Errors iff the two arrays have a different length.
 #[inline]
 pub fn check_same_len(lhs: &dyn Array, rhs: &dyn Array) -> Result<()> {
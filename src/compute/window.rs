@@ -15,14 +15,18 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines windowing functions, like `shift`ing
+//! Defines windowing functions, like `shift`ing, and ranking functions over sort indices
 
 use crate::compute::concatenate::concatenate;
 use num_traits::{abs, clamp};
 
 use crate::{
-    array::{new_null_array, Array},
+    array::{new_full_array, new_null_array, ord::build_compare, Array, PrimitiveArray},
+    buffer::MutableBuffer,
+    datatypes::DataType,
     error::{ArrowError, Result},
+    scalar::Scalar,
+    types::Index,
 };
 
 /// Shifts array by defined number of items (to left or right)
@@ -63,3 +67,211 @@ pub fn shift(array: &dyn Array, offset: i64) -> Result<Box<dyn Array>> {
         concatenate(&[slice.as_ref(), null_array.as_ref()])
     }
 }
+
+/// Shifts array by defined number of items (to left or right), filling the
+/// remaining slots with `fill`. See [`shift`] for the meaning of `offset`.
+/// # Errors
+/// Errors iff the absolute `offset` is larger than the length of the array or
+/// if `fill` is valid and its data type differs from the array's.
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::window::shift_fill;
+/// use arrow2::scalar::PrimitiveScalar;
+/// use arrow2::datatypes::DataType;
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(3)]);
+/// let fill = PrimitiveScalar::<i32>::new(DataType::Int32, Some(0));
+/// let result = shift_fill(&array, 1, &fill).unwrap();
+/// let expected = Int32Array::from(&[Some(0), Some(1), None]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn shift_fill(array: &dyn Array, offset: i64, fill: &dyn Scalar) -> Result<Box<dyn Array>> {
+    if !fill.is_valid() {
+        return shift(array, offset);
+    }
+    if fill.data_type() != array.data_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The fill value of a shift must be of the array's type. Fill is {:?}, array is {:?}",
+            fill.data_type(),
+            array.data_type()
+        )));
+    }
+    if abs(offset) as usize > array.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Shift's absolute offset must be smaller or equal to the arrays length. Offset is {}, length is {}",
+            abs(offset), array.len()
+        )));
+    }
+
+    let slice_offset = clamp(-offset, 0, array.len() as i64) as usize;
+    let length = array.len() - abs(offset) as usize;
+    let slice = array.slice(slice_offset, length);
+
+    let filled = new_full_array(fill, abs(offset) as usize)?;
+
+    if offset > 0 {
+        concatenate(&[filled.as_ref(), slice.as_ref()])
+    } else {
+        concatenate(&[slice.as_ref(), filled.as_ref()])
+    }
+}
+
+/// Returns, for each slot, the value `n` slots before it (SQL's `LAG`), or `fill` (null when
+/// `None`) when there is no such slot.
+/// # Errors
+/// Errors iff `fill` is valid and its data type differs from the array's.
+pub fn lag(array: &dyn Array, n: usize, fill: Option<&dyn Scalar>) -> Result<Box<dyn Array>> {
+    let offset = n.min(array.len()) as i64;
+    match fill {
+        Some(fill) => shift_fill(array, offset, fill),
+        None => shift(array, offset),
+    }
+}
+
+/// Returns, for each slot, the value `n` slots after it (SQL's `LEAD`), or `fill` (null when
+/// `None`) when there is no such slot.
+/// # Errors
+/// Errors iff `fill` is valid and its data type differs from the array's.
+pub fn lead(array: &dyn Array, n: usize, fill: Option<&dyn Scalar>) -> Result<Box<dyn Array>> {
+    let offset = -(n.min(array.len()) as i64);
+    match fill {
+        Some(fill) => shift_fill(array, offset, fill),
+        None => shift(array, offset),
+    }
+}
+
+/// Returns the row number of each row given the `indices` that sort the rows (SQL's `ROW_NUMBER`),
+/// as returned by e.g. [`crate::compute::sort::sort_to_indices`]: the row `indices[i]` has
+/// number `i + 1`, so that numbers start at 1 and follow the order of `indices`.
+///
+/// Rows with null values are numbered like any other row, at the position the sort placed
+/// them (see [`crate::compute::sort::SortOptions::nulls_first`]), and the result has no nulls.
+/// The validity of `indices` is ignored and rows absent from `indices` have number 0.
+/// # Panics
+/// Panics iff an index is larger or equal to `indices.len()`.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, UInt64Array};
+/// use arrow2::compute::sort::{sort_to_indices, SortOptions};
+/// use arrow2::compute::window::row_number;
+///
+/// let array = Int32Array::from(&[Some(3), None, Some(1)]);
+/// let options = SortOptions { descending: false, nulls_first: true };
+/// let indices = sort_to_indices::<u32>(&array, &options, None).unwrap();
+///
+/// assert_eq!(row_number(&indices), UInt64Array::from_slice(&[3, 1, 2]));
+/// ```
+pub fn row_number<I: Index>(indices: &PrimitiveArray<I>) -> PrimitiveArray<u64> {
+    let mut values = MutableBuffer::<u64>::from_len_zeroed(indices.len());
+    indices
+        .values()
+        .iter()
+        .enumerate()
+        .for_each(|(position, index)| values[index.to_usize()] = position as u64 + 1);
+    PrimitiveArray::<u64>::from_data(DataType::UInt64, values.into(), None)
+}
+
+/// Returns a function that returns whether the rows `i` and `j` of `columns` are equal,
+/// where nulls are equal to nulls.
+fn build_is_peer<'a>(columns: &'a [&'a dyn Array]) -> Result<impl Fn(usize, usize) -> bool + 'a> {
+    let comparators = columns
+        .iter()
+        .map(|column| build_compare(*column, *column))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(move |i: usize, j: usize| {
+        columns
+            .iter()
+            .zip(comparators.iter())
+            .all(
+                |(column, comparator)| match (column.is_valid(i), column.is_valid(j)) {
+                    (true, true) => comparator(i, j) == std::cmp::Ordering::Equal,
+                    (false, false) => true,
+                    _ => false,
+                },
+            )
+    })
+}
+
+fn rank_impl<I: Index>(
+    columns: &[&dyn Array],
+    indices: &PrimitiveArray<I>,
+    dense: bool,
+) -> Result<PrimitiveArray<u64>> {
+    if columns.iter().any(|column| column.len() != indices.len()) {
+        return Err(ArrowError::InvalidArgumentError(
+            "Ranking requires all columns to have the same length as the indices".to_string(),
+        ));
+    }
+    if let Some(index) = indices
+        .values()
+        .iter()
+        .find(|index| index.to_usize() >= indices.len())
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Ranking requires indices smaller than their length ({}), but found {}",
+            indices.len(),
+            index.to_usize()
+        )));
+    }
+    let is_peer = build_is_peer(columns)?;
+
+    let mut values = MutableBuffer::<u64>::from_len_zeroed(indices.len());
+    let mut rank = 0;
+    let mut previous: Option<usize> = None;
+    for (position, index) in indices.values().iter().enumerate() {
+        let index = index.to_usize();
+        let is_new = previous
+            .map(|previous| !is_peer(previous, index))
+            .unwrap_or(true);
+        if is_new {
+            rank = if dense { rank + 1 } else { position as u64 + 1 };
+        }
+        values[index] = rank;
+        previous = Some(index);
+    }
+    Ok(PrimitiveArray::<u64>::from_data(
+        DataType::UInt64,
+        values.into(),
+        None,
+    ))
+}
+
+/// Returns the rank of each row given the `columns` the rows were sorted by and the
+/// `indices` that sort them (SQL's `RANK`): peers (rows with equal values in all `columns`)
+/// have the same rank and ranks have gaps after peers, e.g. `[1, 1, 3]`.
+/// # Errors
+/// Errors iff the columns do not have the same length as `indices`, an index is larger or equal
+/// to `indices.len()`, or the columns cannot be compared.
+/// # Examples
+/// ```
+/// use arrow2::array::{Int32Array, UInt64Array};
+/// use arrow2::compute::sort::{sort_to_indices, SortOptions};
+/// use arrow2::compute::window::{dense_rank, rank};
+///
+/// let array = Int32Array::from_slice(&[3, 1, 3, 4]);
+/// let options = SortOptions { descending: false, nulls_first: true };
+/// let indices = sort_to_indices::<u32>(&array, &options, None).unwrap();
+///
+/// assert_eq!(rank(&[&array], &indices).unwrap(), UInt64Array::from_slice(&[2, 1, 2, 4]));
+/// assert_eq!(dense_rank(&[&array], &indices).unwrap(), UInt64Array::from_slice(&[2, 1, 2, 3]));
+/// ```
+pub fn rank<I: Index>(
+    columns: &[&dyn Array],
+    indices: &PrimitiveArray<I>,
+) -> Result<PrimitiveArray<u64>> {
+    rank_impl(columns, indices, false)
+}
+
+/// Returns the dense rank of each row given the `columns` the rows were sorted by and the
+/// `indices` that sort them (SQL's `DENSE_RANK`): peers (rows with equal values in all
+/// `columns`) have the same rank and ranks have no gaps, e.g. `[1, 1, 2]`.
+/// # Errors
+/// Errors iff the columns do not have the same length as `indices`, an index is larger or equal
+/// to `indices.len()`, or the columns cannot be compared.
+pub fn dense_rank<I: Index>(
+    columns: &[&dyn Array],
+    indices: &PrimitiveArray<I>,
+) -> Result<PrimitiveArray<u64>> {
+    rank_impl(columns, indices, true)
+}
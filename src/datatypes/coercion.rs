@@ -0,0 +1,165 @@
+use super::{DataType, TimeUnit};
+
+/// Returns the [`DataType`] that both `lhs` and `rhs` can be losslessly (when possible) cast to,
+/// following Arrow's promotion rules of numeric and temporal types.
+/// Returns `None` when there is no such type.
+///
+/// The rules are:
+/// * equal types and [`DataType::Null`] with any type coerce to that type
+/// * integers of the same signedness coerce to the widest of them
+/// * a signed and an unsigned integer coerce to the narrowest signed integer that can represent
+///   both, up to `Int64` (e.g. `Int8` and `UInt8` coerce to `Int16`; `UInt64` coerces to `Int64`)
+/// * a floating point and any numeric type coerce to the widest floating point among them
+/// * decimals coerce to a decimal with enough integer and fractional digits for both, and
+///   integers to decimals with as many digits as their maximum value
+/// * `Date32` and `Date64` coerce to `Date64`, and dates and timestamps to the timestamp
+/// * times, timestamps of the same timezone and durations coerce to the finest of their units
+/// * dictionaries and extensions coerce as their values and logical types respectively
+///
+/// # Example
+/// ```
+/// use arrow2::datatypes::{common_supertype, DataType, TimeUnit};
+///
+/// assert_eq!(common_supertype(&DataType::Int8, &DataType::UInt8), Some(DataType::Int16));
+/// assert_eq!(
+///     common_supertype(&DataType::Date32, &DataType::Timestamp(TimeUnit::Second, None)),
+///     Some(DataType::Timestamp(TimeUnit::Second, None))
+/// );
+/// assert_eq!(common_supertype(&DataType::Int8, &DataType::Utf8), None);
+/// ```
+pub fn common_supertype(lhs: &DataType, rhs: &DataType) -> Option<DataType> {
+    use DataType::*;
+    let lhs = lhs.to_logical_type();
+    let rhs = rhs.to_logical_type();
+
+    if lhs == rhs {
+        return Some(lhs.clone());
+    }
+    match (lhs, rhs) {
+        (Null, other) | (other, Null) => Some(other.clone()),
+        (Dictionary(_, lhs, _), rhs) | (rhs, Dictionary(_, lhs, _)) => common_supertype(lhs, rhs),
+        (Decimal(p1, s1), Decimal(p2, s2)) => common_decimal(*p1, *s1, *p2, *s2),
+        (Decimal(precision, scale), other) | (other, Decimal(precision, scale)) => {
+            if other.is_floating() {
+                Some(Float64)
+            } else {
+                let digits = integer_digits(other)?;
+                common_decimal(*precision, *scale, digits, 0)
+            }
+        }
+        (lhs, rhs) if lhs.is_floating() && rhs.is_numeric() => Some(widest_float(lhs, rhs)),
+        (lhs, rhs) if lhs.is_numeric() && rhs.is_floating() => Some(widest_float(lhs, rhs)),
+        (lhs, rhs) if lhs.is_integer() && rhs.is_integer() => Some(common_integer(lhs, rhs)),
+        (Date32, Date64) | (Date64, Date32) => Some(Date64),
+        (Date32 | Date64, Timestamp(unit, tz)) | (Timestamp(unit, tz), Date32 | Date64) => {
+            Some(Timestamp(*unit, tz.clone()))
+        }
+        (Timestamp(lhs, lhs_tz), Timestamp(rhs, rhs_tz)) if lhs_tz == rhs_tz => {
+            Some(Timestamp(finest_unit(*lhs, *rhs), lhs_tz.clone()))
+        }
+        (Time32(lhs) | Time64(lhs), Time32(rhs) | Time64(rhs)) => {
+            let unit = finest_unit(*lhs, *rhs);
+            match unit {
+                TimeUnit::Second | TimeUnit::Millisecond => Some(Time32(unit)),
+                TimeUnit::Microsecond | TimeUnit::Nanosecond => Some(Time64(unit)),
+            }
+        }
+        (Duration(lhs), Duration(rhs)) => Some(Duration(finest_unit(*lhs, *rhs))),
+        _ => None,
+    }
+}
+
+/// The (bit width, is signed) of an integer type
+fn integer_width(data_type: &DataType) -> (usize, bool) {
+    use DataType::*;
+    match data_type {
+        Int8 => (8, true),
+        Int16 => (16, true),
+        Int32 => (32, true),
+        Int64 => (64, true),
+        UInt8 => (8, false),
+        UInt16 => (16, false),
+        UInt32 => (32, false),
+        UInt64 => (64, false),
+        _ => unreachable!(),
+    }
+}
+
+fn integer_of_width(width: usize, is_signed: bool) -> DataType {
+    use DataType::*;
+    match (width, is_signed) {
+        (8, true) => Int8,
+        (16, true) => Int16,
+        (32, true) => Int32,
+        (64, true) => Int64,
+        (8, false) => UInt8,
+        (16, false) => UInt16,
+        (32, false) => UInt32,
+        (64, false) => UInt64,
+        _ => unreachable!(),
+    }
+}
+
+fn common_integer(lhs: &DataType, rhs: &DataType) -> DataType {
+    let (lhs_width, lhs_signed) = integer_width(lhs);
+    let (rhs_width, rhs_signed) = integer_width(rhs);
+    if lhs_signed == rhs_signed {
+        return integer_of_width(lhs_width.max(rhs_width), lhs_signed);
+    }
+    let (signed, unsigned) = if lhs_signed {
+        (lhs_width, rhs_width)
+    } else {
+        (rhs_width, lhs_width)
+    };
+    if signed > unsigned {
+        integer_of_width(signed, true)
+    } else {
+        // lossy for `UInt64`, consistent with Arrow's promotion rules
+        integer_of_width((2 * unsigned).min(64), true)
+    }
+}
+
+fn widest_float(lhs: &DataType, rhs: &DataType) -> DataType {
+    use DataType::*;
+    if lhs == &Float64 || rhs == &Float64 {
+        Float64
+    } else if lhs == &Float32 || rhs == &Float32 {
+        Float32
+    } else {
+        Float16
+    }
+}
+
+/// The number of decimal digits of the maximum value of an integer type
+fn integer_digits(data_type: &DataType) -> Option<usize> {
+    use DataType::*;
+    match data_type {
+        Int8 | UInt8 => Some(3),
+        Int16 | UInt16 => Some(5),
+        Int32 | UInt32 => Some(10),
+        Int64 => Some(19),
+        UInt64 => Some(20),
+        _ => None,
+    }
+}
+
+fn common_decimal(p1: usize, s1: usize, p2: usize, s2: usize) -> Option<DataType> {
+    let scale = s1.max(s2);
+    let precision = p1.saturating_sub(s1).max(p2.saturating_sub(s2)) + scale;
+    // the maximum precision of a 128-bit decimal
+    if precision > 38 {
+        None
+    } else {
+        Some(DataType::Decimal(precision, scale))
+    }
+}
+
+fn finest_unit(lhs: TimeUnit, rhs: TimeUnit) -> TimeUnit {
+    use TimeUnit::*;
+    match (lhs, rhs) {
+        (Nanosecond, _) | (_, Nanosecond) => Nanosecond,
+        (Microsecond, _) | (_, Microsecond) => Microsecond,
+        (Millisecond, _) | (_, Millisecond) => Millisecond,
+        _ => Second,
+    }
+}
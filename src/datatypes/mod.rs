@@ -1,9 +1,11 @@
 #![deny(missing_docs)]
 //! Contains all metadata, such as [`PhysicalType`], [`DataType`], [`Field`] and [`Schema`].
+mod coercion;
 mod field;
 mod physical_type;
 mod schema;
 
+pub use coercion::common_supertype;
 pub use field::Field;
 pub use physical_type::*;
 pub use schema::Schema;
@@ -118,9 +120,10 @@ pub enum DataType {
     /// for Map can make Map an alias for List. The "layout" attribute for the Map
     /// field must have the same contents as a List.
     Map(Box<Field>, bool),
-    /// A dictionary encoded array (`key_type`, `value_type`), where
+    /// A dictionary encoded array (`key_type`, `value_type`, `is_ordered`), where
     /// each array element is an index of `key_type` into an
-    /// associated dictionary of `value_type`.
+    /// associated dictionary of `value_type`. When `is_ordered` is true, the order of
+    /// the dictionary's values is semantically meaningful (e.g. ordered categories).
     ///
     /// Dictionary arrays are used to store columns of `value_type`
     /// that contain many repeated values using less memory, but with
@@ -128,7 +131,7 @@ pub enum DataType {
     ///
     /// This type mostly used to represent low cardinality string
     /// arrays or a limited set of primitive types as integers.
-    Dictionary(IntegerType, Box<DataType>),
+    Dictionary(IntegerType, Box<DataType>, bool),
     /// Decimal value with precision and scale
     /// precision is the number of digits in the number and
     /// scale is the number of decimal places.
@@ -267,11 +270,51 @@ impl DataType {
             Struct(_) => PhysicalType::Struct,
             Union(_, _, _) => PhysicalType::Union,
             Map(_, _) => PhysicalType::Map,
-            Dictionary(key, _) => PhysicalType::Dictionary(*key),
+            Dictionary(key, _, _) => PhysicalType::Dictionary(*key),
             Extension(_, key, _) => key.to_physical_type(),
         }
     }
 
+    /// Returns whether this is a signed or unsigned integer.
+    pub fn is_integer(&self) -> bool {
+        use DataType::*;
+        matches!(
+            self.to_logical_type(),
+            Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64
+        )
+    }
+
+    /// Returns whether this is a floating point number.
+    pub fn is_floating(&self) -> bool {
+        use DataType::*;
+        matches!(self.to_logical_type(), Float16 | Float32 | Float64)
+    }
+
+    /// Returns whether this is numeric: an integer, a floating point or a [`DataType::Decimal`].
+    pub fn is_numeric(&self) -> bool {
+        self.is_integer()
+            || self.is_floating()
+            || matches!(self.to_logical_type(), DataType::Decimal(_, _))
+    }
+
+    /// Returns whether this is temporal: a date, time, timestamp, duration or interval.
+    pub fn is_temporal(&self) -> bool {
+        use DataType::*;
+        matches!(
+            self.to_logical_type(),
+            Date32 | Date64 | Time32(_) | Time64(_) | Timestamp(_, _) | Duration(_) | Interval(_)
+        )
+    }
+
+    /// Returns whether this is nested, i.e. whether it has child fields: a list, struct, union or map.
+    pub fn is_nested(&self) -> bool {
+        use DataType::*;
+        matches!(
+            self.to_logical_type(),
+            List(_) | FixedSizeList(_, _) | LargeList(_) | Struct(_) | Union(_, _, _) | Map(_, _)
+        )
+    }
+
     /// Returns `&self` for all but [`DataType::Extension`]. For [`DataType::Extension`],
     /// (recursively) returns the inner [`DataType`].
     /// Never returns the variant [`DataType::Extension`].
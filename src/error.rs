@@ -4,6 +4,21 @@ use std::fmt::{Debug, Display, Formatter};
 use std::error::Error;
 
 /// Enum with all errors in this crate.
+///
+/// Readers that know where in the data an error happened (the CSV, Avro and parquet readers
+/// and [`utf8_to_interval`](crate::temporal_conversions::utf8_to_interval)) return it wrapped
+/// in [`ArrowError::WithContext`]. Matching on the variant of those errors thus no longer sees
+/// e.g. the [`ArrowError::External`] of a CSV error: match on [`ArrowError::code`] or
+/// [`ArrowError::without_context`] instead, or follow [`Error::source`].
+/// ```
+/// use arrow2::error::{ArrowError, ErrorCode, ErrorContext};
+///
+/// let error = ArrowError::OutOfSpec("invalid".to_string())
+///     .with_context(ErrorContext::default().with_row(1));
+/// assert!(!matches!(error, ArrowError::OutOfSpec(_)));
+/// assert!(matches!(error.without_context(), ArrowError::OutOfSpec(_)));
+/// assert_eq!(error.code(), ErrorCode::OutOfSpec);
+/// ```
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum ArrowError {
@@ -22,6 +37,183 @@ pub enum ArrowError {
     Overflow,
     /// Whenever incoming data from the C data interface, IPC or Flight does not fulfil the Arrow specification.
     OutOfSpec(String),
+    /// Returned by readers when their hooks cancel the read.
+    Cancelled,
+    /// An error with context about where in the data it happened.
+    /// Use [`ArrowError::code`] and [`ArrowError::context`] to inspect it, and
+    /// [`ArrowError::without_context`] to match on the error it wraps.
+    WithContext {
+        /// Where the error happened
+        context: ErrorContext,
+        /// The error
+        source: Box<ArrowError>,
+    },
+}
+
+/// The kind of an [`ArrowError`], independent of its message and context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// [`ArrowError::NotYetImplemented`]
+    NotYetImplemented,
+    /// [`ArrowError::External`]
+    External,
+    /// [`ArrowError::Io`]
+    Io,
+    /// [`ArrowError::InvalidArgumentError`]
+    InvalidArgument,
+    /// [`ArrowError::ExternalFormat`]
+    ExternalFormat,
+    /// [`ArrowError::Overflow`]
+    Overflow,
+    /// [`ArrowError::OutOfSpec`]
+    OutOfSpec,
+    /// [`ArrowError::Cancelled`]
+    Cancelled,
+}
+
+/// A location within a file of a given format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Location {
+    /// A line of a CSV file, starting at 1.
+    CsvLine(u64),
+    /// A page of a parquet file. The page is the last page of the column chunk
+    /// read when the error happened.
+    ParquetPage {
+        /// The row group
+        row_group: Option<usize>,
+        /// The column chunk of the column, as nested columns have one column chunk per leaf
+        column_chunk: Option<usize>,
+        /// The page within the column chunk
+        page: Option<usize>,
+    },
+    /// A block of an Avro file, starting at 0.
+    AvroBlock(usize),
+}
+
+impl Location {
+    // fills the missing information of `self` from `other`
+    fn merge(self, other: Location) -> Self {
+        match (self, other) {
+            (
+                Location::ParquetPage {
+                    row_group,
+                    column_chunk,
+                    page,
+                },
+                Location::ParquetPage {
+                    row_group: other_row_group,
+                    column_chunk: other_column_chunk,
+                    page: other_page,
+                },
+            ) => Location::ParquetPage {
+                row_group: row_group.or(other_row_group),
+                column_chunk: column_chunk.or(other_column_chunk),
+                page: page.or(other_page),
+            },
+            (location, _) => location,
+        }
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Location::CsvLine(line) => write!(f, "CSV line {}", line),
+            Location::ParquetPage {
+                row_group,
+                column_chunk,
+                page,
+            } => {
+                write!(f, "parquet")?;
+                if let Some(row_group) = row_group {
+                    write!(f, " row group {}", row_group)?;
+                }
+                if let Some(column_chunk) = column_chunk {
+                    write!(f, " column chunk {}", column_chunk)?;
+                }
+                if let Some(page) = page {
+                    write!(f, " page {}", page)?;
+                }
+                Ok(())
+            }
+            Location::AvroBlock(block) => write!(f, "Avro block {}", block),
+        }
+    }
+}
+
+/// Where in the data an [`ArrowError`] happened. All fields are optional, as not every
+/// format nor every error can locate it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ErrorContext {
+    /// The name of the column
+    pub column: Option<String>,
+    /// The row, relative to the start of the file for CSV and to the start of
+    /// [`location`](Self::location) otherwise
+    pub row: Option<usize>,
+    /// The offset in bytes from the start of the file
+    pub byte_offset: Option<u64>,
+    /// The location within the file
+    pub location: Option<Location>,
+}
+
+impl ErrorContext {
+    /// Sets the name of the column
+    pub fn with_column(mut self, column: impl Into<String>) -> Self {
+        self.column = Some(column.into());
+        self
+    }
+
+    /// Sets the row
+    pub fn with_row(mut self, row: usize) -> Self {
+        self.row = Some(row);
+        self
+    }
+
+    /// Sets the offset in bytes
+    pub fn with_byte_offset(mut self, byte_offset: u64) -> Self {
+        self.byte_offset = Some(byte_offset);
+        self
+    }
+
+    /// Sets the location
+    pub fn with_location(mut self, location: Location) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    // fills the missing information of `self` from `other`
+    fn merge(self, other: ErrorContext) -> Self {
+        Self {
+            column: self.column.or(other.column),
+            row: self.row.or(other.row),
+            byte_offset: self.byte_offset.or(other.byte_offset),
+            location: match (self.location, other.location) {
+                (Some(location), Some(other)) => Some(location.merge(other)),
+                (location, other) => location.or(other),
+            },
+        }
+    }
+}
+
+impl Display for ErrorContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut parts = vec![];
+        if let Some(column) = &self.column {
+            parts.push(format!("column \"{}\"", column));
+        }
+        if let Some(row) = self.row {
+            parts.push(format!("row {}", row));
+        }
+        if let Some(byte_offset) = self.byte_offset {
+            parts.push(format!("byte {}", byte_offset));
+        }
+        if let Some(location) = &self.location {
+            parts.push(location.to_string());
+        }
+        write!(f, "{}", parts.join(", "))
+    }
 }
 
 impl ArrowError {
@@ -29,6 +221,55 @@ impl ArrowError {
     pub fn from_external_error(error: impl std::error::Error + Send + Sync + 'static) -> Self {
         Self::External("".to_string(), Box::new(error))
     }
+
+    /// Adds `context` to this error. When the error already has context, its information
+    /// takes precedence and only the missing information is taken from `context`.
+    pub fn with_context(self, context: ErrorContext) -> Self {
+        match self {
+            ArrowError::WithContext {
+                context: inner,
+                source,
+            } => ArrowError::WithContext {
+                context: inner.merge(context),
+                source,
+            },
+            error => ArrowError::WithContext {
+                context,
+                source: Box::new(error),
+            },
+        }
+    }
+
+    /// Returns the context of this error, if any.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            ArrowError::WithContext { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Returns this error without its context.
+    pub fn without_context(&self) -> &ArrowError {
+        match self {
+            ArrowError::WithContext { source, .. } => source.without_context(),
+            error => error,
+        }
+    }
+
+    /// Returns the [`ErrorCode`] of this error.
+    pub fn code(&self) -> ErrorCode {
+        match self.without_context() {
+            ArrowError::NotYetImplemented(_) => ErrorCode::NotYetImplemented,
+            ArrowError::External(_, _) => ErrorCode::External,
+            ArrowError::Io(_) => ErrorCode::Io,
+            ArrowError::InvalidArgumentError(_) => ErrorCode::InvalidArgument,
+            ArrowError::ExternalFormat(_) => ErrorCode::ExternalFormat,
+            ArrowError::Overflow => ErrorCode::Overflow,
+            ArrowError::OutOfSpec(_) => ErrorCode::OutOfSpec,
+            ArrowError::Cancelled => ErrorCode::Cancelled,
+            ArrowError::WithContext { .. } => unreachable!(),
+        }
+    }
 }
 
 impl From<::std::io::Error> for ArrowError {
@@ -71,11 +312,24 @@ impl Display for ArrowError {
             ArrowError::OutOfSpec(message) => {
                 write!(f, "{}", message)
             }
+            ArrowError::Cancelled => write!(f, "The operation was cancelled"),
+            ArrowError::WithContext { context, source } => {
+                write!(f, "{} (at {})", source, context)
+            }
         }
     }
 }
 
-impl Error for ArrowError {}
+impl Error for ArrowError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ArrowError::External(_, source) => Some(source.as_ref()),
+            ArrowError::Io(source) => Some(source),
+            ArrowError::WithContext { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
 
 /// Typedef for a [`std::result::Result`] of an [`ArrowError`].
 pub type Result<T> = std::result::Result<T, ArrowError>;
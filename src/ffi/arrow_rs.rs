@@ -0,0 +1,146 @@
+//! Conversion of [`Array`]s, [`Field`]s, [`Schema`]s and [`RecordBatch`]es from and to
+//! the [arrow](https://crates.io/crates/arrow) crate.
+//!
+//! Arrays are exchanged via the C data interface and therefore are not copied,
+//! so that both crates can be used on the same data in one process.
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use arrow::ffi::{ArrowArray as ArrowRsArray, FFI_ArrowSchema};
+
+use crate::array::Array;
+use crate::datatypes::{Field, Schema};
+use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
+
+use super::{
+    export_array_to_c, export_field_to_c, import_array_from_c, import_field_from_c, Ffi_ArrowArray,
+    Ffi_ArrowSchema,
+};
+
+impl From<arrow::error::ArrowError> for ArrowError {
+    fn from(error: arrow::error::ArrowError) -> Self {
+        ArrowError::External("".to_string(), Box::new(error))
+    }
+}
+
+/// Converts an [`Array`] into an array of the arrow crate.
+/// # Errors
+/// This function errors if the arrow crate does not support the array's [`DataType`](crate::datatypes::DataType).
+pub fn to_arrow_rs(array: Arc<dyn Array>) -> Result<arrow::array::ArrayRef> {
+    let field = Field::new("", array.data_type().clone(), true);
+
+    // the arrow crate imports from structs it allocated, which are empty and thus
+    // do not need to be dropped when overwritten
+    let (array_ptr, schema_ptr) = ArrowRsArray::into_raw(unsafe { ArrowRsArray::empty() });
+    unsafe {
+        export_field_to_c(&field, schema_ptr as *mut Ffi_ArrowSchema);
+        export_array_to_c(array, array_ptr as *mut Ffi_ArrowArray);
+    }
+
+    let array = unsafe { ArrowRsArray::try_from_raw(array_ptr, schema_ptr)? };
+    let data = arrow::array::ArrayData::try_from(array)?;
+    Ok(arrow::array::make_array(data))
+}
+
+/// Converts an array of the arrow crate into an [`Array`].
+/// # Errors
+/// This function errors if this crate does not support the array's data type.
+pub fn from_arrow_rs(array: &dyn arrow::array::Array) -> Result<Arc<dyn Array>> {
+    let array = ArrowRsArray::try_from(array.data().clone())?;
+    let (array_ptr, schema_ptr) = ArrowRsArray::into_raw(array);
+
+    let (field, array) = unsafe {
+        let field = import_field_from_c(&*(schema_ptr as *const Ffi_ArrowSchema));
+        // move the array out of the arrow crate's struct, leaving a released struct behind
+        let array = Box::new(std::ptr::replace(
+            array_ptr as *mut Ffi_ArrowArray,
+            Ffi_ArrowArray::empty(),
+        ));
+        // release the schema and free the structs allocated by the arrow crate
+        drop(ArrowRsArray::try_from_raw(array_ptr, schema_ptr));
+        (field, array)
+    };
+
+    Ok(unsafe { import_array_from_c(array, &field?)? }.into())
+}
+
+/// Converts a [`Field`] into a field of the arrow crate.
+/// # Errors
+/// This function errors if the arrow crate does not support the field's [`DataType`](crate::datatypes::DataType).
+pub fn field_to_arrow_rs(field: &Field) -> Result<arrow::datatypes::Field> {
+    let mut schema = Box::new(Ffi_ArrowSchema::empty());
+    unsafe { export_field_to_c(field, schema.as_mut()) };
+
+    let schema = unsafe { &*(schema.as_ref() as *const Ffi_ArrowSchema as *const FFI_ArrowSchema) };
+    let mut result = arrow::datatypes::Field::try_from(schema)?;
+    // the arrow crate does not import metadata
+    result.set_metadata(field.metadata.clone());
+    Ok(result)
+}
+
+/// Converts a field of the arrow crate into a [`Field`].
+/// # Errors
+/// This function errors if this crate does not support the field's data type.
+pub fn field_from_arrow_rs(field: &arrow::datatypes::Field) -> Result<Field> {
+    let schema = FFI_ArrowSchema::try_from(field)?;
+
+    let schema = unsafe { &*(&schema as *const FFI_ArrowSchema as *const Ffi_ArrowSchema) };
+    let mut result = unsafe { import_field_from_c(schema)? };
+    // the arrow crate does not export metadata
+    result.set_metadata(field.metadata().clone());
+    Ok(result)
+}
+
+/// Converts a [`Schema`] into a schema of the arrow crate.
+/// # Errors
+/// This function errors if any of the fields cannot be converted.
+pub fn schema_to_arrow_rs(schema: &Schema) -> Result<arrow::datatypes::Schema> {
+    let fields = schema
+        .fields()
+        .iter()
+        .map(field_to_arrow_rs)
+        .collect::<Result<Vec<_>>>()?;
+    Ok(arrow::datatypes::Schema::new_with_metadata(
+        fields,
+        schema.metadata.clone(),
+    ))
+}
+
+/// Converts a schema of the arrow crate into a [`Schema`].
+/// # Errors
+/// This function errors if any of the fields cannot be converted.
+pub fn schema_from_arrow_rs(schema: &arrow::datatypes::Schema) -> Result<Schema> {
+    let fields = schema
+        .fields()
+        .iter()
+        .map(field_from_arrow_rs)
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Schema::new_from(fields, schema.metadata().clone()))
+}
+
+/// Converts a [`RecordBatch`] into a record batch of the arrow crate.
+/// # Errors
+/// This function errors if any of its columns cannot be converted.
+pub fn batch_to_arrow_rs(batch: &RecordBatch) -> Result<arrow::record_batch::RecordBatch> {
+    let schema = Arc::new(schema_to_arrow_rs(batch.schema())?);
+    let columns = batch
+        .columns()
+        .iter()
+        .map(|column| to_arrow_rs(column.clone()))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(arrow::record_batch::RecordBatch::try_new(schema, columns)?)
+}
+
+/// Converts a record batch of the arrow crate into a [`RecordBatch`].
+/// # Errors
+/// This function errors if any of its columns cannot be converted.
+pub fn batch_from_arrow_rs(batch: &arrow::record_batch::RecordBatch) -> Result<RecordBatch> {
+    let schema = Arc::new(schema_from_arrow_rs(batch.schema().as_ref())?);
+    let columns = batch
+        .columns()
+        .iter()
+        .map(|column| from_arrow_rs(column.as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+    RecordBatch::try_new(schema, columns)
+}
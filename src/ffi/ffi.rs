@@ -26,7 +26,7 @@ use crate::{
     },
     datatypes::{DataType, Field, PhysicalType},
     error::{ArrowError, Result},
-    ffi::schema::get_field_child,
+    ffi::{bridge::align_to_c_data_interface, schema::get_field_child},
     types::NativeType,
 };
 
@@ -42,15 +42,15 @@ pub struct Ffi_ArrowArray {
     pub(crate) n_buffers: i64,
     pub(crate) n_children: i64,
     pub(crate) buffers: *mut *const ::std::os::raw::c_void,
-    children: *mut *mut Ffi_ArrowArray,
-    dictionary: *mut Ffi_ArrowArray,
-    release: ::std::option::Option<unsafe extern "C" fn(arg1: *mut Ffi_ArrowArray)>,
+    pub(crate) children: *mut *mut Ffi_ArrowArray,
+    pub(crate) dictionary: *mut Ffi_ArrowArray,
+    pub(crate) release: ::std::option::Option<unsafe extern "C" fn(arg1: *mut Ffi_ArrowArray)>,
     // When exported, this MUST contain everything that is owned by this array.
     // for example, any buffer pointed to in `buffers` must be here, as well as the `buffers` pointer
     // itself.
     // In other words, everything in [Ffi_ArrowArray] must be owned by `private_data` and can assume
     // that they do not outlive `private_data`.
-    private_data: *mut ::std::os::raw::c_void,
+    pub(crate) private_data: *mut ::std::os::raw::c_void,
 }
 
 impl Drop for Ffi_ArrowArray {
@@ -108,14 +108,23 @@ impl Ffi_ArrowArray {
             .collect::<Box<[_]>>();
         let n_buffers = buffers.len() as i64;
 
+        // children are exported with their own offset, since the parent's offset is not
+        // applied to them (e.g. the children of a sliced struct are themselves sliced)
         let children_ptr = children
             .into_iter()
-            .map(|child| Box::into_raw(Box::new(Ffi_ArrowArray::new(child))))
+            .map(|child| {
+                Box::into_raw(Box::new(Ffi_ArrowArray::new(align_to_c_data_interface(
+                    child,
+                ))))
+            })
             .collect::<Box<_>>();
         let n_children = children_ptr.len() as i64;
 
-        let dictionary_ptr =
-            dictionary.map(|array| Box::into_raw(Box::new(Ffi_ArrowArray::new(array))));
+        let dictionary_ptr = dictionary.map(|array| {
+            Box::into_raw(Box::new(Ffi_ArrowArray::new(align_to_c_data_interface(
+                array,
+            ))))
+        });
 
         let length = array.len() as i64;
         let null_count = array.null_count() as i64;
@@ -248,6 +257,13 @@ fn buffer_offset(array: &Ffi_ArrowArray, data_type: &DataType, i: usize) -> usiz
     use PhysicalType::*;
     match (data_type.to_physical_type(), i) {
         (LargeUtf8, 2) | (LargeBinary, 2) | (Utf8, 2) | (Binary, 2) => 0,
+        (FixedSizeBinary, 1) => {
+            if let DataType::FixedSizeBinary(size) = data_type.to_logical_type() {
+                *size * array.offset as usize
+            } else {
+                unreachable!()
+            }
+        }
         _ => array.offset as usize,
     }
 }
@@ -331,7 +347,7 @@ fn create_dictionary(
     field: &Field,
     parent: Arc<ArrowArray>,
 ) -> Result<Option<ArrowArrayChild<'static>>> {
-    if let DataType::Dictionary(_, values) = field.data_type() {
+    if let DataType::Dictionary(_, values, _) = field.data_type() {
         let field = Field::new("", values.as_ref().clone(), true);
         assert!(!array.dictionary.is_null());
         let array = unsafe { &*array.dictionary };
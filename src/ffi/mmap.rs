@@ -0,0 +1,302 @@
+//! Zero-copy import of Arrow IPC record batches from memory regions shared between processes,
+//! such as memory-mapped files or shared memory (e.g. `memfd` or `shm_open`).
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use arrow_format::ipc;
+
+use crate::datatypes::{DataType, PhysicalType, Schema};
+use crate::error::{ArrowError, Result};
+use crate::io::ipc::CONTINUATION_MARKER;
+use crate::record_batch::RecordBatch;
+
+use super::{import_array_from_c, Ffi_ArrowArray};
+
+#[allow(dead_code)]
+struct PrivateData<T> {
+    // the owner of the region that the buffers point to
+    data: Arc<T>,
+    buffers_ptr: Box<[*const std::os::raw::c_void]>,
+    children_ptr: Box<[*mut Ffi_ArrowArray]>,
+}
+
+// callback used to drop [Ffi_ArrowArray] created from a region
+unsafe extern "C" fn release<T>(array: *mut Ffi_ArrowArray) {
+    if array.is_null() {
+        return;
+    }
+    let array = &mut *array;
+
+    // take ownership of `private_data`, therefore dropping it
+    let private = Box::from_raw(array.private_data as *mut PrivateData<T>);
+    for child in private.children_ptr.iter() {
+        let _ = Box::from_raw(*child);
+    }
+
+    array.release = None;
+}
+
+/// Creates a [`Ffi_ArrowArray`] whose `buffers` point to `data` and that owns `data`.
+fn create_array<T>(
+    data: Arc<T>,
+    length: usize,
+    null_count: usize,
+    buffers: Vec<Option<*const u8>>,
+    children: Vec<Ffi_ArrowArray>,
+) -> Ffi_ArrowArray {
+    let buffers_ptr = buffers
+        .into_iter()
+        .map(|maybe_buffer| match maybe_buffer {
+            Some(b) => b as *const std::os::raw::c_void,
+            None => std::ptr::null(),
+        })
+        .collect::<Box<[_]>>();
+    let n_buffers = buffers_ptr.len() as i64;
+
+    let children_ptr = children
+        .into_iter()
+        .map(|child| Box::into_raw(Box::new(child)))
+        .collect::<Box<_>>();
+    let n_children = children_ptr.len() as i64;
+
+    let mut private_data = Box::new(PrivateData {
+        data,
+        buffers_ptr,
+        children_ptr,
+    });
+
+    Ffi_ArrowArray {
+        length: length as i64,
+        null_count: null_count as i64,
+        offset: 0,
+        n_buffers,
+        n_children,
+        buffers: private_data.buffers_ptr.as_mut_ptr(),
+        children: private_data.children_ptr.as_mut_ptr(),
+        dictionary: std::ptr::null_mut(),
+        release: Some(release::<T>),
+        private_data: Box::into_raw(private_data) as *mut ::std::os::raw::c_void,
+    }
+}
+
+fn get_buffer(body: &[u8], buffers: &mut VecDeque<&ipc::Schema::Buffer>) -> Result<*const u8> {
+    let buffer = buffers
+        .pop_front()
+        .ok_or_else(|| ArrowError::OutOfSpec("IPC: missing buffer".to_string()))?;
+    let offset = buffer.offset() as usize;
+    let length = buffer.length() as usize;
+
+    let end = offset.checked_add(length);
+    if end.map(|end| end > body.len()).unwrap_or(true) {
+        return Err(ArrowError::OutOfSpec(
+            "IPC: buffer out of bounds of the message's body".to_string(),
+        ));
+    }
+    let ptr = body[offset..].as_ptr();
+    if ptr.align_offset(8) != 0 {
+        return Err(ArrowError::OutOfSpec(
+            "IPC: buffers must be aligned to 8 bytes to be used without copying".to_string(),
+        ));
+    }
+    Ok(ptr)
+}
+
+fn get_validity(
+    body: &[u8],
+    buffers: &mut VecDeque<&ipc::Schema::Buffer>,
+    null_count: usize,
+) -> Result<Option<*const u8>> {
+    let validity = get_buffer(body, buffers)?;
+    Ok(if null_count > 0 { Some(validity) } else { None })
+}
+
+fn get_array<T>(
+    data: Arc<T>,
+    data_type: &DataType,
+    body: &[u8],
+    nodes: &mut VecDeque<&ipc::Message::FieldNode>,
+    buffers: &mut VecDeque<&ipc::Schema::Buffer>,
+) -> Result<Ffi_ArrowArray> {
+    let node = nodes
+        .pop_front()
+        .ok_or_else(|| ArrowError::OutOfSpec("IPC: missing field node".to_string()))?;
+    let length = node.length() as usize;
+    let null_count = node.null_count() as usize;
+
+    use PhysicalType::*;
+    match data_type.to_physical_type() {
+        Null => Ok(create_array(data, length, 0, vec![], vec![])),
+        Boolean | Primitive(_) | FixedSizeBinary => {
+            let validity = get_validity(body, buffers, null_count)?;
+            let values = get_buffer(body, buffers)?;
+            Ok(create_array(
+                data,
+                length,
+                null_count,
+                vec![validity, Some(values)],
+                vec![],
+            ))
+        }
+        Utf8 | LargeUtf8 | Binary | LargeBinary => {
+            let validity = get_validity(body, buffers, null_count)?;
+            let offsets = get_buffer(body, buffers)?;
+            let values = get_buffer(body, buffers)?;
+            Ok(create_array(
+                data,
+                length,
+                null_count,
+                vec![validity, Some(offsets), Some(values)],
+                vec![],
+            ))
+        }
+        List | LargeList => {
+            let validity = get_validity(body, buffers, null_count)?;
+            let offsets = get_buffer(body, buffers)?;
+            let child = match data_type.to_logical_type() {
+                DataType::List(field) | DataType::LargeList(field) => field.data_type(),
+                _ => unreachable!(),
+            };
+            let values = get_array(data.clone(), child, body, nodes, buffers)?;
+            Ok(create_array(
+                data,
+                length,
+                null_count,
+                vec![validity, Some(offsets)],
+                vec![values],
+            ))
+        }
+        FixedSizeList => {
+            let validity = get_validity(body, buffers, null_count)?;
+            let child = match data_type.to_logical_type() {
+                DataType::FixedSizeList(field, _) => field.data_type(),
+                _ => unreachable!(),
+            };
+            let values = get_array(data.clone(), child, body, nodes, buffers)?;
+            Ok(create_array(
+                data,
+                length,
+                null_count,
+                vec![validity],
+                vec![values],
+            ))
+        }
+        Struct => {
+            let validity = get_validity(body, buffers, null_count)?;
+            let fields = match data_type.to_logical_type() {
+                DataType::Struct(fields) => fields,
+                _ => unreachable!(),
+            };
+            let values = fields
+                .iter()
+                .map(|field| get_array(data.clone(), field.data_type(), body, nodes, buffers))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(create_array(
+                data,
+                length,
+                null_count,
+                vec![validity],
+                values,
+            ))
+        }
+        Dictionary(_) | Union | Map => Err(ArrowError::NotYetImplemented(format!(
+            "Reading {:?} from a shared memory region",
+            data_type
+        ))),
+    }
+}
+
+/// Returns a [`RecordBatch`] whose arrays reference the IPC-encoded record batch in `data`,
+/// without copying it.
+///
+/// `data` must start with an encapsulated IPC message of a record batch followed by its body,
+/// e.g. as written by [`StreamWriter::write`](crate::io::ipc::write::StreamWriter::write)
+/// after the schema, and `schema` must be the schema of the record batch.
+/// The arrays keep `data` alive, so that the region is released (e.g. unmapped) when the
+/// last of them is dropped.
+/// # Errors
+/// This function errors when:
+/// * the message is not a record batch or is not consistent with `schema`
+/// * the record batch is compressed
+/// * the buffers are not aligned to 8 bytes (e.g. because `data` is not)
+/// * `schema` contains dictionary-encoded, union or map fields
+/// # Safety
+/// The buffers are neither copied nor validated. The caller must guarantee that
+/// * the record batch is valid Arrow (e.g. offsets are within bounds and utf8 is valid), for
+///   example because it was written by this crate
+/// * the record batch was written with the native endianness of this machine
+/// * the region is not modified (e.g. by another process) while any of the arrays is alive
+pub unsafe fn mmap_record_batch<T: AsRef<[u8]> + Send + Sync + 'static>(
+    data: Arc<T>,
+    schema: Arc<Schema>,
+) -> Result<RecordBatch> {
+    let bytes = data.as_ref().as_ref();
+
+    let read_i32 = |offset: usize| -> Result<[u8; 4]> {
+        bytes
+            .get(offset..offset + 4)
+            .map(|x| [x[0], x[1], x[2], x[3]])
+            .ok_or_else(|| ArrowError::OutOfSpec("IPC: the message is too short".to_string()))
+    };
+    let mut start = 0;
+    let mut meta_length = read_i32(start)?;
+    start += 4;
+    if meta_length == CONTINUATION_MARKER {
+        meta_length = read_i32(start)?;
+        start += 4;
+    }
+    let meta_length = i32::from_le_bytes(meta_length) as usize;
+
+    let meta = bytes
+        .get(start..start.saturating_add(meta_length))
+        .ok_or_else(|| ArrowError::OutOfSpec("IPC: the message is too short".to_string()))?;
+    let message = ipc::Message::root_as_message(meta).map_err(|err| {
+        ArrowError::OutOfSpec(format!("Unable to get root as message: {:?}", err))
+    })?;
+    let batch = message.header_as_record_batch().ok_or_else(|| {
+        ArrowError::OutOfSpec("Unable to read IPC message as record batch".to_string())
+    })?;
+    if batch.compression().is_some() {
+        return Err(ArrowError::NotYetImplemented(
+            "Reading compressed IPC from a shared memory region".to_string(),
+        ));
+    }
+
+    let body_start = start + meta_length;
+    let body = bytes
+        .get(body_start..body_start.saturating_add(message.bodyLength() as usize))
+        .ok_or_else(|| {
+            ArrowError::OutOfSpec("IPC: the message's body is out of bounds".to_string())
+        })?;
+
+    let mut buffers = batch
+        .buffers()
+        .ok_or_else(|| {
+            ArrowError::OutOfSpec("Unable to get buffers from IPC RecordBatch".to_string())
+        })?
+        .iter()
+        .collect::<VecDeque<_>>();
+    let mut nodes = batch
+        .nodes()
+        .ok_or_else(|| {
+            ArrowError::OutOfSpec("Unable to get field nodes from IPC RecordBatch".to_string())
+        })?
+        .iter()
+        .collect::<VecDeque<_>>();
+
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            let array = get_array(
+                data.clone(),
+                field.data_type(),
+                body,
+                &mut nodes,
+                &mut buffers,
+            )?;
+            import_array_from_c(Box::new(array), field).map(Arc::from)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    RecordBatch::try_new(schema, columns)
+}
@@ -7,6 +7,16 @@ mod bridge;
 mod ffi;
 mod schema;
 
+#[cfg(feature = "ffi_arrow_rs")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ffi_arrow_rs")))]
+pub mod arrow_rs;
+#[cfg(feature = "io_ipc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_ipc")))]
+pub mod mmap;
+#[cfg(feature = "ffi_pyarrow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ffi_pyarrow")))]
+pub mod pyarrow;
+
 pub(crate) use array::try_from;
 pub(crate) use ffi::{ArrowArray, ArrowArrayRef};
 
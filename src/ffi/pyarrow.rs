@@ -0,0 +1,134 @@
+//! Conversion of [`Array`]s and [`Field`]s from and to [pyarrow](https://arrow.apache.org/docs/python/)
+//! objects via [pyo3](https://pyo3.rs).
+//!
+//! This uses pyarrow's `_export_to_c` and `_import_from_c` methods, which exchange
+//! pointers to the C data interface and therefore do not copy data.
+use std::sync::Arc;
+
+use pyo3::exceptions::{
+    PyNotImplementedError, PyOSError, PyOverflowError, PyRuntimeError, PyValueError,
+};
+use pyo3::ffi::Py_uintptr_t;
+use pyo3::prelude::*;
+
+use crate::{
+    array::Array,
+    datatypes::Field,
+    error::{ArrowError, ErrorCode},
+};
+
+use super::{
+    export_array_to_c, export_field_to_c, import_array_from_c, import_field_from_c, Ffi_ArrowArray,
+    Ffi_ArrowSchema,
+};
+
+/// Maps the [`ErrorCode`] of an [`ArrowError`] to the closest Python exception.
+impl From<ArrowError> for PyErr {
+    fn from(error: ArrowError) -> Self {
+        let message = error.to_string();
+        match error.code() {
+            ErrorCode::NotYetImplemented => PyNotImplementedError::new_err(message),
+            ErrorCode::Io => PyOSError::new_err(message),
+            ErrorCode::InvalidArgument | ErrorCode::ExternalFormat | ErrorCode::OutOfSpec => {
+                PyValueError::new_err(message)
+            }
+            ErrorCode::Overflow => PyOverflowError::new_err(message),
+            ErrorCode::External | ErrorCode::Cancelled => PyRuntimeError::new_err(message),
+        }
+    }
+}
+
+/// Imports a `pyarrow.Array` into an [`Array`].
+/// # Errors
+/// This function errors if `ob` does not implement `_export_to_c` or if the
+/// exported array is not supported by this crate.
+pub fn from_py(ob: &PyAny) -> PyResult<Arc<dyn Array>> {
+    // prepare pointers to receive the ArrowArray and ArrowSchema structs.
+    // pyarrow writes to them, so they are owned raw pointers rather than borrows of the boxes.
+    let array_ptr = Box::into_raw(Box::new(Ffi_ArrowArray::empty()));
+    let schema_ptr = Box::into_raw(Box::new(Ffi_ArrowSchema::empty()));
+
+    let result = ob.call_method1(
+        "_export_to_c",
+        (array_ptr as Py_uintptr_t, schema_ptr as Py_uintptr_t),
+    );
+
+    // reclaim the structs, so that they are released also when pyarrow errors
+    let array = unsafe { Box::from_raw(array_ptr) };
+    let schema = unsafe { Box::from_raw(schema_ptr) };
+    result?;
+
+    let field = unsafe { import_field_from_c(schema.as_ref())? };
+    let array = unsafe { import_array_from_c(array, &field)? };
+
+    Ok(array.into())
+}
+
+/// Exports an [`Array`] into a `pyarrow.Array`.
+/// # Errors
+/// This function errors if `pyarrow` cannot be imported or if it rejects the array.
+pub fn to_py(array: Arc<dyn Array>, py: Python) -> PyResult<PyObject> {
+    let field = Field::new("", array.data_type().clone(), true);
+
+    let array_ptr = Box::into_raw(Box::new(Ffi_ArrowArray::empty()));
+    let schema_ptr = Box::into_raw(Box::new(Ffi_ArrowSchema::empty()));
+
+    unsafe {
+        export_field_to_c(&field, schema_ptr);
+        export_array_to_c(array, array_ptr);
+    };
+
+    let result = py
+        .import("pyarrow")
+        .and_then(|pa| pa.getattr("Array"))
+        .and_then(|pa_array| {
+            pa_array.call_method1(
+                "_import_from_c",
+                (array_ptr as Py_uintptr_t, schema_ptr as Py_uintptr_t),
+            )
+        });
+
+    // on success, pyarrow moved the contents of the structs and dropping them is a no-op.
+    // On failure, dropping them releases the exported array.
+    unsafe {
+        drop(Box::from_raw(array_ptr));
+        drop(Box::from_raw(schema_ptr));
+    };
+
+    Ok(result?.to_object(py))
+}
+
+/// Imports a `pyarrow.Field` into a [`Field`].
+/// # Errors
+/// This function errors if `ob` does not implement `_export_to_c` or if the
+/// exported field is not supported by this crate.
+pub fn field_from_py(ob: &PyAny) -> PyResult<Field> {
+    let schema_ptr = Box::into_raw(Box::new(Ffi_ArrowSchema::empty()));
+
+    let result = ob.call_method1("_export_to_c", (schema_ptr as Py_uintptr_t,));
+
+    let schema = unsafe { Box::from_raw(schema_ptr) };
+    result?;
+
+    Ok(unsafe { import_field_from_c(schema.as_ref())? })
+}
+
+/// Exports a [`Field`] into a `pyarrow.Field`.
+/// # Errors
+/// This function errors if `pyarrow` cannot be imported or if it rejects the field.
+pub fn field_to_py(field: &Field, py: Python) -> PyResult<PyObject> {
+    let schema_ptr = Box::into_raw(Box::new(Ffi_ArrowSchema::empty()));
+
+    unsafe { export_field_to_c(field, schema_ptr) };
+
+    let result = py
+        .import("pyarrow")
+        .and_then(|pa| pa.getattr("Field"))
+        .and_then(|pa_field| {
+            pa_field.call_method1("_import_from_c", (schema_ptr as Py_uintptr_t,))
+        });
+
+    unsafe { drop(Box::from_raw(schema_ptr)) };
+
+    Ok(result?.to_object(py))
+}
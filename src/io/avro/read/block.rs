@@ -1,4 +1,5 @@
 //! APIs to read from Avro format to arrow.
+use std::convert::TryFrom;
 use std::io::Read;
 
 use fallible_streaming_iterator::FallibleStreamingIterator;
@@ -7,40 +8,83 @@ use crate::error::{ArrowError, Result};
 
 use super::util;
 
-fn read_size<R: Read>(reader: &mut R) -> Result<(usize, usize)> {
-    let rows = match util::zigzag_i64(reader) {
-        Ok(a) => a,
-        Err(ArrowError::Io(io_err)) => {
-            if let std::io::ErrorKind::UnexpectedEof = io_err.kind() {
-                // end
-                return Ok((0, 0));
-            } else {
-                return Err(ArrowError::Io(io_err));
-            }
+/// Reads the number of rows and bytes of the next block, or `None` at the end of the file.
+/// # Errors
+/// Errors iff the sizes are truncated or negative.
+fn read_size<R: Read>(reader: &mut R) -> Result<Option<(usize, usize)>> {
+    let mut first = [0u8; 1];
+    if reader.read(&mut first)? == 0 {
+        // end
+        return Ok(None);
+    }
+    let rows = read_length(&mut first.as_ref().chain(&mut *reader))?;
+    let bytes = read_length(reader)?;
+    Ok(Some((rows, bytes)))
+}
+
+/// Reads a non-negative zigzag-encoded length
+fn read_length<R: Read>(reader: &mut R) -> Result<usize> {
+    let length = match util::zigzag_i64(reader) {
+        Ok(length) => length,
+        Err(ArrowError::Io(io_err)) if io_err.kind() == std::io::ErrorKind::UnexpectedEof => {
+            return Err(ArrowError::OutOfSpec(
+                "Avro block size is truncated".to_string(),
+            ))
         }
         Err(other) => return Err(other),
     };
-    let bytes = util::zigzag_i64(reader)?;
-    Ok((rows as usize, bytes as usize))
+    usize::try_from(length).map_err(|_| {
+        ArrowError::OutOfSpec(format!(
+            "Avro block size must be non-negative, got {}",
+            length
+        ))
+    })
 }
 
 /// Reads a block from the file into `buf`.
-/// # Panic
-/// Panics iff the block marker does not equal to the file's marker
-fn read_block<R: Read>(reader: &mut R, buf: &mut Vec<u8>, file_marker: [u8; 16]) -> Result<usize> {
-    let (rows, bytes) = read_size(reader)?;
+/// # Errors
+/// Errors iff the block is corrupt (e.g. truncated, with a negative size or not followed by
+/// the file's marker). When `strict`, also errors iff the file contains an empty block, which
+/// writers do not emit (e.g. zeroed trailing bytes); otherwise such a block ends the file.
+fn read_block<R: Read>(
+    reader: &mut R,
+    buf: &mut Vec<u8>,
+    file_marker: [u8; 16],
+    strict: bool,
+) -> Result<usize> {
+    let (rows, bytes) = match read_size(reader)? {
+        Some(size) => size,
+        None => return Ok(0),
+    };
     if rows == 0 {
-        return Ok(0);
+        return if strict {
+            Err(ArrowError::ExternalFormat(
+                "Avro file contains trailing bytes after its last sync marker".to_string(),
+            ))
+        } else {
+            Ok(0)
+        };
     };
 
     buf.clear();
-    buf.resize(bytes, 0);
-    reader.read_exact(buf)?;
+    // read through `take` so that a corrupt size does not allocate more than the file has
+    reader.take(bytes as u64).read_to_end(buf)?;
+    if buf.len() != bytes {
+        return Err(ArrowError::OutOfSpec(format!(
+            "Avro block declares {} bytes but only {} are available",
+            bytes,
+            buf.len()
+        )));
+    }
 
     let mut marker = [0u8; 16];
     reader.read_exact(&mut marker)?;
 
-    assert!(!(marker != file_marker));
+    if marker != file_marker {
+        return Err(ArrowError::OutOfSpec(
+            "Avro block is not followed by the file's sync marker".to_string(),
+        ));
+    }
     Ok(rows)
 }
 
@@ -49,6 +93,7 @@ pub struct BlockStreamIterator<R: Read> {
     buf: (Vec<u8>, usize),
     reader: R,
     file_marker: [u8; 16],
+    strict: bool,
 }
 
 impl<R: Read> BlockStreamIterator<R> {
@@ -58,9 +103,18 @@ impl<R: Read> BlockStreamIterator<R> {
             reader,
             file_marker,
             buf: (vec![], 0),
+            strict: false,
         }
     }
 
+    /// Sets whether to error, instead of stopping, when the data after the last block
+    /// is an empty block (e.g. zeroed bytes after the last sync marker).
+    /// Corrupt blocks (e.g. truncated) are an error regardless of this setting.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
     /// The buffer of [`BlockStreamIterator`].
     pub fn buffer(&mut self) -> &mut Vec<u8> {
         &mut self.buf.0
@@ -78,7 +132,7 @@ impl<R: Read> FallibleStreamingIterator for BlockStreamIterator<R> {
 
     fn advance(&mut self) -> Result<()> {
         let (buf, rows) = &mut self.buf;
-        *rows = read_block(&mut self.reader, buf, self.file_marker)?;
+        *rows = read_block(&mut self.reader, buf, self.file_marker, self.strict)?;
         Ok(())
     }
 
@@ -51,12 +51,58 @@ pub fn decompress_block(
     }
 }
 
+#[cfg(feature = "io_avro_compression")]
+fn verify_snappy_checksum(block: &[u8], decompressed: &[u8]) -> Result<()> {
+    use std::convert::TryInto;
+
+    if block.len() < 4 {
+        return Err(ArrowError::ExternalFormat(
+            "Snappy-compressed Avro block is missing its checksum".to_string(),
+        ));
+    }
+    let expected = u32::from_be_bytes(block[block.len() - 4..].try_into().unwrap());
+    let checksum = crc32fast::hash(decompressed);
+    if checksum != expected {
+        return Err(ArrowError::ExternalFormat(format!(
+            "Avro block checksum mismatch: expected {:#010x}, computed {:#010x}",
+            expected, checksum
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "io_avro_compression"))]
+fn verify_snappy_checksum(_: &[u8], _: &[u8]) -> Result<()> {
+    Err(ArrowError::InvalidArgumentError(
+        "The avro file is snappy-encoded but feature 'io_avro_compression' is not active."
+            .to_string(),
+    ))
+}
+
+/// Verifies the checksum of a `block` against its `decompressed` data.
+/// Only snappy-compressed blocks have a checksum (a CRC32 of the decompressed data);
+/// this is a no-op for other compressions.
+/// # Errors
+/// Errors iff the checksum does not match.
+pub fn verify_checksum(
+    block: &[u8],
+    decompressed: &[u8],
+    compression: Option<Compression>,
+) -> Result<()> {
+    match compression {
+        Some(Compression::Snappy) => verify_snappy_checksum(block, decompressed),
+        _ => Ok(()),
+    }
+}
+
 /// [`FallibleStreamingIterator`] of decompressed Avro blocks
 pub struct Decompressor<R: Read> {
     blocks: BlockStreamIterator<R>,
     codec: Option<Compression>,
     buf: (Vec<u8>, usize),
+    block_size: usize,
     was_swapped: bool,
+    verify_checksum: bool,
 }
 
 impl<R: Read> Decompressor<R> {
@@ -66,14 +112,27 @@ impl<R: Read> Decompressor<R> {
             blocks,
             codec,
             buf: (vec![], 0),
+            block_size: 0,
             was_swapped: false,
+            verify_checksum: false,
         }
     }
 
+    /// Sets whether to verify the checksum of each block (see [`verify_checksum`]).
+    pub fn with_checksum(mut self, verify_checksum: bool) -> Self {
+        self.verify_checksum = verify_checksum;
+        self
+    }
+
     /// Deconstructs itself into its internal reader
     pub fn into_inner(self) -> R {
         self.blocks.into_inner().0
     }
+
+    /// The size of the current block as stored in the file, i.e. before decompression.
+    pub(super) fn block_size(&self) -> usize {
+        self.block_size
+    }
 }
 
 impl<'a, R: Read> FallibleStreamingIterator for Decompressor<R> {
@@ -85,7 +144,11 @@ impl<'a, R: Read> FallibleStreamingIterator for Decompressor<R> {
             std::mem::swap(self.blocks.buffer(), &mut self.buf.0);
         }
         self.blocks.advance()?;
+        self.block_size = self.blocks.buffer().len();
         self.was_swapped = decompress_block(self.blocks.buffer(), &mut self.buf.0, self.codec)?;
+        if self.verify_checksum && !self.was_swapped {
+            verify_checksum(self.blocks.buffer(), &self.buf.0, self.codec)?;
+        }
         self.buf.1 = self.blocks.get().map(|(_, rows)| *rows).unwrap_or_default();
         Ok(())
     }
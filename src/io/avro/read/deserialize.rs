@@ -5,8 +5,7 @@ use avro_rs::Schema as AvroSchema;
 
 use crate::array::*;
 use crate::datatypes::*;
-use crate::error::ArrowError;
-use crate::error::Result;
+use crate::error::{ArrowError, ErrorContext, Result};
 use crate::record_batch::RecordBatch;
 use crate::types::months_days_ns;
 
@@ -260,13 +259,20 @@ pub fn deserialize(
         .collect::<Result<_>>()?;
 
     // this is _the_ expensive transpose (rows -> columns)
-    for _ in 0..rows {
+    for row in 0..rows {
         for ((array, field), avro_field) in arrays
             .iter_mut()
             .zip(schema.fields().iter())
             .zip(avro_schemas.iter())
         {
-            block = deserialize_item(array.as_mut(), field.is_nullable(), avro_field, block)?
+            block = deserialize_item(array.as_mut(), field.is_nullable(), avro_field, block)
+                .map_err(|e| {
+                    e.with_context(
+                        ErrorContext::default()
+                            .with_column(field.name().as_str())
+                            .with_row(row),
+                    )
+                })?
         }
     }
     let columns = arrays.iter_mut().map(|array| array.as_arc()).collect();
@@ -9,7 +9,7 @@ use fallible_streaming_iterator::FallibleStreamingIterator;
 mod block;
 mod decompress;
 pub use block::BlockStreamIterator;
-pub use decompress::{decompress_block, Decompressor};
+pub use decompress::{decompress_block, verify_checksum, Decompressor};
 mod deserialize;
 pub use deserialize::deserialize;
 mod header;
@@ -21,7 +21,8 @@ pub(super) use header::deserialize_header;
 pub(super) use schema::convert_schema;
 
 use crate::datatypes::Schema;
-use crate::error::Result;
+use crate::error::{ErrorContext, Location, Result};
+use crate::io::{HooksState, ReadHooks};
 use crate::record_batch::RecordBatch;
 
 /// Valid compressions
@@ -51,10 +52,15 @@ pub fn read_metadata<R: std::io::Read>(
 }
 
 /// Single threaded, blocking reader of Avro; [`Iterator`] of [`RecordBatch`]es.
+///
+/// Its errors are wrapped in an [`ArrowError::WithContext`](crate::error::ArrowError::WithContext)
+/// with the Avro block, and the column and row when deserializing a block fails.
 pub struct Reader<R: Read> {
     iter: Decompressor<R>,
     schema: Arc<Schema>,
     avro_schemas: Vec<AvroSchema>,
+    block: usize,
+    hooks: HooksState,
 }
 
 impl<R: Read> Reader<R> {
@@ -64,9 +70,16 @@ impl<R: Read> Reader<R> {
             iter,
             avro_schemas,
             schema,
+            block: 0,
+            hooks: HooksState::default(),
         }
     }
 
+    /// Sets the [`ReadHooks`] of this reader, used to cancel the read and report its progress.
+    pub fn set_hooks(&mut self, hooks: Arc<dyn ReadHooks>) {
+        self.hooks.set_hooks(hooks);
+    }
+
     /// Deconstructs itself into its internal reader
     pub fn into_inner(self) -> R {
         self.iter.into_inner()
@@ -77,12 +90,40 @@ impl<R: Read> Iterator for Reader<R> {
     type Item = Result<RecordBatch>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.hooks.is_cancelled() {
+            return None;
+        }
         let schema = self.schema.clone();
         let avro_schemas = &self.avro_schemas;
+        let hooks = &mut self.hooks;
+
+        let block = self.block;
+        self.block += 1;
+
+        if let Err(e) = hooks.check_cancelled() {
+            return Some(Err(e));
+        }
 
-        self.iter.next().transpose().map(|x| {
-            let (data, rows) = x?;
-            deserialize(data, *rows, schema, avro_schemas)
-        })
+        let iter = &mut self.iter;
+        iter.advance()
+            .map(|_| {
+                let block_size = iter.block_size();
+                iter.get().map(|(data, rows)| (data, *rows, block_size))
+            })
+            .transpose()
+            .map(|x| {
+                let (data, rows, block_size) = x?;
+                hooks.add_bytes(block_size as u64);
+                let batch = deserialize(data, rows, schema, avro_schemas)?;
+                hooks.add_rows(batch.num_rows());
+                Result::Ok(batch)
+            })
+            .map(|x| {
+                x.map_err(|e| {
+                    e.with_context(
+                        ErrorContext::default().with_location(Location::AvroBlock(block)),
+                    )
+                })
+            })
     }
 }
@@ -139,6 +139,7 @@ impl FixedItemsUtf8Dictionary {
             data_type: DataType::Dictionary(
                 IntegerType::Int32,
                 Box::new(values.data_type().clone()),
+                false,
             ),
             keys: MutablePrimitiveArray::<i32>::with_capacity(capacity),
             values,
@@ -0,0 +1,4 @@
+#![deny(missing_docs)]
+//! APIs to write to Avro format.
+mod schema;
+pub use schema::to_avro_schema;
@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use avro_rs::schema::{Name, RecordField, RecordFieldOrder};
+use avro_rs::Schema as AvroSchema;
+
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+
+/// Converts a [`Schema`] into an Avro record schema, whose fields are converted as follows:
+/// * nullable fields are converted to the union of `null` and their type
+/// * integers of up to 32 bits and `UInt8`/`UInt16` to `int`; `Int64` and `UInt32` to `long`
+/// * `Float32` and `Float64` to `float` and `double`
+/// * `(Large)Binary` and `(Large)Utf8` to `bytes` and `string`
+/// * `FixedSizeBinary` to `fixed` and `Decimal` to the `decimal` logical type of `bytes`
+/// * extensions to the type of their storage (e.g. `arrow.uuid` to `fixed`)
+/// * `Date32`, `Time32(Millisecond)`, `Time64(Microsecond)`, `Timestamp(Millisecond | Microsecond)`
+///   and `Interval(MonthDayNano)` to the logical types `date`, `time-millis`, `time-micros`,
+///   `timestamp-millis | timestamp-micros` and `duration`
+/// * lists to `array`, maps with `Utf8` keys to `map`, structs to `record` and unions to unions
+/// * dictionaries to the type of their values
+///
+/// The named types (`record` and `fixed`) are named after the path of their field from the
+/// root record (e.g. `record.a.item` for the items of the list field `a`), so that their
+/// names are unique.
+///
+/// The `avro::doc` metadata key of the schema and fields, as set by the reader, is used as the
+/// documentation of the record and its fields.
+/// # Errors
+/// Errors when a field's [`DataType`] has no Avro equivalent (e.g. `UInt64` or
+/// `Timestamp(Nanosecond)`).
+pub fn to_avro_schema(schema: &Schema) -> Result<AvroSchema> {
+    let doc = schema.metadata.get("avro::doc").cloned();
+    to_record("record", doc, schema.fields())
+}
+
+/// Returns a record named `name`, whose fields are named under `name`.
+fn to_record(name: &str, doc: Option<String>, fields: &[Field]) -> Result<AvroSchema> {
+    let fields = fields
+        .iter()
+        .enumerate()
+        .map(|(position, field)| {
+            Ok(RecordField {
+                name: field.name().to_string(),
+                doc: field
+                    .metadata
+                    .as_ref()
+                    .and_then(|metadata| metadata.get("avro::doc").cloned()),
+                default: None,
+                schema: field_to_schema(field, name)?,
+                order: RecordFieldOrder::Ascending,
+                position,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let lookup = fields
+        .iter()
+        .map(|field| (field.name.clone(), field.position))
+        .collect::<HashMap<_, _>>();
+    Ok(AvroSchema::Record {
+        name: Name::new(name),
+        doc,
+        fields,
+        lookup,
+    })
+}
+
+/// Returns the schema of `field`, whose named types are named under `parent`.
+fn field_to_schema(field: &Field, parent: &str) -> Result<AvroSchema> {
+    let schema = type_to_schema(field.data_type(), &field_path(parent, field.name()))?;
+    if !field.is_nullable() {
+        return Ok(schema);
+    }
+    match schema {
+        AvroSchema::Null => Ok(schema),
+        AvroSchema::Union(union) => {
+            let mut variants = union.variants().to_vec();
+            if !variants.contains(&AvroSchema::Null) {
+                variants.insert(0, AvroSchema::Null);
+            }
+            to_union(variants)
+        }
+        schema => to_union(vec![AvroSchema::Null, schema]),
+    }
+}
+
+/// Returns the union of `variants`.
+fn to_union(variants: Vec<AvroSchema>) -> Result<AvroSchema> {
+    // `avro_rs` only supports constructing unions by parsing them
+    let value = serde_json::to_value(variants)
+        .map_err(|e| ArrowError::External("".to_string(), Box::new(e)))?;
+    Ok(AvroSchema::parse(&value)?)
+}
+
+fn field_path(parent: &str, name: &str) -> String {
+    format!("{}.{}", parent, name)
+}
+
+/// Returns the schema of `data_type` of the field at `path`, used as the full name of
+/// named types.
+fn type_to_schema(data_type: &DataType, path: &str) -> Result<AvroSchema> {
+    use DataType::*;
+    Ok(match data_type {
+        Extension(_, inner, _) => type_to_schema(inner, path)?,
+        Null => AvroSchema::Null,
+        Boolean => AvroSchema::Boolean,
+        Int8 | Int16 | Int32 | UInt8 | UInt16 => AvroSchema::Int,
+        Int64 | UInt32 => AvroSchema::Long,
+        Float32 => AvroSchema::Float,
+        Float64 => AvroSchema::Double,
+        Binary | LargeBinary => AvroSchema::Bytes,
+        Utf8 | LargeUtf8 => AvroSchema::String,
+        FixedSizeBinary(size) => AvroSchema::Fixed {
+            name: Name::new(path),
+            size: *size,
+        },
+        Decimal(precision, scale) => AvroSchema::Decimal {
+            precision: *precision,
+            scale: *scale,
+            inner: Box::new(AvroSchema::Bytes),
+        },
+        Date32 => AvroSchema::Date,
+        Time32(TimeUnit::Millisecond) => AvroSchema::TimeMillis,
+        Time64(TimeUnit::Microsecond) => AvroSchema::TimeMicros,
+        Timestamp(TimeUnit::Millisecond, _) => AvroSchema::TimestampMillis,
+        Timestamp(TimeUnit::Microsecond, _) => AvroSchema::TimestampMicros,
+        Interval(IntervalUnit::MonthDayNano) => AvroSchema::Duration,
+        List(field) | LargeList(field) | FixedSizeList(field, _) => {
+            AvroSchema::Array(Box::new(field_to_schema(field, path)?))
+        }
+        Map(field, _) => match field.data_type() {
+            Struct(fields)
+                if fields.len() == 2 && matches!(fields[0].data_type(), Utf8 | LargeUtf8) =>
+            {
+                AvroSchema::Map(Box::new(field_to_schema(&fields[1], path)?))
+            }
+            _ => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Avro maps only support string keys, found {:?}",
+                    data_type
+                )))
+            }
+        },
+        Struct(fields) => to_record(path, None, fields)?,
+        Union(fields, _, _) => {
+            let variants = fields
+                .iter()
+                .map(|field| type_to_schema(field.data_type(), &field_path(path, field.name())))
+                .collect::<Result<Vec<_>>>()?;
+            to_union(variants)?
+        }
+        Dictionary(_, values, _) => type_to_schema(values, path)?,
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Writing {:?} to Avro",
+                other
+            )))
+        }
+    })
+}
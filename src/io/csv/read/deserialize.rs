@@ -1,12 +1,14 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use csv::ByteRecord;
 
 use crate::{
-    array::Array,
+    array::{Array, PrimitiveArray},
     datatypes::{DataType, Field},
-    error::Result,
+    error::{ArrowError, Result},
     record_batch::RecordBatch,
+    types::NativeType,
 };
 
 use super::super::read_utils::{
@@ -34,6 +36,10 @@ pub fn deserialize_column(
 /// Deserializes rows [`ByteRecord`] into a [`RecordBatch`].
 /// Note that this is a convenience function: column deserialization
 /// is trivially parallelizable (e.g. rayon).
+/// # Errors
+/// Errors iff a column cannot be deserialized, with the error of `deserialize_column`
+/// wrapped in an [`ArrowError::WithContext`](crate::error::ArrowError::WithContext) with the
+/// column's name.
 pub fn deserialize_batch<F>(
     rows: &[ByteRecord],
     fields: &[Field],
@@ -46,3 +52,135 @@ where
 {
     deserialize_batch_gen(rows, fields, projection, line_number, deserialize_column)
 }
+
+type ColumnParser =
+    Box<dyn Fn(&[ByteRecord], usize, DataType, usize) -> Result<Arc<dyn Array>> + Send + Sync>;
+
+/// A set of custom parsers, one per column, that take precedence over [`deserialize_column`].
+///
+/// This allows deserializing values that the default parsers do not understand
+/// (e.g. `12,5%` or `$1,000.00`) directly into their typed array, without a second pass
+/// over an intermediary [`DataType::Utf8`] array.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Float64Array};
+/// use arrow2::datatypes::{DataType, Field};
+/// use arrow2::error::Result;
+/// use arrow2::io::csv::read::{deserialize_batch, read_rows, ByteRecord, ColumnParsers, ReaderBuilder};
+///
+/// fn main() -> Result<()> {
+///     // `,` is the decimal separator, so the fields are delimited by `;`
+///     let data = "name;rate\na;12,5%\nb;7%\n";
+///     let mut reader = ReaderBuilder::new()
+///         .delimiter(b';')
+///         .from_reader(data.as_bytes());
+///     let fields = vec![
+///         Field::new("name", DataType::Utf8, true),
+///         Field::new("rate", DataType::Float64, true),
+///     ];
+///
+///     let parsers = ColumnParsers::new().with_primitive(1, |bytes: &[u8]| {
+///         std::str::from_utf8(bytes)
+///             .ok()?
+///             .trim_end_matches('%')
+///             .replace(',', ".")
+///             .parse::<f64>()
+///             .ok()
+///             .map(|x| x / 100.0)
+///     });
+///
+///     let mut rows = vec![ByteRecord::default(); 2];
+///     let rows_read = read_rows(&mut reader, 0, &mut rows)?;
+///     let batch = deserialize_batch(
+///         &rows[..rows_read],
+///         &fields,
+///         None,
+///         0,
+///         |rows, column, data_type, line_number| {
+///             parsers.deserialize_column(rows, column, data_type, line_number)
+///         },
+///     )?;
+///
+///     let expected = Float64Array::from_slice(&[0.125, 0.07]);
+///     assert_eq!(batch.column(1).as_ref(), &expected as &dyn Array);
+///     Ok(())
+/// }
+/// ```
+#[derive(Default)]
+pub struct ColumnParsers {
+    parsers: HashMap<usize, ColumnParser>,
+}
+
+impl std::fmt::Debug for ColumnParsers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut columns = self.parsers.keys().collect::<Vec<_>>();
+        columns.sort_unstable();
+        f.debug_struct("ColumnParsers")
+            .field("columns", &columns)
+            .finish()
+    }
+}
+
+impl ColumnParsers {
+    /// Creates a new [`ColumnParsers`] without custom parsers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `op` as the parser of the values of `column` into a [`PrimitiveArray<T>`].
+    /// `op` is not called on empty fields, which are null; it returns `None` for values that it
+    /// can't parse, which are also null.
+    /// The [`DataType`] of the array is the one of the column's field (e.g. [`DataType::Decimal`] for `i128`),
+    /// and deserializing errors when `T` does not support it.
+    pub fn with_primitive<T, F>(self, column: usize, op: F) -> Self
+    where
+        T: NativeType,
+        F: Fn(&[u8]) -> Option<T> + Send + Sync + 'static,
+    {
+        self.with_parser(column, move |rows, column, data_type, _| {
+            if !T::is_valid(&data_type) {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The custom parser of column {} returns {}, which does not support the logical type {:?}",
+                    column,
+                    std::any::type_name::<T>(),
+                    data_type
+                )));
+            }
+            let iter = rows.iter().map(|row| match row.get(column) {
+                Some(bytes) if !bytes.is_empty() => op(bytes),
+                _ => None,
+            });
+            Ok(Arc::new(
+                PrimitiveArray::<T>::from_trusted_len_iter(iter).to(data_type),
+            ))
+        })
+    }
+
+    /// Registers `op` as the parser of `column`. `op` has the same signature as [`deserialize_column`],
+    /// and can thus deserialize into any [`Array`].
+    pub fn with_parser<F>(mut self, column: usize, op: F) -> Self
+    where
+        F: Fn(&[ByteRecord], usize, DataType, usize) -> Result<Arc<dyn Array>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.parsers.insert(column, Box::new(op));
+        self
+    }
+
+    /// Deserializes `column` of `rows` into an [`Array`] of [`DataType`] `datatype`, using
+    /// the custom parser of `column` if registered and [`deserialize_column`] otherwise.
+    pub fn deserialize_column(
+        &self,
+        rows: &[ByteRecord],
+        column: usize,
+        datatype: DataType,
+        line_number: usize,
+    ) -> Result<Arc<dyn Array>> {
+        match self.parsers.get(&column) {
+            Some(parser) => parser(rows, column, datatype, line_number),
+            None => deserialize_column(rows, column, datatype, line_number),
+        }
+    }
+}
@@ -8,6 +8,6 @@ pub use csv::{ByteRecord, Reader, ReaderBuilder};
 mod infer_schema;
 
 pub use super::utils::infer;
-pub use deserialize::{deserialize_batch, deserialize_column};
+pub use deserialize::{deserialize_batch, deserialize_column, ColumnParsers};
 pub use infer_schema::infer_schema;
 pub use reader::*;
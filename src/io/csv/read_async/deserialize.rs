@@ -34,6 +34,10 @@ pub fn deserialize_column(
 /// Deserializes rows [`ByteRecord`] into a [`RecordBatch`].
 /// Note that this is a convenience function: column deserialization
 /// is trivially parallelizable (e.g. rayon).
+/// # Errors
+/// Errors iff a column cannot be deserialized, with the error of `deserialize_column`
+/// wrapped in an [`ArrowError::WithContext`](crate::error::ArrowError::WithContext) with the
+/// column's name.
 pub fn deserialize_batch<F>(
     rows: &[ByteRecord],
     fields: &[Field],
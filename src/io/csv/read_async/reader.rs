@@ -2,11 +2,14 @@ use futures::AsyncRead;
 
 use super::{AsyncReader, ByteRecord};
 
-use crate::error::{ArrowError, Result};
+use crate::error::{ArrowError, ErrorContext, Location, Result};
 
 /// Asynchronosly read `len` rows from `reader` into `row`, skiping the first `skip`.
 /// This operation has minimal CPU work and is thus the fastest way to read through a CSV
 /// without deserializing the contents to Arrow.
+/// # Errors
+/// Errors iff a row cannot be read, with the [`ArrowError::External`] of the CSV error wrapped
+/// in an [`ArrowError::WithContext`] with its row, line and byte offset.
 pub async fn read_rows<R>(
     reader: &mut AsyncReader<R>,
     skip: usize,
@@ -27,7 +30,13 @@ where
     let mut row_number = 0;
     for row in rows.iter_mut() {
         let has_more = reader.read_byte_record(row).await.map_err(|e| {
-            ArrowError::External(format!(" at line {}", skip + row_number), Box::new(e))
+            let mut context = ErrorContext::default().with_row(skip + row_number);
+            if let Some(position) = e.position() {
+                context = context
+                    .with_byte_offset(position.byte())
+                    .with_location(Location::CsvLine(position.line()));
+            }
+            ArrowError::from_external_error(e).with_context(context)
         })?;
         if !has_more {
             break;
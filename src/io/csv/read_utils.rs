@@ -12,7 +12,7 @@ pub(crate) trait ByteRecordGeneric {
 use crate::{
     array::*,
     datatypes::*,
-    error::{ArrowError, Result},
+    error::{ArrowError, ErrorContext, Result},
     record_batch::RecordBatch,
     temporal_conversions,
     types::{NativeType, NaturalDataType},
@@ -288,7 +288,9 @@ where
             let column = *column;
             let field = &fields[column];
             let data_type = field.data_type();
-            deserialize_column(rows, column, data_type.clone(), line_number)
+            deserialize_column(rows, column, data_type.clone(), line_number).map_err(|e| {
+                e.with_context(ErrorContext::default().with_column(field.name().as_str()))
+            })
         })
         .collect::<Result<Vec<_>>>()?;
 
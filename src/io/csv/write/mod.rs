@@ -11,6 +11,7 @@ pub use csv::{ByteRecord, Writer, WriterBuilder};
 
 pub use serialize::*;
 
+use crate::io::{check_schema, ChunkSink};
 use crate::record_batch::RecordBatch;
 use crate::{datatypes::Schema, error::Result};
 
@@ -76,3 +77,47 @@ pub fn write_header<W: Write>(writer: &mut Writer<W>, schema: &Schema) -> Result
     writer.write_record(&fields)?;
     Ok(())
 }
+
+/// A CSV writer that writes a header on creation and implements [`ChunkSink`].
+pub struct FileWriter<W: Write> {
+    writer: Writer<W>,
+    schema: Schema,
+    options: SerializeOptions,
+}
+
+impl<W: Write> FileWriter<W> {
+    /// Creates a new [`FileWriter`], writing the header of `schema` to `writer`.
+    pub fn try_new(
+        mut writer: Writer<W>,
+        schema: Schema,
+        options: SerializeOptions,
+    ) -> Result<Self> {
+        write_header(&mut writer, &schema)?;
+        Ok(Self {
+            writer,
+            schema,
+            options,
+        })
+    }
+
+    /// Consumes itself, returning the inner [`Writer`].
+    pub fn into_inner(self) -> Writer<W> {
+        self.writer
+    }
+}
+
+impl<W: Write> ChunkSink for FileWriter<W> {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        check_schema(&self.schema, batch)?;
+        write_batch(&mut self.writer, batch, &self.options)
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.writer.flush()?;
+        Ok(())
+    }
+}
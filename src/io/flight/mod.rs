@@ -10,12 +10,18 @@ use crate::{
     datatypes::*,
     error::{ArrowError, Result},
     io::ipc::fb_to_schema,
-    io::ipc::read::read_record_batch,
+    io::ipc::read::{read_dictionary, read_record_batch, ValidationMode},
     io::ipc::write,
     io::ipc::write::common::{encoded_batch, DictionaryTracker, EncodedData, WriteOptions},
     record_batch::RecordBatch,
 };
 
+#[cfg(feature = "io_flight_async")]
+mod stream;
+#[cfg(feature = "io_flight_async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_flight_async")))]
+pub use stream::{deserialize_stream, serialize_stream, serialize_stream_with_metadata};
+
 /// Serializes a [`RecordBatch`] to a vector of [`FlightData`] representing the serialized dictionaries
 /// and a [`FlightData`] representing the batch.
 pub fn serialize_batch(
@@ -81,10 +87,10 @@ fn schema_as_encoded_data(schema: &Schema) -> EncodedData {
     }
 }
 
-/// Deserialize an IPC message into a schema
-fn schema_from_bytes(bytes: &[u8]) -> Result<Schema> {
+/// Deserialize an IPC message into a schema and whether its data is little endian
+fn schema_from_bytes(bytes: &[u8]) -> Result<(Schema, bool)> {
     if let Ok(ipc) = ipc::Message::root_as_message(bytes) {
-        if let Some((schema, _)) = ipc.header_as_schema().map(fb_to_schema) {
+        if let Some(schema) = ipc.header_as_schema().map(fb_to_schema) {
             Ok(schema)
         } else {
             Err(ArrowError::OutOfSpec(
@@ -101,24 +107,28 @@ fn schema_from_bytes(bytes: &[u8]) -> Result<Schema> {
 impl TryFrom<&FlightData> for Schema {
     type Error = ArrowError;
     fn try_from(data: &FlightData) -> Result<Self> {
-        schema_from_bytes(&data.data_header[..]).map_err(|err| {
-            ArrowError::OutOfSpec(format!(
-                "Unable to convert flight data to Arrow schema: {}",
-                err
-            ))
-        })
+        schema_from_bytes(&data.data_header[..])
+            .map(|(schema, _)| schema)
+            .map_err(|err| {
+                ArrowError::OutOfSpec(format!(
+                    "Unable to convert flight data to Arrow schema: {}",
+                    err
+                ))
+            })
     }
 }
 
 impl TryFrom<&SchemaResult> for Schema {
     type Error = ArrowError;
     fn try_from(data: &SchemaResult) -> Result<Self> {
-        schema_from_bytes(&data.schema[..]).map_err(|err| {
-            ArrowError::OutOfSpec(format!(
-                "Unable to convert schema result to Arrow schema: {}",
-                err
-            ))
-        })
+        schema_from_bytes(&data.schema[..])
+            .map(|(schema, _)| schema)
+            .map_err(|err| {
+                ArrowError::OutOfSpec(format!(
+                    "Unable to convert schema result to Arrow schema: {}",
+                    err
+                ))
+            })
     }
 }
 
@@ -153,6 +163,37 @@ pub fn deserialize_batch(
                 ipc::Schema::MetadataVersion::V5,
                 &mut reader,
                 0,
+                ValidationMode::Full,
             )
         })?
 }
+
+/// Deserializes [`FlightData`] of a dictionary batch into `dictionaries`, so that batches
+/// that follow it can be deserialized via [`deserialize_batch`].
+pub fn deserialize_dictionary(
+    data: &FlightData,
+    schema: &Schema,
+    is_little_endian: bool,
+    dictionaries: &mut HashMap<usize, Arc<dyn Array>>,
+) -> Result<()> {
+    let message = ipc::Message::root_as_message(&data.data_header[..]).map_err(|err| {
+        ArrowError::OutOfSpec(format!("Unable to get root as message: {:?}", err))
+    })?;
+
+    let batch = message.header_as_dictionary_batch().ok_or_else(|| {
+        ArrowError::OutOfSpec(
+            "Unable to convert flight data header to a dictionary batch".to_string(),
+        )
+    })?;
+
+    let mut reader = std::io::Cursor::new(&data.data_body);
+    read_dictionary(
+        batch,
+        schema,
+        is_little_endian,
+        dictionaries,
+        &mut reader,
+        0,
+        ValidationMode::Full,
+    )
+}
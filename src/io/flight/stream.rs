@@ -0,0 +1,108 @@
+//! `async` adapters between streams of [`RecordBatch`]es and streams of [`FlightData`].
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_format::flight::data::FlightData;
+use arrow_format::ipc;
+use async_stream::try_stream;
+use futures::{pin_mut, Stream, StreamExt};
+
+use crate::{
+    datatypes::Schema,
+    error::{ArrowError, Result},
+    io::ipc::write::common::{encoded_batch, DictionaryTracker, WriteOptions},
+    record_batch::RecordBatch,
+};
+
+use super::{deserialize_batch, deserialize_dictionary, schema_from_bytes, serialize_schema};
+
+/// Returns a [`Stream`] of [`FlightData`] of `batches`, e.g. to respond to a `DoGet` request.
+///
+/// The stream starts with the `schema`, followed by each batch. A batch is preceded by its
+/// dictionaries that were not sent before.
+pub fn serialize_stream<'a, S>(
+    schema: &Schema,
+    batches: S,
+    options: WriteOptions,
+) -> impl Stream<Item = Result<FlightData>> + 'a
+where
+    S: Stream<Item = Result<RecordBatch>> + 'a,
+{
+    serialize_stream_with_metadata(
+        schema,
+        batches.map(|batch| batch.map(|batch| (batch, vec![]))),
+        options,
+    )
+}
+
+/// Same as [`serialize_stream`], but each batch is accompanied by the `app_metadata` of its
+/// [`FlightData`].
+pub fn serialize_stream_with_metadata<'a, S>(
+    schema: &Schema,
+    batches: S,
+    options: WriteOptions,
+) -> impl Stream<Item = Result<FlightData>> + 'a
+where
+    S: Stream<Item = Result<(RecordBatch, Vec<u8>)>> + 'a,
+{
+    let schema = serialize_schema(schema);
+    try_stream! {
+        yield schema;
+
+        let mut dictionary_tracker = DictionaryTracker::new(false);
+        pin_mut!(batches);
+        while let Some(item) = batches.next().await {
+            let (batch, app_metadata) = item?;
+            let (dictionaries, batch) = encoded_batch(&batch, &mut dictionary_tracker, &options)?;
+            for dictionary in dictionaries {
+                yield dictionary.into();
+            }
+            let mut batch: FlightData = batch.into();
+            batch.app_metadata = app_metadata;
+            yield batch;
+        }
+    }
+}
+
+/// Deserializes a [`Stream`] of [`FlightData`], e.g. the response to a `DoGet` request, into
+/// its [`Schema`] and a [`Stream`] of its [`RecordBatch`]es and their `app_metadata`.
+///
+/// The stream must start with the schema, and the dictionaries of a batch must precede it,
+/// as written by [`serialize_stream`].
+/// # Errors
+/// Errors iff the stream is empty or its first message is not a schema.
+pub async fn deserialize_stream<S, E>(
+    mut stream: S,
+) -> Result<(
+    Arc<Schema>,
+    impl Stream<Item = Result<(RecordBatch, Vec<u8>)>>,
+)>
+where
+    ArrowError: From<E>,
+    S: Stream<Item = std::result::Result<FlightData, E>> + Unpin,
+{
+    let data = stream.next().await.ok_or_else(|| {
+        ArrowError::OutOfSpec("A flight stream must start with a schema".to_string())
+    })??;
+    let (schema, is_little_endian) = schema_from_bytes(&data.data_header[..])?;
+    let schema = Arc::new(schema);
+
+    let stream_schema = schema.clone();
+    let batches = try_stream! {
+        let schema = stream_schema;
+        let mut dictionaries = HashMap::new();
+        while let Some(data) = stream.next().await {
+            let data = data?;
+            let message = ipc::Message::root_as_message(&data.data_header[..]).map_err(|err| {
+                ArrowError::OutOfSpec(format!("Unable to get root as message: {:?}", err))
+            })?;
+            if message.header_as_dictionary_batch().is_some() {
+                deserialize_dictionary(&data, &schema, is_little_endian, &mut dictionaries)?;
+            } else {
+                let batch = deserialize_batch(&data, schema.clone(), is_little_endian, &dictionaries)?;
+                yield (batch, data.app_metadata);
+            }
+        }
+    };
+    Ok((schema, batches))
+}
@@ -0,0 +1,93 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::error::{ArrowError, Result};
+
+/// The progress of a reader, reported to [`ReadHooks::on_progress`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ReadProgress {
+    /// The number of rows returned so far
+    pub rows: usize,
+    /// The number of bytes read so far, as stored in the file (i.e. before decompression)
+    pub bytes: u64,
+}
+
+/// Hooks called by readers while they read, so that long-running reads can be cancelled
+/// and their progress reported (e.g. by interactive applications).
+///
+/// Readers call [`ReadHooks::should_cancel`] before every unit of work (e.g. a row group,
+/// a column chunk, a message or a block) and [`ReadHooks::on_progress`] after returning every
+/// [`RecordBatch`](crate::record_batch::RecordBatch).
+///
+/// [`AtomicBool`] implements this trait and can thus be used as a cancellation token:
+/// ```
+/// use std::sync::atomic::{AtomicBool, Ordering};
+/// use std::sync::Arc;
+///
+/// use arrow2::io::ReadHooks;
+///
+/// let token = Arc::new(AtomicBool::new(false));
+/// // e.g. passed to a reader's `set_hooks` and stored by the application
+/// let hooks: Arc<dyn ReadHooks> = token.clone();
+///
+/// token.store(true, Ordering::Relaxed);
+/// assert!(hooks.should_cancel());
+/// ```
+pub trait ReadHooks: Send + Sync {
+    /// Returns whether the read should stop. Once it returns `true`, the reader
+    /// returns a single [`ArrowError::Cancelled`] and then finishes.
+    fn should_cancel(&self) -> bool {
+        false
+    }
+
+    /// Called with the total progress of the reader after it returns a batch.
+    fn on_progress(&self, _progress: ReadProgress) {}
+}
+
+impl ReadHooks for AtomicBool {
+    fn should_cancel(&self) -> bool {
+        self.load(Ordering::Relaxed)
+    }
+}
+
+/// The [`ReadHooks`] of a reader and its state.
+#[derive(Default)]
+pub(crate) struct HooksState {
+    hooks: Option<Arc<dyn ReadHooks>>,
+    progress: ReadProgress,
+    is_cancelled: bool,
+}
+
+impl HooksState {
+    pub fn set_hooks(&mut self, hooks: Arc<dyn ReadHooks>) {
+        self.hooks = Some(hooks);
+    }
+
+    /// Errors iff the hooks request the read to be cancelled.
+    pub fn check_cancelled(&mut self) -> Result<()> {
+        if matches!(&self.hooks, Some(hooks) if hooks.should_cancel()) {
+            self.is_cancelled = true;
+            Err(ArrowError::Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Whether the read was cancelled, in which case the reader must finish.
+    pub fn is_cancelled(&self) -> bool {
+        self.is_cancelled
+    }
+
+    /// Adds `bytes` to the bytes read.
+    pub fn add_bytes(&mut self, bytes: u64) {
+        self.progress.bytes += bytes;
+    }
+
+    /// Adds `rows` to the rows read and reports the progress.
+    pub fn add_rows(&mut self, rows: usize) {
+        self.progress.rows += rows;
+        if let Some(hooks) = self.hooks.as_ref() {
+            hooks.on_progress(self.progress)
+        }
+    }
+}
@@ -38,7 +38,7 @@
 //! # use arrow2::record_batch::RecordBatch;
 //! # use arrow2::error::ArrowError;
 //! // Setup the writer
-//! let path = "example.arrow".to_string();
+//! let path = std::env::temp_dir().join("example.arrow");
 //! let mut file = File::create(&path)?;
 //! let x_coord = Field::new("x", DataType::Int32, false);
 //! let y_coord = Field::new("y", DataType::Int32, false);
@@ -87,4 +87,4 @@ pub mod read;
 pub mod write;
 
 const ARROW_MAGIC: [u8; 6] = [b'A', b'R', b'R', b'O', b'W', b'1'];
-const CONTINUATION_MARKER: [u8; 4] = [0xff; 4];
+pub(crate) const CONTINUATION_MARKER: [u8; 4] = [0xff; 4];
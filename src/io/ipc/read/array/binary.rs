@@ -12,7 +12,9 @@ use crate::types::NativeType;
 
 use super::super::deserialize::Node;
 use super::super::read_basic::*;
+use super::super::ValidationMode;
 
+#[allow(clippy::too_many_arguments)]
 pub fn read_binary<O: Offset, R: Read + Seek>(
     field_nodes: &mut VecDeque<Node>,
     data_type: DataType,
@@ -21,6 +23,7 @@ pub fn read_binary<O: Offset, R: Read + Seek>(
     block_offset: u64,
     is_little_endian: bool,
     compression: Option<ipc::Message::BodyCompression>,
+    validation: ValidationMode,
 ) -> Result<BinaryArray<O>>
 where
     Vec<u8>: TryInto<O::Bytes> + TryInto<<u8 as NativeType>::Bytes>,
@@ -57,9 +60,12 @@ where
         compression,
     )?;
 
-    Ok(BinaryArray::<O>::from_data(
-        data_type, offsets, values, validity,
-    ))
+    Ok(match validation {
+        ValidationMode::Full => BinaryArray::<O>::from_data(data_type, offsets, values, validity),
+        // Safety: the caller of `ValidationMode::shallow` or `ValidationMode::none` guarantees
+        // that the offsets are valid
+        _ => unsafe { BinaryArray::<O>::from_data_unchecked(data_type, offsets, values, validity) },
+    })
 }
 
 pub fn skip_binary(field_nodes: &mut VecDeque<Node>, buffers: &mut VecDeque<&ipc::Schema::Buffer>) {
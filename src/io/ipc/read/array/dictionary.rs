@@ -48,7 +48,8 @@ where
         compression,
     )?;
 
-    Ok(DictionaryArray::<T>::from_data(keys, values))
+    let is_ordered = DictionaryArray::<T>::get_is_ordered(field.data_type());
+    Ok(DictionaryArray::<T>::from_data(keys, values).with_ordered(is_ordered))
 }
 
 pub fn skip_dictionary(
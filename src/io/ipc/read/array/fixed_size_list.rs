@@ -10,6 +10,7 @@ use crate::error::Result;
 
 use super::super::deserialize::{read, skip, Node};
 use super::super::read_basic::*;
+use super::super::ValidationMode;
 
 #[allow(clippy::too_many_arguments)]
 pub fn read_fixed_size_list<R: Read + Seek>(
@@ -22,6 +23,7 @@ pub fn read_fixed_size_list<R: Read + Seek>(
     is_little_endian: bool,
     compression: Option<ipc::Message::BodyCompression>,
     version: ipc::Schema::MetadataVersion,
+    validation: ValidationMode,
 ) -> Result<FixedSizeListArray> {
     let field_node = field_nodes.pop_front().unwrap();
 
@@ -46,6 +48,7 @@ pub fn read_fixed_size_list<R: Read + Seek>(
         is_little_endian,
         compression,
         version,
+        validation,
     )?;
     Ok(FixedSizeListArray::from_data(data_type, values, validity))
 }
@@ -12,6 +12,7 @@ use crate::error::Result;
 
 use super::super::deserialize::{read, skip, Node};
 use super::super::read_basic::*;
+use super::super::ValidationMode;
 
 #[allow(clippy::too_many_arguments)]
 pub fn read_list<O: Offset, R: Read + Seek>(
@@ -24,6 +25,7 @@ pub fn read_list<O: Offset, R: Read + Seek>(
     is_little_endian: bool,
     compression: Option<ipc::Message::BodyCompression>,
     version: ipc::Schema::MetadataVersion,
+    validation: ValidationMode,
 ) -> Result<ListArray<O>>
 where
     Vec<u8>: TryInto<O::Bytes>,
@@ -62,8 +64,14 @@ where
         is_little_endian,
         compression,
         version,
+        validation,
     )?;
-    Ok(ListArray::from_data(data_type, offsets, values, validity))
+    Ok(match validation {
+        ValidationMode::Full => ListArray::from_data(data_type, offsets, values, validity),
+        // Safety: the caller of `ValidationMode::shallow` or `ValidationMode::none` guarantees
+        // that the offsets are valid
+        _ => unsafe { ListArray::from_data_unchecked(data_type, offsets, values, validity) },
+    })
 }
 
 pub fn skip_list<O: Offset>(
@@ -11,6 +11,7 @@ use crate::error::Result;
 
 use super::super::deserialize::{read, skip, Node};
 use super::super::read_basic::*;
+use super::super::ValidationMode;
 
 #[allow(clippy::too_many_arguments)]
 pub fn read_map<R: Read + Seek>(
@@ -23,6 +24,7 @@ pub fn read_map<R: Read + Seek>(
     is_little_endian: bool,
     compression: Option<ipc::Message::BodyCompression>,
     version: ipc::Schema::MetadataVersion,
+    validation: ValidationMode,
 ) -> Result<MapArray> {
     let field_node = field_nodes.pop_front().unwrap();
 
@@ -58,8 +60,14 @@ pub fn read_map<R: Read + Seek>(
         is_little_endian,
         compression,
         version,
+        validation,
     )?;
-    Ok(MapArray::from_data(data_type, offsets, field, validity))
+    Ok(match validation {
+        ValidationMode::Full => MapArray::from_data(data_type, offsets, field, validity),
+        // Safety: the caller of `ValidationMode::shallow` or `ValidationMode::none` guarantees
+        // that the offsets are valid
+        _ => unsafe { MapArray::from_data_unchecked(data_type, offsets, field, validity) },
+    })
 }
 
 pub fn skip_map(
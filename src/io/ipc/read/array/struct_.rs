@@ -10,6 +10,7 @@ use crate::error::Result;
 
 use super::super::deserialize::{read, skip, Node};
 use super::super::read_basic::*;
+use super::super::ValidationMode;
 
 #[allow(clippy::too_many_arguments)]
 pub fn read_struct<R: Read + Seek>(
@@ -22,6 +23,7 @@ pub fn read_struct<R: Read + Seek>(
     is_little_endian: bool,
     compression: Option<ipc::Message::BodyCompression>,
     version: ipc::Schema::MetadataVersion,
+    validation: ValidationMode,
 ) -> Result<StructArray> {
     let field_node = field_nodes.pop_front().unwrap();
 
@@ -49,6 +51,7 @@ pub fn read_struct<R: Read + Seek>(
                 is_little_endian,
                 compression,
                 version,
+                validation,
             )
         })
         .collect::<Result<Vec<_>>>()?;
@@ -11,6 +11,7 @@ use crate::error::Result;
 
 use super::super::deserialize::{read, skip, Node};
 use super::super::read_basic::*;
+use super::super::ValidationMode;
 
 #[allow(clippy::too_many_arguments)]
 pub fn read_union<R: Read + Seek>(
@@ -23,6 +24,7 @@ pub fn read_union<R: Read + Seek>(
     is_little_endian: bool,
     compression: Option<ipc::Message::BodyCompression>,
     version: ipc::Schema::MetadataVersion,
+    validation: ValidationMode,
 ) -> Result<UnionArray> {
     let field_node = field_nodes.pop_front().unwrap();
 
@@ -71,6 +73,7 @@ pub fn read_union<R: Read + Seek>(
                 is_little_endian,
                 compression,
                 version,
+                validation,
             )
         })
         .collect::<Result<Vec<_>>>()?;
@@ -31,6 +31,53 @@ use super::deserialize::{read, skip};
 
 type ArrayRef = Arc<dyn Array>;
 
+/// The level of validation of the data read from Arrow IPC.
+///
+/// [`ValidationMode::Full`] is the default and the only mode that can be used on untrusted
+/// inputs. The other modes skip checks whose invariants the arrays rely on for soundness and
+/// can only be constructed via `unsafe` functions, [`ValidationMode::shallow`] and
+/// [`ValidationMode::none`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValidationMode {
+    /// Every invariant of every array is checked, e.g. that offsets are monotonically
+    /// increasing and that utf8 arrays are valid utf8. This is `O(N)` on the size of the data.
+    Full,
+    /// Only the invariants that can be checked in `O(1)` are checked, e.g. that the last offset
+    /// is consistent with the length of the values, and that the batch is consistent with its schema.
+    #[non_exhaustive]
+    Shallow,
+    /// Only the invariants that can be checked in `O(1)` per array are checked; the record batch
+    /// is assumed to be consistent with its schema.
+    #[non_exhaustive]
+    None,
+}
+
+// the only mode that is sound on untrusted data must be the one readers get unless they opt out
+// via `unsafe`; not derived because `#[default]` needs a newer toolchain than the one we support
+#[allow(clippy::derivable_impls)]
+impl Default for ValidationMode {
+    fn default() -> Self {
+        ValidationMode::Full
+    }
+}
+
+impl ValidationMode {
+    /// Returns [`ValidationMode::Shallow`].
+    /// # Safety
+    /// The data read with this mode MUST be valid Arrow, e.g. because it was written by this crate.
+    pub unsafe fn shallow() -> Self {
+        ValidationMode::Shallow
+    }
+
+    /// Returns [`ValidationMode::None`].
+    /// # Safety
+    /// The data read with this mode MUST be valid Arrow and consistent with the schema,
+    /// e.g. because it was written by this crate.
+    pub unsafe fn none() -> Self {
+        ValidationMode::None
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Hash)]
 enum ProjectionResult<A> {
     Selected(A),
@@ -102,6 +149,7 @@ pub fn read_record_batch<R: Read + Seek>(
     version: MetadataVersion,
     reader: &mut R,
     block_offset: u64,
+    validation: ValidationMode,
 ) -> Result<RecordBatch> {
     let buffers = batch.buffers().ok_or_else(|| {
         ArrowError::OutOfSpec("Unable to get buffers from IPC RecordBatch".to_string())
@@ -130,6 +178,7 @@ pub fn read_record_batch<R: Read + Seek>(
                     is_little_endian,
                     batch.compression(),
                     version,
+                    validation,
                 )),
                 ProjectionResult::NotSelected(field) => {
                     skip(&mut field_nodes, field.data_type(), &mut buffers);
@@ -154,18 +203,22 @@ pub fn read_record_batch<R: Read + Seek>(
                     is_little_endian,
                     batch.compression(),
                     version,
+                    validation,
                 )
             })
             .collect::<Result<Vec<_>>>()?;
         (schema.clone(), arrays)
     };
-    RecordBatch::try_new(schema, columns)
+    match validation {
+        ValidationMode::None => Ok(RecordBatch::new_unchecked(schema, columns)),
+        _ => RecordBatch::try_new(schema, columns),
+    }
 }
 
 fn find_first_dict_field_d(id: usize, data_type: &DataType) -> Option<&Field> {
     use DataType::*;
     match data_type {
-        Dictionary(_, inner) => find_first_dict_field_d(id, inner.as_ref()),
+        Dictionary(_, inner, _) => find_first_dict_field_d(id, inner.as_ref()),
         Map(field, _) => find_first_dict_field(id, field.as_ref()),
         List(field) => find_first_dict_field(id, field.as_ref()),
         LargeList(field) => find_first_dict_field(id, field.as_ref()),
@@ -191,7 +244,7 @@ fn find_first_dict_field_d(id: usize, data_type: &DataType) -> Option<&Field> {
 }
 
 fn find_first_dict_field(id: usize, field: &Field) -> Option<&Field> {
-    if let DataType::Dictionary(_, _) = &field.data_type {
+    if let DataType::Dictionary(_, _, _) = &field.data_type {
         if field.dict_id as usize == id {
             return Some(field);
         }
@@ -220,6 +273,7 @@ pub fn read_dictionary<R: Read + Seek>(
     dictionaries: &mut HashMap<usize, Arc<dyn Array>>,
     reader: &mut R,
     block_offset: u64,
+    validation: ValidationMode,
 ) -> Result<()> {
     if batch.isDelta() {
         return Err(ArrowError::NotYetImplemented(
@@ -234,7 +288,7 @@ pub fn read_dictionary<R: Read + Seek>(
     // values array, we need to retrieve this from the schema.
     // Get an array representing this dictionary's values.
     let dictionary_values: ArrayRef = match first_field.data_type() {
-        DataType::Dictionary(_, ref value_type) => {
+        DataType::Dictionary(_, ref value_type, _) => {
             // Make a fake schema for the dictionary batch.
             let schema = Arc::new(Schema {
                 fields: vec![Field::new("", value_type.as_ref().clone(), false)],
@@ -250,6 +304,7 @@ pub fn read_dictionary<R: Read + Seek>(
                 MetadataVersion::V5,
                 reader,
                 block_offset,
+                validation,
             )?;
             Some(record_batch.column(0).clone())
         }
@@ -10,8 +10,10 @@ use arrow_format::ipc::{Message::BodyCompression, Schema::MetadataVersion};
 use crate::array::*;
 use crate::datatypes::{DataType, Field, PhysicalType};
 use crate::error::Result;
+use crate::temporal_conversions::floor_date64_array;
 
 use super::array::*;
+use super::ValidationMode;
 
 pub type Node<'a> = &'a ipc::Message::FieldNode;
 
@@ -26,6 +28,7 @@ pub fn read<R: Read + Seek>(
     is_little_endian: bool,
     compression: Option<BodyCompression>,
     version: MetadataVersion,
+    validation: ValidationMode,
 ) -> Result<Arc<dyn Array>> {
     use PhysicalType::*;
     let data_type = field.data_type().clone();
@@ -45,6 +48,17 @@ pub fn read<R: Read + Seek>(
             compression,
         )
         .map(|x| Arc::new(x) as Arc<dyn Array>),
+        // Date64 values written by other implementations are not always at midnight
+        Primitive(_) if data_type == DataType::Date64 => read_primitive::<i64, _>(
+            field_nodes,
+            data_type,
+            buffers,
+            reader,
+            block_offset,
+            is_little_endian,
+            compression,
+        )
+        .map(|x| Arc::new(floor_date64_array(&x)) as Arc<dyn Array>),
         Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
             read_primitive::<$T, _>(
                 field_nodes,
@@ -66,6 +80,7 @@ pub fn read<R: Read + Seek>(
                 block_offset,
                 is_little_endian,
                 compression,
+                validation,
             )?;
             Ok(Arc::new(array))
         }
@@ -78,6 +93,7 @@ pub fn read<R: Read + Seek>(
                 block_offset,
                 is_little_endian,
                 compression,
+                validation,
             )?;
             Ok(Arc::new(array))
         }
@@ -102,6 +118,7 @@ pub fn read<R: Read + Seek>(
                 block_offset,
                 is_little_endian,
                 compression,
+                validation,
             )?;
             Ok(Arc::new(array))
         }
@@ -114,6 +131,7 @@ pub fn read<R: Read + Seek>(
                 block_offset,
                 is_little_endian,
                 compression,
+                validation,
             )?;
             Ok(Arc::new(array))
         }
@@ -127,6 +145,7 @@ pub fn read<R: Read + Seek>(
             is_little_endian,
             compression,
             version,
+            validation,
         )
         .map(|x| Arc::new(x) as Arc<dyn Array>),
         LargeList => read_list::<i64, _>(
@@ -139,6 +158,7 @@ pub fn read<R: Read + Seek>(
             is_little_endian,
             compression,
             version,
+            validation,
         )
         .map(|x| Arc::new(x) as Arc<dyn Array>),
         FixedSizeList => read_fixed_size_list(
@@ -151,6 +171,7 @@ pub fn read<R: Read + Seek>(
             is_little_endian,
             compression,
             version,
+            validation,
         )
         .map(|x| Arc::new(x) as Arc<dyn Array>),
         Struct => read_struct(
@@ -163,6 +184,7 @@ pub fn read<R: Read + Seek>(
             is_little_endian,
             compression,
             version,
+            validation,
         )
         .map(|x| Arc::new(x) as Arc<dyn Array>),
         Dictionary(key_type) => {
@@ -190,6 +212,7 @@ pub fn read<R: Read + Seek>(
             is_little_endian,
             compression,
             version,
+            validation,
         )
         .map(|x| Arc::new(x) as Arc<dyn Array>),
         Map => read_map(
@@ -202,6 +225,7 @@ pub fn read<R: Read + Seek>(
             is_little_endian,
             compression,
             version,
+            validation,
         )
         .map(|x| Arc::new(x) as Arc<dyn Array>),
     }
@@ -4,6 +4,14 @@
 //! which provides arbitrary access to any of its messages, and the
 //! [`StreamReader`](stream::StreamReader), which only supports reading
 //! data in the order it was written in.
+//!
+//! # Date64
+//! The Arrow specification requires [`DataType::Date64`](crate::datatypes::DataType::Date64)
+//! values to be at midnight, which other implementations do not always enforce. Readers thus
+//! floor every `Date64` array to midnight via
+//! [`floor_date64_array`](crate::temporal_conversions::floor_date64_array). This cannot be
+//! disabled, and costs an extra scan over each `Date64` array read (and a copy when a value
+//! is not at midnight).
 
 mod array;
 mod common;
@@ -12,6 +20,6 @@ mod read_basic;
 mod reader;
 mod stream;
 
-pub use common::{read_dictionary, read_record_batch};
+pub use common::{read_dictionary, read_record_batch, ValidationMode};
 pub use reader::{read_file_metadata, FileMetadata, FileReader};
 pub use stream::{read_stream_metadata, StreamMetadata, StreamReader, StreamState};
@@ -26,6 +26,7 @@ use arrow_format::ipc::File::Block;
 use crate::array::*;
 use crate::datatypes::Schema;
 use crate::error::{ArrowError, Result};
+use crate::io::{HooksState, ReadHooks};
 use crate::record_batch::RecordBatch;
 
 use super::super::convert;
@@ -65,6 +66,8 @@ pub struct FileReader<R: Read + Seek> {
     current_block: usize,
     projection: Option<(Vec<usize>, Arc<Schema>)>,
     buffer: Vec<u8>,
+    validation: ValidationMode,
+    hooks: HooksState,
 }
 
 fn read_dictionary_message<R: Read + Seek>(
@@ -117,6 +120,7 @@ fn read_dictionaries<R: Read + Seek>(
                     &mut dictionaries,
                     reader,
                     block_offset,
+                    ValidationMode::Full,
                 )?;
             }
             t => {
@@ -224,6 +228,7 @@ pub fn read_batch<R: Read + Seek>(
     projection: Option<(&[usize], Arc<Schema>)>,
     block: usize,
     block_data: &mut Vec<u8>,
+    validation: ValidationMode,
 ) -> Result<RecordBatch> {
     let block = metadata.blocks[block];
 
@@ -255,6 +260,7 @@ pub fn read_batch<R: Read + Seek>(
         metadata.version,
         reader,
         block.offset() as u64 + block.metaDataLength() as u64,
+        validation,
     )
 }
 
@@ -286,9 +292,24 @@ impl<R: Read + Seek> FileReader<R> {
             projection,
             current_block: 0,
             buffer: vec![],
+            validation: ValidationMode::default(),
+            hooks: HooksState::default(),
         }
     }
 
+    /// Sets the [`ValidationMode`] used to read the record batches of this reader.
+    /// The dictionaries, read by [`read_file_metadata`], are always fully validated.
+    pub fn set_validation(&mut self, validation: ValidationMode) {
+        self.validation = validation;
+    }
+
+    /// Sets the [`ReadHooks`] of this reader, which are checked for cancellation before reading
+    /// each record batch and notified of the progress after returning it.
+    /// The bytes of the progress are the sizes of the record batch messages read.
+    pub fn set_hooks(&mut self, hooks: Arc<dyn ReadHooks>) {
+        self.hooks.set_hooks(hooks);
+    }
+
     /// Return the schema of the file
     pub fn schema(&self) -> &Arc<Schema> {
         self.projection
@@ -307,11 +328,17 @@ impl<R: Read + Seek> Iterator for FileReader<R> {
     type Item = Result<RecordBatch>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.hooks.is_cancelled() {
+            return None;
+        }
         // get current block
         if self.current_block < self.metadata.blocks.len() {
+            if let Err(error) = self.hooks.check_cancelled() {
+                return Some(Err(error));
+            }
             let block = self.current_block;
             self.current_block += 1;
-            Some(read_batch(
+            let batch = read_batch(
                 &mut self.reader,
                 &self.metadata,
                 self.projection
@@ -319,7 +346,15 @@ impl<R: Read + Seek> Iterator for FileReader<R> {
                     .map(|x| (x.0.as_ref(), x.1.clone())),
                 block,
                 &mut self.buffer,
-            ))
+                self.validation,
+            );
+            if let Ok(batch) = &batch {
+                let block = &self.metadata.blocks[block];
+                self.hooks
+                    .add_bytes(block.metaDataLength() as u64 + block.bodyLength() as u64);
+                self.hooks.add_rows(batch.num_rows());
+            }
+            Some(batch)
         } else {
             None
         }
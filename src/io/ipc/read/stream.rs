@@ -25,6 +25,7 @@ use arrow_format::ipc::Schema::MetadataVersion;
 use crate::array::*;
 use crate::datatypes::Schema;
 use crate::error::{ArrowError, Result};
+use crate::io::{HooksState, ReadHooks};
 use crate::record_batch::RecordBatch;
 
 use super::super::convert;
@@ -116,7 +117,11 @@ fn read_next<R: Read>(
     dictionaries: &mut HashMap<usize, Arc<dyn Array>>,
     message_buffer: &mut Vec<u8>,
     data_buffer: &mut Vec<u8>,
+    validation: ValidationMode,
+    hooks: &mut HooksState,
 ) -> Result<Option<StreamState>> {
+    hooks.check_cancelled()?;
+
     // determine metadata length
     let mut meta_length: [u8; 4] = [0; 4];
 
@@ -139,9 +144,11 @@ fn read_next<R: Read>(
         // the size from the next four bytes.
         if meta_length == CONTINUATION_MARKER {
             reader.read_exact(&mut meta_length)?;
+            hooks.add_bytes(4);
         }
         i32::from_le_bytes(meta_length) as usize
     };
+    hooks.add_bytes(4);
 
     if meta_length == 0 {
         // the stream has ended, mark the reader as finished
@@ -151,6 +158,7 @@ fn read_next<R: Read>(
     message_buffer.clear();
     message_buffer.resize(meta_length, 0);
     reader.read_exact(message_buffer)?;
+    hooks.add_bytes(meta_length as u64);
 
     let message = ipc::Message::root_as_message(message_buffer).map_err(|err| {
         ArrowError::OutOfSpec(format!("Unable to get root as message: {:?}", err))
@@ -168,6 +176,7 @@ fn read_next<R: Read>(
             data_buffer.clear();
             data_buffer.resize(message.bodyLength() as usize, 0);
             reader.read_exact(data_buffer)?;
+            hooks.add_bytes(data_buffer.len() as u64);
 
             let mut reader = std::io::Cursor::new(data_buffer);
 
@@ -180,6 +189,7 @@ fn read_next<R: Read>(
                 metadata.version,
                 &mut reader,
                 0,
+                validation,
             )
             .map(|x| Some(StreamState::Some(x)))
         }
@@ -190,6 +200,7 @@ fn read_next<R: Read>(
             // read the block that makes up the dictionary batch into a buffer
             let mut buf = vec![0; message.bodyLength() as usize];
             reader.read_exact(&mut buf)?;
+            hooks.add_bytes(buf.len() as u64);
 
             let mut dict_reader = std::io::Cursor::new(buf);
 
@@ -200,10 +211,19 @@ fn read_next<R: Read>(
                 dictionaries,
                 &mut dict_reader,
                 0,
+                validation,
             )?;
 
             // read the next message until we encounter a RecordBatch
-            read_next(reader, metadata, dictionaries, message_buffer, data_buffer)
+            read_next(
+                reader,
+                metadata,
+                dictionaries,
+                message_buffer,
+                data_buffer,
+                validation,
+                hooks,
+            )
         }
         ipc::Message::MessageHeader::NONE => Ok(Some(StreamState::Waiting)),
         t => Err(ArrowError::OutOfSpec(format!(
@@ -226,6 +246,8 @@ pub struct StreamReader<R: Read> {
     finished: bool,
     data_buffer: Vec<u8>,
     message_buffer: Vec<u8>,
+    validation: ValidationMode,
+    hooks: HooksState,
 }
 
 impl<R: Read> StreamReader<R> {
@@ -242,9 +264,23 @@ impl<R: Read> StreamReader<R> {
             finished: false,
             data_buffer: vec![],
             message_buffer: vec![],
+            validation: ValidationMode::default(),
+            hooks: HooksState::default(),
         }
     }
 
+    /// Sets the [`ValidationMode`] used to read the dictionaries and record batches of this reader.
+    pub fn set_validation(&mut self, validation: ValidationMode) {
+        self.validation = validation;
+    }
+
+    /// Sets the [`ReadHooks`] of this reader, which are checked for cancellation before reading
+    /// each message and notified of the progress after returning each record batch.
+    /// The bytes of the progress are the sizes of the messages read.
+    pub fn set_hooks(&mut self, hooks: Arc<dyn ReadHooks>) {
+        self.hooks.set_hooks(hooks);
+    }
+
     /// Return the schema of the stream
     pub fn schema(&self) -> &Arc<Schema> {
         &self.metadata.schema
@@ -265,10 +301,18 @@ impl<R: Read> StreamReader<R> {
             &mut self.dictionaries,
             &mut self.message_buffer,
             &mut self.data_buffer,
-        )?;
-        if batch.is_none() {
+            self.validation,
+            &mut self.hooks,
+        );
+        if self.hooks.is_cancelled() {
             self.finished = true;
         }
+        let batch = batch?;
+        match &batch {
+            None => self.finished = true,
+            Some(StreamState::Some(batch)) => self.hooks.add_rows(batch.num_rows()),
+            Some(StreamState::Waiting) => {}
+        }
         Ok(batch)
     }
 }
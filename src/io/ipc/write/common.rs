@@ -25,7 +25,8 @@ pub enum Compression {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub struct WriteOptions {
     /// Whether the buffers should be compressed and which codec to use.
-    /// Note: to use compression the crate must be compiled with feature `io_ipc_compression`.
+    /// Note: to use compression the crate must be compiled with feature `io_ipc_compression`
+    /// (or `io_ipc_compression_lz4` / `io_ipc_compression_zstd` for a single codec).
     pub compression: Option<Compression>,
 }
 
@@ -195,6 +196,20 @@ pub fn encoded_batch(
     dictionary_tracker: &mut DictionaryTracker,
     options: &WriteOptions,
 ) -> Result<(Vec<EncodedData>, EncodedData)> {
+    let encoded_dictionaries = encoded_dictionaries(batch, dictionary_tracker, options)?;
+
+    let body = encode_body(batch.columns(), options);
+    let encoded_message = record_batch_to_bytes(batch.num_rows(), body, options);
+
+    Ok((encoded_dictionaries, encoded_message))
+}
+
+/// Encodes the dictionaries of `batch` that were not yet written.
+pub(crate) fn encoded_dictionaries(
+    batch: &RecordBatch,
+    dictionary_tracker: &mut DictionaryTracker,
+    options: &WriteOptions,
+) -> Result<Vec<EncodedData>> {
     let schema = batch.schema();
     let mut encoded_dictionaries = Vec::with_capacity(schema.fields().len());
 
@@ -207,32 +222,65 @@ pub fn encoded_batch(
             &mut encoded_dictionaries,
         )?;
     }
+    Ok(encoded_dictionaries)
+}
 
-    let encoded_message = record_batch_to_bytes(batch, options);
-
-    Ok((encoded_dictionaries, encoded_message))
+/// The nodes, buffers and data of the body of a record batch message
+#[derive(Debug, Default)]
+pub(crate) struct EncodedBody {
+    nodes: Vec<ipc::Message::FieldNode>,
+    buffers: Vec<ipc::Schema::Buffer>,
+    arrow_data: Vec<u8>,
 }
 
-/// Write a `RecordBatch` into two sets of bytes, one for the header (ipc::Schema::Message) and the
-/// other for the batch's data
-fn record_batch_to_bytes(batch: &RecordBatch, options: &WriteOptions) -> EncodedData {
-    let mut fbb = FlatBufferBuilder::new();
+impl EncodedBody {
+    /// Appends `other` to `self`, as if its arrays were encoded after the ones of `self`.
+    pub(crate) fn extend(&mut self, other: EncodedBody) {
+        // buffers are 8-byte aligned, so `other`'s buffers only need to be shifted
+        let shift = self.arrow_data.len() as i64;
+        self.nodes.extend(other.nodes);
+        self.buffers.extend(
+            other
+                .buffers
+                .iter()
+                .map(|buffer| ipc::Schema::Buffer::new(buffer.offset() + shift, buffer.length())),
+        );
+        self.arrow_data.extend_from_slice(&other.arrow_data);
+    }
+}
 
-    let mut nodes: Vec<ipc::Message::FieldNode> = vec![];
-    let mut buffers: Vec<ipc::Schema::Buffer> = vec![];
-    let mut arrow_data: Vec<u8> = vec![];
+/// Encodes (and compresses) `arrays` into the body of a record batch message
+pub(crate) fn encode_body(arrays: &[Arc<dyn Array>], options: &WriteOptions) -> EncodedBody {
+    let mut body = EncodedBody::default();
     let mut offset = 0;
-    for array in batch.columns() {
+    for array in arrays {
         write(
             array.as_ref(),
-            &mut buffers,
-            &mut arrow_data,
-            &mut nodes,
+            &mut body.buffers,
+            &mut body.arrow_data,
+            &mut body.nodes,
             &mut offset,
             is_native_little_endian(),
             options.compression,
         )
     }
+    body
+}
+
+/// Write a record batch with `length` rows and `body` into two sets of bytes, one for the
+/// header (ipc::Schema::Message) and the other for the batch's data
+pub(crate) fn record_batch_to_bytes(
+    length: usize,
+    body: EncodedBody,
+    options: &WriteOptions,
+) -> EncodedData {
+    let mut fbb = FlatBufferBuilder::new();
+
+    let EncodedBody {
+        nodes,
+        buffers,
+        arrow_data,
+    } = body;
 
     // write data
     let buffers = fbb.create_vector(&buffers);
@@ -252,7 +300,7 @@ fn record_batch_to_bytes(batch: &RecordBatch, options: &WriteOptions) -> Encoded
 
     let root = {
         let mut batch_builder = ipc::Message::RecordBatchBuilder::new(&mut fbb);
-        batch_builder.add_length(batch.num_rows() as i64);
+        batch_builder.add_length(length as i64);
         batch_builder.add_nodes(nodes);
         batch_builder.add_buffers(buffers);
         if let Some(compression) = compression {
@@ -381,7 +429,7 @@ impl DictionaryTracker {
     ///   inserted.
     pub fn insert(&mut self, dict_id: i64, array: &Arc<dyn Array>) -> Result<bool> {
         let values = match array.data_type() {
-            DataType::Dictionary(key_type, _) => {
+            DataType::Dictionary(key_type, _, _) => {
                 match_integer_type!(key_type, |$T| {
                     let array = array
                         .as_any()
@@ -1,11 +1,13 @@
 //! APIs to write to Arrow's IPC format.
 pub(crate) mod common;
+mod parallel;
 mod schema;
 mod serialize;
 mod stream;
 mod writer;
 
 pub use common::{Compression, WriteOptions};
+pub use parallel::{ParallelOptions, Spawner};
 pub use schema::schema_to_bytes;
 pub use serialize::{write, write_dictionary};
 pub use stream::StreamWriter;
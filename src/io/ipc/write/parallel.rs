@@ -0,0 +1,105 @@
+//! APIs to encode and compress the columns of record batches in parallel.
+use std::sync::mpsc::channel;
+use std::sync::{Arc, Mutex};
+
+use super::common::{
+    encode_body, encoded_dictionaries, record_batch_to_bytes, DictionaryTracker, EncodedBody,
+    EncodedData, WriteOptions,
+};
+use crate::error::{ArrowError, Result};
+pub use crate::io::Spawner;
+use crate::record_batch::RecordBatch;
+
+/// Options to encode and compress the columns of record batches in parallel,
+/// used by [`super::FileWriter::with_parallel`] and [`super::StreamWriter::with_parallel`].
+#[derive(Clone)]
+pub struct ParallelOptions {
+    /// The maximum number of columns encoded and compressed concurrently.
+    /// Batches are encoded in the calling thread when this is one.
+    pub num_tasks: usize,
+    /// The function used to run each task, e.g. `Arc::new(|task| rayon::spawn(task))`.
+    pub spawn: Spawner,
+}
+
+impl std::fmt::Debug for ParallelOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParallelOptions")
+            .field("num_tasks", &self.num_tasks)
+            .finish()
+    }
+}
+
+// threads cannot be spawned on wasm32, where a `Spawner` must be provided
+#[cfg(not(target_arch = "wasm32"))]
+impl Default for ParallelOptions {
+    /// One task per available core, each on its own thread.
+    fn default() -> Self {
+        Self {
+            num_tasks: std::thread::available_parallelism()
+                .map(|x| x.get())
+                .unwrap_or(1),
+            spawn: Arc::new(|task| {
+                std::thread::spawn(task);
+            }),
+        }
+    }
+}
+
+/// Encodes the columns of `batch` in parallel into the same messages as [`super::common::encoded_batch`].
+/// Dictionaries are encoded in the calling thread.
+pub(crate) fn encoded_batch_parallel(
+    batch: &RecordBatch,
+    dictionary_tracker: &mut DictionaryTracker,
+    options: &WriteOptions,
+    parallel: &ParallelOptions,
+) -> Result<(Vec<EncodedData>, EncodedData)> {
+    let encoded_dictionaries = encoded_dictionaries(batch, dictionary_tracker, options)?;
+
+    let columns = batch.columns();
+    let num_tasks = parallel.num_tasks.min(columns.len());
+    let body = if num_tasks <= 1 {
+        encode_body(columns, options)
+    } else {
+        let (jobs, receivers): (Vec<_>, Vec<_>) = columns
+            .iter()
+            .map(|array| {
+                let (sender, receiver) = channel();
+                ((array.clone(), sender), receiver)
+            })
+            .unzip();
+
+        let jobs = Arc::new(Mutex::new(jobs.into_iter()));
+        for _ in 0..num_tasks {
+            let jobs = jobs.clone();
+            let options = *options;
+            (parallel.spawn)(Box::new(move || loop {
+                let job = jobs.lock().unwrap().next();
+                match job {
+                    Some((array, sender)) => {
+                        let _ = sender.send(encode_body(std::slice::from_ref(&array), &options));
+                    }
+                    None => break,
+                }
+            }));
+        }
+        // the senders of jobs that are never run must be dropped for their receivers to error
+        drop(jobs);
+
+        // the bodies are concatenated in order of the columns, so that the message
+        // is the same as if the batch was encoded sequentially
+        let mut body = EncodedBody::default();
+        for (column, receiver) in receivers.into_iter().enumerate() {
+            let encoded = receiver.recv().map_err(|_| {
+                ArrowError::ExternalFormat(format!(
+                    "The task encoding column {} stopped before finishing",
+                    column
+                ))
+            })?;
+            body.extend(encoded);
+        }
+        body
+    };
+    let encoded_message = record_batch_to_bytes(batch.num_rows(), body, options);
+
+    Ok((encoded_dictionaries, encoded_message))
+}
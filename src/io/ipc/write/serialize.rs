@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use arrow_format::ipc::{Message, Schema};
 
 use crate::{
@@ -311,7 +313,7 @@ pub fn write_union(
         compression,
     );
 
-    if let Some(offsets) = array.offsets() {
+    let fields = if let Some(offsets) = array.offsets() {
         write_buffer(
             offsets,
             buffers,
@@ -320,8 +322,16 @@ pub fn write_union(
             is_little_endian,
             compression,
         );
-    }
-    array.fields().iter().for_each(|array| {
+        array.fields().clone()
+    } else {
+        // the fields of sparse unions have the length of the union
+        array
+            .child_slices()
+            .into_iter()
+            .map(|x| x.into())
+            .collect::<Vec<Arc<dyn Array>>>()
+    };
+    fields.iter().for_each(|array| {
         write(
             array.as_ref(),
             buffers,
@@ -470,7 +480,7 @@ pub fn write_dictionary(
     write_keys: bool,
 ) -> usize {
     match array.data_type() {
-        DataType::Dictionary(key_type, _) => {
+        DataType::Dictionary(key_type, _, _) => {
             match_integer_type!(key_type, |$T| {
                 _write_dictionary::<$T>(
                     array,
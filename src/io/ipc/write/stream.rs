@@ -24,10 +24,12 @@ use std::io::Write;
 
 use super::common::{encoded_batch, DictionaryTracker, EncodedData, WriteOptions};
 use super::common_sync::{write_continuation, write_message};
+use super::parallel::{encoded_batch_parallel, ParallelOptions};
 use super::schema_to_bytes;
 
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
+use crate::io::{check_schema, ChunkSink};
 use crate::record_batch::RecordBatch;
 
 /// Arrow stream writer
@@ -41,10 +43,14 @@ pub struct StreamWriter<W: Write> {
     writer: W,
     /// IPC write options
     write_options: WriteOptions,
+    /// The schema of the stream
+    schema: Schema,
     /// Whether the stream has been finished
     finished: bool,
     /// Keeps track of dictionaries that have been written
     dictionary_tracker: DictionaryTracker,
+    /// Whether and how to encode the columns of batches in parallel
+    parallel: Option<ParallelOptions>,
 }
 
 impl<W: Write> StreamWriter<W> {
@@ -59,11 +65,20 @@ impl<W: Write> StreamWriter<W> {
         Ok(Self {
             writer,
             write_options,
+            schema: schema.clone(),
             finished: false,
             dictionary_tracker: DictionaryTracker::new(false),
+            parallel: None,
         })
     }
 
+    /// Encodes and compresses the columns of each batch in parallel according to `parallel`.
+    /// Batches are still written in order, and the stream is the same as without this option.
+    pub fn with_parallel(mut self, parallel: ParallelOptions) -> Self {
+        self.parallel = Some(parallel);
+        self
+    }
+
     /// Write a record batch to the stream
     pub fn write(&mut self, batch: &RecordBatch) -> Result<()> {
         if self.finished {
@@ -73,8 +88,15 @@ impl<W: Write> StreamWriter<W> {
             )));
         }
 
-        let (encoded_dictionaries, encoded_message) =
-            encoded_batch(batch, &mut self.dictionary_tracker, &self.write_options)?;
+        let (encoded_dictionaries, encoded_message) = match &self.parallel {
+            Some(parallel) => encoded_batch_parallel(
+                batch,
+                &mut self.dictionary_tracker,
+                &self.write_options,
+                parallel,
+            )?,
+            None => encoded_batch(batch, &mut self.dictionary_tracker, &self.write_options)?,
+        };
 
         for encoded_dictionary in encoded_dictionaries {
             write_message(&mut self.writer, encoded_dictionary)?;
@@ -98,3 +120,18 @@ impl<W: Write> StreamWriter<W> {
         self.writer
     }
 }
+
+impl<W: Write> ChunkSink for StreamWriter<W> {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        check_schema(&self.schema, batch)?;
+        self.write(batch)
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.finish()
+    }
+}
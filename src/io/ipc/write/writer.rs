@@ -30,11 +30,13 @@ use super::{
     super::convert,
     common::{encoded_batch, DictionaryTracker, EncodedData, WriteOptions},
     common_sync::{write_continuation, write_message},
+    parallel::{encoded_batch_parallel, ParallelOptions},
     schema_to_bytes,
 };
 
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
+use crate::io::{check_schema, ChunkSink};
 use crate::record_batch::RecordBatch;
 
 /// Arrow file writer
@@ -55,6 +57,8 @@ pub struct FileWriter<W: Write> {
     finished: bool,
     /// Keeps track of dictionaries that have been written
     dictionary_tracker: DictionaryTracker,
+    /// Whether and how to encode the columns of batches in parallel
+    parallel: Option<ParallelOptions>,
 }
 
 impl<W: Write> FileWriter<W> {
@@ -79,9 +83,17 @@ impl<W: Write> FileWriter<W> {
             record_blocks: vec![],
             finished: false,
             dictionary_tracker: DictionaryTracker::new(true),
+            parallel: None,
         })
     }
 
+    /// Encodes and compresses the columns of each batch in parallel according to `parallel`.
+    /// Batches are still written in order, and the file is the same as without this option.
+    pub fn with_parallel(mut self, parallel: ParallelOptions) -> Self {
+        self.parallel = Some(parallel);
+        self
+    }
+
     pub fn into_inner(self) -> W {
         self.writer
     }
@@ -95,8 +107,15 @@ impl<W: Write> FileWriter<W> {
             )));
         }
 
-        let (encoded_dictionaries, encoded_message) =
-            encoded_batch(batch, &mut self.dictionary_tracker, &self.options)?;
+        let (encoded_dictionaries, encoded_message) = match &self.parallel {
+            Some(parallel) => encoded_batch_parallel(
+                batch,
+                &mut self.dictionary_tracker,
+                &self.options,
+                parallel,
+            )?,
+            None => encoded_batch(batch, &mut self.dictionary_tracker, &self.options)?,
+        };
 
         for encoded_dictionary in encoded_dictionaries {
             let (meta, data) = write_message(&mut self.writer, encoded_dictionary)?;
@@ -148,3 +167,18 @@ impl<W: Write> FileWriter<W> {
         Ok(())
     }
 }
+
+impl<W: Write> ChunkSink for FileWriter<W> {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        check_schema(&self.schema, batch)?;
+        self.write(batch)
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.finish()
+    }
+}
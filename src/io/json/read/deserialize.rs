@@ -16,7 +16,10 @@
 // under the License.
 
 use std::hash::Hasher;
-use std::{collections::hash_map::DefaultHasher, sync::Arc};
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap as StdHashMap},
+    sync::Arc,
+};
 
 use hash_hasher::HashedMap;
 use indexmap::map::IndexMap as HashMap;
@@ -28,10 +31,50 @@ use crate::{
     array::*,
     bitmap::MutableBitmap,
     buffer::MutableBuffer,
-    datatypes::{DataType, IntervalUnit},
+    datatypes::{DataType, Field, IntervalUnit},
     types::NativeType,
 };
 
+/// A function that converts the JSON value of a field before it is deserialized into the field's
+/// [`DataType`], e.g. to parse stringified JSON or custom date encodings.
+/// It is also called on missing and null values, as [`Value::Null`].
+pub type DeserializeHook = Arc<dyn Fn(&Value) -> Value + Send + Sync>;
+
+/// A set of [`DeserializeHook`], one per field path.
+/// The path of a field is the list of names of the fields from the root of the schema to it,
+/// including the child fields of lists (e.g. `["a", "item"]` for the items of the list `a`).
+#[derive(Clone, Default)]
+pub struct DeserializeHooks {
+    hooks: StdHashMap<Vec<String>, DeserializeHook>,
+}
+
+impl std::fmt::Debug for DeserializeHooks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.hooks.keys()).finish()
+    }
+}
+
+impl DeserializeHooks {
+    /// Returns an empty [`DeserializeHooks`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `hook` for the field at `path`, replacing any previous hook of that field.
+    pub fn insert<F>(&mut self, path: &[&str], hook: F)
+    where
+        F: Fn(&Value) -> Value + Send + Sync + 'static,
+    {
+        let path = path.iter().map(|x| x.to_string()).collect();
+        self.hooks.insert(path, Arc::new(hook));
+    }
+
+    /// Whether there are no hooks.
+    pub fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+}
+
 /// A function that converts a &Value into an optional tuple of a byte slice and a Value.
 /// This is used to create a dictionary, where the hashing depends on the DataType of the child object.
 type Extract = Box<dyn Fn(&Value) -> Option<(u64, &Value)>>;
@@ -125,8 +168,13 @@ fn read_utf8<O: Offset>(rows: &[&Value]) -> Utf8Array<O> {
     Utf8Array::<O>::from_trusted_len_iter(iter)
 }
 
-fn read_list<O: Offset>(rows: &[&Value], data_type: DataType) -> ListArray<O> {
-    let child = ListArray::<O>::get_child_type(&data_type);
+fn read_list<O: Offset>(
+    rows: &[&Value],
+    data_type: DataType,
+    hooks: &DeserializeHooks,
+    path: &mut Vec<String>,
+) -> ListArray<O> {
+    let child = ListArray::<O>::get_child_field(&data_type);
 
     let mut validity = MutableBitmap::with_capacity(rows.len());
     let mut inner = Vec::<&Value>::with_capacity(rows.len());
@@ -150,22 +198,22 @@ fn read_list<O: Offset>(rows: &[&Value], data_type: DataType) -> ListArray<O> {
         }
     });
 
-    let values = read(&inner, child.clone());
+    let values = read_field(&inner, child, hooks, path);
 
     ListArray::<O>::from_data(data_type, offsets.into(), values, validity.into())
 }
 
-fn read_struct(rows: &[&Value], data_type: DataType) -> StructArray {
+fn read_struct(
+    rows: &[&Value],
+    data_type: DataType,
+    hooks: &DeserializeHooks,
+    path: &mut Vec<String>,
+) -> StructArray {
     let fields = StructArray::get_fields(&data_type);
 
     let mut values = fields
         .iter()
-        .map(|f| {
-            (
-                f.name(),
-                (f.data_type(), Vec::<&Value>::with_capacity(rows.len())),
-            )
-        })
+        .map(|f| (f.name(), (f, Vec::<&Value>::with_capacity(rows.len()))))
         .collect::<HashMap<_, _>>();
 
     rows.iter().for_each(|row| {
@@ -185,13 +233,18 @@ fn read_struct(rows: &[&Value], data_type: DataType) -> StructArray {
 
     let values = values
         .into_iter()
-        .map(|(_, (data_type, values))| read(&values, data_type.clone()))
+        .map(|(_, (field, values))| read_field(&values, field, hooks, path))
         .collect::<Vec<_>>();
 
     StructArray::from_data(data_type, values, None)
 }
 
-fn read_dictionary<K: DictionaryKey>(rows: &[&Value], data_type: DataType) -> DictionaryArray<K> {
+fn read_dictionary<K: DictionaryKey>(
+    rows: &[&Value],
+    data_type: DataType,
+    hooks: &DeserializeHooks,
+    path: &mut Vec<String>,
+) -> DictionaryArray<K> {
     let child = DictionaryArray::<K>::get_child(&data_type);
 
     let mut map = HashedMap::<u64, K>::default();
@@ -218,11 +271,37 @@ fn read_dictionary<K: DictionaryKey>(rows: &[&Value], data_type: DataType) -> Di
         .collect::<PrimitiveArray<K>>()
         .to(K::DATA_TYPE);
 
-    let values = read(&inner, child.clone());
+    let values = read(&inner, child.clone(), hooks, path);
     DictionaryArray::<K>::from_data(keys, values)
+        .with_ordered(DictionaryArray::<K>::get_is_ordered(&data_type))
 }
 
-pub fn read(rows: &[&Value], data_type: DataType) -> Arc<dyn Array> {
+/// Deserializes `rows` of the child `field`, applying its hook, if any
+fn read_field(
+    rows: &[&Value],
+    field: &Field,
+    hooks: &DeserializeHooks,
+    path: &mut Vec<String>,
+) -> Arc<dyn Array> {
+    path.push(field.name().clone());
+    let array = match hooks.hooks.get(path) {
+        Some(hook) => {
+            let values = rows.iter().map(|row| hook(row)).collect::<Vec<_>>();
+            let rows = values.iter().collect::<Vec<_>>();
+            read(&rows, field.data_type().clone(), hooks, path)
+        }
+        None => read(rows, field.data_type().clone(), hooks, path),
+    };
+    path.pop();
+    array
+}
+
+pub fn read(
+    rows: &[&Value],
+    data_type: DataType,
+    hooks: &DeserializeHooks,
+    path: &mut Vec<String>,
+) -> Arc<dyn Array> {
     match &data_type {
         DataType::Null => Arc::new(NullArray::from_data(data_type, rows.len())),
         DataType::Boolean => Arc::new(read_boolean(rows)),
@@ -249,14 +328,14 @@ pub fn read(rows: &[&Value], data_type: DataType) -> Arc<dyn Array> {
         DataType::Float64 => Arc::new(read_float::<f64>(rows, data_type)),
         DataType::Utf8 => Arc::new(read_utf8::<i32>(rows)),
         DataType::LargeUtf8 => Arc::new(read_utf8::<i64>(rows)),
-        DataType::List(_) => Arc::new(read_list::<i32>(rows, data_type)),
-        DataType::LargeList(_) => Arc::new(read_list::<i64>(rows, data_type)),
+        DataType::List(_) => Arc::new(read_list::<i32>(rows, data_type, hooks, path)),
+        DataType::LargeList(_) => Arc::new(read_list::<i64>(rows, data_type, hooks, path)),
         DataType::Binary => Arc::new(read_binary::<i32>(rows)),
         DataType::LargeBinary => Arc::new(read_binary::<i64>(rows)),
-        DataType::Struct(_) => Arc::new(read_struct(rows, data_type)),
-        DataType::Dictionary(key_type, _) => {
+        DataType::Struct(_) => Arc::new(read_struct(rows, data_type, hooks, path)),
+        DataType::Dictionary(key_type, _, _) => {
             match_integer_type!(key_type, |$T| {
-                Arc::new(read_dictionary::<$T>(rows, data_type))
+                Arc::new(read_dictionary::<$T>(rows, data_type, hooks, path))
             })
         }
         _ => todo!(),
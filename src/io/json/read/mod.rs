@@ -20,5 +20,6 @@ mod infer_schema;
 mod reader;
 mod util;
 
+pub use deserialize::{DeserializeHook, DeserializeHooks};
 pub use infer_schema::*;
 pub use reader::*;
@@ -25,7 +25,11 @@ use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::record_batch::RecordBatch;
 
-use super::{deserialize::read, infer_json_schema_from_seekable, util::ValueIter};
+use super::{
+    deserialize::{read, DeserializeHooks},
+    infer_json_schema_from_seekable,
+    util::ValueIter,
+};
 
 #[derive(Debug)]
 struct Decoder {
@@ -35,6 +39,8 @@ struct Decoder {
     projection: Option<Vec<String>>,
     /// Batch size (number of records to load each time)
     batch_size: usize,
+    /// Hooks applied to the values of fields before they are deserialized
+    hooks: DeserializeHooks,
 }
 
 impl Decoder {
@@ -64,6 +70,7 @@ impl Decoder {
             schema,
             projection,
             batch_size,
+            hooks: DeserializeHooks::default(),
         }
     }
 
@@ -111,7 +118,7 @@ impl Decoder {
         };
 
         let data_type = DataType::Struct(projected_fields.clone());
-        let array = read(&rows, data_type);
+        let array = read(&rows, data_type, &self.hooks, &mut vec![]);
         let array = array.as_any().downcast_ref::<StructArray>().unwrap();
         let arrays = array.values().to_vec();
 
@@ -185,6 +192,37 @@ impl<R: Read> Reader<R> {
         }
     }
 
+    /// Sets the [`DeserializeHooks`] applied to the values of fields before they are
+    /// deserialized, replacing any previous hooks.
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use std::io::Cursor;
+    /// use arrow2::array::{Array, Int32Array};
+    /// use arrow2::datatypes::{DataType, Field, Schema};
+    /// use arrow2::io::json::{DeserializeHooks, Reader};
+    /// use serde_json::Value;
+    ///
+    /// let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+    /// // values of "a" are numbers encoded as strings
+    /// let mut hooks = DeserializeHooks::new();
+    /// hooks.insert(&["a"], |value: &Value| match value {
+    ///     Value::String(v) => v.parse::<i32>().map(Value::from).unwrap_or(Value::Null),
+    ///     _ => Value::Null,
+    /// });
+    ///
+    /// let data = r#"{"a": "1"}
+    /// {"a": null}"#;
+    /// let mut reader = Reader::new(Cursor::new(data), schema, 1024, None).with_hooks(hooks);
+    /// let batch = reader.next().unwrap().unwrap();
+    /// let expected = Int32Array::from([Some(1), None]);
+    /// assert_eq!(batch.column(0).as_ref(), &expected as &dyn Array);
+    /// ```
+    pub fn with_hooks(mut self, hooks: DeserializeHooks) -> Self {
+        self.decoder.hooks = hooks;
+        self
+    }
+
     /// Returns the schema of the reader, useful for getting the schema without reading
     /// record batches
     pub fn schema(&self) -> &Arc<Schema> {
@@ -217,6 +255,8 @@ pub struct ReaderBuilder {
     batch_size: usize,
     /// Optional projection for which columns to load (zero-based column indices)
     projection: Option<Vec<String>>,
+    /// Hooks applied to the values of fields before they are deserialized
+    hooks: DeserializeHooks,
 }
 
 impl Default for ReaderBuilder {
@@ -226,6 +266,7 @@ impl Default for ReaderBuilder {
             max_records: None,
             batch_size: 1024,
             projection: None,
+            hooks: DeserializeHooks::default(),
         }
     }
 }
@@ -262,6 +303,12 @@ impl ReaderBuilder {
         self
     }
 
+    /// Set the [`DeserializeHooks`] applied to the values of fields before they are deserialized
+    pub fn with_hooks(mut self, hooks: DeserializeHooks) -> Self {
+        self.hooks = hooks;
+        self
+    }
+
     /// Create a new `Reader` from the `ReaderBuilder`
     pub fn build<R>(self, source: R) -> Result<Reader<R>>
     where
@@ -278,11 +325,9 @@ impl ReaderBuilder {
             )?),
         };
 
-        Ok(Reader::from_buf_reader(
-            buf_reader,
-            schema,
-            self.batch_size,
-            self.projection,
-        ))
+        Ok(
+            Reader::from_buf_reader(buf_reader, schema, self.batch_size, self.projection)
+                .with_hooks(self.hooks),
+        )
     }
 }
@@ -106,7 +106,9 @@ use std::{fmt::Debug, io::Write};
 
 use serde_json::Value;
 
+use crate::datatypes::Schema;
 use crate::error::Result;
+use crate::io::{check_schema, ChunkSink};
 use crate::record_batch::RecordBatch;
 
 use super::write_record_batches;
@@ -209,6 +211,9 @@ where
 
     /// Determines how the byte stream is formatted
     format: F,
+
+    /// The schema of the written batches (empty until a batch is written)
+    schema: Schema,
 }
 
 impl<W, F> Writer<W, F>
@@ -223,6 +228,7 @@ where
             started: false,
             finished: false,
             format: F::default(),
+            schema: Schema::empty(),
         }
     }
 
@@ -242,6 +248,11 @@ where
 
     /// Convert the [`RecordBatch`] into JSON rows, and write them to the output
     pub fn write_batches(&mut self, batches: &[RecordBatch]) -> Result<()> {
+        if let Some(batch) = batches.first() {
+            if self.schema.fields().is_empty() {
+                self.schema = batch.schema().as_ref().clone();
+            }
+        }
         for row in write_record_batches(batches) {
             self.write_row(&Value::Object(row))?;
         }
@@ -264,3 +275,25 @@ where
         self.writer
     }
 }
+
+impl<W, F> ChunkSink for Writer<W, F>
+where
+    W: Write,
+    F: JsonFormat,
+{
+    /// The schema of the first written batch, since JSON is written without a schema.
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        if !self.schema.fields().is_empty() {
+            check_schema(&self.schema, batch)?;
+        }
+        self.write_batches(std::slice::from_ref(batch))
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.finish()
+    }
+}
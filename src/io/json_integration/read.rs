@@ -270,7 +270,10 @@ fn to_dictionary<K: DictionaryKey>(
         dictionaries,
     )?;
 
-    Ok(Arc::new(DictionaryArray::<K>::from_data(keys, values)))
+    let is_ordered = DictionaryArray::<K>::get_is_ordered(&data_type);
+    Ok(Arc::new(
+        DictionaryArray::<K>::from_data(keys, values).with_ordered(is_ordered),
+    ))
 }
 
 /// Construct an [`Array`] from the JSON integration format
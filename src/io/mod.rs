@@ -1,5 +1,12 @@
 //! Contains modules to interface with other formats such as [`csv`],
 //! [`parquet`], [`json`], [`ipc`], [`mod@print`] and [`avro`].
+//!
+//! Writers of these formats implement [`ChunkSink`], so that generic code can write
+//! [`RecordBatch`]es without knowing the format it writes to.
+use crate::datatypes::Schema;
+use crate::error::Result;
+use crate::record_batch::RecordBatch;
+
 #[cfg(any(
     feature = "io_csv_read",
     feature = "io_csv_read_async",
@@ -42,3 +49,54 @@ pub mod avro;
 #[cfg(feature = "io_print")]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_print")))]
 pub mod print;
+
+#[cfg(any(feature = "io_ipc", feature = "io_parquet", feature = "io_avro"))]
+mod hooks;
+#[cfg(any(feature = "io_ipc", feature = "io_parquet", feature = "io_avro"))]
+pub(crate) use hooks::HooksState;
+#[cfg(any(feature = "io_ipc", feature = "io_parquet", feature = "io_avro"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(any(feature = "io_ipc", feature = "io_parquet", feature = "io_avro")))
+)]
+pub use hooks::{ReadHooks, ReadProgress};
+
+/// A function that runs a task to completion, e.g. in a new thread or in a thread pool.
+/// Used by writers that encode and compress in parallel.
+pub type Spawner = std::sync::Arc<dyn Fn(Box<dyn FnOnce() + Send>) + Send + Sync>;
+
+/// A sink of [`RecordBatch`]es, implemented by writers of every supported format.
+///
+/// The typical usage is to call [`ChunkSink::write`] for each batch and
+/// [`ChunkSink::close`] once all batches have been written.
+pub trait ChunkSink {
+    /// The [`Schema`] of the batches written to this sink.
+    fn schema(&self) -> &Schema;
+
+    /// Writes a [`RecordBatch`] to this sink.
+    /// # Errors
+    /// Errors iff the batch cannot be written, e.g. because the sink was already closed,
+    /// because the schema of the batch differs from [`ChunkSink::schema`] or because of
+    /// an IO error.
+    fn write(&mut self, batch: &RecordBatch) -> Result<()>;
+
+    /// Finishes writing to this sink, e.g. by writing a footer.
+    /// No batches can be written after the sink is closed.
+    fn close(&mut self) -> Result<()>;
+}
+
+/// Errors iff the schema of `batch` differs from `schema`, the schema of a [`ChunkSink`].
+#[cfg(any(
+    feature = "io_csv_write",
+    feature = "io_json",
+    feature = "io_ipc",
+    feature = "io_parquet"
+))]
+pub(crate) fn check_schema(schema: &Schema, batch: &RecordBatch) -> Result<()> {
+    if batch.schema().as_ref() != schema {
+        return Err(crate::error::ArrowError::InvalidArgumentError(
+            "Cannot write a batch with a schema different from the sink's schema".to_string(),
+        ));
+    }
+    Ok(())
+}
@@ -9,10 +9,20 @@ const ARROW_SCHEMA_META_KEY: &str = "ARROW:schema";
 impl From<parquet2::error::ParquetError> for ArrowError {
     fn from(error: parquet2::error::ParquetError) -> Self {
         match error {
-            parquet2::error::ParquetError::FeatureNotActive(_, _) => {
-                let message = "Failed to read a compressed parquet file. \
-                    Use the cargo feature \"io_parquet_compression\" to read compressed parquet files."
-                    .to_string();
+            parquet2::error::ParquetError::FeatureNotActive(feature, _) => {
+                let feature = match feature {
+                    parquet2::error::Feature::Snappy => "io_parquet_compression_snappy",
+                    parquet2::error::Feature::Brotli => "io_parquet_compression_brotli",
+                    parquet2::error::Feature::Gzip => "io_parquet_compression_gzip",
+                    parquet2::error::Feature::Lz4 => "io_parquet_compression_lz4",
+                    parquet2::error::Feature::Zstd => "io_parquet_compression_zstd",
+                    _ => "io_parquet_compression",
+                };
+                let message = format!(
+                    "Failed to read or write a compressed parquet file. \
+                    Use the cargo feature \"{}\" to read and write parquet files with this compression.",
+                    feature
+                );
                 ArrowError::ExternalFormat(message)
             }
             _ => ArrowError::ExternalFormat(error.to_string()),
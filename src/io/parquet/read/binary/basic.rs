@@ -1,5 +1,7 @@
+use std::convert::TryFrom;
+
 use parquet2::{
-    encoding::{delta_length_byte_array, hybrid_rle, Encoding},
+    encoding::{hybrid_rle, Encoding},
     metadata::ColumnDescriptor,
     page::{BinaryPageDict, DataPage},
 };
@@ -8,7 +10,7 @@ use crate::{
     array::Offset,
     bitmap::{utils::BitmapIter, MutableBitmap},
     buffer::MutableBuffer,
-    error::Result,
+    error::{ArrowError, Result},
 };
 
 use super::super::utils;
@@ -23,20 +25,14 @@ fn read_dict_buffer<O: Offset>(
     offsets: &mut MutableBuffer<O>,
     values: &mut MutableBuffer<u8>,
     validity: &mut MutableBitmap,
-) {
+) -> Result<()> {
     let length = (offsets.len() - 1) + additional;
-    let dict_values = dict.values();
-    let dict_offsets = dict.offsets();
     let mut last_offset = *offsets.as_mut_slice().last().unwrap();
 
-    // SPEC: Data page format: the bit width used to encode the entry ids stored as 1 byte (max bit width = 32),
-    // SPEC: followed by the values encoded using RLE/Bit packed described above (with the given bit width).
-    let bit_width = indices_buffer[0];
-    let indices_buffer = &indices_buffer[1..];
-
-    let mut indices = hybrid_rle::HybridRleDecoder::new(indices_buffer, bit_width as u32, length);
+    // the indices of the non-null values, at most `additional`
+    let mut indices = utils::dict_indices_decoder(indices_buffer, additional)?;
 
-    let validity_iterator = hybrid_rle::Decoder::new(validity_buffer, 1);
+    let validity_iterator = utils::validity_runs(validity_buffer, additional)?;
 
     for run in validity_iterator {
         match run {
@@ -45,12 +41,10 @@ fn read_dict_buffer<O: Offset>(
                 let len = std::cmp::min(packed.len() * 8, remaining);
                 for is_valid in BitmapIter::new(packed, 0, len) {
                     if is_valid {
-                        let index = indices.next().unwrap() as usize;
-                        let dict_offset_i = dict_offsets[index] as usize;
-                        let dict_offset_ip1 = dict_offsets[index + 1] as usize;
-                        let length = dict_offset_ip1 - dict_offset_i;
-                        last_offset += O::from_usize(length).unwrap();
-                        values.extend_from_slice(&dict_values[dict_offset_i..dict_offset_ip1]);
+                        let index = indices.next().ok_or_else(utils::missing_values)?;
+                        let value = dict_value(dict, index)?;
+                        last_offset = next_offset(last_offset, value.len())?;
+                        values.extend_from_slice(value);
                     };
                     offsets.push(last_offset);
                 }
@@ -60,21 +54,40 @@ fn read_dict_buffer<O: Offset>(
                 let is_set = value[0] == 1;
                 validity.extend_constant(additional, is_set);
                 if is_set {
-                    (0..additional).for_each(|_| {
-                        let index = indices.next().unwrap() as usize;
-                        let dict_offset_i = dict_offsets[index] as usize;
-                        let dict_offset_ip1 = dict_offsets[index + 1] as usize;
-                        let length = dict_offset_ip1 - dict_offset_i;
-                        last_offset += O::from_usize(length).unwrap();
+                    for _ in 0..additional {
+                        let index = indices.next().ok_or_else(utils::missing_values)?;
+                        let value = dict_value(dict, index)?;
+                        last_offset = next_offset(last_offset, value.len())?;
                         offsets.push(last_offset);
-                        values.extend_from_slice(&dict_values[dict_offset_i..dict_offset_ip1]);
-                    })
+                        values.extend_from_slice(value);
+                    }
                 } else {
                     offsets.extend_constant(additional, last_offset)
                 }
             }
         }
     }
+    Ok(())
+}
+
+/// Returns the value of the dictionary at `index`.
+#[inline]
+fn dict_value(dict: &BinaryPageDict, index: u32) -> Result<&[u8]> {
+    let dict_offsets = dict.offsets();
+    let index = utils::check_dict_index(index, dict_offsets.len().saturating_sub(1))?;
+    let start = dict_offsets[index] as usize;
+    let end = dict_offsets[index + 1] as usize;
+    dict.values().get(start..end).ok_or_else(|| {
+        ArrowError::ExternalFormat("The offsets of the dictionary page are invalid".to_string())
+    })
+}
+
+/// Returns the offset following `last_offset` for a value of `length` bytes.
+#[inline]
+pub(super) fn next_offset<O: Offset>(last_offset: O, length: usize) -> Result<O> {
+    O::from_usize(length)
+        .and_then(|length| last_offset.checked_add(&length))
+        .ok_or(ArrowError::Overflow)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -85,28 +98,19 @@ fn read_dict_required<O: Offset>(
     offsets: &mut MutableBuffer<O>,
     values: &mut MutableBuffer<u8>,
     validity: &mut MutableBitmap,
-) {
-    let dict_values = dict.values();
-    let dict_offsets = dict.offsets();
+) -> Result<()> {
     let mut last_offset = *offsets.as_mut_slice().last().unwrap();
 
-    // SPEC: Data page format: the bit width used to encode the entry ids stored as 1 byte (max bit width = 32),
-    // SPEC: followed by the values encoded using RLE/Bit packed described above (with the given bit width).
-    let bit_width = indices_buffer[0];
-    let indices_buffer = &indices_buffer[1..];
-
-    let indices = hybrid_rle::HybridRleDecoder::new(indices_buffer, bit_width as u32, additional);
+    let indices = utils::dict_indices_decoder(indices_buffer, additional)?;
 
     for index in indices {
-        let index = index as usize;
-        let dict_offset_i = dict_offsets[index] as usize;
-        let dict_offset_ip1 = dict_offsets[index + 1] as usize;
-        let length = dict_offset_ip1 - dict_offset_i;
-        last_offset += O::from_usize(length).unwrap();
+        let value = dict_value(dict, index)?;
+        last_offset = next_offset(last_offset, value.len())?;
         offsets.push(last_offset);
-        values.extend_from_slice(&dict_values[dict_offset_i..dict_offset_ip1]);
+        values.extend_from_slice(value);
     }
     validity.extend_constant(additional, true);
+    Ok(())
 }
 
 fn read_delta_optional<O: Offset>(
@@ -116,14 +120,16 @@ fn read_delta_optional<O: Offset>(
     offsets: &mut MutableBuffer<O>,
     values: &mut MutableBuffer<u8>,
     validity: &mut MutableBitmap,
-) {
+) -> Result<()> {
     let length = (offsets.len() - 1) + additional;
     let mut last_offset = *offsets.as_mut_slice().last().unwrap();
+    let start = last_offset.to_usize();
 
-    // values_buffer: first 4 bytes are len, remaining is values
-    let mut values_iterator = delta_length_byte_array::Decoder::new(values_buffer);
+    // values_buffer: the delta-bitpacked lengths, followed by the concatenated values
+    let (lengths, consumed) = utils::decode_delta_bitpacked(values_buffer, additional)?;
+    let mut values_iterator = lengths.into_iter();
 
-    let validity_iterator = hybrid_rle::Decoder::new(validity_buffer, 1);
+    let validity_iterator = utils::validity_runs(validity_buffer, additional)?;
 
     // offsets:
     for run in validity_iterator {
@@ -134,8 +140,8 @@ fn read_delta_optional<O: Offset>(
                 let len = std::cmp::min(packed.len() * 8, remaining);
                 for is_valid in BitmapIter::new(packed, 0, len) {
                     if is_valid {
-                        let value = values_iterator.next().unwrap() as isize;
-                        last_offset += O::from_isize(value).unwrap();
+                        let value = values_iterator.next().ok_or_else(utils::missing_values)?;
+                        last_offset = next_offset(last_offset, delta_length(value)?)?;
                     }
                     offsets.push(last_offset);
                 }
@@ -145,11 +151,11 @@ fn read_delta_optional<O: Offset>(
                 let is_set = value[0] == 1;
                 validity.extend_constant(additional, is_set);
                 if is_set {
-                    (0..additional).for_each(|_| {
-                        let value = values_iterator.next().unwrap() as isize;
-                        last_offset += O::from_isize(value).unwrap();
+                    for _ in 0..additional {
+                        let value = values_iterator.next().ok_or_else(utils::missing_values)?;
+                        last_offset = next_offset(last_offset, delta_length(value)?)?;
                         offsets.push(last_offset);
-                    })
+                    }
                 } else {
                     offsets.extend_constant(additional, last_offset)
                 }
@@ -158,8 +164,21 @@ fn read_delta_optional<O: Offset>(
     }
 
     // values:
-    let new_values = values_iterator.into_values();
+    let new_values = values_buffer[consumed..]
+        .get(..last_offset.to_usize() - start)
+        .ok_or_else(utils::missing_values)?;
     values.extend_from_slice(new_values);
+    Ok(())
+}
+
+// converts a length of the delta-length encoding to `usize`
+fn delta_length(length: i64) -> Result<usize> {
+    usize::try_from(length).map_err(|_| {
+        ArrowError::ExternalFormat(format!(
+            "The length of a binary value is negative ({})",
+            length
+        ))
+    })
 }
 
 fn read_plain_optional<O: Offset>(
@@ -169,14 +188,14 @@ fn read_plain_optional<O: Offset>(
     offsets: &mut MutableBuffer<O>,
     values: &mut MutableBuffer<u8>,
     validity: &mut MutableBitmap,
-) {
+) -> Result<()> {
     let length = (offsets.len() - 1) + additional;
     let mut last_offset = *offsets.as_mut_slice().last().unwrap();
 
     // values_buffer: first 4 bytes are len, remaining is values
     let mut values_iterator = utils::BinaryIter::new(values_buffer);
 
-    let validity_iterator = hybrid_rle::Decoder::new(validity_buffer, 1);
+    let validity_iterator = utils::validity_runs(validity_buffer, additional)?;
 
     for run in validity_iterator {
         match run {
@@ -186,8 +205,8 @@ fn read_plain_optional<O: Offset>(
                 let len = std::cmp::min(packed.len() * 8, remaining);
                 for is_valid in BitmapIter::new(packed, 0, len) {
                     if is_valid {
-                        let value = values_iterator.next().unwrap();
-                        last_offset += O::from_usize(value.len()).unwrap();
+                        let value = values_iterator.next().ok_or_else(utils::missing_values)?;
+                        last_offset = next_offset(last_offset, value.len())?;
                         values.extend_from_slice(value);
                     }
                     offsets.push(last_offset);
@@ -198,18 +217,19 @@ fn read_plain_optional<O: Offset>(
                 let is_set = value[0] == 1;
                 validity.extend_constant(additional, is_set);
                 if is_set {
-                    (0..additional).for_each(|_| {
-                        let value = values_iterator.next().unwrap();
-                        last_offset += O::from_usize(value.len()).unwrap();
+                    for _ in 0..additional {
+                        let value = values_iterator.next().ok_or_else(utils::missing_values)?;
+                        last_offset = next_offset(last_offset, value.len())?;
                         offsets.push(last_offset);
                         values.extend_from_slice(value)
-                    })
+                    }
                 } else {
                     offsets.extend_constant(additional, last_offset)
                 }
             }
         }
     }
+    Ok(())
 }
 
 pub(super) fn read_plain_required<O: Offset>(
@@ -217,20 +237,20 @@ pub(super) fn read_plain_required<O: Offset>(
     additional: usize,
     offsets: &mut MutableBuffer<O>,
     values: &mut MutableBuffer<u8>,
-) {
+) -> Result<()> {
     let mut last_offset = *offsets.as_mut_slice().last().unwrap();
 
-    let values_iterator = utils::BinaryIter::new(buffer);
+    let mut values_iterator = utils::BinaryIter::new(buffer);
 
     // each value occupies 4 bytes + len declared in 4 bytes => reserve accordingly.
-    values.reserve(buffer.len() - 4 * additional);
-    let a = values.capacity();
-    for value in values_iterator {
-        last_offset += O::from_usize(value.len()).unwrap();
+    values.reserve(buffer.len().saturating_sub(4 * additional));
+    for _ in 0..additional {
+        let value = values_iterator.next().ok_or_else(utils::missing_values)?;
+        last_offset = next_offset(last_offset, value.len())?;
         values.extend_from_slice(value);
         offsets.push(last_offset);
     }
-    debug_assert_eq!(a, values.capacity());
+    Ok(())
 }
 
 pub(super) fn extend_from_page<O: Offset>(
@@ -245,7 +265,7 @@ pub(super) fn extend_from_page<O: Offset>(
     assert!(descriptor.max_def_level() <= 1);
     let is_optional = descriptor.max_def_level() == 1;
 
-    let (_, validity_buffer, values_buffer, version) = utils::split_buffer(page, descriptor);
+    let (_, validity_buffer, values_buffer, version) = utils::split_buffer(page, descriptor)?;
 
     match (&page.encoding(), page.dictionary_page(), is_optional) {
         (Encoding::PlainDictionary | Encoding::RleDictionary, Some(dict), true) => {
@@ -253,7 +273,7 @@ pub(super) fn extend_from_page<O: Offset>(
                 validity_buffer,
                 values_buffer,
                 additional,
-                dict.as_any().downcast_ref().unwrap(),
+                utils::downcast_dict(dict.as_ref())?,
                 offsets,
                 values,
                 validity,
@@ -263,7 +283,7 @@ pub(super) fn extend_from_page<O: Offset>(
             read_dict_required::<O>(
                 values_buffer,
                 additional,
-                dict.as_any().downcast_ref().unwrap(),
+                utils::downcast_dict(dict.as_ref())?,
                 offsets,
                 values,
                 validity,
@@ -288,15 +308,12 @@ pub(super) fn extend_from_page<O: Offset>(
         (Encoding::Plain, _, false) => {
             read_plain_required::<O>(page.buffer(), page.num_values(), offsets, values)
         }
-        _ => {
-            return Err(utils::not_implemented(
-                &page.encoding(),
-                is_optional,
-                page.dictionary_page().is_some(),
-                version,
-                "Binary",
-            ))
-        }
-    };
-    Ok(())
+        _ => Err(utils::not_implemented(
+            &page.encoding(),
+            is_optional,
+            page.dictionary_page().is_some(),
+            version,
+            "Binary",
+        )),
+    }
 }
@@ -26,27 +26,29 @@ fn read_dict_optional<K, O>(
     offsets: &mut MutableBuffer<O>,
     values: &mut MutableBuffer<u8>,
     validity: &mut MutableBitmap,
-) where
+) -> Result<()>
+where
     K: DictionaryKey,
     O: Offset,
 {
     let length = indices.len() + additional;
-    values.extend_from_slice(dict.values());
-    offsets.extend_from_trusted_len_iter(
-        dict.offsets()
-            .iter()
-            .map(|x| O::from_usize(*x as usize).unwrap()),
-    );
-
-    // SPEC: Data page format: the bit width used to encode the entry ids stored as 1 byte (max bit width = 32),
-    // SPEC: followed by the values encoded using RLE/Bit packed described above (with the given bit width).
-    let bit_width = indices_buffer[0];
-    let indices_buffer = &indices_buffer[1..];
+    // all pages of a column chunk share its dictionary
+    if offsets.is_empty() {
+        values.extend_from_slice(dict.values());
+        for offset in dict.offsets() {
+            offsets.push(O::from_usize(*offset as usize).ok_or(ArrowError::Overflow)?);
+        }
+    }
+    let dict_length = dict.offsets().len().saturating_sub(1);
 
-    let mut new_indices =
-        hybrid_rle::HybridRleDecoder::new(indices_buffer, bit_width as u32, additional);
+    let mut new_indices = other_utils::dict_indices_decoder(indices_buffer, additional)?;
+    let mut next_index = || {
+        let index = new_indices.next().ok_or_else(other_utils::missing_values)?;
+        let index = other_utils::check_dict_index(index, dict_length)?;
+        K::from_usize(index).ok_or(ArrowError::Overflow)
+    };
 
-    let validity_iterator = hybrid_rle::Decoder::new(validity_buffer, 1);
+    let validity_iterator = other_utils::validity_runs(validity_buffer, additional)?;
 
     for run in validity_iterator {
         match run {
@@ -55,7 +57,7 @@ fn read_dict_optional<K, O>(
                 let len = std::cmp::min(packed.len() * 8, remaining);
                 for is_valid in BitmapIter::new(packed, 0, len) {
                     let value = if is_valid {
-                        K::from_u32(new_indices.next().unwrap()).unwrap()
+                        next_index()?
                     } else {
                         K::default()
                     };
@@ -67,16 +69,16 @@ fn read_dict_optional<K, O>(
                 let is_set = value[0] == 1;
                 validity.extend_constant(additional, is_set);
                 if is_set {
-                    (0..additional).for_each(|_| {
-                        let index = K::from_u32(new_indices.next().unwrap()).unwrap();
-                        indices.push(index)
-                    })
+                    for _ in 0..additional {
+                        indices.push(next_index()?)
+                    }
                 } else {
-                    indices.extend_constant(additional, *indices.last().unwrap())
+                    indices.extend_constant(additional, K::default())
                 }
             }
         }
     }
+    Ok(())
 }
 
 fn extend_from_page<K, O>(
@@ -96,7 +98,7 @@ where
     assert_eq!(descriptor.max_rep_level(), 0);
     let is_optional = descriptor.max_def_level() == 1;
 
-    let (_, validity_buffer, values_buffer, version) = other_utils::split_buffer(page, descriptor);
+    let (_, validity_buffer, values_buffer, version) = other_utils::split_buffer(page, descriptor)?;
 
     match (&page.encoding(), page.dictionary_page(), is_optional) {
         (Encoding::PlainDictionary | Encoding::RleDictionary, Some(dict), true) => {
@@ -104,29 +106,27 @@ where
                 validity_buffer,
                 values_buffer,
                 additional,
-                dict.as_any().downcast_ref().unwrap(),
+                other_utils::downcast_dict(dict.as_ref())?,
                 indices,
                 offsets,
                 values,
                 validity,
             )
         }
-        _ => {
-            return Err(other_utils::not_implemented(
-                &page.encoding(),
-                is_optional,
-                page.dictionary_page().is_some(),
-                version,
-                "primitive",
-            ))
-        }
+        _ => Err(other_utils::not_implemented(
+            &page.encoding(),
+            is_optional,
+            page.dictionary_page().is_some(),
+            version,
+            "primitive",
+        )),
     }
-    Ok(())
 }
 
 pub fn iter_to_array<K, O, I, E>(
     mut iter: I,
     metadata: &ColumnChunkMetaData,
+    capacity: usize,
     data_type: DataType,
 ) -> Result<Box<dyn Array>>
 where
@@ -135,7 +135,6 @@ where
     K: DictionaryKey,
     I: FallibleStreamingIterator<Item = DataPage, Error = E>,
 {
-    let capacity = metadata.num_values() as usize;
     let mut indices = MutableBuffer::<K>::with_capacity(capacity);
     let mut values = MutableBuffer::<u8>::with_capacity(0);
     let mut offsets = MutableBuffer::<O>::with_capacity(1 + capacity);
@@ -156,12 +155,16 @@ where
         offsets.push(O::zero());
     };
     let keys = PrimitiveArray::from_data(K::DATA_TYPE, indices.into(), validity.into());
+    let is_ordered = DictionaryArray::<K>::get_is_ordered(&data_type);
     let data_type = DictionaryArray::<K>::get_child(&data_type).clone();
+    super::utils::check_utf8(&offsets, &values)?;
     let values = Arc::new(Utf8Array::from_data(
         data_type,
         offsets.into(),
         values.into(),
         None,
     ));
-    Ok(Box::new(DictionaryArray::<K>::from_data(keys, values)))
+    Ok(Box::new(
+        DictionaryArray::<K>::from_data(keys, values).with_ordered(is_ordered),
+    ))
 }
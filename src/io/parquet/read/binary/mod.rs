@@ -22,6 +22,7 @@ use super::nested_utils::Nested;
 pub fn iter_to_array<O, I, E>(
     mut iter: I,
     metadata: &ColumnChunkMetaData,
+    capacity: usize,
     data_type: DataType,
     nested: &mut Vec<Box<dyn Nested>>,
 ) -> Result<Box<dyn Array>>
@@ -30,7 +31,6 @@ where
     ArrowError: From<E>,
     I: FallibleStreamingIterator<Item = DataPage, Error = E>,
 {
-    let capacity = metadata.num_values() as usize;
     let mut values = MutableBuffer::<u8>::with_capacity(0);
     let mut offsets = MutableBuffer::<O>::with_capacity(1 + capacity);
     offsets.push(O::default());
@@ -61,7 +61,7 @@ where
             )?
         }
     }
-    Ok(utils::finish_array(data_type, offsets, values, validity))
+    utils::finish_array(data_type, offsets, values, validity)
 }
 
 pub async fn stream_to_array<O, I, E>(
@@ -93,5 +93,5 @@ where
         )?
     }
 
-    Ok(finish_array(data_type.clone(), offsets, values, validity))
+    finish_array(data_type.clone(), offsets, values, validity)
 }
@@ -1,15 +1,17 @@
 use parquet2::{
-    encoding::{hybrid_rle::HybridRleDecoder, Encoding},
-    metadata::ColumnDescriptor,
-    page::DataPage,
-    read::levels::get_bit_width,
+    encoding::Encoding, metadata::ColumnDescriptor, page::DataPage, read::levels::get_bit_width,
 };
 
 use super::super::nested_utils::*;
 use super::super::utils;
-use super::basic::read_plain_required;
+use super::basic::{next_offset, read_plain_required};
 
-use crate::{array::Offset, bitmap::MutableBitmap, buffer::MutableBuffer, error::Result};
+use crate::{
+    array::Offset,
+    bitmap::MutableBitmap,
+    buffer::MutableBuffer,
+    error::{ArrowError, Result},
+};
 
 fn read_values<'a, O, D, G>(
     def_levels: D,
@@ -18,22 +20,24 @@ fn read_values<'a, O, D, G>(
     offsets: &mut MutableBuffer<O>,
     values: &mut MutableBuffer<u8>,
     validity: &mut MutableBitmap,
-) where
+) -> Result<()>
+where
     O: Offset,
     D: Iterator<Item = u32>,
     G: Iterator<Item = &'a [u8]>,
 {
-    def_levels.for_each(|def| {
+    for def in def_levels {
         if def == max_def {
-            let v = new_values.next().unwrap();
+            let v = new_values.next().ok_or_else(utils::missing_values)?;
             values.extend_from_slice(v);
-            offsets.push(*offsets.last().unwrap() + O::from_usize(v.len()).unwrap());
+            offsets.push(next_offset(*offsets.last().unwrap(), v.len())?);
             validity.push(true);
         } else if def == max_def - 1 {
             offsets.push(*offsets.last().unwrap());
             validity.push(false);
         }
-    });
+    }
+    Ok(())
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -49,36 +53,37 @@ fn read<O: Offset>(
     offsets: &mut MutableBuffer<O>,
     values: &mut MutableBuffer<u8>,
     validity: &mut MutableBitmap,
-) {
+) -> Result<()> {
     let max_rep_level = rep_level_encoding.1 as u32;
     let max_def_level = def_level_encoding.1 as u32;
 
     match (rep_level_encoding.0, def_level_encoding.0) {
         (Encoding::Rle, Encoding::Rle) => {
-            let rep_levels =
-                HybridRleDecoder::new(rep_levels, get_bit_width(rep_level_encoding.1), additional);
+            let rep_levels = utils::hybrid_rle_decoder(
+                rep_levels,
+                get_bit_width(rep_level_encoding.1),
+                additional,
+            )?;
+            let def_levels = utils::hybrid_rle_decoder(
+                def_levels,
+                get_bit_width(def_level_encoding.1),
+                additional,
+            )?;
             if is_nullable {
-                let def_levels = HybridRleDecoder::new(
-                    def_levels,
-                    get_bit_width(def_level_encoding.1),
-                    additional,
-                );
                 let new_values = utils::BinaryIter::new(values_buffer);
                 read_values(
-                    def_levels,
+                    def_levels.clone(),
                     max_def_level,
                     new_values,
                     offsets,
                     values,
                     validity,
-                )
+                )?
             } else {
-                read_plain_required(values_buffer, additional, offsets, values)
+                let num_values = count_values(def_levels.clone(), max_def_level);
+                read_plain_required(values_buffer, num_values, offsets, values)?
             }
 
-            let def_levels =
-                HybridRleDecoder::new(def_levels, get_bit_width(def_level_encoding.1), additional);
-
             extend_offsets(
                 rep_levels,
                 def_levels,
@@ -88,7 +93,10 @@ fn read<O: Offset>(
                 nested,
             )
         }
-        _ => todo!(),
+        _ => Err(ArrowError::NotYetImplemented(format!(
+            "Decoding levels of encodings {:?} and {:?}",
+            rep_level_encoding.0, def_level_encoding.0
+        ))),
     }
 }
 
@@ -103,7 +111,7 @@ pub(super) fn extend_from_page<O: Offset>(
 ) -> Result<()> {
     let additional = page.num_values();
 
-    let (rep_levels, def_levels, values_buffer, version) = utils::split_buffer(page, descriptor);
+    let (rep_levels, def_levels, values_buffer, version) = utils::split_buffer(page, descriptor)?;
 
     match (&page.encoding(), page.dictionary_page()) {
         (Encoding::Plain, None) => read(
@@ -125,15 +133,12 @@ pub(super) fn extend_from_page<O: Offset>(
             values,
             validity,
         ),
-        _ => {
-            return Err(utils::not_implemented(
-                &page.encoding(),
-                is_nullable,
-                page.dictionary_page().is_some(),
-                version,
-                "primitive",
-            ))
-        }
+        _ => Err(utils::not_implemented(
+            &page.encoding(),
+            is_nullable,
+            page.dictionary_page().is_some(),
+            version,
+            "primitive",
+        )),
     }
-    Ok(())
 }
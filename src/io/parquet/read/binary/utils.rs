@@ -3,27 +3,45 @@ use crate::{
     bitmap::MutableBitmap,
     buffer::MutableBuffer,
     datatypes::DataType,
+    error::{ArrowError, Result},
 };
 
+/// Checks that the values between consecutive `offsets` are utf8.
+/// The offsets must be monotonically increasing and within the bounds of `values`.
+pub(super) fn check_utf8<O: Offset>(offsets: &[O], values: &[u8]) -> Result<()> {
+    if values.is_ascii() {
+        return Ok(());
+    }
+    offsets.windows(2).try_for_each(|window| {
+        let value = &values[window[0].to_usize()..window[1].to_usize()];
+        simdutf8::basic::from_utf8(value).map(|_| ()).map_err(|_| {
+            ArrowError::ExternalFormat("A value of a utf8 column is not valid utf8".to_string())
+        })
+    })
+}
+
 pub(super) fn finish_array<O: Offset>(
     data_type: DataType,
     offsets: MutableBuffer<O>,
     values: MutableBuffer<u8>,
     validity: MutableBitmap,
-) -> Box<dyn Array> {
-    match data_type {
+) -> Result<Box<dyn Array>> {
+    Ok(match data_type {
         DataType::LargeBinary | DataType::Binary => Box::new(BinaryArray::from_data(
             data_type,
             offsets.into(),
             values.into(),
             validity.into(),
         )),
-        DataType::LargeUtf8 | DataType::Utf8 => Box::new(Utf8Array::from_data(
-            data_type,
-            offsets.into(),
-            values.into(),
-            validity.into(),
-        )),
+        DataType::LargeUtf8 | DataType::Utf8 => {
+            check_utf8(&offsets, &values)?;
+            Box::new(Utf8Array::from_data(
+                data_type,
+                offsets.into(),
+                values.into(),
+                validity.into(),
+            ))
+        }
         _ => unreachable!(),
-    }
+    })
 }
@@ -1,3 +1,5 @@
+use std::convert::TryInto;
+
 use crate::{
     array::BooleanArray,
     bitmap::{utils::BitmapIter, MutableBitmap},
@@ -14,27 +16,49 @@ use parquet2::{
     page::DataPage,
 };
 
-pub(super) fn read_required(buffer: &[u8], additional: usize, values: &mut MutableBitmap) {
+pub(super) fn read_required(
+    buffer: &[u8],
+    additional: usize,
+    values: &mut MutableBitmap,
+) -> Result<()> {
+    if buffer.len() * 8 < additional {
+        return Err(utils::missing_values());
+    }
     // in PLAIN, booleans are LSB bitpacked and thus we can read them as if they were a bitmap.
     values.extend_from_slice(buffer, 0, additional);
+    Ok(())
 }
 
-fn read_optional(
+/// Returns an iterator over the first `length` RLE-encoded booleans of `buffer`.
+pub(super) fn rle_values(buffer: &[u8], length: usize) -> Result<impl Iterator<Item = bool> + '_> {
+    // SPEC: RLE-encoded values are prefixed by the length of their encoding, as 4 bytes in little endian
+    let (prefix, buffer) = if buffer.len() >= 4 {
+        buffer.split_at(4)
+    } else {
+        return Err(utils::missing_values());
+    };
+    let size = u32::from_le_bytes(prefix.try_into().unwrap()) as usize;
+    let buffer = buffer.get(..size).ok_or_else(utils::missing_values)?;
+
+    Ok(utils::hybrid_rle_decoder(buffer, 1, length)?.map(|x| x == 1))
+}
+
+fn read_required_rle(buffer: &[u8], additional: usize, values: &mut MutableBitmap) -> Result<()> {
+    rle_values(buffer, additional)?.for_each(|x| values.push(x));
+    Ok(())
+}
+
+fn read_optional<I: Iterator<Item = bool>>(
     validity_buffer: &[u8],
-    values_buffer: &[u8],
-    length: usize,
+    mut values_iterator: I,
+    additional: usize,
     values: &mut MutableBitmap,
     validity: &mut MutableBitmap,
-) {
-    let validity_iterator = hybrid_rle::Decoder::new(validity_buffer, 1);
-
-    // in PLAIN, booleans are LSB bitpacked and thus we can read them as if they were a bitmap.
-    // note that `values_buffer` contains only non-null values.
-    // thus, at this point, it is not known how many values this buffer contains
-    // values_len is the upper bound. The actual number depends on how many nulls there is.
-    let values_len = values_buffer.len() * 8;
-    let mut values_iterator = BitmapIter::new(values_buffer, 0, values_len);
+) -> Result<()> {
+    let length = values.len() + additional;
+    let validity_iterator = utils::validity_runs(validity_buffer, additional)?;
 
+    // note: `values_iterator` contains only non-null values.
     for run in validity_iterator {
         match run {
             hybrid_rle::HybridEncoded::Bitpacked(packed_validity) => {
@@ -43,7 +67,7 @@ fn read_optional(
                 let len = std::cmp::min(packed_validity.len() * 8, remaining);
                 for is_valid in BitmapIter::new(packed_validity, 0, len) {
                     let value = if is_valid {
-                        values_iterator.next().unwrap()
+                        values_iterator.next().ok_or_else(utils::missing_values)?
                     } else {
                         false
                     };
@@ -55,16 +79,17 @@ fn read_optional(
                 let is_set = value[0] == 1;
                 validity.extend_constant(additional, is_set);
                 if is_set {
-                    (0..additional).for_each(|_| {
-                        let value = values_iterator.next().unwrap();
+                    for _ in 0..additional {
+                        let value = values_iterator.next().ok_or_else(utils::missing_values)?;
                         values.push(value)
-                    })
+                    }
                 } else {
                     values.extend_constant(additional, false)
                 }
             }
         }
     }
+    Ok(())
 }
 
 pub async fn stream_to_array<I, E>(pages: I, metadata: &ColumnChunkMetaData) -> Result<BooleanArray>
@@ -105,26 +130,40 @@ pub(super) fn extend_from_page(
     assert!(descriptor.max_def_level() <= 1);
     let is_optional = descriptor.max_def_level() == 1;
 
-    let (_, validity_buffer, values_buffer, version) = utils::split_buffer(page, descriptor);
+    let (_, validity_buffer, values_buffer, version) = utils::split_buffer(page, descriptor)?;
 
     match (page.encoding(), page.dictionary_page(), is_optional) {
-        (Encoding::Plain, None, true) => read_optional(
-            validity_buffer,
-            values_buffer,
-            page.num_values(),
-            values,
-            validity,
-        ),
-        (Encoding::Plain, None, false) => read_required(page.buffer(), page.num_values(), values),
-        _ => {
-            return Err(utils::not_implemented(
-                &page.encoding(),
-                is_optional,
-                page.dictionary_page().is_some(),
-                version,
-                "Boolean",
-            ))
+        (Encoding::Plain, None, true) => {
+            // in PLAIN, booleans are LSB bitpacked and thus we can read them as if they were a bitmap.
+            // note that `values_buffer` contains only non-null values and thus the number of
+            // values is not known: `values_buffer.len() * 8` is an upper bound.
+            let values_iterator = BitmapIter::new(values_buffer, 0, values_buffer.len() * 8);
+            read_optional(
+                validity_buffer,
+                values_iterator,
+                page.num_values(),
+                values,
+                validity,
+            )
+        }
+        (Encoding::Rle, None, true) => {
+            let num_valid = utils::count_valid(validity_buffer, page.num_values())?;
+            read_optional(
+                validity_buffer,
+                rle_values(values_buffer, num_valid)?,
+                page.num_values(),
+                values,
+                validity,
+            )
         }
+        (Encoding::Plain, None, false) => read_required(page.buffer(), page.num_values(), values),
+        (Encoding::Rle, None, false) => read_required_rle(values_buffer, page.num_values(), values),
+        _ => Err(utils::not_implemented(
+            &page.encoding(),
+            is_optional,
+            page.dictionary_page().is_some(),
+            version,
+            "Boolean",
+        )),
     }
-    Ok(())
 }
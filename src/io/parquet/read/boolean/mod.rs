@@ -17,6 +17,7 @@ use super::nested_utils::Nested;
 pub fn iter_to_array<I, E>(
     mut iter: I,
     metadata: &ColumnChunkMetaData,
+    capacity: usize,
     data_type: DataType,
     nested: &mut Vec<Box<dyn Nested>>,
 ) -> Result<Box<dyn Array>>
@@ -24,7 +25,6 @@ where
     ArrowError: From<E>,
     I: FallibleStreamingIterator<Item = DataPage, Error = E>,
 {
-    let capacity = metadata.num_values() as usize;
     let mut values = MutableBitmap::with_capacity(capacity);
     let mut validity = MutableBitmap::with_capacity(capacity);
 
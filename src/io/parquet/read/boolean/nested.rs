@@ -1,37 +1,39 @@
 use parquet2::{
-    encoding::{hybrid_rle::HybridRleDecoder, Encoding},
-    metadata::ColumnDescriptor,
-    page::DataPage,
-    read::levels::get_bit_width,
+    encoding::Encoding, metadata::ColumnDescriptor, page::DataPage, read::levels::get_bit_width,
 };
 
 use super::super::nested_utils::*;
 use super::super::utils;
-use super::basic::read_required;
+use super::basic::rle_values;
 use crate::{
     bitmap::{utils::BitmapIter, MutableBitmap},
-    error::Result,
+    error::{ArrowError, Result},
 };
 
 fn read_values<D, G>(
     def_levels: D,
     max_def: u32,
+    is_nullable: bool,
     mut new_values: G,
     values: &mut MutableBitmap,
     validity: &mut MutableBitmap,
-) where
+) -> Result<()>
+where
     D: Iterator<Item = u32>,
     G: Iterator<Item = bool>,
 {
-    def_levels.for_each(|def| {
+    for def in def_levels {
         if def == max_def {
-            values.push(new_values.next().unwrap());
-            validity.push(true);
-        } else if def == max_def - 1 {
+            values.push(new_values.next().ok_or_else(utils::missing_values)?);
+            if is_nullable {
+                validity.push(true);
+            }
+        } else if is_nullable && def == max_def - 1 {
             values.push(false);
             validity.push(false);
         }
-    });
+    }
+    Ok(())
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -40,39 +42,57 @@ fn read(
     def_levels: &[u8],
     values_buffer: &[u8],
     additional: usize,
+    encoding: &Encoding,
     rep_level_encoding: (&Encoding, i16),
     def_level_encoding: (&Encoding, i16),
     is_nullable: bool,
     nested: &mut Vec<Box<dyn Nested>>,
     values: &mut MutableBitmap,
     validity: &mut MutableBitmap,
-) {
+) -> Result<()> {
     let max_rep_level = rep_level_encoding.1 as u32;
     let max_def_level = def_level_encoding.1 as u32;
 
     match (rep_level_encoding.0, def_level_encoding.0) {
         (Encoding::Rle, Encoding::Rle) => {
-            let rep_levels =
-                HybridRleDecoder::new(rep_levels, get_bit_width(rep_level_encoding.1), additional);
-            if is_nullable {
-                let def_levels = HybridRleDecoder::new(
-                    def_levels,
-                    get_bit_width(def_level_encoding.1),
-                    additional,
-                );
-
-                // don't know how many values there is: using the max possible
-                let num_valid_values = additional.min(values_buffer.len() * 8);
+            let rep_levels = utils::hybrid_rle_decoder(
+                rep_levels,
+                get_bit_width(rep_level_encoding.1),
+                additional,
+            )?;
+            let def_levels = utils::hybrid_rle_decoder(
+                def_levels,
+                get_bit_width(def_level_encoding.1),
+                additional,
+            )?;
 
-                let new_values = BitmapIter::new(values_buffer, 0, num_valid_values);
-                read_values(def_levels, max_def_level, new_values, values, validity)
+            // only the levels at the maximum definition level have a value
+            let num_values = count_values(def_levels.clone(), max_def_level);
+            if let Encoding::Rle = encoding {
+                let new_values = rle_values(values_buffer, num_values)?;
+                read_values(
+                    def_levels.clone(),
+                    max_def_level,
+                    is_nullable,
+                    new_values,
+                    values,
+                    validity,
+                )?
             } else {
-                read_required(values_buffer, additional, values)
+                if values_buffer.len() * 8 < num_values {
+                    return Err(utils::missing_values());
+                }
+                let new_values = BitmapIter::new(values_buffer, 0, num_values);
+                read_values(
+                    def_levels.clone(),
+                    max_def_level,
+                    is_nullable,
+                    new_values,
+                    values,
+                    validity,
+                )?
             }
 
-            let def_levels =
-                HybridRleDecoder::new(def_levels, get_bit_width(def_level_encoding.1), additional);
-
             extend_offsets(
                 rep_levels,
                 def_levels,
@@ -82,7 +102,10 @@ fn read(
                 nested,
             )
         }
-        _ => todo!(),
+        _ => Err(ArrowError::NotYetImplemented(format!(
+            "Decoding levels of encodings {:?} and {:?}",
+            rep_level_encoding.0, def_level_encoding.0
+        ))),
     }
 }
 
@@ -96,14 +119,15 @@ pub(super) fn extend_from_page(
 ) -> Result<()> {
     let additional = page.num_values();
 
-    let (rep_levels, def_levels, values_buffer, version) = utils::split_buffer(page, descriptor);
+    let (rep_levels, def_levels, values_buffer, version) = utils::split_buffer(page, descriptor)?;
 
     match (&page.encoding(), page.dictionary_page()) {
-        (Encoding::Plain, None) => read(
+        (Encoding::Plain, None) | (Encoding::Rle, None) => read(
             rep_levels,
             def_levels,
             values_buffer,
             additional,
+            &page.encoding(),
             (
                 &page.repetition_level_encoding(),
                 descriptor.max_rep_level(),
@@ -117,15 +141,12 @@ pub(super) fn extend_from_page(
             values,
             validity,
         ),
-        _ => {
-            return Err(utils::not_implemented(
-                &page.encoding(),
-                is_nullable,
-                page.dictionary_page().is_some(),
-                version,
-                "primitive",
-            ))
-        }
+        _ => Err(utils::not_implemented(
+            &page.encoding(),
+            is_nullable,
+            page.dictionary_page().is_some(),
+            version,
+            "Boolean",
+        )),
     }
-    Ok(())
 }
@@ -16,6 +16,22 @@ use crate::{
 
 use super::utils;
 
+/// Returns the value at `index` of the dictionary `dict_values` of values of `size` bytes.
+#[inline]
+fn dict_value(dict_values: &[u8], size: usize, index: u32) -> Result<&[u8]> {
+    let index = index as usize;
+    index
+        .checked_mul(size)
+        .and_then(|start| dict_values.get(start..start + size))
+        .ok_or_else(|| {
+            ArrowError::ExternalFormat(format!(
+                "The dictionary index {} is out of bounds of a dictionary with {} values",
+                index,
+                dict_values.len().checked_div(size).unwrap_or(0)
+            ))
+        })
+}
+
 /// Assumptions: No rep levels
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn read_dict_buffer(
@@ -26,29 +42,24 @@ pub(crate) fn read_dict_buffer(
     dict: &FixedLenByteArrayPageDict,
     values: &mut MutableBuffer<u8>,
     validity: &mut MutableBitmap,
-) {
-    let length = values.len() + additional * size;
+) -> Result<()> {
+    let length = validity.len() + additional;
     let dict_values = dict.values();
 
-    // SPEC: Data page format: the bit width used to encode the entry ids stored as 1 byte (max bit width = 32),
-    // SPEC: followed by the values encoded using RLE/Bit packed described above (with the given bit width).
-    let bit_width = indices_buffer[0];
-    let indices_buffer = &indices_buffer[1..];
-
-    let mut indices = hybrid_rle::HybridRleDecoder::new(indices_buffer, bit_width as u32, length);
+    let mut indices = utils::dict_indices_decoder(indices_buffer, additional)?;
 
-    let validity_iterator = hybrid_rle::Decoder::new(validity_buffer, 1);
+    let validity_iterator = utils::validity_runs(validity_buffer, additional)?;
 
     for run in validity_iterator {
         match run {
             hybrid_rle::HybridEncoded::Bitpacked(packed) => {
-                let remaining = (length - values.len()) / size;
+                let remaining = length - validity.len();
                 let len = std::cmp::min(packed.len() * 8, remaining);
                 for is_valid in BitmapIter::new(packed, 0, len) {
                     validity.push(is_valid);
                     if is_valid {
-                        let index = indices.next().unwrap() as usize;
-                        values.extend_from_slice(&dict_values[index * size..(index + 1) * size]);
+                        let index = indices.next().ok_or_else(utils::missing_values)?;
+                        values.extend_from_slice(dict_value(dict_values, size, index)?);
                     } else {
                         values.extend_constant(size, 0);
                     }
@@ -58,16 +69,17 @@ pub(crate) fn read_dict_buffer(
                 let is_set = value[0] == 1;
                 validity.extend_constant(additional, is_set);
                 if is_set {
-                    (0..additional).for_each(|_| {
-                        let index = indices.next().unwrap() as usize;
-                        values.extend_from_slice(&dict_values[index * size..(index + 1) * size]);
-                    })
+                    for _ in 0..additional {
+                        let index = indices.next().ok_or_else(utils::missing_values)?;
+                        values.extend_from_slice(dict_value(dict_values, size, index)?);
+                    }
                 } else {
                     values.extend_constant(additional * size, 0)
                 }
             }
         }
     }
+    Ok(())
 }
 
 /// Assumptions: No rep levels
@@ -78,21 +90,16 @@ pub(crate) fn read_dict_required(
     dict: &FixedLenByteArrayPageDict,
     values: &mut MutableBuffer<u8>,
     validity: &mut MutableBitmap,
-) {
+) -> Result<()> {
     let dict_values = dict.values();
 
-    // SPEC: Data page format: the bit width used to encode the entry ids stored as 1 byte (max bit width = 32),
-    // SPEC: followed by the values encoded using RLE/Bit packed described above (with the given bit width).
-    let bit_width = indices_buffer[0];
-    let indices_buffer = &indices_buffer[1..];
-
-    let indices = hybrid_rle::HybridRleDecoder::new(indices_buffer, bit_width as u32, additional);
+    let indices = utils::dict_indices_decoder(indices_buffer, additional)?;
 
     for index in indices {
-        let index = index as usize;
-        values.extend_from_slice(&dict_values[index * size..(index + 1) * size]);
+        values.extend_from_slice(dict_value(dict_values, size, index)?);
     }
-    validity.extend_constant(additional * size, true);
+    validity.extend_constant(additional, true);
+    Ok(())
 }
 
 pub(crate) fn read_optional(
@@ -102,25 +109,25 @@ pub(crate) fn read_optional(
     size: usize,
     values: &mut MutableBuffer<u8>,
     validity: &mut MutableBitmap,
-) {
-    let length = values.len() + additional * size;
+) -> Result<()> {
+    let length = validity.len() + additional;
 
-    assert_eq!(values_buffer.len() % size, 0);
-    let mut values_iterator = values_buffer.chunks_exact(size);
+    // note: `chunks_exact` panics on chunks of size 0
+    let mut values_iterator = values_buffer.chunks_exact(size.max(1));
 
-    let validity_iterator = hybrid_rle::Decoder::new(validity_buffer, 1);
+    let validity_iterator = utils::validity_runs(validity_buffer, additional)?;
 
     for run in validity_iterator {
         match run {
             hybrid_rle::HybridEncoded::Bitpacked(packed) => {
                 // the pack may contain more items than needed.
-                let remaining = (length - values.len()) / size;
+                let remaining = length - validity.len();
                 let len = std::cmp::min(packed.len() * 8, remaining);
                 for is_valid in BitmapIter::new(packed, 0, len) {
                     validity.push(is_valid);
                     if is_valid {
-                        let value = values_iterator.next().unwrap();
-                        values.extend_from_slice(value);
+                        let value = values_iterator.next().ok_or_else(utils::missing_values)?;
+                        values.extend_from_slice(&value[..size]);
                     } else {
                         values.extend_constant(size, 0)
                     }
@@ -130,16 +137,17 @@ pub(crate) fn read_optional(
                 let is_set = value[0] == 1;
                 validity.extend_constant(additional, is_set);
                 if is_set {
-                    (0..additional).for_each(|_| {
-                        let value = values_iterator.next().unwrap();
-                        values.extend_from_slice(value)
-                    })
+                    for _ in 0..additional {
+                        let value = values_iterator.next().ok_or_else(utils::missing_values)?;
+                        values.extend_from_slice(&value[..size])
+                    }
                 } else {
                     values.extend_constant(additional * size, 0)
                 }
             }
         }
     }
+    Ok(())
 }
 
 pub(crate) fn read_required(
@@ -147,15 +155,24 @@ pub(crate) fn read_required(
     additional: usize,
     size: usize,
     values: &mut MutableBuffer<u8>,
-) {
-    assert_eq!(buffer.len(), additional * size);
+) -> Result<()> {
+    if additional.checked_mul(size) != Some(buffer.len()) {
+        return Err(ArrowError::ExternalFormat(format!(
+            "The page declares {} values of {} bytes but its buffer has {} bytes",
+            additional,
+            size,
+            buffer.len()
+        )));
+    }
     values.extend_from_slice(buffer);
+    Ok(())
 }
 
 pub fn iter_to_array<I, E>(
     mut iter: I,
     data_type: DataType,
     metadata: &ColumnChunkMetaData,
+    capacity: usize,
 ) -> Result<FixedSizeBinaryArray>
 where
     ArrowError: From<E>,
@@ -163,7 +180,6 @@ where
 {
     let size = FixedSizeBinaryArray::get_size(&data_type);
 
-    let capacity = metadata.num_values() as usize;
     let mut values = MutableBuffer::<u8>::with_capacity(capacity * size);
     let mut validity = MutableBitmap::with_capacity(capacity);
     while let Some(page) = iter.next()? {
@@ -230,7 +246,7 @@ pub(crate) fn extend_from_page(
     assert!(descriptor.max_def_level() <= 1);
     let is_optional = descriptor.max_def_level() == 1;
 
-    let (_, validity_buffer, values_buffer, version) = utils::split_buffer(page, descriptor);
+    let (_, validity_buffer, values_buffer, version) = utils::split_buffer(page, descriptor)?;
 
     match (page.encoding(), page.dictionary_page(), is_optional) {
         (Encoding::PlainDictionary, Some(dict), true) => read_dict_buffer(
@@ -238,7 +254,7 @@ pub(crate) fn extend_from_page(
             values_buffer,
             additional,
             size,
-            dict.as_any().downcast_ref().unwrap(),
+            utils::downcast_dict(dict.as_ref())?,
             values,
             validity,
         ),
@@ -246,7 +262,7 @@ pub(crate) fn extend_from_page(
             values_buffer,
             additional,
             size,
-            dict.as_any().downcast_ref().unwrap(),
+            utils::downcast_dict(dict.as_ref())?,
             values,
             validity,
         ),
@@ -261,15 +277,12 @@ pub(crate) fn extend_from_page(
         // it can happen that there is a dictionary but the encoding is plain because
         // it falled back.
         (Encoding::Plain, _, false) => read_required(page.buffer(), additional, size, values),
-        _ => {
-            return Err(utils::not_implemented(
-                &page.encoding(),
-                is_optional,
-                page.dictionary_page().is_some(),
-                version,
-                "FixedSizeBinary",
-            ))
-        }
+        _ => Err(utils::not_implemented(
+            &page.encoding(),
+            is_optional,
+            page.dictionary_page().is_some(),
+            version,
+            "FixedSizeBinary",
+        )),
     }
-    Ok(())
 }
@@ -1,9 +1,15 @@
 //! APIs to read from Parquet format.
+//!
+//! # Date64
+//! The Arrow specification requires [`DataType::Date64`] values to be at midnight, which
+//! other implementations do not always enforce. Deserializing a `Date64` column thus floors
+//! each of its values to midnight via [`date64_floor`]. This cannot be disabled, and costs an
+//! extra operation per value compared to other 64-bit columns.
 #![allow(clippy::type_complexity)]
 
 use std::{
     collections::VecDeque,
-    convert::TryInto,
+    convert::{TryFrom, TryInto},
     io::{Read, Seek},
     sync::Arc,
 };
@@ -31,8 +37,9 @@ pub use parquet2::{
 use crate::{
     array::{Array, DictionaryKey, NullArray, PrimitiveArray, StructArray},
     datatypes::{DataType, Field, IntervalUnit, TimeUnit},
-    error::{ArrowError, Result},
+    error::{ArrowError, ErrorContext, Location, Result},
     io::parquet::read::nested_utils::{create_list, init_nested},
+    temporal_conversions::date64_floor,
 };
 
 mod binary;
@@ -44,6 +51,7 @@ mod record_batch;
 pub mod schema;
 pub mod statistics;
 mod utils;
+pub mod vectored;
 
 pub use record_batch::RecordReader;
 pub(crate) use schema::is_type_nullable;
@@ -89,16 +97,49 @@ pub async fn read_metadata_async<R: AsyncRead + AsyncSeek + Send + Unpin>(
     Ok(_read_metadata_async(reader).await?)
 }
 
+/// Parses parquets' metadata from `bytes`, the last bytes of a file (e.g. its prefetched tail).
+/// `bytes` must contain the whole footer of the file, i.e. its metadata followed by
+/// the metadata's length and the magic number `PAR1`.
+/// # Errors
+/// Errors iff `bytes` is not the tail of a parquet file or does not contain the whole footer,
+/// in which case the error states the number of bytes required.
+pub fn parse_metadata(bytes: &[u8]) -> Result<FileMetaData> {
+    if bytes.len() < 8 || bytes[bytes.len() - 4..] != *b"PAR1" {
+        return Err(ArrowError::OutOfSpec(
+            "A parquet footer must end with its metadata's length and the magic number PAR1"
+                .to_string(),
+        ));
+    }
+    let length = i32::from_le_bytes(bytes[bytes.len() - 8..bytes.len() - 4].try_into().unwrap());
+    let length = usize::try_from(length).map_err(|_| {
+        ArrowError::OutOfSpec(format!(
+            "The parquet metadata length {} is negative",
+            length
+        ))
+    })?;
+    if bytes.len() < length + 8 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The parquet footer requires {} bytes but only {} were provided",
+            length + 8,
+            bytes.len()
+        )));
+    }
+    // the footer is parsed as a file only containing it
+    let footer = &bytes[bytes.len() - length - 8..];
+    Ok(_read_metadata(&mut std::io::Cursor::new(footer))?)
+}
+
 fn dict_read<
     K: DictionaryKey,
     I: FallibleStreamingIterator<Item = DataPage, Error = ParquetError>,
 >(
     iter: &mut I,
     metadata: &ColumnChunkMetaData,
+    capacity: usize,
     data_type: DataType,
 ) -> Result<Box<dyn Array>> {
     use DataType::*;
-    let values_data_type = if let Dictionary(_, v) = &data_type {
+    let values_data_type = if let Dictionary(_, v, _) = &data_type {
         v.as_ref()
     } else {
         panic!()
@@ -108,30 +149,35 @@ fn dict_read<
         UInt8 => primitive::iter_to_dict_array::<K, _, _, _, _, _>(
             iter,
             metadata,
+            capacity,
             data_type,
             |x: i32| x as u8,
         ),
         UInt16 => primitive::iter_to_dict_array::<K, _, _, _, _, _>(
             iter,
             metadata,
+            capacity,
             data_type,
             |x: i32| x as u16,
         ),
         UInt32 => primitive::iter_to_dict_array::<K, _, _, _, _, _>(
             iter,
             metadata,
+            capacity,
             data_type,
             |x: i32| x as u32,
         ),
         Int8 => primitive::iter_to_dict_array::<K, _, _, _, _, _>(
             iter,
             metadata,
+            capacity,
             data_type,
             |x: i32| x as i8,
         ),
         Int16 => primitive::iter_to_dict_array::<K, _, _, _, _, _>(
             iter,
             metadata,
+            capacity,
             data_type,
             |x: i32| x as i16,
         ),
@@ -139,6 +185,7 @@ fn dict_read<
             primitive::iter_to_dict_array::<K, _, _, _, _, _>(
                 iter,
                 metadata,
+                capacity,
                 data_type,
                 |x: i32| x as i32,
             )
@@ -148,23 +195,40 @@ fn dict_read<
                 PhysicalType::Int96 => primitive::iter_to_dict_array::<K, _, _, _, _, _>(
                     iter,
                     metadata,
+                    capacity,
                     DataType::Timestamp(TimeUnit::Nanosecond, None),
                     int96_to_i64_ns,
                 ),
                 _ => primitive::iter_to_dict_array::<K, _, _, _, _, _>(
                     iter,
                     metadata,
+                    capacity,
                     data_type,
                     |x: i64| x,
                 ),
             },
             _ => unreachable!(),
         },
-        Int64 | Date64 | Time64(_) | Duration(_) | Timestamp(_, _) => {
-            primitive::iter_to_dict_array::<K, _, _, _, _, _>(iter, metadata, data_type, |x: i64| x)
+        Int64 | Time64(_) | Duration(_) | Timestamp(_, _) => {
+            primitive::iter_to_dict_array::<K, _, _, _, _, _>(
+                iter,
+                metadata,
+                capacity,
+                data_type,
+                |x: i64| x,
+            )
+        }
+        Date64 => primitive::iter_to_dict_array::<K, _, _, _, _, _>(
+            iter,
+            metadata,
+            capacity,
+            data_type,
+            date64_floor,
+        ),
+        Utf8 => binary::iter_to_dict_array::<K, i32, _, _>(iter, metadata, capacity, data_type),
+        LargeUtf8 => {
+            binary::iter_to_dict_array::<K, i64, _, _>(iter, metadata, capacity, data_type)
         }
-        Utf8 => binary::iter_to_dict_array::<K, i32, _, _>(iter, metadata, data_type),
-        LargeUtf8 => binary::iter_to_dict_array::<K, i64, _, _>(iter, metadata, data_type),
         other => Err(ArrowError::NotYetImplemented(format!(
             "Reading dictionaries of type {:?}",
             other
@@ -222,6 +286,7 @@ fn page_iter_to_array<I: FallibleStreamingIterator<Item = DataPage, Error = Parq
     iter: &mut I,
     nested: &mut Vec<Box<dyn Nested>>,
     metadata: &ColumnChunkMetaData,
+    capacity: usize,
     data_type: DataType,
 ) -> Result<Box<dyn Array>> {
     use DataType::*;
@@ -231,15 +296,31 @@ fn page_iter_to_array<I: FallibleStreamingIterator<Item = DataPage, Error = Parq
             metadata.num_values() as usize,
         ))),
 
-        Boolean => boolean::iter_to_array(iter, metadata, data_type, nested),
+        Boolean => boolean::iter_to_array(iter, metadata, capacity, data_type, nested),
 
-        UInt8 => primitive::iter_to_array(iter, metadata, data_type, nested, |x: i32| x as u8),
-        UInt16 => primitive::iter_to_array(iter, metadata, data_type, nested, |x: i32| x as u16),
-        UInt32 => primitive::iter_to_array(iter, metadata, data_type, nested, |x: i32| x as u32),
-        Int8 => primitive::iter_to_array(iter, metadata, data_type, nested, |x: i32| x as i8),
-        Int16 => primitive::iter_to_array(iter, metadata, data_type, nested, |x: i32| x as i16),
+        UInt8 => primitive::iter_to_array(iter, metadata, capacity, data_type, nested, |x: i32| {
+            x as u8
+        }),
+        UInt16 => {
+            primitive::iter_to_array(iter, metadata, capacity, data_type, nested, |x: i32| {
+                x as u16
+            })
+        }
+        UInt32 => {
+            primitive::iter_to_array(iter, metadata, capacity, data_type, nested, |x: i32| {
+                x as u32
+            })
+        }
+        Int8 => primitive::iter_to_array(iter, metadata, capacity, data_type, nested, |x: i32| {
+            x as i8
+        }),
+        Int16 => primitive::iter_to_array(iter, metadata, capacity, data_type, nested, |x: i32| {
+            x as i16
+        }),
         Int32 | Date32 | Time32(_) | Interval(IntervalUnit::YearMonth) => {
-            primitive::iter_to_array(iter, metadata, data_type, nested, |x: i32| x as i32)
+            primitive::iter_to_array(iter, metadata, capacity, data_type, nested, |x: i32| {
+                x as i32
+            })
         }
 
         Timestamp(TimeUnit::Nanosecond, None) => match metadata.descriptor().type_() {
@@ -247,26 +328,44 @@ fn page_iter_to_array<I: FallibleStreamingIterator<Item = DataPage, Error = Parq
                 PhysicalType::Int96 => primitive::iter_to_array(
                     iter,
                     metadata,
+                    capacity,
                     DataType::Timestamp(TimeUnit::Nanosecond, None),
                     nested,
                     int96_to_i64_ns,
                 ),
-                _ => primitive::iter_to_array(iter, metadata, data_type, nested, |x: i64| x),
+                _ => primitive::iter_to_array(
+                    iter,
+                    metadata,
+                    capacity,
+                    data_type,
+                    nested,
+                    |x: i64| x,
+                ),
             },
             _ => unreachable!(),
         },
 
         FixedSizeBinary(_) => Ok(Box::new(fixed_size_binary::iter_to_array(
-            iter, data_type, metadata,
+            iter, data_type, metadata, capacity,
         )?)),
         Decimal(_, _) => match metadata.descriptor().type_() {
             ParquetType::PrimitiveType { physical_type, .. } => match physical_type {
-                PhysicalType::Int32 => {
-                    primitive::iter_to_array(iter, metadata, data_type, nested, |x: i32| x as i128)
-                }
-                PhysicalType::Int64 => {
-                    primitive::iter_to_array(iter, metadata, data_type, nested, |x: i64| x as i128)
-                }
+                PhysicalType::Int32 => primitive::iter_to_array(
+                    iter,
+                    metadata,
+                    capacity,
+                    data_type,
+                    nested,
+                    |x: i32| x as i128,
+                ),
+                PhysicalType::Int64 => primitive::iter_to_array(
+                    iter,
+                    metadata,
+                    capacity,
+                    data_type,
+                    nested,
+                    |x: i64| x as i128,
+                ),
                 PhysicalType::FixedLenByteArray(n) => {
                     if *n > 16 {
                         Err(ArrowError::NotYetImplemented(format!(
@@ -280,6 +379,7 @@ fn page_iter_to_array<I: FallibleStreamingIterator<Item = DataPage, Error = Parq
                             iter,
                             DataType::FixedSizeBinary(*n as usize),
                             metadata,
+                            capacity,
                         )
                         .map(|e| {
                             let a = e
@@ -312,29 +412,45 @@ fn page_iter_to_array<I: FallibleStreamingIterator<Item = DataPage, Error = Parq
         },
 
         // INT64
-        Int64 | Date64 | Time64(_) | Duration(_) | Timestamp(_, _) => {
-            primitive::iter_to_array(iter, metadata, data_type, nested, |x: i64| x)
+        Int64 | Time64(_) | Duration(_) | Timestamp(_, _) => {
+            primitive::iter_to_array(iter, metadata, capacity, data_type, nested, |x: i64| x)
+        }
+        // Date64 values written by other implementations are not always at midnight
+        Date64 => {
+            primitive::iter_to_array(iter, metadata, capacity, data_type, nested, date64_floor)
+        }
+        UInt64 => {
+            primitive::iter_to_array(iter, metadata, capacity, data_type, nested, |x: i64| {
+                x as u64
+            })
         }
-        UInt64 => primitive::iter_to_array(iter, metadata, data_type, nested, |x: i64| x as u64),
 
-        Float32 => primitive::iter_to_array(iter, metadata, data_type, nested, |x: f32| x),
-        Float64 => primitive::iter_to_array(iter, metadata, data_type, nested, |x: f64| x),
+        Float32 => {
+            primitive::iter_to_array(iter, metadata, capacity, data_type, nested, |x: f32| x)
+        }
+        Float64 => {
+            primitive::iter_to_array(iter, metadata, capacity, data_type, nested, |x: f64| x)
+        }
 
-        Binary | Utf8 => binary::iter_to_array::<i32, _, _>(iter, metadata, data_type, nested),
+        Binary | Utf8 => {
+            binary::iter_to_array::<i32, _, _>(iter, metadata, capacity, data_type, nested)
+        }
         LargeBinary | LargeUtf8 => {
-            binary::iter_to_array::<i64, _, _>(iter, metadata, data_type, nested)
+            binary::iter_to_array::<i64, _, _>(iter, metadata, capacity, data_type, nested)
         }
 
-        Dictionary(key_type, _) => match_integer_type!(key_type, |$T| {
-            dict_read::<$T, _>(iter, metadata, data_type)
+        Dictionary(key_type, _, _) => match_integer_type!(key_type, |$T| {
+            dict_read::<$T, _>(iter, metadata, capacity, data_type)
         }),
 
         List(ref inner) => {
-            let values = page_iter_to_array(iter, nested, metadata, inner.data_type().clone())?;
+            let values =
+                page_iter_to_array(iter, nested, metadata, capacity, inner.data_type().clone())?;
             create_list(data_type, nested, values.into())
         }
         LargeList(ref inner) => {
-            let values = page_iter_to_array(iter, nested, metadata, inner.data_type().clone())?;
+            let values =
+                page_iter_to_array(iter, nested, metadata, capacity, inner.data_type().clone())?;
             create_list(data_type, nested, values.into())
         }
 
@@ -369,8 +485,51 @@ fn finish_array(data_type: DataType, arrays: &mut VecDeque<Box<dyn Array>>) -> B
     }
 }
 
+/// Returns whether columns of `data_type` can be deserialized page by page via [`page_to_array`],
+/// i.e. whether they are stored in a single column chunk whose values are its rows.
+fn is_flat(data_type: &DataType) -> bool {
+    use crate::datatypes::PhysicalType::*;
+    matches!(
+        data_type.to_physical_type(),
+        Boolean
+            | Primitive(_)
+            | Binary
+            | LargeBinary
+            | Utf8
+            | LargeUtf8
+            | FixedSizeBinary
+            | Dictionary(_)
+    )
+}
+
+/// Returns an [`Array`] built from a single page of the column chunk of `field`, which must
+/// be flat (see [`is_flat`]). It also returns the buffer used to decompress the page.
+fn page_to_array(
+    page: CompressedDataPage,
+    metadata: &ColumnChunkMetaData,
+    field: &Field,
+    buffer: Vec<u8>,
+) -> Result<(Box<dyn Array>, Vec<u8>)> {
+    let mut nested_info = vec![];
+    init_nested(field, 0, &mut nested_info);
+
+    let capacity = page.num_values();
+    let mut iterator = BasicDecompressor::new(std::iter::once(Ok(page)), buffer);
+    let array = page_iter_to_array(
+        &mut iterator,
+        &mut nested_info,
+        metadata,
+        capacity,
+        field.data_type().clone(),
+    )?;
+    Ok((array, iterator.into_inner()))
+}
+
 /// Returns an [`Array`] built from an iterator of column chunks. It also returns
 /// the two buffers used to decompress and deserialize pages (to be re-used).
+/// # Errors
+/// Errors iff a page cannot be deserialized, with the error wrapped in an
+/// [`ArrowError::WithContext`] with the column and the page where it happened.
 #[allow(clippy::type_complexity)]
 pub fn column_iter_to_array<II, I>(
     mut columns: I,
@@ -394,10 +553,30 @@ where
             State::Some(mut new_iter) => {
                 let data_type = column_datatype(&data_type, column);
                 if let Some((pages, metadata)) = new_iter.get() {
+                    // the number of pages read, to locate errors
+                    let read_pages = std::cell::Cell::new(0usize);
+                    let pages = pages.inspect(|_| read_pages.set(read_pages.get() + 1));
                     let mut iterator = BasicDecompressor::new(pages, buffer);
 
-                    let array =
-                        page_iter_to_array(&mut iterator, &mut nested_info, metadata, data_type)?;
+                    let array = page_iter_to_array(
+                        &mut iterator,
+                        &mut nested_info,
+                        metadata,
+                        metadata.num_values() as usize,
+                        data_type,
+                    )
+                    .map_err(|e| {
+                        e.with_context(
+                            ErrorContext::default()
+                                .with_column(field.name().as_str())
+                                .with_byte_offset(metadata.byte_range().0)
+                                .with_location(Location::ParquetPage {
+                                    row_group: None,
+                                    column_chunk: Some(column),
+                                    page: read_pages.get().checked_sub(1),
+                                }),
+                        )
+                    })?;
                     buffer = iterator.into_inner();
                     arrays.push_back(array)
                 }
@@ -455,9 +634,10 @@ pub async fn page_stream_to_array<I: Stream<Item = std::result::Result<DataPage,
         },
 
         // INT64
-        Int64 | Date64 | Time64(_) | Duration(_) | Timestamp(_, _) => {
+        Int64 | Time64(_) | Duration(_) | Timestamp(_, _) => {
             primitive::stream_to_array(pages, metadata, data_type, |x: i64| x).await
         }
+        Date64 => primitive::stream_to_array(pages, metadata, data_type, date64_floor).await,
         UInt64 => primitive::stream_to_array(pages, metadata, data_type, |x: i64| x as u64).await,
 
         Float32 => primitive::stream_to_array(pages, metadata, data_type, |x: f32| x).await,
@@ -141,6 +141,12 @@ impl NestedValid {
     }
 }
 
+/// Returns the number of values of a page of a required leaf, i.e. the number of definition
+/// levels equal to `max_def`: null and empty lists have levels but no values.
+pub fn count_values<D: Iterator<Item = u32>>(def_levels: D, max_def: u32) -> usize {
+    def_levels.filter(|def| *def == max_def).count()
+}
+
 pub fn extend_offsets<R, D>(
     rep_levels: R,
     def_levels: D,
@@ -148,7 +154,8 @@ pub fn extend_offsets<R, D>(
     max_rep: u32,
     max_def: u32,
     nested: &mut Vec<Box<dyn Nested>>,
-) where
+) -> Result<()>
+where
     R: Iterator<Item = u32>,
     D: Iterator<Item = u32>,
 {
@@ -156,7 +163,13 @@ pub fn extend_offsets<R, D>(
     let mut prev_def: u32 = 0;
     let mut is_first = true;
 
-    rep_levels.zip(def_levels).for_each(|(rep, def)| {
+    for (rep, def) in rep_levels.zip(def_levels) {
+        if rep > max_rep || def > max_def {
+            return Err(ArrowError::ExternalFormat(format!(
+                "The levels (repetition {}, definition {}) exceed the maximum levels of the column ({}, {})",
+                rep, def, max_rep, max_def
+            )));
+        }
         let mut closures = max_rep - rep;
         if prev_def <= 1 {
             closures = 1;
@@ -174,7 +187,7 @@ pub fn extend_offsets<R, D>(
             .skip(rep as usize)
             .take((rep + closures) as usize)
             .for_each(|(depth, (nested, length))| {
-                let is_null = (def - rep) as usize == depth && depth == rep as usize;
+                let is_null = def.wrapping_sub(rep) as usize == depth && depth == rep as usize;
                 nested.push(*length, !is_null);
             });
 
@@ -201,7 +214,7 @@ pub fn extend_offsets<R, D>(
                 }
             });
         prev_def = def;
-    });
+    }
 
     // close validities
     nested
@@ -210,6 +223,7 @@ pub fn extend_offsets<R, D>(
         .for_each(|(nested, length)| {
             nested.close(*length);
         });
+    Ok(())
 }
 
 pub fn init_nested(field: &Field, capacity: usize, container: &mut Vec<Box<dyn Nested>>) {
@@ -258,6 +272,10 @@ pub fn create_list(
     Ok(match data_type {
         DataType::List(_) => {
             let (offsets, validity) = nested.pop().unwrap().inner();
+            check_list_offsets(&offsets, validity.as_ref(), values.len())?;
+            if values.len() > i32::MAX as usize {
+                return Err(ArrowError::Overflow);
+            }
 
             let offsets = Buffer::<i32>::from_trusted_len_iter(offsets.iter().map(|x| *x as i32));
             Box::new(ListArray::<i32>::from_data(
@@ -266,6 +284,7 @@ pub fn create_list(
         }
         DataType::LargeList(_) => {
             let (offsets, validity) = nested.pop().unwrap().inner();
+            check_list_offsets(&offsets, validity.as_ref(), values.len())?;
 
             Box::new(ListArray::<i64>::from_data(
                 data_type, offsets, values, validity,
@@ -279,3 +298,22 @@ pub fn create_list(
         }
     })
 }
+
+// checks that the offsets derived from the levels of a page are valid offsets of a list
+// whose values have length `values_len`
+fn check_list_offsets(offsets: &[i64], validity: Option<&Bitmap>, values_len: usize) -> Result<()> {
+    let is_valid = offsets.first() == Some(&0)
+        && offsets.windows(2).all(|window| window[0] <= window[1])
+        && offsets.last().map(|x| *x as usize) == Some(values_len)
+        && validity
+            .map(|validity| validity.len() == offsets.len() - 1)
+            .unwrap_or(true);
+    if is_valid {
+        Ok(())
+    } else {
+        Err(ArrowError::ExternalFormat(
+            "The repetition and definition levels of the column are inconsistent with its values"
+                .to_string(),
+        ))
+    }
+}
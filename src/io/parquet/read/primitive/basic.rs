@@ -10,7 +10,7 @@ use super::ColumnDescriptor;
 use crate::{
     bitmap::{utils::BitmapIter, MutableBitmap},
     buffer::MutableBuffer,
-    error::Result,
+    error::{ArrowError, Result},
     types::NativeType as ArrowNativeType,
 };
 
@@ -22,23 +22,22 @@ fn read_dict_buffer_optional<T, A, F>(
     values: &mut MutableBuffer<A>,
     validity: &mut MutableBitmap,
     op: F,
-) where
+) -> Result<()>
+where
     T: NativeType,
     A: ArrowNativeType,
     F: Fn(T) -> A,
 {
     let length = additional + values.len();
     let dict_values = dict.values();
+    let dict_value = |index: Option<u32>| {
+        let index = index.ok_or_else(other_utils::missing_values)?;
+        other_utils::check_dict_index(index, dict_values.len()).map(|index| op(dict_values[index]))
+    };
 
-    // SPEC: Data page format: the bit width used to encode the entry ids stored as 1 byte (max bit width = 32),
-    // SPEC: followed by the values encoded using RLE/Bit packed described above (with the given bit width).
-    let bit_width = indices_buffer[0];
-    let indices_buffer = &indices_buffer[1..];
-
-    let mut indices =
-        hybrid_rle::HybridRleDecoder::new(indices_buffer, bit_width as u32, additional);
+    let mut indices = other_utils::dict_indices_decoder(indices_buffer, additional)?;
 
-    let validity_iterator = hybrid_rle::Decoder::new(validity_buffer, 1);
+    let validity_iterator = other_utils::validity_runs(validity_buffer, additional)?;
 
     for run in validity_iterator {
         match run {
@@ -47,7 +46,7 @@ fn read_dict_buffer_optional<T, A, F>(
                 let len = std::cmp::min(packed.len() * 8, remaining);
                 for is_valid in BitmapIter::new(packed, 0, len) {
                     let value = if is_valid {
-                        op(dict_values[indices.next().unwrap() as usize])
+                        dict_value(indices.next())?
                     } else {
                         A::default()
                     };
@@ -59,17 +58,16 @@ fn read_dict_buffer_optional<T, A, F>(
                 let is_set = value[0] == 1;
                 validity.extend_constant(additional, is_set);
                 if is_set {
-                    (0..additional).for_each(|_| {
-                        let index = indices.next().unwrap() as usize;
-                        let value = op(dict_values[index]);
-                        values.push(value)
-                    })
+                    for _ in 0..additional {
+                        values.push(dict_value(indices.next())?)
+                    }
                 } else {
                     values.extend_constant(additional, A::default())
                 }
             }
         }
     }
+    Ok(())
 }
 
 fn read_dict_buffer_required<T, A, F>(
@@ -79,23 +77,24 @@ fn read_dict_buffer_required<T, A, F>(
     values: &mut MutableBuffer<A>,
     validity: &mut MutableBitmap,
     op: F,
-) where
+) -> Result<()>
+where
     T: NativeType,
     A: ArrowNativeType,
     F: Fn(T) -> A,
 {
     let dict_values = dict.values();
 
-    // SPEC: Data page format: the bit width used to encode the entry ids stored as 1 byte (max bit width = 32),
-    // SPEC: followed by the values encoded using RLE/Bit packed described above (with the given bit width).
-    let bit_width = indices_buffer[0];
-    let indices_buffer = &indices_buffer[1..];
-
-    let indices = hybrid_rle::HybridRleDecoder::new(indices_buffer, bit_width as u32, additional);
+    let indices = other_utils::dict_indices_decoder(indices_buffer, additional)?;
 
-    values.extend(indices.map(|index| op(dict_values[index as usize])));
+    values.reserve(additional);
+    for index in indices {
+        let index = other_utils::check_dict_index(index, dict_values.len())?;
+        values.push(op(dict_values[index]));
+    }
 
     validity.extend_constant(additional, true);
+    Ok(())
 }
 
 fn read_nullable<T, A, F>(
@@ -105,7 +104,8 @@ fn read_nullable<T, A, F>(
     values: &mut MutableBuffer<A>,
     validity: &mut MutableBitmap,
     op: F,
-) where
+) -> Result<()>
+where
     T: NativeType,
     A: ArrowNativeType,
     F: Fn(T) -> A,
@@ -113,7 +113,7 @@ fn read_nullable<T, A, F>(
     let length = additional + values.len();
     let mut chunks = ExactChunksIter::<T>::new(values_buffer);
 
-    let validity_iterator = hybrid_rle::Decoder::new(validity_buffer, 1);
+    let validity_iterator = other_utils::validity_runs(validity_buffer, additional)?;
 
     for run in validity_iterator {
         match run {
@@ -123,7 +123,7 @@ fn read_nullable<T, A, F>(
                 let len = std::cmp::min(packed.len() * 8, remaining);
                 for is_valid in BitmapIter::new(packed, 0, len) {
                     let value = if is_valid {
-                        op(chunks.next().unwrap())
+                        op(chunks.next().ok_or_else(other_utils::missing_values)?)
                     } else {
                         A::default()
                     };
@@ -135,16 +135,17 @@ fn read_nullable<T, A, F>(
                 let is_set = value[0] == 1;
                 validity.extend_constant(additional, is_set);
                 if is_set {
-                    (0..additional).for_each(|_| {
-                        let value = op(chunks.next().unwrap());
+                    for _ in 0..additional {
+                        let value = op(chunks.next().ok_or_else(other_utils::missing_values)?);
                         values.push(value)
-                    })
+                    }
                 } else {
                     values.extend_constant(additional, A::default())
                 }
             }
         }
     }
+    Ok(())
 }
 
 fn read_required<T, A, F>(
@@ -152,17 +153,25 @@ fn read_required<T, A, F>(
     additional: usize,
     values: &mut MutableBuffer<A>,
     op: F,
-) where
+) -> Result<()>
+where
     T: NativeType,
     A: ArrowNativeType,
     F: Fn(T) -> A,
 {
-    assert_eq!(values_buffer.len(), additional * std::mem::size_of::<T>());
+    if additional.checked_mul(std::mem::size_of::<T>()) != Some(values_buffer.len()) {
+        return Err(ArrowError::ExternalFormat(format!(
+            "The page declares {} values but its buffer has {} bytes",
+            additional,
+            values_buffer.len()
+        )));
+    }
     let iterator = ExactChunksIter::<T>::new(values_buffer);
 
     let iterator = iterator.map(op);
 
     values.extend_from_trusted_len_iter(iterator);
+    Ok(())
 }
 
 pub fn extend_from_page<T, A, F>(
@@ -182,7 +191,7 @@ where
     assert_eq!(descriptor.max_rep_level(), 0);
     let is_optional = descriptor.max_def_level() == 1;
 
-    let (_, validity_buffer, values_buffer, version) = other_utils::split_buffer(page, descriptor);
+    let (_, validity_buffer, values_buffer, version) = other_utils::split_buffer(page, descriptor)?;
 
     match (&page.encoding(), page.dictionary_page(), is_optional) {
         (Encoding::PlainDictionary | Encoding::RleDictionary, Some(dict), true) => {
@@ -190,7 +199,7 @@ where
                 validity_buffer,
                 values_buffer,
                 additional,
-                dict.as_any().downcast_ref().unwrap(),
+                other_utils::downcast_dict(dict.as_ref())?,
                 values,
                 validity,
                 op,
@@ -200,7 +209,7 @@ where
             read_dict_buffer_required(
                 values_buffer,
                 additional,
-                dict.as_any().downcast_ref().unwrap(),
+                other_utils::downcast_dict(dict.as_ref())?,
                 values,
                 validity,
                 op,
@@ -217,15 +226,12 @@ where
             op,
         ),
         (Encoding::Plain, _, false) => read_required(page.buffer(), additional, values, op),
-        _ => {
-            return Err(other_utils::not_implemented(
-                &page.encoding(),
-                is_optional,
-                page.dictionary_page().is_some(),
-                version,
-                "primitive",
-            ))
-        }
+        _ => Err(other_utils::not_implemented(
+            &page.encoding(),
+            is_optional,
+            page.dictionary_page().is_some(),
+            version,
+            "primitive",
+        )),
     }
-    Ok(())
 }
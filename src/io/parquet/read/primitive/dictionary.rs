@@ -28,33 +28,37 @@ fn read_dict_optional<K, T, A, F>(
     values: &mut MutableBuffer<A>,
     validity: &mut MutableBitmap,
     op: F,
-) where
+) -> Result<()>
+where
     K: DictionaryKey,
     T: NativeType,
     A: ArrowNativeType,
     F: Fn(T) -> A,
 {
+    let length = indices.len() + additional;
     let dict_values = dict.values();
-    values.extend_from_trusted_len_iter(dict_values.iter().map(|x| op(*x)));
-
-    // SPEC: Data page format: the bit width used to encode the entry ids stored as 1 byte (max bit width = 32),
-    // SPEC: followed by the values encoded using RLE/Bit packed described above (with the given bit width).
-    let bit_width = indices_buffer[0];
-    let indices_buffer = &indices_buffer[1..];
+    // all pages of a column chunk share the same dictionary
+    if values.is_empty() {
+        values.extend_from_trusted_len_iter(dict_values.iter().map(|x| op(*x)));
+    }
+    let dict_key = |index: Option<u32>| {
+        let index = index.ok_or_else(utils::missing_values)?;
+        let index = utils::check_dict_index(index, dict_values.len())?;
+        K::from_usize(index).ok_or(ArrowError::Overflow)
+    };
 
-    let mut new_indices =
-        hybrid_rle::HybridRleDecoder::new(indices_buffer, bit_width as u32, additional);
+    let mut new_indices = utils::dict_indices_decoder(indices_buffer, additional)?;
 
-    let validity_iterator = hybrid_rle::Decoder::new(validity_buffer, 1);
+    let validity_iterator = utils::validity_runs(validity_buffer, additional)?;
 
     for run in validity_iterator {
         match run {
             hybrid_rle::HybridEncoded::Bitpacked(packed) => {
-                let remaining = additional - indices.len();
+                let remaining = length - indices.len();
                 let len = std::cmp::min(packed.len() * 8, remaining);
                 for is_valid in BitmapIter::new(packed, 0, len) {
                     let value = if is_valid {
-                        K::from_u32(new_indices.next().unwrap()).unwrap()
+                        dict_key(new_indices.next())?
                     } else {
                         K::default()
                     };
@@ -66,16 +70,16 @@ fn read_dict_optional<K, T, A, F>(
                 let is_set = value[0] == 1;
                 validity.extend_constant(additional, is_set);
                 if is_set {
-                    (0..additional).for_each(|_| {
-                        let index = K::from_u32(new_indices.next().unwrap()).unwrap();
-                        indices.push(index)
-                    })
+                    for _ in 0..additional {
+                        indices.push(dict_key(new_indices.next())?)
+                    }
                 } else {
-                    values.extend_constant(additional, A::default())
+                    indices.extend_constant(additional, K::default())
                 }
             }
         }
     }
+    Ok(())
 }
 
 fn extend_from_page<K, T, A, F>(
@@ -97,7 +101,7 @@ where
     assert_eq!(descriptor.max_rep_level(), 0);
     let is_optional = descriptor.max_def_level() == 1;
 
-    let (_, validity_buffer, values_buffer, version) = utils::split_buffer(page, descriptor);
+    let (_, validity_buffer, values_buffer, version) = utils::split_buffer(page, descriptor)?;
 
     match (&page.encoding(), page.dictionary_page(), is_optional) {
         (Encoding::PlainDictionary | Encoding::RleDictionary, Some(dict), true) => {
@@ -105,29 +109,27 @@ where
                 validity_buffer,
                 values_buffer,
                 additional,
-                dict.as_any().downcast_ref().unwrap(),
+                utils::downcast_dict(dict.as_ref())?,
                 indices,
                 values,
                 validity,
                 op,
             )
         }
-        _ => {
-            return Err(utils::not_implemented(
-                &page.encoding(),
-                is_optional,
-                page.dictionary_page().is_some(),
-                version,
-                "primitive",
-            ))
-        }
+        _ => Err(utils::not_implemented(
+            &page.encoding(),
+            is_optional,
+            page.dictionary_page().is_some(),
+            version,
+            "primitive",
+        )),
     }
-    Ok(())
 }
 
 pub fn iter_to_array<K, T, A, I, E, F>(
     mut iter: I,
     metadata: &ColumnChunkMetaData,
+    capacity: usize,
     data_type: DataType,
     op: F,
 ) -> Result<Box<dyn Array>>
@@ -139,7 +141,6 @@ where
     F: Copy + Fn(T) -> A,
     I: FallibleStreamingIterator<Item = DataPage, Error = E>,
 {
-    let capacity = metadata.num_values() as usize;
     let mut indices = MutableBuffer::<K>::with_capacity(capacity);
     let mut values = MutableBuffer::<A>::with_capacity(capacity);
     let mut validity = MutableBitmap::with_capacity(capacity);
@@ -155,7 +156,10 @@ where
     }
 
     let keys = PrimitiveArray::from_data(K::DATA_TYPE, indices.into(), validity.into());
+    let is_ordered = DictionaryArray::<K>::get_is_ordered(&data_type);
     let data_type = DictionaryArray::<K>::get_child(&data_type).clone();
     let values = Arc::new(PrimitiveArray::from_data(data_type, values.into(), None));
-    Ok(Box::new(DictionaryArray::<K>::from_data(keys, values)))
+    Ok(Box::new(
+        DictionaryArray::<K>::from_data(keys, values).with_ordered(is_ordered),
+    ))
 }
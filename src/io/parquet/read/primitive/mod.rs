@@ -50,7 +50,7 @@ where
     }
 
     let data_type = match data_type {
-        DataType::Dictionary(_, values) => values.as_ref().clone(),
+        DataType::Dictionary(_, values, _) => values.as_ref().clone(),
         _ => data_type,
     };
 
@@ -64,6 +64,7 @@ where
 pub fn iter_to_array<T, A, I, E, F>(
     mut iter: I,
     metadata: &ColumnChunkMetaData,
+    capacity: usize,
     data_type: DataType,
     nested: &mut Vec<Box<dyn Nested>>,
     op: F,
@@ -76,7 +77,6 @@ where
     F: Copy + Fn(T) -> A,
     I: FallibleStreamingIterator<Item = DataPage, Error = E>,
 {
-    let capacity = metadata.num_values() as usize;
     let mut values = MutableBuffer::<A>::with_capacity(capacity);
     let mut validity = MutableBitmap::with_capacity(capacity);
 
@@ -101,7 +101,7 @@ where
     }
 
     let data_type = match data_type {
-        DataType::Dictionary(_, values) => values.as_ref().clone(),
+        DataType::Dictionary(_, values, _) => values.as_ref().clone(),
         _ => data_type,
     };
 
@@ -1,15 +1,15 @@
 use parquet2::{
-    encoding::{hybrid_rle::HybridRleDecoder, Encoding},
-    page::DataPage,
-    read::levels::get_bit_width,
-    types::NativeType,
+    encoding::Encoding, page::DataPage, read::levels::get_bit_width, types::NativeType,
 };
 
-use super::super::nested_utils::extend_offsets;
+use super::super::nested_utils::{count_values, extend_offsets};
 use super::ColumnDescriptor;
 use super::{super::utils, utils::ExactChunksIter, Nested};
 use crate::{
-    bitmap::MutableBitmap, buffer::MutableBuffer, error::Result, trusted_len::TrustedLen,
+    bitmap::MutableBitmap,
+    buffer::MutableBuffer,
+    error::{ArrowError, Result},
+    trusted_len::TrustedLen,
     types::NativeType as ArrowNativeType,
 };
 
@@ -20,22 +20,24 @@ fn read_values<T, D, G, F, A>(
     op: F,
     values: &mut MutableBuffer<A>,
     validity: &mut MutableBitmap,
-) where
+) -> Result<()>
+where
     T: NativeType,
     D: Iterator<Item = u32>,
     G: Iterator<Item = T>,
     A: ArrowNativeType,
     F: Fn(T) -> A,
 {
-    def_levels.for_each(|def| {
+    for def in def_levels {
         if def == max_def {
-            values.push(op(new_values.next().unwrap()));
+            values.push(op(new_values.next().ok_or_else(utils::missing_values)?));
             validity.push(true);
         } else if def == max_def - 1 {
             values.push(A::default());
             validity.push(false);
         }
-    });
+    }
+    Ok(())
 }
 
 fn read_values_required<T, G, F, A>(new_values: G, op: F, values: &mut MutableBuffer<A>)
@@ -62,34 +64,46 @@ fn read<T, A, F>(
     values: &mut MutableBuffer<A>,
     validity: &mut MutableBitmap,
     op: F,
-) where
+) -> Result<()>
+where
     T: NativeType,
     A: ArrowNativeType,
     F: Fn(T) -> A,
 {
-    let new_values = ExactChunksIter::<T>::new(values_buffer);
-
     let max_rep_level = rep_level_encoding.1 as u32;
     let max_def_level = def_level_encoding.1 as u32;
 
     match (rep_level_encoding.0, def_level_encoding.0) {
         (Encoding::Rle, Encoding::Rle) => {
-            let rep_levels =
-                HybridRleDecoder::new(rep_levels, get_bit_width(rep_level_encoding.1), additional);
+            let rep_levels = utils::hybrid_rle_decoder(
+                rep_levels,
+                get_bit_width(rep_level_encoding.1),
+                additional,
+            )?;
+            let def_levels = utils::hybrid_rle_decoder(
+                def_levels,
+                get_bit_width(def_level_encoding.1),
+                additional,
+            )?;
             if is_nullable {
-                let def_levels = HybridRleDecoder::new(
-                    def_levels,
-                    get_bit_width(def_level_encoding.1),
-                    additional,
-                );
-                read_values(def_levels, max_def_level, new_values, op, values, validity)
+                let new_values = ExactChunksIter::<T>::new(values_buffer);
+                read_values(
+                    def_levels.clone(),
+                    max_def_level,
+                    new_values,
+                    op,
+                    values,
+                    validity,
+                )?
             } else {
+                let num_values = count_values(def_levels.clone(), max_def_level);
+                let values_buffer = values_buffer
+                    .get(..num_values * std::mem::size_of::<T>())
+                    .ok_or_else(utils::missing_values)?;
+                let new_values = ExactChunksIter::<T>::new(values_buffer);
                 read_values_required(new_values, op, values)
             }
 
-            let def_levels =
-                HybridRleDecoder::new(def_levels, get_bit_width(def_level_encoding.1), additional);
-
             extend_offsets(
                 rep_levels,
                 def_levels,
@@ -99,7 +113,10 @@ fn read<T, A, F>(
                 nested,
             )
         }
-        _ => todo!(),
+        _ => Err(ArrowError::NotYetImplemented(format!(
+            "Decoding levels of encodings {:?} and {:?}",
+            rep_level_encoding.0, def_level_encoding.0
+        ))),
     }
 }
 
@@ -119,7 +136,7 @@ where
 {
     let additional = page.num_values();
 
-    let (rep_levels, def_levels, values_buffer, version) = utils::split_buffer(page, descriptor);
+    let (rep_levels, def_levels, values_buffer, version) = utils::split_buffer(page, descriptor)?;
 
     match (&page.encoding(), page.dictionary_page()) {
         (Encoding::Plain, None) => read(
@@ -141,15 +158,12 @@ where
             validity,
             op,
         ),
-        _ => {
-            return Err(utils::not_implemented(
-                &page.encoding(),
-                is_nullable,
-                page.dictionary_page().is_some(),
-                version,
-                "primitive",
-            ))
-        }
+        _ => Err(utils::not_implemented(
+            &page.encoding(),
+            is_nullable,
+            page.dictionary_page().is_some(),
+            version,
+            "primitive",
+        )),
     }
-    Ok(())
 }
@@ -10,9 +10,10 @@ pub struct ExactChunksIter<'a, T: NativeType> {
 }
 
 impl<'a, T: NativeType> ExactChunksIter<'a, T> {
+    /// Returns an iterator over the values of `slice`, ignoring its trailing bytes
+    /// when its length is not a multiple of the size of `T`.
     #[inline]
     pub fn new(slice: &'a [u8]) -> Self {
-        assert_eq!(slice.len() % std::mem::size_of::<T>(), 0);
         let chunks = slice.chunks_exact(std::mem::size_of::<T>());
         Self {
             chunks,
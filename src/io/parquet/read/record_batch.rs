@@ -1,22 +1,31 @@
 use std::{
+    collections::VecDeque,
     io::{Read, Seek},
     sync::Arc,
 };
 
+use parquet2::read::get_field_columns;
+
 use crate::{
+    array::{growable::make_growable, Array},
     datatypes::{Field, Schema},
-    error::{ArrowError, Result},
+    error::{ArrowError, ErrorContext, Location, Result},
+    io::{HooksState, ReadHooks},
     record_batch::RecordBatch,
 };
 
 use super::{
-    column_iter_to_array, get_column_iterator, get_schema, read_metadata, FileMetaData, PageFilter,
-    RowGroupMetaData,
+    column_iter_to_array, get_column_iterator, get_page_iterator, get_schema, is_flat,
+    page_to_array, read_metadata, ColumnChunkMetaData, CompressedDataPage, FileMetaData,
+    PageFilter, RowGroupMetaData,
 };
 
 type GroupFilter = Arc<dyn Fn(usize, &RowGroupMetaData) -> bool>;
 
 /// Single threaded iterator of [`RecordBatch`] from a parquet file.
+///
+/// Its errors are wrapped in an [`ArrowError::WithContext`] with the column and the
+/// location (row group and page) where they happened.
 pub struct RecordReader<R: Read + Seek> {
     reader: R,
     schema: Arc<Schema>,
@@ -28,6 +37,107 @@ pub struct RecordReader<R: Read + Seek> {
     metadata: FileMetaData,
     current_group: usize,
     remaining_rows: usize,
+    chunk_size: Option<usize>,
+    // the row group being read in chunks
+    current_chunk: Option<ChunkedGroup>,
+    hooks: HooksState,
+}
+
+/// A column of a row group being read in chunks.
+enum ChunkedColumn {
+    /// A flat column, deserialized page by page: its pages not deserialized yet and the
+    /// deserialized arrays whose rows were not returned yet.
+    Pages {
+        metadata: ColumnChunkMetaData,
+        pages: VecDeque<CompressedDataPage>,
+        arrays: VecDeque<Arc<dyn Array>>,
+        read_pages: usize,
+    },
+    /// A nested column, whose records may span pages, deserialized in full, and the offset
+    /// of its next chunk.
+    Whole(Arc<dyn Array>, usize),
+}
+
+/// A row group being read in chunks.
+struct ChunkedGroup {
+    row_group: usize,
+    columns: Vec<ChunkedColumn>,
+    // the rows of the row group not returned yet
+    remaining: usize,
+}
+
+/// Returns the next `length` rows of `column`, deserializing its pages as needed.
+fn next_rows(
+    column: &mut ChunkedColumn,
+    length: usize,
+    field: &Field,
+    buffer: &mut Vec<u8>,
+) -> Result<Arc<dyn Array>> {
+    let (metadata, pages, arrays, read_pages) = match column {
+        ChunkedColumn::Pages {
+            metadata,
+            pages,
+            arrays,
+            read_pages,
+        } => (metadata, pages, arrays, read_pages),
+        ChunkedColumn::Whole(array, offset) => {
+            let result = array.slice(*offset, length).into();
+            *offset += length;
+            return Ok(result);
+        }
+    };
+
+    let mut available = arrays.iter().map(|array| array.len()).sum::<usize>();
+    while available < length {
+        let page = pages.pop_front().ok_or_else(|| {
+            ArrowError::OutOfSpec(
+                "The pages of a column chunk have less rows than its row group".to_string(),
+            )
+        })?;
+        let (array, new_buffer) = page_to_array(page, metadata, field, std::mem::take(buffer))
+            .map_err(|e| {
+                e.with_context(
+                    ErrorContext::default()
+                        .with_byte_offset(metadata.byte_range().0)
+                        .with_location(Location::ParquetPage {
+                            row_group: None,
+                            column_chunk: Some(0),
+                            page: Some(*read_pages),
+                        }),
+                )
+            })?;
+        *buffer = new_buffer;
+        *read_pages += 1;
+        available += array.len();
+        arrays.push_back(array.into());
+    }
+
+    let mut pieces: Vec<Arc<dyn Array>> = vec![];
+    let mut needed = length;
+    while needed > 0 {
+        // there are at least `needed` rows in `arrays`
+        let array = arrays.pop_front().unwrap();
+        if array.len() > needed {
+            pieces.push(array.slice(0, needed).into());
+            arrays.push_front(array.slice(needed, array.len() - needed).into());
+            needed = 0;
+        } else {
+            needed -= array.len();
+            pieces.push(array);
+        }
+    }
+
+    Ok(if pieces.len() == 1 {
+        pieces.pop().unwrap()
+    } else {
+        let pieces = pieces.iter().map(|x| x.as_ref()).collect::<Vec<_>>();
+        let use_validity = pieces.iter().any(|x| x.null_count() > 0);
+        let mut growable = make_growable(&pieces, use_validity, length);
+        for (index, piece) in pieces.iter().enumerate() {
+            growable.extend(index, 0, piece.len());
+        }
+        growable.as_arc()
+    })
 }
 
 impl<R: Read + Seek> RecordReader<R> {
@@ -87,6 +197,9 @@ impl<R: Read + Seek> RecordReader<R> {
             buffer: vec![],
             decompress_buffer: vec![],
             remaining_rows: limit.unwrap_or(usize::MAX),
+            chunk_size: None,
+            current_chunk: None,
+            hooks: HooksState::default(),
         })
     }
 
@@ -104,73 +217,241 @@ impl<R: Read + Seek> RecordReader<R> {
     pub fn set_groups_filter(&mut self, groups_filter: GroupFilter) {
         self.groups_filter = Some(groups_filter);
     }
-}
 
-impl<R: Read + Seek> Iterator for RecordReader<R> {
-    type Item = Result<RecordBatch>;
+    /// Sets the maximum number of rows of the [`RecordBatch`]es returned by this iterator.
+    /// By default, each row group is returned as a single [`RecordBatch`].
+    ///
+    /// When set, row groups are deserialized in chunks of `chunk_size` rows (the last chunk of
+    /// a row group may be smaller): the compressed pages of each column of a row group are read
+    /// and deserialized one at a time, as the chunks need them. Columns of nested types
+    /// (lists and structs), whose records may span pages, are still deserialized in full.
+    /// # Panics
+    /// Panics iff `chunk_size` is zero.
+    pub fn set_chunk_size(&mut self, chunk_size: usize) {
+        assert!(chunk_size > 0, "The chunk size must be larger than zero");
+        self.chunk_size = Some(chunk_size);
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.schema.fields().is_empty() {
-            return None;
-        }
-        if self.current_group == self.metadata.row_groups.len() {
-            return None;
-        };
-        let remaining_rows = self.remaining_rows;
-        if remaining_rows == 0 {
-            return None;
-        }
+    /// Sets the [`ReadHooks`] of this reader, which are checked for cancellation before reading
+    /// each column chunk and notified of the progress after returning each [`RecordBatch`].
+    /// The bytes of the progress are the compressed sizes of the column chunks read.
+    pub fn set_hooks(&mut self, hooks: Arc<dyn ReadHooks>) {
+        self.hooks.set_hooks(hooks);
+    }
 
-        let row_group = self.current_group;
-        let metadata = &self.metadata;
-        let group = &metadata.row_groups[row_group];
-        if let Some(groups_filter) = self.groups_filter.as_ref() {
-            if !(groups_filter)(row_group, group) {
-                self.current_group += 1;
-                return self.next();
-            }
+    /// Reads and deserializes the column of `row_group` of the field at `index` of the schema.
+    fn read_array(&mut self, row_group: usize, index: usize) -> Result<Box<dyn Array>> {
+        let schema = self.schema.clone();
+        let field = &schema.fields()[index];
+        let field_index = self.indices[index]; // project into the original schema
+
+        let column_iter = get_column_iterator(
+            &mut self.reader,
+            &self.metadata,
+            row_group,
+            field_index,
+            self.pages_filter.clone(),
+            std::mem::take(&mut self.buffer),
+        );
+        let (array, b1, b2) = column_iter_to_array(
+            column_iter,
+            field,
+            std::mem::take(&mut self.decompress_buffer),
+        )
+        .map_err(|e| {
+            e.with_context(
+                ErrorContext::default()
+                    .with_column(field.name().as_str())
+                    .with_location(Location::ParquetPage {
+                        row_group: Some(row_group),
+                        column_chunk: None,
+                        page: None,
+                    }),
+            )
+        })?;
+        self.buffer = b1;
+        self.decompress_buffer = b2;
+
+        let bytes = get_field_columns(
+            &self.metadata,
+            row_group,
+            &self.metadata.schema().fields()[field_index],
+        )
+        .map(|column| column.compressed_size() as u64)
+        .sum();
+        self.hooks.add_bytes(bytes);
+        Ok(array)
+    }
+
+    /// Reads the compressed pages of the column of `row_group` of the field at `index` of
+    /// the schema, or deserializes it in full when it cannot be deserialized page by page.
+    fn read_chunked_column(&mut self, row_group: usize, index: usize) -> Result<ChunkedColumn> {
+        let schema = self.schema.clone();
+        let field = &schema.fields()[index];
+        if !is_flat(field.data_type()) {
+            return Ok(ChunkedColumn::Whole(
+                self.read_array(row_group, index)?.into(),
+                0,
+            ));
         }
+        let field_index = self.indices[index]; // project into the original schema
 
-        // todo: avoid these clones.
-        let schema = self.schema().clone();
+        let metadata = get_field_columns(
+            &self.metadata,
+            row_group,
+            &self.metadata.schema().fields()[field_index],
+        )
+        .next()
+        .cloned()
+        .ok_or_else(|| {
+            ArrowError::OutOfSpec(format!(
+                "The row group {} has no column chunk for the field \"{}\"",
+                row_group,
+                field.name()
+            ))
+        })?;
+        let pages = get_page_iterator(
+            &metadata,
+            &mut self.reader,
+            self.pages_filter.clone(),
+            vec![],
+        )
+        .and_then(|pages| Ok(pages.collect::<std::result::Result<VecDeque<_>, _>>()?))
+        .map_err(|e| {
+            e.with_context(
+                ErrorContext::default()
+                    .with_column(field.name().as_str())
+                    .with_location(Location::ParquetPage {
+                        row_group: Some(row_group),
+                        column_chunk: Some(0),
+                        page: None,
+                    }),
+            )
+        })?;
+        self.hooks.add_bytes(metadata.compressed_size() as u64);
 
-        let b1 = std::mem::take(&mut self.buffer);
-        let b2 = std::mem::take(&mut self.decompress_buffer);
+        Ok(ChunkedColumn::Pages {
+            metadata,
+            pages,
+            arrays: VecDeque::new(),
+            read_pages: 0,
+        })
+    }
 
-        let a = schema.fields().iter().enumerate().try_fold(
-            (b1, b2, Vec::with_capacity(schema.fields().len())),
-            |(b1, b2, mut columns), (field_index, field)| {
-                let field_index = self.indices[field_index]; // project into the original schema
-                let column_iter = get_column_iterator(
-                    &mut self.reader,
-                    &self.metadata,
-                    row_group,
-                    field_index,
-                    self.pages_filter.clone(),
-                    b1,
-                );
+    /// Returns the index of the next row group to read, if any.
+    fn next_group_index(&mut self) -> Option<usize> {
+        if self.schema.fields().is_empty() || self.remaining_rows == 0 {
+            return None;
+        }
+        while self.current_group < self.metadata.row_groups.len() {
+            let row_group = self.current_group;
+            self.current_group += 1;
+            let group = &self.metadata.row_groups[row_group];
+            match self.groups_filter.as_ref() {
+                Some(groups_filter) if !(groups_filter)(row_group, group) => continue,
+                _ => return Some(row_group),
+            }
+        }
+        None
+    }
 
-                let (array, b1, b2) = column_iter_to_array(column_iter, field, b2)?;
+    fn next_group(&mut self) -> Option<Result<RecordBatch>> {
+        let row_group = self.next_group_index()?;
+        let remaining_rows = self.remaining_rows;
 
+        let columns = (0..self.schema.fields().len())
+            .map(|index| {
+                self.hooks.check_cancelled()?;
+                let array = self.read_array(row_group, index)?;
                 let array = if array.len() > remaining_rows {
                     array.slice(0, remaining_rows)
                 } else {
                     array
                 };
+                Ok(array.into())
+            })
+            .collect::<Result<Vec<_>>>();
 
-                columns.push(array.into());
-                Result::Ok((b1, b2, columns))
-            },
-        );
-
-        self.current_group += 1;
-        Some(a.and_then(|(b1, b2, columns)| {
-            self.buffer = b1;
-            self.decompress_buffer = b2;
+        Some(columns.and_then(|columns| {
             RecordBatch::try_new(self.schema.clone(), columns).map(|batch| {
                 self.remaining_rows -= batch.num_rows();
                 batch
             })
         }))
     }
+
+    fn next_chunk(&mut self, chunk_size: usize) -> Option<Result<RecordBatch>> {
+        while self
+            .current_chunk
+            .as_ref()
+            .map(|group| group.remaining == 0)
+            .unwrap_or(true)
+        {
+            self.current_chunk = None;
+            let row_group = self.next_group_index()?;
+            let columns = (0..self.schema.fields().len())
+                .map(|index| {
+                    self.hooks.check_cancelled()?;
+                    self.read_chunked_column(row_group, index)
+                })
+                .collect::<Result<Vec<_>>>();
+            let columns = match columns {
+                Ok(columns) => columns,
+                Err(error) => return Some(Err(error)),
+            };
+            self.current_chunk = Some(ChunkedGroup {
+                row_group,
+                columns,
+                remaining: self.metadata.row_groups[row_group].num_rows() as usize,
+            });
+        }
+        if self.remaining_rows == 0 {
+            return None;
+        }
+
+        let group = self.current_chunk.as_mut().unwrap();
+        let length = chunk_size.min(group.remaining).min(self.remaining_rows);
+        let row_group = group.row_group;
+        let buffer = &mut self.decompress_buffer;
+        let columns = group
+            .columns
+            .iter_mut()
+            .zip(self.schema.fields().iter())
+            .map(|(column, field)| {
+                next_rows(column, length, field, buffer).map_err(|e| {
+                    e.with_context(
+                        ErrorContext::default()
+                            .with_column(field.name().as_str())
+                            .with_location(Location::ParquetPage {
+                                row_group: Some(row_group),
+                                column_chunk: None,
+                                page: None,
+                            }),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>>>();
+        group.remaining -= length;
+        self.remaining_rows -= length;
+
+        Some(columns.and_then(|columns| RecordBatch::try_new(self.schema.clone(), columns)))
+    }
+}
+
+impl<R: Read + Seek> Iterator for RecordReader<R> {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.hooks.is_cancelled() {
+            return None;
+        }
+        let batch = match self.chunk_size {
+            Some(chunk_size) => self.next_chunk(chunk_size)?,
+            None => self.next_group()?,
+        };
+        if let Ok(batch) = &batch {
+            self.hooks.add_rows(batch.num_rows());
+        }
+        Some(batch)
+    }
 }
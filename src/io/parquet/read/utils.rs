@@ -1,10 +1,11 @@
-use std::convert::TryInto;
+use std::convert::{TryFrom, TryInto};
 
-use parquet2::encoding::Encoding;
+use parquet2::encoding::{bitpacking, hybrid_rle, Encoding};
 use parquet2::metadata::ColumnDescriptor;
-use parquet2::page::{split_buffer as _split_buffer, DataPage, DataPageHeader};
+use parquet2::page::{DataPage, DataPageHeader, DictPage};
 
-use crate::error::ArrowError;
+use crate::bitmap::utils::{bytes_for, count_zeros};
+use crate::error::{ArrowError, Result};
 
 pub struct BinaryIter<'a> {
     values: &'a [u8],
@@ -19,15 +20,14 @@ impl<'a> BinaryIter<'a> {
 impl<'a> Iterator for BinaryIter<'a> {
     type Item = &'a [u8];
 
+    /// Returns `None` when the values end or are truncated; callers error when they
+    /// expect more values.
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.values.is_empty() {
-            return None;
-        }
-        let length = u32::from_le_bytes(self.values[0..4].try_into().unwrap()) as usize;
-        self.values = &self.values[4..];
-        let result = &self.values[..length];
-        self.values = &self.values[length..];
+        let length = self.values.get(0..4)?;
+        let length = u32::from_le_bytes(length.try_into().unwrap()) as usize;
+        let result = self.values[4..].get(..length)?;
+        self.values = &self.values[4 + length..];
         Some(result)
     }
 }
@@ -47,15 +47,347 @@ pub fn not_implemented(
     ))
 }
 
+/// Returns the error of a page declaring more values than it contains.
+pub fn missing_values() -> ArrowError {
+    ArrowError::ExternalFormat("The page contains fewer values than it declares".to_string())
+}
+
+fn out_of_bounds(what: &str) -> ArrowError {
+    ArrowError::ExternalFormat(format!(
+        "The {} of the page are out of the bounds of its buffer",
+        what
+    ))
+}
+
+/// Splits the buffer of `page` into its repetition levels, definition levels and values.
+/// Unlike [`parquet2::page::split_buffer`], it errors when the page is malformed.
 pub fn split_buffer<'a>(
     page: &'a DataPage,
     descriptor: &ColumnDescriptor,
-) -> (&'a [u8], &'a [u8], &'a [u8], &'static str) {
-    let (rep_levels, validity_buffer, values_buffer) = _split_buffer(page, descriptor);
+) -> Result<(&'a [u8], &'a [u8], &'a [u8], &'static str)> {
+    // splits a V1 level buffer, prefixed by its length
+    fn split_levels<'a>(buffer: &'a [u8], what: &str) -> Result<(&'a [u8], &'a [u8])> {
+        let length = buffer.get(0..4).ok_or_else(|| out_of_bounds(what))?;
+        let length = u32::from_le_bytes(length.try_into().unwrap()) as usize;
+        let buffer = &buffer[4..];
+        if length > buffer.len() {
+            return Err(out_of_bounds(what));
+        }
+        Ok(buffer.split_at(length))
+    }
+
+    let buffer = page.buffer();
+    match page.header() {
+        DataPageHeader::V1(_) => {
+            let (rep_levels, buffer) = if descriptor.max_rep_level() > 0 {
+                split_levels(buffer, "repetition levels")?
+            } else {
+                (&[] as &[u8], buffer)
+            };
+            let (def_levels, values) = if descriptor.max_def_level() > 0 {
+                split_levels(buffer, "definition levels")?
+            } else {
+                (&[] as &[u8], buffer)
+            };
+            Ok((rep_levels, def_levels, values, "V1"))
+        }
+        DataPageHeader::V2(header) => {
+            let rep_length = usize::try_from(header.repetition_levels_byte_length)
+                .map_err(|_| out_of_bounds("repetition levels"))?;
+            let def_length = usize::try_from(header.definition_levels_byte_length)
+                .map_err(|_| out_of_bounds("definition levels"))?;
+            if rep_length > buffer.len() {
+                return Err(out_of_bounds("repetition levels"));
+            }
+            let (rep_levels, buffer) = buffer.split_at(rep_length);
+            if def_length > buffer.len() {
+                return Err(out_of_bounds("definition levels"));
+            }
+            let (def_levels, values) = buffer.split_at(def_length);
+            Ok((rep_levels, def_levels, values, "V2"))
+        }
+    }
+}
+
+/// Checks that `buffer` is a valid hybrid RLE-bitpacked encoding of at least `length`
+/// values of `num_bits` bits, so that the decoders of `parquet2` do not panic on it.
+pub fn check_hybrid_rle(buffer: &[u8], num_bits: u32, length: usize) -> Result<()> {
+    if num_bits > 32 {
+        return Err(ArrowError::ExternalFormat(format!(
+            "The bit width of hybrid RLE-bitpacked values must be at most 32, got {}",
+            num_bits
+        )));
+    }
+    if num_bits == 0 {
+        // all values are zero, and no run is read
+        return Ok(());
+    }
+    let malformed = |reason: &str| {
+        ArrowError::ExternalFormat(format!("Malformed hybrid RLE-bitpacked values: {}", reason))
+    };
+
+    let mut buffer = buffer;
+    let mut decoded = 0usize;
+    while decoded < length {
+        if buffer.is_empty() {
+            return Err(malformed("the runs contain fewer values than declared"));
+        }
+        let (indicator, consumed) =
+            decode_uleb128(buffer).ok_or_else(|| malformed("invalid run header"))?;
+        buffer = &buffer[consumed..];
+        if indicator & 1 == 1 {
+            let bytes = ((indicator >> 1) as usize)
+                .checked_mul(num_bits as usize)
+                .ok_or_else(|| malformed("the length of a bitpacked run overflows"))?;
+            let bytes = bytes.min(buffer.len());
+            if bytes == 0 {
+                return Err(malformed("empty bitpacked run"));
+            }
+            buffer = &buffer[bytes..];
+            decoded = decoded.saturating_add(bytes * 8 / num_bits as usize);
+        } else {
+            let value_bytes = bytes_for(num_bits as usize);
+            if value_bytes > buffer.len() {
+                return Err(malformed("truncated RLE run"));
+            }
+            buffer = &buffer[value_bytes..];
+            decoded = decoded.saturating_add((indicator >> 1) as usize);
+        }
+    }
+    Ok(())
+}
+
+// decodes an unsigned LEB128 integer of at most 64 bits, returning it and the number of bytes consumed
+fn decode_uleb128(values: &[u8]) -> Option<(u64, usize)> {
+    let mut result = 0u64;
+    for (i, byte) in values.iter().enumerate().take(10) {
+        let shift = 7 * i as u32;
+        if shift == 63 && *byte > 1 {
+            return None;
+        }
+        result |= u64::from(byte & 0b01111111) << shift;
+        if byte & 0b10000000 == 0 {
+            return Some((result, i + 1));
+        }
+    }
+    None
+}
+
+/// Returns a decoder of the dictionary indices of a data page, `indices_buffer`, with
+/// `length` indices.
+pub fn dict_indices_decoder(
+    indices_buffer: &[u8],
+    length: usize,
+) -> Result<hybrid_rle::HybridRleDecoder<'_>> {
+    // SPEC: Data page format: the bit width used to encode the entry ids stored as 1 byte (max bit width = 32),
+    // SPEC: followed by the values encoded using RLE/Bit packed described above (with the given bit width).
+    let (bit_width, indices_buffer) = indices_buffer
+        .split_first()
+        .ok_or_else(|| out_of_bounds("dictionary indices"))?;
+    hybrid_rle_decoder(indices_buffer, *bit_width as u32, length)
+}
+
+/// Returns a decoder of `length` hybrid RLE-bitpacked values of `num_bits` bits,
+/// e.g. repetition or definition levels.
+/// # Errors
+/// Errors iff `buffer` is malformed (see [`check_hybrid_rle`]).
+pub fn hybrid_rle_decoder(
+    buffer: &[u8],
+    num_bits: u32,
+    length: usize,
+) -> Result<hybrid_rle::HybridRleDecoder<'_>> {
+    if length == 0 {
+        // the decoder reads the first run on creation, which an empty buffer does not have
+        return Ok(hybrid_rle::HybridRleDecoder::new(&[], 0, 0));
+    }
+    check_hybrid_rle(buffer, num_bits, length)?;
+    Ok(hybrid_rle::HybridRleDecoder::new(buffer, num_bits, length))
+}
+
+/// Checks that `index` is a valid index of a dictionary with `length` values.
+#[inline]
+pub fn check_dict_index(index: u32, length: usize) -> Result<usize> {
+    let index = index as usize;
+    if index < length {
+        Ok(index)
+    } else {
+        Err(ArrowError::ExternalFormat(format!(
+            "The dictionary index {} is out of bounds of a dictionary with {} values",
+            index, length
+        )))
+    }
+}
+
+/// An iterator over the runs of a validity (definition levels of bit width 1) that stops
+/// after `length` values, whose last RLE run is clamped to them.
+#[derive(Debug, Clone)]
+pub struct ValidityRuns<'a> {
+    decoder: hybrid_rle::Decoder<'a>,
+    remaining: usize,
+}
+
+/// Returns the runs of the first `length` values of the validity `buffer`.
+/// # Errors
+/// Errors iff `buffer` is malformed (see [`check_hybrid_rle`]).
+pub fn validity_runs(buffer: &[u8], length: usize) -> Result<ValidityRuns<'_>> {
+    check_hybrid_rle(buffer, 1, length)?;
+    Ok(ValidityRuns {
+        decoder: hybrid_rle::Decoder::new(buffer, 1),
+        remaining: length,
+    })
+}
+
+/// Returns the number of valid values of the first `length` values of the validity `buffer`.
+/// # Errors
+/// Errors iff `buffer` is malformed (see [`check_hybrid_rle`]).
+pub fn count_valid(buffer: &[u8], length: usize) -> Result<usize> {
+    let mut remaining = length;
+    let mut valid = 0;
+    for run in validity_runs(buffer, length)? {
+        match run {
+            hybrid_rle::HybridEncoded::Bitpacked(packed) => {
+                let len = std::cmp::min(packed.len() * 8, remaining);
+                valid += len - count_zeros(packed, 0, len);
+                remaining -= len;
+            }
+            hybrid_rle::HybridEncoded::Rle(value, len) => {
+                if value[0] == 1 {
+                    valid += len;
+                }
+                remaining -= len;
+            }
+        }
+    }
+    Ok(valid)
+}
+
+impl<'a> Iterator for ValidityRuns<'a> {
+    type Item = hybrid_rle::HybridEncoded<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        match self.decoder.next()? {
+            hybrid_rle::HybridEncoded::Bitpacked(packed) => {
+                self.remaining -= std::cmp::min(packed.len() * 8, self.remaining);
+                Some(hybrid_rle::HybridEncoded::Bitpacked(packed))
+            }
+            hybrid_rle::HybridEncoded::Rle(value, length) => {
+                let length = std::cmp::min(length, self.remaining);
+                self.remaining -= length;
+                Some(hybrid_rle::HybridEncoded::Rle(value, length))
+            }
+        }
+    }
+}
 
-    let version = match page.header() {
-        DataPageHeader::V1(_) => "V1",
-        DataPageHeader::V2(_) => "V2",
+/// Decodes `DELTA_BINARY_PACKED`-encoded values of at most `max_length` values, returning
+/// them and the number of bytes they occupy in `buffer`.
+///
+/// Unlike the decoder of `parquet2`, it errors when `buffer` is malformed.
+pub fn decode_delta_bitpacked(buffer: &[u8], max_length: usize) -> Result<(Vec<i64>, usize)> {
+    let malformed = |reason: &str| {
+        ArrowError::ExternalFormat(format!("Malformed delta-bitpacked values: {}", reason))
     };
-    (rep_levels, validity_buffer, values_buffer, version)
+    let mut values_buffer = buffer;
+    let next_uleb128 = |buffer: &mut &[u8]| {
+        let (value, consumed) =
+            decode_uleb128(buffer).ok_or_else(|| malformed("invalid integer"))?;
+        *buffer = &buffer[consumed..];
+        Result::Ok(value)
+    };
+    let zigzag = |value: u64| (value >> 1) as i64 ^ -((value & 1) as i64);
+
+    let block_size = next_uleb128(&mut values_buffer)?;
+    let num_mini_blocks = next_uleb128(&mut values_buffer)?;
+    let total_count = next_uleb128(&mut values_buffer)?;
+    let first_value = zigzag(next_uleb128(&mut values_buffer)?);
+
+    if block_size == 0 || block_size % 128 != 0 {
+        return Err(malformed("the block size must be a multiple of 128"));
+    }
+    if num_mini_blocks == 0 || block_size % num_mini_blocks != 0 {
+        return Err(malformed(
+            "the number of miniblocks must divide the block size",
+        ));
+    }
+    let values_per_mini_block = usize::try_from(block_size / num_mini_blocks)
+        .map_err(|_| malformed("the block size is too large"))?;
+    if values_per_mini_block % 8 != 0 {
+        return Err(malformed(
+            "the number of values per miniblock must be a multiple of 8",
+        ));
+    }
+    let num_mini_blocks = num_mini_blocks as usize;
+    let total_count = match usize::try_from(total_count) {
+        Ok(total_count) if total_count <= max_length => total_count,
+        _ => {
+            return Err(malformed(&format!(
+                "the number of values ({}) exceeds the number of values of the page ({})",
+                total_count, max_length
+            )))
+        }
+    };
+
+    let mut values = Vec::with_capacity(total_count);
+    if total_count == 0 {
+        return Ok((values, buffer.len() - values_buffer.len()));
+    }
+    values.push(first_value);
+    let mut last = first_value;
+    while values.len() < total_count {
+        let min_delta = zigzag(next_uleb128(&mut values_buffer)?);
+        if values_buffer.len() < num_mini_blocks {
+            return Err(malformed("truncated block"));
+        }
+        let (bit_widths, remaining) = values_buffer.split_at(num_mini_blocks);
+        values_buffer = remaining;
+
+        for &num_bits in bit_widths {
+            if values.len() == total_count {
+                break;
+            }
+            let length = std::cmp::min(values_per_mini_block, total_count - values.len());
+            if num_bits == 0 {
+                for _ in 0..length {
+                    last = last.wrapping_add(min_delta);
+                    values.push(last);
+                }
+                continue;
+            }
+            if num_bits > 32 {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Decoding delta-bitpacked values of {} bits",
+                    num_bits
+                )));
+            }
+            let size = values_per_mini_block
+                .checked_mul(num_bits as usize)
+                .map(bytes_for)
+                .filter(|size| *size <= values_buffer.len())
+                .ok_or_else(|| malformed("truncated miniblock"))?;
+            let (mini_block, remaining) = values_buffer.split_at(size);
+            values_buffer = remaining;
+
+            for delta in bitpacking::Decoder::new(mini_block, num_bits, length) {
+                last = last.wrapping_add(min_delta).wrapping_add(delta as i64);
+                values.push(last);
+            }
+        }
+    }
+    Ok((values, buffer.len() - values_buffer.len()))
+}
+
+/// Downcasts the dictionary page of a data page to its concrete type.
+/// # Errors
+/// Errors iff the dictionary page is not of type `T`, e.g. when it does not match the
+/// physical type of the column.
+pub fn downcast_dict<T: 'static>(dict: &dyn DictPage) -> Result<&T> {
+    dict.as_any().downcast_ref().ok_or_else(|| {
+        ArrowError::ExternalFormat(format!(
+            "The dictionary page of physical type {:?} does not match the column",
+            dict.physical_type()
+        ))
+    })
 }
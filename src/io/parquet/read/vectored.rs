@@ -0,0 +1,199 @@
+//! APIs to read the column chunks of a row group in a single pass, e.g. from remote storage.
+//!
+//! The typical usage is to compute the byte ranges of the projected fields with
+//! [`get_field_ranges`], coalesce them with [`coalesce_ranges`], fetch them (e.g. via one
+//! vectored request to an object store), and decode them from the resulting [`RangesReader`]
+//! with the same APIs used to read from a file (e.g. [`super::get_column_iterator`]).
+use std::future::Future;
+use std::io::{Read, Seek, SeekFrom};
+
+use futures::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
+use parquet2::read::get_field_columns;
+
+use super::FileMetaData;
+use crate::error::{ArrowError, Result};
+
+/// Returns the byte ranges, as `(start, length)`, of all column chunks of `fields`
+/// in the row group `row_group`, sorted by `start`.
+pub fn get_field_ranges(
+    metadata: &FileMetaData,
+    row_group: usize,
+    fields: &[usize],
+) -> Vec<(u64, u64)> {
+    let mut ranges = fields
+        .iter()
+        .flat_map(|field| {
+            get_field_columns(metadata, row_group, &metadata.schema().fields()[*field])
+        })
+        .map(|column| column.byte_range())
+        .collect::<Vec<_>>();
+    ranges.sort_unstable();
+    ranges
+}
+
+/// Coalesces `ranges`, as `(start, length)`, whose gap between them is at most `max_gap` bytes,
+/// thereby trading reading unused bytes for fewer requests.
+/// The result is sorted by `start` and its ranges do not overlap.
+pub fn coalesce_ranges(ranges: &[(u64, u64)], max_gap: u64) -> Vec<(u64, u64)> {
+    let mut ranges = ranges.to_vec();
+    ranges.sort_unstable();
+
+    let mut result: Vec<(u64, u64)> = Vec::with_capacity(ranges.len());
+    for (start, length) in ranges {
+        match result.last_mut() {
+            Some((last_start, last_length))
+                if start <= (*last_start + *last_length).saturating_add(max_gap) =>
+            {
+                let end = (*last_start + *last_length).max(start + length);
+                *last_length = end - *last_start;
+            }
+            _ => result.push((start, length)),
+        }
+    }
+    result
+}
+
+/// A [`Read`] and [`Seek`] over a set of byte ranges of a file held in memory.
+/// Reading from a position not covered by any of the ranges errors.
+#[derive(Debug, Clone)]
+pub struct RangesReader {
+    ranges: Vec<(u64, Vec<u8>)>,
+    position: u64,
+}
+
+impl RangesReader {
+    /// Creates a new [`RangesReader`] from pairs of `(start, data)` of a file.
+    /// # Errors
+    /// Errors iff the ranges overlap.
+    pub fn try_new(mut ranges: Vec<(u64, Vec<u8>)>) -> Result<Self> {
+        ranges.sort_unstable_by_key(|(start, _)| *start);
+        if ranges
+            .windows(2)
+            .any(|w| w[0].0 + w[0].1.len() as u64 > w[1].0)
+        {
+            return Err(ArrowError::InvalidArgumentError(
+                "The ranges of a RangesReader must not overlap".to_string(),
+            ));
+        }
+        Ok(Self {
+            ranges,
+            position: 0,
+        })
+    }
+
+    /// Returns the ranges, as `(start, data)`, of this reader.
+    pub fn ranges(&self) -> &[(u64, Vec<u8>)] {
+        &self.ranges
+    }
+}
+
+impl Read for RangesReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let position = self.position;
+        let range = self
+            .ranges
+            .iter()
+            .find(|(start, data)| *start <= position && position < start + data.len() as u64);
+        let (start, data) = match range {
+            Some(range) => range,
+            None if buf.is_empty() => return Ok(0),
+            None => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    format!("The position {} was not fetched", position),
+                ))
+            }
+        };
+        let data = &data[(position - start) as usize..];
+        let read = data.len().min(buf.len());
+        buf[..read].copy_from_slice(&data[..read]);
+        self.position += read as u64;
+        Ok(read)
+    }
+}
+
+impl Seek for RangesReader {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(position) => Some(position),
+            SeekFrom::Current(offset) => {
+                if offset >= 0 {
+                    self.position.checked_add(offset as u64)
+                } else {
+                    self.position.checked_sub(offset.unsigned_abs())
+                }
+            }
+            SeekFrom::End(_) => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    "RangesReader does not know the length of the file",
+                ))
+            }
+        };
+        self.position = position.ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+        Ok(self.position)
+    }
+}
+
+/// Fetches all column chunks of `fields` in the row group `row_group` by calling `fetch` once
+/// with the coalesced ranges (see [`coalesce_ranges`]), as `(start, length)`.
+/// `fetch` must return the data of each range, in the same order.
+/// # Errors
+/// Errors iff `fetch` errors or returns a different number of ranges or ranges of
+/// different lengths than requested.
+pub async fn read_ranges_vectored<F, Fut>(
+    fetch: F,
+    metadata: &FileMetaData,
+    row_group: usize,
+    fields: &[usize],
+    max_gap: u64,
+) -> Result<RangesReader>
+where
+    F: FnOnce(Vec<(u64, u64)>) -> Fut,
+    Fut: Future<Output = Result<Vec<Vec<u8>>>>,
+{
+    let ranges = coalesce_ranges(&get_field_ranges(metadata, row_group, fields), max_gap);
+    let data = fetch(ranges.clone()).await?;
+    if data.len() != ranges.len()
+        || ranges
+            .iter()
+            .zip(data.iter())
+            .any(|((_, length), data)| *length != data.len() as u64)
+    {
+        return Err(ArrowError::ExternalFormat(
+            "The fetched data does not correspond to the requested ranges".to_string(),
+        ));
+    }
+    RangesReader::try_new(
+        ranges
+            .into_iter()
+            .map(|(start, _)| start)
+            .zip(data)
+            .collect(),
+    )
+}
+
+/// Fetches all column chunks of `fields` in the row group `row_group` from `reader`,
+/// issuing one read per coalesced range (see [`coalesce_ranges`]).
+pub async fn read_ranges_async<R: AsyncRead + AsyncSeek + Send + Unpin>(
+    reader: &mut R,
+    metadata: &FileMetaData,
+    row_group: usize,
+    fields: &[usize],
+    max_gap: u64,
+) -> Result<RangesReader> {
+    let ranges = coalesce_ranges(&get_field_ranges(metadata, row_group, fields), max_gap);
+    let mut data = Vec::with_capacity(ranges.len());
+    for (start, length) in ranges {
+        reader.seek(SeekFrom::Start(start)).await?;
+        let mut buffer = vec![0; length as usize];
+        reader.read_exact(&mut buffer).await?;
+        data.push((start, buffer));
+    }
+    RangesReader::try_new(data)
+}
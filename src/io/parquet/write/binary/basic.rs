@@ -2,10 +2,10 @@ use parquet2::{
     encoding::{delta_bitpacked, Encoding},
     metadata::ColumnDescriptor,
     page::DataPage,
-    statistics::{serialize_statistics, BinaryStatistics, ParquetStatistics, Statistics},
     write::WriteOptions,
 };
 
+use super::super::statistics::build_statistics;
 use super::super::utils;
 use crate::{
     array::{Array, BinaryArray, Offset},
@@ -78,7 +78,7 @@ pub fn array_to_page<O: Offset>(
     }
 
     let statistics = if options.write_statistics {
-        Some(build_statistics(array, descriptor.clone()))
+        Some(build_statistics(array, descriptor.clone())?)
     } else {
         None
     };
@@ -96,28 +96,6 @@ pub fn array_to_page<O: Offset>(
     )
 }
 
-pub(super) fn build_statistics<O: Offset>(
-    array: &BinaryArray<O>,
-    descriptor: ColumnDescriptor,
-) -> ParquetStatistics {
-    let statistics = &BinaryStatistics {
-        descriptor,
-        null_count: Some(array.null_count() as i64),
-        distinct_count: None,
-        max_value: array
-            .iter()
-            .flatten()
-            .max_by(|x, y| ord_binary(x, y))
-            .map(|x| x.to_vec()),
-        min_value: array
-            .iter()
-            .flatten()
-            .min_by(|x, y| ord_binary(x, y))
-            .map(|x| x.to_vec()),
-    } as &dyn Statistics;
-    serialize_statistics(statistics)
-}
-
 pub(crate) fn encode_delta<O: Offset>(
     values: &[u8],
     offsets: &[O],
@@ -149,23 +127,3 @@ pub(crate) fn encode_delta<O: Offset>(
         &values[offsets.first().unwrap().to_usize()..offsets.last().unwrap().to_usize()],
     )
 }
-
-/// Returns the ordering of two binary values. This corresponds to pyarrows' ordering
-/// of statistics.
-pub(crate) fn ord_binary<'a>(a: &'a [u8], b: &'a [u8]) -> std::cmp::Ordering {
-    use std::cmp::Ordering::*;
-    match (a.is_empty(), b.is_empty()) {
-        (true, true) => return Equal,
-        (true, false) => return Less,
-        (false, true) => return Greater,
-        (false, false) => {}
-    }
-
-    for (v1, v2) in a.iter().zip(b.iter()) {
-        match v1.cmp(v2) {
-            Equal => continue,
-            other => return other,
-        }
-    }
-    Equal
-}
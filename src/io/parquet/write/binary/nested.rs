@@ -2,8 +2,9 @@ use parquet2::{
     encoding::Encoding, metadata::ColumnDescriptor, page::DataPage, write::WriteOptions,
 };
 
+use super::super::statistics::build_statistics;
 use super::super::{levels, utils};
-use super::basic::{build_statistics, encode_plain};
+use super::basic::encode_plain;
 use crate::{
     array::{Array, BinaryArray, Offset},
     error::Result,
@@ -34,14 +35,14 @@ where
     encode_plain(array, is_optional, &mut buffer);
 
     let statistics = if options.write_statistics {
-        Some(build_statistics(array, descriptor.clone()))
+        Some(build_statistics(array, descriptor.clone())?)
     } else {
         None
     };
 
     utils::build_plain_page(
         buffer,
-        levels::num_values(nested.offsets()),
+        nested.num_values(),
         array.null_count(),
         repetition_levels_byte_length,
         definition_levels_byte_length,
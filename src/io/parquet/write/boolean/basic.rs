@@ -1,11 +1,14 @@
 use parquet2::{
-    encoding::{hybrid_rle::bitpacked_encode, Encoding},
+    encoding::{
+        hybrid_rle::{bitpacked_encode, encode_bool},
+        Encoding,
+    },
     metadata::ColumnDescriptor,
     page::DataPage,
-    statistics::{serialize_statistics, BooleanStatistics, ParquetStatistics, Statistics},
     write::WriteOptions,
 };
 
+use super::super::statistics::build_statistics;
 use super::super::utils;
 use crate::error::Result;
 use crate::{array::*, io::parquet::read::is_type_nullable};
@@ -24,24 +27,49 @@ pub(super) fn encode_plain(
     buffer: &mut Vec<u8>,
 ) -> Result<()> {
     if is_optional {
-        let iter = array.iter().flatten().take(
-            array
-                .validity()
-                .as_ref()
-                .map(|x| x.len() - x.null_count())
-                .unwrap_or_else(|| array.len()),
-        );
-        encode(iter, buffer)
+        encode(valid_values(array), buffer)
     } else {
         let iter = array.values().iter();
         encode(iter, buffer)
     }
 }
 
+pub(super) fn encode_rle(
+    array: &BooleanArray,
+    is_optional: bool,
+    buffer: &mut Vec<u8>,
+) -> Result<()> {
+    // SPEC: RLE-encoded values are prefixed by the length of their encoding, as 4 bytes in little endian
+    let start = buffer.len();
+    buffer.extend_from_slice(&[0; 4]);
+
+    if is_optional {
+        encode_bool(buffer, valid_values(array))?;
+    } else {
+        encode_bool(buffer, array.values().iter())?;
+    }
+
+    let length = (buffer.len() - start - 4) as u32;
+    buffer[start..start + 4].copy_from_slice(&length.to_le_bytes());
+    Ok(())
+}
+
+// the non-null values of `array`
+fn valid_values(array: &BooleanArray) -> impl Iterator<Item = bool> + '_ {
+    array.iter().flatten().take(
+        array
+            .validity()
+            .as_ref()
+            .map(|x| x.len() - x.null_count())
+            .unwrap_or_else(|| array.len()),
+    )
+}
+
 pub fn array_to_page(
     array: &BooleanArray,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
+    encoding: Encoding,
 ) -> Result<DataPage> {
     let is_optional = is_type_nullable(descriptor.type_());
 
@@ -58,10 +86,13 @@ pub fn array_to_page(
 
     let definition_levels_byte_length = buffer.len();
 
-    encode_plain(array, is_optional, &mut buffer)?;
+    match encoding {
+        Encoding::Rle => encode_rle(array, is_optional, &mut buffer)?,
+        _ => encode_plain(array, is_optional, &mut buffer)?,
+    }
 
     let statistics = if options.write_statistics {
-        Some(build_statistics(array))
+        Some(build_statistics(array, descriptor.clone())?)
     } else {
         None
     };
@@ -75,16 +106,6 @@ pub fn array_to_page(
         statistics,
         descriptor,
         options,
-        Encoding::Plain,
+        encoding,
     )
 }
-
-pub(super) fn build_statistics(array: &BooleanArray) -> ParquetStatistics {
-    let statistics = &BooleanStatistics {
-        null_count: Some(array.null_count() as i64),
-        distinct_count: None,
-        max_value: array.iter().flatten().max(),
-        min_value: array.iter().flatten().min(),
-    } as &dyn Statistics;
-    serialize_statistics(statistics)
-}
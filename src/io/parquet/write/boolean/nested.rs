@@ -2,8 +2,9 @@ use parquet2::{
     encoding::Encoding, metadata::ColumnDescriptor, page::DataPage, write::WriteOptions,
 };
 
+use super::super::statistics::build_statistics;
 use super::super::{levels, utils};
-use super::basic::{build_statistics, encode_plain};
+use super::basic::{encode_plain, encode_rle};
 use crate::{
     array::{Array, BooleanArray, Offset},
     error::Result,
@@ -15,6 +16,7 @@ pub fn array_to_page<O>(
     options: WriteOptions,
     descriptor: ColumnDescriptor,
     nested: levels::NestedInfo<O>,
+    encoding: Encoding,
 ) -> Result<DataPage>
 where
     O: Offset,
@@ -30,23 +32,26 @@ where
     levels::write_def_levels(&mut buffer, &nested, validity, options.version)?;
     let definition_levels_byte_length = buffer.len() - repetition_levels_byte_length;
 
-    encode_plain(array, is_optional, &mut buffer)?;
+    match encoding {
+        Encoding::Rle => encode_rle(array, is_optional, &mut buffer)?,
+        _ => encode_plain(array, is_optional, &mut buffer)?,
+    }
 
     let statistics = if options.write_statistics {
-        Some(build_statistics(array))
+        Some(build_statistics(array, descriptor.clone())?)
     } else {
         None
     };
 
     utils::build_plain_page(
         buffer,
-        levels::num_values(nested.offsets()),
+        nested.num_values(),
         array.null_count(),
         repetition_levels_byte_length,
         definition_levels_byte_length,
         statistics,
         descriptor,
         options,
-        Encoding::Plain,
+        encoding,
     )
 }
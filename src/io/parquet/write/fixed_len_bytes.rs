@@ -1,12 +1,8 @@
 use parquet2::{
-    encoding::Encoding,
-    metadata::ColumnDescriptor,
-    page::DataPage,
-    statistics::{deserialize_statistics, serialize_statistics, ParquetStatistics},
-    write::WriteOptions,
+    encoding::Encoding, metadata::ColumnDescriptor, page::DataPage, write::WriteOptions,
 };
 
-use super::{binary::ord_binary, utils};
+use super::{statistics::build_statistics, utils};
 use crate::{
     array::{Array, FixedSizeBinaryArray},
     error::Result,
@@ -45,7 +41,7 @@ pub fn array_to_page(
     }
 
     let statistics = if options.write_statistics {
-        build_statistics(array, descriptor.clone())
+        Some(build_statistics(array, descriptor.clone())?)
     } else {
         None
     };
@@ -62,28 +58,3 @@ pub fn array_to_page(
         Encoding::Plain,
     )
 }
-
-pub(super) fn build_statistics(
-    array: &FixedSizeBinaryArray,
-    descriptor: ColumnDescriptor,
-) -> Option<ParquetStatistics> {
-    let pq_statistics = &ParquetStatistics {
-        max: None,
-        min: None,
-        null_count: Some(array.null_count() as i64),
-        distinct_count: None,
-        max_value: array
-            .iter()
-            .flatten()
-            .max_by(|x, y| ord_binary(x, y))
-            .map(|x| x.to_vec()),
-        min_value: array
-            .iter()
-            .flatten()
-            .min_by(|x, y| ord_binary(x, y))
-            .map(|x| x.to_vec()),
-    };
-    deserialize_statistics(pq_statistics, descriptor)
-        .map(|e| serialize_statistics(&*e))
-        .ok()
-}
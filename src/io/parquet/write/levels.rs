@@ -7,35 +7,85 @@ use crate::{
     error::Result,
 };
 
-pub fn num_values<O: Offset>(offsets: &[O]) -> usize {
-    offsets
-        .windows(2)
-        .map(|w| {
-            let length = w[1].to_usize() - w[0].to_usize();
-            if length == 0 {
-                1
-            } else {
-                length
+/// The offsets of a nested array, from which its levels are derived without allocating.
+#[derive(Debug, Clone, Copy)]
+pub enum NestedOffsets<'a, O: Offset> {
+    /// The offsets of a [`ListArray`](crate::array::ListArray)
+    Offsets(&'a [O]),
+    /// The `size` and number of items of a
+    /// [`FixedSizeListArray`](crate::array::FixedSizeListArray)
+    Fixed { size: usize, length: usize },
+}
+
+impl<'a, O: Offset> NestedOffsets<'a, O> {
+    /// Returns an iterator over the lengths of each item
+    pub fn lengths(&self) -> LengthsIter<'a, O> {
+        match *self {
+            Self::Offsets(offsets) => LengthsIter::Offsets(offsets.windows(2)),
+            Self::Fixed { size, length } => LengthsIter::Fixed {
+                size,
+                remaining: length,
+            },
+        }
+    }
+}
+
+/// Iterator over the lengths of the items of [`NestedOffsets`]
+#[derive(Debug, Clone)]
+pub enum LengthsIter<'a, O: Offset> {
+    Offsets(std::slice::Windows<'a, O>),
+    Fixed { size: usize, remaining: usize },
+}
+
+impl<O: Offset> Iterator for LengthsIter<'_, O> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Offsets(iter) => iter.next().map(|w| w[1].to_usize() - w[0].to_usize()),
+            Self::Fixed { size, remaining } => {
+                if *remaining == 0 {
+                    None
+                } else {
+                    *remaining -= 1;
+                    Some(*size)
+                }
             }
-        })
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Offsets(iter) => iter.size_hint(),
+            Self::Fixed { remaining, .. } => (*remaining, Some(*remaining)),
+        }
+    }
+}
+
+/// Returns the number of levels of `offsets`, i.e. the number of values, where empty
+/// and null items count as one.
+pub fn num_values<O: Offset>(offsets: NestedOffsets<O>) -> usize {
+    offsets
+        .lengths()
+        .map(|length| if length == 0 { 1 } else { length })
         .sum()
 }
 
 /// Iterator adapter of parquet / dremel repetition levels
 #[derive(Debug)]
 pub struct RepLevelsIter<'a, O: Offset> {
-    iter: std::slice::Windows<'a, O>,
+    iter: LengthsIter<'a, O>,
     remaining: usize,
     length: usize,
     total_size: usize,
 }
 
 impl<'a, O: Offset> RepLevelsIter<'a, O> {
-    pub fn new(offsets: &'a [O]) -> Self {
-        let total_size = num_values(offsets);
-
+    pub fn new(offsets: NestedOffsets<'a, O>, total_size: usize) -> Self {
         Self {
-            iter: offsets.windows(2),
+            iter: offsets.lengths(),
             remaining: 0,
             length: 0,
             total_size,
@@ -48,10 +98,8 @@ impl<O: Offset> Iterator for RepLevelsIter<'_, O> {
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.remaining == self.length {
-            if let Some(w) = self.iter.next() {
-                let start = w[0].to_usize();
-                let end = w[1].to_usize();
-                self.length = end - start;
+            if let Some(length) = self.iter.next() {
+                self.length = length;
                 self.remaining = 0;
                 if self.length == 0 {
                     self.total_size -= 1;
@@ -73,9 +121,12 @@ impl<O: Offset> Iterator for RepLevelsIter<'_, O> {
 }
 
 /// Iterator adapter of parquet / dremel definition levels
+#[derive(Debug)]
 pub struct DefLevelsIter<'a, O: Offset> {
-    iter: std::iter::Zip<std::slice::Windows<'a, O>, Box<dyn Iterator<Item = bool> + 'a>>,
+    iter: LengthsIter<'a, O>,
+    validity: Option<BitmapIter<'a>>,
     primitive_validity: Option<BitmapIter<'a>>,
+    is_optional: bool,
     remaining: usize,
     is_valid: bool,
     length: usize,
@@ -84,24 +135,17 @@ pub struct DefLevelsIter<'a, O: Offset> {
 
 impl<'a, O: Offset> DefLevelsIter<'a, O> {
     pub fn new(
-        offsets: &'a [O],
+        offsets: NestedOffsets<'a, O>,
+        total_size: usize,
         validity: Option<&'a Bitmap>,
         primitive_validity: Option<&'a Bitmap>,
+        is_optional: bool,
     ) -> Self {
-        let total_size = num_values(offsets);
-
-        let primitive_validity = primitive_validity.map(|x| x.iter());
-
-        let validity = validity
-            .map(|x| Box::new(x.iter()) as Box<dyn Iterator<Item = bool>>)
-            .unwrap_or_else(|| {
-                Box::new(std::iter::repeat(true).take(offsets.len() - 1))
-                    as Box<dyn Iterator<Item = bool>>
-            });
-
         Self {
-            iter: offsets.windows(2).zip(validity),
-            primitive_validity,
+            iter: offsets.lengths(),
+            validity: validity.map(|x| x.iter()),
+            primitive_validity: primitive_validity.map(|x| x.iter()),
+            is_optional,
             remaining: 0,
             length: 0,
             is_valid: false,
@@ -115,10 +159,13 @@ impl<O: Offset> Iterator for DefLevelsIter<'_, O> {
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.remaining == self.length {
-            if let Some((w, is_valid)) = self.iter.next() {
-                let start = w[0].to_usize();
-                let end = w[1].to_usize();
-                self.length = end - start;
+            if let Some(length) = self.iter.next() {
+                let is_valid = self
+                    .validity
+                    .as_mut()
+                    .map(|x| x.next().unwrap())
+                    .unwrap_or(true);
+                self.length = length;
                 self.remaining = 0;
                 self.is_valid = is_valid;
                 if self.length == 0 {
@@ -132,12 +179,16 @@ impl<O: Offset> Iterator for DefLevelsIter<'_, O> {
         self.remaining += 1;
         self.total_size -= 1;
 
-        let (base_def, p_is_valid) = self
-            .primitive_validity
-            .as_mut()
-            .map(|x| (1, x.next().unwrap() as u32))
-            .unwrap_or((0, 0));
-        let def_ = (base_def + 1) * self.is_valid as u32 + p_is_valid;
+        // a required primitive has no definition level of its own
+        let p_is_valid = if self.is_optional {
+            self.primitive_validity
+                .as_mut()
+                .map(|x| x.next().unwrap())
+                .unwrap_or(true) as u32
+        } else {
+            0
+        };
+        let def_ = 2 * self.is_valid as u32 + p_is_valid;
         Some(def_)
     }
 
@@ -148,22 +199,29 @@ impl<O: Offset> Iterator for DefLevelsIter<'_, O> {
 
 #[derive(Debug)]
 pub struct NestedInfo<'a, O: Offset> {
-    _is_optional: bool,
-    offsets: &'a [O],
+    is_optional: bool,
+    offsets: NestedOffsets<'a, O>,
     validity: Option<&'a Bitmap>,
+    num_values: usize,
 }
 
 impl<'a, O: Offset> NestedInfo<'a, O> {
-    pub fn new(offsets: &'a [O], validity: Option<&'a Bitmap>, is_optional: bool) -> Self {
+    pub fn new(
+        offsets: NestedOffsets<'a, O>,
+        validity: Option<&'a Bitmap>,
+        is_optional: bool,
+    ) -> Self {
         Self {
-            _is_optional: is_optional,
+            is_optional,
             offsets,
             validity,
+            num_values: num_values(offsets),
         }
     }
 
-    pub fn offsets(&self) -> &'a [O] {
-        self.offsets
+    /// The number of levels (and of values) of this nested array
+    pub fn num_values(&self) -> usize {
+        self.num_values
     }
 }
 
@@ -196,13 +254,13 @@ pub fn write_rep_levels<O: Offset>(
     match version {
         Version::V1 => {
             write_levels_v1(buffer, |buffer: &mut Vec<u8>| {
-                let levels = RepLevelsIter::new(nested.offsets);
+                let levels = RepLevelsIter::new(nested.offsets, nested.num_values);
                 encode_u32(buffer, levels, num_bits)?;
                 Ok(())
             })?;
         }
         Version::V2 => {
-            let levels = RepLevelsIter::new(nested.offsets);
+            let levels = RepLevelsIter::new(nested.offsets, nested.num_values);
 
             encode_u32(buffer, levels, num_bits)?;
         }
@@ -223,13 +281,25 @@ pub fn write_def_levels<O: Offset>(
     match version {
         Version::V1 => {
             write_levels_v1(buffer, |buffer: &mut Vec<u8>| {
-                let levels = DefLevelsIter::new(nested.offsets, nested.validity, validity);
+                let levels = DefLevelsIter::new(
+                    nested.offsets,
+                    nested.num_values,
+                    nested.validity,
+                    validity,
+                    nested.is_optional,
+                );
                 encode_u32(buffer, levels, num_bits)?;
                 Ok(())
             })?;
         }
         Version::V2 => {
-            let levels = DefLevelsIter::new(nested.offsets, nested.validity, validity);
+            let levels = DefLevelsIter::new(
+                nested.offsets,
+                nested.num_values,
+                nested.validity,
+                validity,
+                nested.is_optional,
+            );
             encode_u32(buffer, levels, num_bits)?;
         }
     }
@@ -243,16 +313,16 @@ mod tests {
 
     #[test]
     fn test_rep_levels() {
-        let offsets = [0, 2, 2, 5, 8, 8, 11, 11, 12].as_ref();
+        let offsets = NestedOffsets::Offsets([0, 2, 2, 5, 8, 8, 11, 11, 12].as_ref());
         let expected = vec![0u32, 1, 0, 0, 1, 1, 0, 1, 1, 0, 0, 1, 1, 0, 0];
 
-        let result = RepLevelsIter::new(offsets).collect::<Vec<_>>();
+        let result = RepLevelsIter::new(offsets, num_values(offsets)).collect::<Vec<_>>();
         assert_eq!(result, expected)
     }
 
     #[test]
     fn test_def_levels() {
-        let offsets = [0, 2, 2, 5, 8, 8, 11, 11, 12].as_ref();
+        let offsets = NestedOffsets::Offsets([0, 2, 2, 5, 8, 8, 11, 11, 12].as_ref());
         let validity = Some(Bitmap::from([
             true, false, true, true, true, true, false, true,
         ]));
@@ -265,8 +335,42 @@ mod tests {
         ]));
         let expected = vec![3u32, 3, 0, 3, 2, 3, 3, 3, 3, 1, 3, 3, 3, 0, 3];
 
-        let result = DefLevelsIter::new(offsets, validity.as_ref(), primitive_validity.as_ref())
-            .collect::<Vec<_>>();
+        let result = DefLevelsIter::new(
+            offsets,
+            num_values(offsets),
+            validity.as_ref(),
+            primitive_validity.as_ref(),
+            true,
+        )
+        .collect::<Vec<_>>();
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn test_def_levels_required_primitive() {
+        let offsets = NestedOffsets::Offsets([0, 2, 2, 5, 8, 8, 11, 11, 12].as_ref());
+        let validity = Some(Bitmap::from([
+            true, false, true, true, true, true, false, true,
+        ]));
+        let expected = vec![2u32, 2, 0, 2, 2, 2, 2, 2, 2, 1, 2, 2, 2, 0, 2];
+
+        let result =
+            DefLevelsIter::new(offsets, num_values(offsets), validity.as_ref(), None, false)
+                .collect::<Vec<_>>();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn test_fixed_levels() {
+        let offsets: NestedOffsets<i32> = NestedOffsets::Fixed { size: 2, length: 3 };
+        let primitive_validity = Some(Bitmap::from([true, false, true, true, true, true]));
+
+        assert_eq!(num_values(offsets), 6);
+        let result = RepLevelsIter::new(offsets, 6).collect::<Vec<_>>();
+        assert_eq!(result, vec![0u32, 1, 0, 1, 0, 1]);
+
+        let result = DefLevelsIter::new(offsets, 6, None, primitive_validity.as_ref(), true)
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![3u32, 2, 3, 3, 3, 3]);
+    }
 }
@@ -4,24 +4,32 @@ mod boolean;
 mod dictionary;
 mod fixed_len_bytes;
 mod levels;
+mod parallel;
 mod primitive;
 mod record_batch;
 mod schema;
+mod statistics;
 mod utf8;
 mod utils;
 
 pub mod stream;
 
+use std::sync::mpsc::{sync_channel, SyncSender};
+use std::thread::JoinHandle;
+
 use crate::array::*;
 use crate::bitmap::Bitmap;
 use crate::buffer::{Buffer, MutableBuffer};
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::io::parquet::read::is_type_nullable;
-use crate::io::parquet::write::levels::NestedInfo;
+use crate::io::parquet::write::levels::{NestedInfo, NestedOffsets};
+use crate::io::{check_schema, ChunkSink};
+use crate::record_batch::RecordBatch;
 use crate::types::days_ms;
 use crate::types::NativeType;
 
+pub use parallel::{ParallelOptions, ParallelRowGroupIterator, Spawner};
 use parquet2::page::DataPage;
 pub use parquet2::{
     compression::Compression,
@@ -39,6 +47,7 @@ pub use parquet2::{
 pub use record_batch::RowGroupIterator;
 use schema::schema_to_metadata_key;
 pub use schema::to_parquet_type;
+pub use statistics::{to_parquet_statistics, StatisticsOptions};
 
 pub(self) fn decimal_length_from_precision(precision: usize) -> usize {
     // digits = floor(log_10(2^(8*n - 1) - 1))
@@ -103,8 +112,15 @@ pub fn can_encode(data_type: &DataType, encoding: Encoding) -> bool {
                 Encoding::DeltaLengthByteArray,
                 DataType::Binary | DataType::LargeBinary | DataType::Utf8 | DataType::LargeUtf8,
             )
-            | (Encoding::RleDictionary, DataType::Dictionary(_, _))
-            | (Encoding::PlainDictionary, DataType::Dictionary(_, _))
+            | (Encoding::RleDictionary, DataType::Dictionary(_, _, _))
+            | (Encoding::PlainDictionary, DataType::Dictionary(_, _, _))
+            | (Encoding::Rle, DataType::Boolean)
+    ) || matches!(
+        (encoding, data_type),
+        (
+            Encoding::Rle,
+            DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _),
+        ) if field.data_type() == &DataType::Boolean
     )
 }
 
@@ -116,7 +132,7 @@ pub fn array_to_pages(
     encoding: Encoding,
 ) -> Result<DynIter<'static, Result<EncodedPage>>> {
     match array.data_type() {
-        DataType::Dictionary(key_type, _) => {
+        DataType::Dictionary(key_type, _, _) => {
             match_integer_type!(key_type, |$T| {
                 dictionary::array_to_pages::<$T>(
                     array.as_any().downcast_ref().unwrap(),
@@ -147,9 +163,12 @@ pub fn array_to_page(
     }
 
     match data_type.to_logical_type() {
-        DataType::Boolean => {
-            boolean::array_to_page(array.as_any().downcast_ref().unwrap(), options, descriptor)
-        }
+        DataType::Boolean => boolean::array_to_page(
+            array.as_any().downcast_ref().unwrap(),
+            options,
+            descriptor,
+            encoding,
+        ),
         // casts below MUST match the casts done at the metadata (field -> parquet type).
         DataType::UInt8 => primitive::array_to_page::<u8, i32>(
             array.as_any().downcast_ref().unwrap(),
@@ -316,7 +335,7 @@ pub fn array_to_page(
             }
         }
         DataType::FixedSizeList(_, _) | DataType::List(_) | DataType::LargeList(_) => {
-            nested_array_to_page(array, descriptor, options)
+            nested_array_to_page(array, descriptor, options, encoding)
         }
         other => Err(ArrowError::NotYetImplemented(format!(
             "Writing parquet V1 pages for data type {:?}",
@@ -340,11 +359,12 @@ macro_rules! dyn_nested_prim {
 }
 
 fn list_array_to_page<O: Offset>(
-    offsets: &[O],
+    offsets: NestedOffsets<O>,
     validity: Option<&Bitmap>,
     values: &dyn Array,
     descriptor: ColumnDescriptor,
     options: WriteOptions,
+    encoding: Encoding,
 ) -> Result<DataPage> {
     use DataType::*;
     let is_optional = is_type_nullable(descriptor.type_());
@@ -353,7 +373,7 @@ fn list_array_to_page<O: Offset>(
     match values.data_type() {
         Boolean => {
             let values = values.as_any().downcast_ref().unwrap();
-            boolean::nested_array_to_page::<O>(values, options, descriptor, nested)
+            boolean::nested_array_to_page::<O>(values, options, descriptor, nested, encoding)
         }
         UInt8 => dyn_nested_prim!(u8, i32, O, values, nested, descriptor, options),
         UInt16 => dyn_nested_prim!(u16, i32, O, values, nested, descriptor, options),
@@ -424,41 +444,255 @@ fn nested_array_to_page(
     array: &dyn Array,
     descriptor: ColumnDescriptor,
     options: WriteOptions,
+    encoding: Encoding,
 ) -> Result<DataPage> {
     match array.data_type() {
         DataType::List(_) => {
             let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
             list_array_to_page(
-                array.offsets(),
+                NestedOffsets::Offsets(array.offsets()),
                 array.validity(),
                 array.values().as_ref(),
                 descriptor,
                 options,
+                encoding,
             )
         }
         DataType::LargeList(_) => {
             let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
             list_array_to_page(
-                array.offsets(),
+                NestedOffsets::Offsets(array.offsets()),
                 array.validity(),
                 array.values().as_ref(),
                 descriptor,
                 options,
+                encoding,
             )
         }
         DataType::FixedSizeList(_, size) => {
             let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
-            let offsets = (0..array.len())
-                .map(|x| (*size * x) as i32)
-                .collect::<Vec<_>>();
+            let offsets: NestedOffsets<i32> = NestedOffsets::Fixed {
+                size: *size,
+                length: array.len(),
+            };
             list_array_to_page(
-                &offsets,
+                offsets,
                 array.validity(),
                 array.values().as_ref(),
                 descriptor,
                 options,
+                encoding,
             )
         }
         _ => todo!(),
     }
 }
+
+/// A parquet writer that implements [`ChunkSink`].
+///
+/// Since a parquet file is written from an iterator of row groups, batches written to
+/// this writer are buffered in memory and only written, one row group per batch, when
+/// [`ChunkSink::close`] is called. See [`BackgroundFileWriter`] for a writer that writes
+/// each batch as it arrives.
+pub struct FileWriter<W: std::io::Write> {
+    writer: W,
+    schema: Schema,
+    options: WriteOptions,
+    encodings: Vec<Encoding>,
+    batches: Vec<RecordBatch>,
+    finished: bool,
+}
+
+impl<W: std::io::Write> FileWriter<W> {
+    /// Creates a new [`FileWriter`]. `encodings` must have one [`Encoding`] per field of `schema`.
+    /// # Errors
+    /// Errors iff `schema` cannot be represented in parquet or the number of encodings
+    /// differs from the number of fields.
+    pub fn try_new(
+        writer: W,
+        schema: Schema,
+        options: WriteOptions,
+        encodings: Vec<Encoding>,
+    ) -> Result<Self> {
+        if schema.fields().len() != encodings.len() {
+            return Err(ArrowError::InvalidArgumentError(
+                "The number of encodings must equal the number of fields".to_string(),
+            ));
+        }
+        // validate that the schema is supported
+        to_parquet_schema(&schema)?;
+        Ok(Self {
+            writer,
+            schema,
+            options,
+            encodings,
+            batches: vec![],
+            finished: false,
+        })
+    }
+
+    /// Consumes itself, returning the inner writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: std::io::Write> ChunkSink for FileWriter<W> {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        if self.finished {
+            return Err(ArrowError::Io(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "Cannot write to a finished file".to_string(),
+            )));
+        }
+        check_schema(&self.schema, batch)?;
+        self.batches.push(batch.clone());
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<()> {
+        if self.finished {
+            return Ok(());
+        }
+        let batches = std::mem::take(&mut self.batches);
+        let row_groups = RowGroupIterator::try_new(
+            batches.into_iter().map(Ok),
+            &self.schema,
+            self.options,
+            self.encodings.clone(),
+        )?;
+        let parquet_schema = row_groups.parquet_schema().clone();
+        write_file(
+            &mut self.writer,
+            row_groups,
+            &self.schema,
+            parquet_schema,
+            self.options,
+            None,
+        )?;
+        self.finished = true;
+        Ok(())
+    }
+}
+
+/// A parquet writer that implements [`ChunkSink`] and writes each batch as a row group
+/// as soon as it is written, so that at most one batch is held in memory at a time.
+///
+/// The file is written by a background thread that receives the batches, and
+/// [`ChunkSink::write`] blocks until the thread is ready to write the batch. Dropping this
+/// writer without calling [`ChunkSink::close`] finishes the file and waits for the thread,
+/// discarding any error.
+pub struct BackgroundFileWriter<W: std::io::Write + Send + 'static> {
+    schema: Schema,
+    sender: Option<SyncSender<RecordBatch>>,
+    handle: Option<JoinHandle<Result<W>>>,
+    writer: Option<W>,
+}
+
+impl<W: std::io::Write + Send + 'static> BackgroundFileWriter<W> {
+    /// Creates a new [`BackgroundFileWriter`], starting its thread.
+    /// `encodings` must have one [`Encoding`] per field of `schema`.
+    /// # Errors
+    /// Errors iff `schema` cannot be represented in parquet or the number of encodings
+    /// differs from the number of fields.
+    pub fn try_new(
+        mut writer: W,
+        schema: Schema,
+        options: WriteOptions,
+        encodings: Vec<Encoding>,
+    ) -> Result<Self> {
+        if schema.fields().len() != encodings.len() {
+            return Err(ArrowError::InvalidArgumentError(
+                "The number of encodings must equal the number of fields".to_string(),
+            ));
+        }
+        let (sender, receiver) = sync_channel::<RecordBatch>(0);
+        let row_groups =
+            RowGroupIterator::try_new(receiver.into_iter().map(Ok), &schema, options, encodings)?;
+
+        let file_schema = schema.clone();
+        let handle = std::thread::spawn(move || {
+            let parquet_schema = row_groups.parquet_schema().clone();
+            write_file(
+                &mut writer,
+                row_groups,
+                &file_schema,
+                parquet_schema,
+                options,
+                None,
+            )?;
+            Ok(writer)
+        });
+
+        Ok(Self {
+            schema,
+            sender: Some(sender),
+            handle: Some(handle),
+            writer: None,
+        })
+    }
+
+    /// Waits for the background thread to finish writing the file, returning its result.
+    fn join(&mut self) -> Result<()> {
+        // dropping the sender ends the iterator of row groups, which finishes the file
+        self.sender = None;
+        if let Some(handle) = self.handle.take() {
+            match handle.join() {
+                Ok(writer) => self.writer = Some(writer?),
+                Err(panic) => std::panic::resume_unwind(panic),
+            }
+        }
+        Ok(())
+    }
+
+    /// Closes this writer if not closed yet and returns the inner writer.
+    /// # Errors
+    /// Errors iff writing the file errored.
+    pub fn into_inner(mut self) -> Result<W> {
+        self.join()?;
+        self.writer.take().ok_or_else(|| {
+            ArrowError::InvalidArgumentError(
+                "The file was not written because writing it errored".to_string(),
+            )
+        })
+    }
+}
+
+impl<W: std::io::Write + Send + 'static> ChunkSink for BackgroundFileWriter<W> {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        let sender = self.sender.as_ref().ok_or_else(|| {
+            ArrowError::Io(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "Cannot write to a finished file".to_string(),
+            ))
+        })?;
+        check_schema(&self.schema, batch)?;
+        if sender.send(batch.clone()).is_err() {
+            // the thread only stops receiving batches when writing errored
+            self.join()?;
+        }
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.join()
+    }
+}
+
+impl<W: std::io::Write + Send + 'static> Drop for BackgroundFileWriter<W> {
+    fn drop(&mut self) {
+        self.sender = None;
+        if let Some(handle) = self.handle.take() {
+            // errors and panics cannot be surfaced from `drop`
+            let _ = handle.join();
+        }
+    }
+}
@@ -0,0 +1,335 @@
+//! APIs to encode and compress the columns of row groups in parallel.
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Condvar, Mutex};
+
+use parquet2::page::CompressedPage;
+
+use super::{
+    array_to_pages, compress, to_parquet_schema, ColumnDescriptor, DynIter, DynStreamingIterator,
+    Encoding, FallibleStreamingIterator, RowGroupIter, SchemaDescriptor, StatisticsOptions,
+    WriteOptions,
+};
+use crate::{
+    array::Array,
+    datatypes::Schema,
+    error::{ArrowError, Result},
+    record_batch::RecordBatch,
+};
+
+pub use crate::io::Spawner;
+
+/// Options of [`ParallelRowGroupIterator`].
+#[derive(Clone)]
+pub struct ParallelOptions {
+    /// The maximum number of columns encoded and compressed concurrently.
+    pub num_tasks: usize,
+    /// The maximum number of bytes of compressed data pages held in memory waiting to be written.
+    /// Pages of the column being written may exceed it, so that writing always progresses.
+    pub max_in_flight: usize,
+    /// The function used to run each task, e.g. `Arc::new(|task| rayon::spawn(task))`.
+    /// Tasks may block while waiting for the writer to consume their pages.
+    pub spawn: Spawner,
+}
+
+impl std::fmt::Debug for ParallelOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParallelOptions")
+            .field("num_tasks", &self.num_tasks)
+            .field("max_in_flight", &self.max_in_flight)
+            .finish()
+    }
+}
+
+// threads cannot be spawned on wasm32, where a `Spawner` must be provided
+#[cfg(not(target_arch = "wasm32"))]
+impl Default for ParallelOptions {
+    /// One task per available core, each on its own thread, and up to 64MB of in-flight pages.
+    fn default() -> Self {
+        Self {
+            num_tasks: std::thread::available_parallelism()
+                .map(|x| x.get())
+                .unwrap_or(1),
+            max_in_flight: 64 * 1024 * 1024,
+            spawn: Arc::new(|task| {
+                std::thread::spawn(task);
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    // bytes of compressed pages sent and not yet written
+    in_flight: usize,
+    // the column being written
+    current: usize,
+    // whether the writer dropped the row group
+    cancelled: bool,
+}
+
+/// State shared between the tasks of a row group and the writer.
+#[derive(Debug)]
+struct Shared {
+    state: Mutex<State>,
+    changed: Condvar,
+    max_in_flight: usize,
+}
+
+impl Shared {
+    /// Blocks until a page of `size` bytes of `column` fits in the budget.
+    /// Returns false iff the row group was cancelled.
+    fn acquire(&self, column: usize, size: usize) -> bool {
+        let mut state = self.state.lock().unwrap();
+        while !state.cancelled
+            && column != state.current
+            && state.in_flight + size > self.max_in_flight
+        {
+            state = self.changed.wait(state).unwrap();
+        }
+        state.in_flight += size;
+        !state.cancelled
+    }
+
+    fn release(&self, size: usize) {
+        let mut state = self.state.lock().unwrap();
+        state.in_flight -= size;
+        self.changed.notify_all();
+    }
+
+    fn set_current(&self, column: usize) {
+        let mut state = self.state.lock().unwrap();
+        if state.current != column {
+            state.current = column;
+            self.changed.notify_all();
+        }
+    }
+}
+
+/// Cancels the tasks of a row group when dropped by the writer.
+#[derive(Debug)]
+struct CancelGuard(Arc<Shared>);
+
+impl Drop for CancelGuard {
+    fn drop(&mut self) {
+        self.0.state.lock().unwrap().cancelled = true;
+        self.0.changed.notify_all();
+    }
+}
+
+// a page and its size in the budget, or `None` at the end of the column
+type Message = Result<Option<(CompressedPage, usize)>>;
+
+struct Job {
+    column: usize,
+    array: Arc<dyn Array>,
+    descriptor: ColumnDescriptor,
+    encoding: Encoding,
+    statistics: StatisticsOptions,
+    sender: Sender<Message>,
+}
+
+fn run_job(job: Job, shared: &Shared, options: WriteOptions) {
+    let Job {
+        column,
+        array,
+        descriptor,
+        encoding,
+        statistics,
+        sender,
+    } = job;
+
+    let pages = match array_to_pages(array.as_ref(), descriptor, options, encoding) {
+        Ok(pages) => pages,
+        Err(error) => {
+            let _ = sender.send(Err(error));
+            return;
+        }
+    };
+    for page in pages {
+        let page = page.and_then(|page| {
+            let page = statistics.apply(page);
+            Ok(compress(page, vec![], options.compression)?)
+        });
+        let page = match page {
+            Ok(page) => page,
+            Err(error) => {
+                let _ = sender.send(Err(error));
+                return;
+            }
+        };
+        let size = match &page {
+            CompressedPage::Data(page) => page.compressed_size(),
+            CompressedPage::Dict(_) => 0,
+        };
+        if !shared.acquire(column, size) || sender.send(Ok(Some((page, size)))).is_err() {
+            shared.release(size);
+            return;
+        }
+    }
+    let _ = sender.send(Ok(None));
+}
+
+/// The compressed pages of a column, in order, as they are produced by the tasks.
+struct ColumnPages {
+    column: usize,
+    receiver: Receiver<Message>,
+    current: Option<(CompressedPage, usize)>,
+    shared: Arc<Shared>,
+    _guard: Arc<CancelGuard>,
+}
+
+impl ColumnPages {
+    fn release_current(&mut self) {
+        if let Some((_, size)) = self.current.take() {
+            self.shared.release(size);
+        }
+    }
+}
+
+impl FallibleStreamingIterator for ColumnPages {
+    type Item = CompressedPage;
+    type Error = ArrowError;
+
+    fn advance(&mut self) -> Result<()> {
+        self.shared.set_current(self.column);
+        self.release_current();
+        match self.receiver.recv() {
+            Ok(message) => self.current = message?,
+            Err(_) => {
+                return Err(ArrowError::ExternalFormat(format!(
+                    "The task compressing column {} stopped before finishing",
+                    self.column
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.current.as_ref().map(|(page, _)| page)
+    }
+}
+
+impl Drop for ColumnPages {
+    fn drop(&mut self) {
+        self.release_current();
+    }
+}
+
+/// An iterator adapter that converts an iterator over [`RecordBatch`] into an iterator
+/// of row groups, like [`super::RowGroupIterator`], whose columns are encoded and compressed in parallel.
+///
+/// Tasks compress the columns of a row group in order of the columns, and the pages are
+/// written in the same order as [`super::RowGroupIterator`] would write them.
+/// The number of bytes of pages held in memory is bounded by [`ParallelOptions::max_in_flight`].
+pub struct ParallelRowGroupIterator<I: Iterator<Item = Result<RecordBatch>>> {
+    iter: I,
+    options: WriteOptions,
+    parallel: ParallelOptions,
+    parquet_schema: SchemaDescriptor,
+    encodings: Vec<Encoding>,
+    statistics: Vec<StatisticsOptions>,
+}
+
+impl<I: Iterator<Item = Result<RecordBatch>>> ParallelRowGroupIterator<I> {
+    /// Creates a new [`ParallelRowGroupIterator`] from an iterator over [`RecordBatch`].
+    pub fn try_new(
+        iter: I,
+        schema: &Schema,
+        options: WriteOptions,
+        encodings: Vec<Encoding>,
+        parallel: ParallelOptions,
+    ) -> Result<Self> {
+        assert_eq!(schema.fields().len(), encodings.len());
+
+        let parquet_schema = to_parquet_schema(schema)?;
+
+        Ok(Self {
+            iter,
+            options,
+            parallel,
+            parquet_schema,
+            statistics: vec![StatisticsOptions::default(); encodings.len()],
+            encodings,
+        })
+    }
+
+    /// Sets the [`StatisticsOptions`] of each column.
+    /// # Panics
+    /// Panics iff `statistics` does not have one entry per field of the schema.
+    pub fn with_statistics(mut self, statistics: Vec<StatisticsOptions>) -> Self {
+        assert_eq!(statistics.len(), self.encodings.len());
+        self.statistics = statistics;
+        self
+    }
+
+    /// Returns the [`SchemaDescriptor`] of the [`ParallelRowGroupIterator`].
+    pub fn parquet_schema(&self) -> &SchemaDescriptor {
+        &self.parquet_schema
+    }
+
+    fn spawn(&self, batch: RecordBatch) -> RowGroupIter<'static, ArrowError> {
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State::default()),
+            changed: Condvar::new(),
+            max_in_flight: self.parallel.max_in_flight,
+        });
+        let guard = Arc::new(CancelGuard(shared.clone()));
+
+        let (jobs, receivers): (Vec<_>, Vec<_>) = batch
+            .columns()
+            .iter()
+            .zip(self.parquet_schema.columns().iter())
+            .zip(self.encodings.iter())
+            .zip(self.statistics.iter())
+            .enumerate()
+            .map(|(column, (((array, descriptor), encoding), statistics))| {
+                let (sender, receiver) = channel();
+                let job = Job {
+                    column,
+                    array: array.clone(),
+                    descriptor: descriptor.clone(),
+                    encoding: *encoding,
+                    statistics: *statistics,
+                    sender,
+                };
+                (job, (column, receiver))
+            })
+            .unzip();
+
+        let num_tasks = self.parallel.num_tasks.max(1).min(jobs.len());
+        let jobs = Arc::new(Mutex::new(jobs.into_iter()));
+        for _ in 0..num_tasks {
+            let jobs = jobs.clone();
+            let shared = shared.clone();
+            let options = self.options;
+            (self.parallel.spawn)(Box::new(move || loop {
+                // jobs are taken in order of the columns, so that the column being
+                // written is always assigned to a task
+                let job = jobs.lock().unwrap().next();
+                match job {
+                    Some(job) => run_job(job, &shared, options),
+                    None => break,
+                }
+            }));
+        }
+
+        DynIter::new(receivers.into_iter().map(move |(column, receiver)| {
+            Ok(DynStreamingIterator::new(ColumnPages {
+                column,
+                receiver,
+                current: None,
+                shared: shared.clone(),
+                _guard: guard.clone(),
+            }))
+        }))
+    }
+}
+
+impl<I: Iterator<Item = Result<RecordBatch>>> Iterator for ParallelRowGroupIterator<I> {
+    type Item = Result<RowGroupIter<'static, ArrowError>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|batch| Ok(self.spawn(batch?)))
+    }
+}
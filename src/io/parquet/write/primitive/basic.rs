@@ -1,12 +1,9 @@
 use parquet2::{
-    encoding::Encoding,
-    metadata::ColumnDescriptor,
-    page::DataPage,
-    statistics::{serialize_statistics, ParquetStatistics, PrimitiveStatistics, Statistics},
-    types::NativeType,
+    encoding::Encoding, metadata::ColumnDescriptor, page::DataPage, types::NativeType,
     write::WriteOptions,
 };
 
+use super::super::statistics::build_statistics;
 use super::super::utils;
 use crate::{
     array::{Array, PrimitiveArray},
@@ -66,7 +63,7 @@ where
     encode_plain(array, is_optional, &mut buffer);
 
     let statistics = if options.write_statistics {
-        Some(build_statistics(array, descriptor.clone()))
+        Some(build_statistics(array, descriptor.clone())?)
     } else {
         None
     };
@@ -83,36 +80,3 @@ where
         Encoding::Plain,
     )
 }
-
-pub fn build_statistics<T, R>(
-    array: &PrimitiveArray<T>,
-    descriptor: ColumnDescriptor,
-) -> ParquetStatistics
-where
-    T: ArrowNativeType,
-    R: NativeType,
-    T: num_traits::AsPrimitive<R>,
-{
-    let statistics = &PrimitiveStatistics::<R> {
-        descriptor,
-        null_count: Some(array.null_count() as i64),
-        distinct_count: None,
-        max_value: array
-            .iter()
-            .flatten()
-            .map(|x| {
-                let x: R = x.as_();
-                x
-            })
-            .max_by(|x, y| x.ord(y)),
-        min_value: array
-            .iter()
-            .flatten()
-            .map(|x| {
-                let x: R = x.as_();
-                x
-            })
-            .min_by(|x, y| x.ord(y)),
-    } as &dyn Statistics;
-    serialize_statistics(statistics)
-}
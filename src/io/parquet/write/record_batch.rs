@@ -3,7 +3,7 @@ use parquet2::FallibleStreamingIterator;
 
 use super::{
     array_to_pages, to_parquet_schema, DynIter, DynStreamingIterator, Encoding, RowGroupIter,
-    SchemaDescriptor, WriteOptions,
+    SchemaDescriptor, StatisticsOptions, WriteOptions,
 };
 use crate::{
     datatypes::Schema,
@@ -19,6 +19,7 @@ pub struct RowGroupIterator<I: Iterator<Item = Result<RecordBatch>>> {
     options: WriteOptions,
     parquet_schema: SchemaDescriptor,
     encodings: Vec<Encoding>,
+    statistics: Vec<StatisticsOptions>,
 }
 
 impl<'a, I: Iterator<Item = Result<RecordBatch>>> RowGroupIterator<I> {
@@ -37,10 +38,20 @@ impl<'a, I: Iterator<Item = Result<RecordBatch>>> RowGroupIterator<I> {
             iter,
             options,
             parquet_schema,
+            statistics: vec![StatisticsOptions::default(); encodings.len()],
             encodings,
         })
     }
 
+    /// Sets the [`StatisticsOptions`] of each column.
+    /// # Panics
+    /// Panics iff `statistics` does not have one entry per field of the schema.
+    pub fn with_statistics(mut self, statistics: Vec<StatisticsOptions>) -> Self {
+        assert_eq!(statistics.len(), self.encodings.len());
+        self.statistics = statistics;
+        self
+    }
+
     /// Returns the [`SchemaDescriptor`] of the [`RowGroupIterator`].
     pub fn parquet_schema(&self) -> &SchemaDescriptor {
         &self.parquet_schema
@@ -57,15 +68,18 @@ impl<I: Iterator<Item = Result<RecordBatch>>> Iterator for RowGroupIterator<I> {
             let batch = batch?;
             let columns = batch.columns().to_vec();
             let encodings = self.encodings.clone();
+            let statistics = self.statistics.clone();
             Ok(DynIter::new(
                 columns
                     .into_iter()
                     .zip(self.parquet_schema.columns().to_vec().into_iter())
                     .zip(encodings.into_iter())
-                    .map(move |((array, descriptor), encoding)| {
+                    .zip(statistics)
+                    .map(move |(((array, descriptor), encoding), statistics)| {
                         array_to_pages(array.as_ref(), descriptor, options, encoding).map(
                             move |pages| {
-                                let encoded_pages = DynIter::new(pages.map(|x| Ok(x?)));
+                                let encoded_pages =
+                                    DynIter::new(pages.map(move |x| Ok(statistics.apply(x?))));
                                 let compressed_pages =
                                     Compressor::new(encoded_pages, options.compression, vec![])
                                         .map_err(ArrowError::from);
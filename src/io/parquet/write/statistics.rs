@@ -0,0 +1,277 @@
+use parquet2::{
+    metadata::ColumnDescriptor,
+    page::{DataPage, DataPageHeader, EncodedPage},
+    schema::types::PhysicalType,
+    statistics::{
+        serialize_statistics, BinaryStatistics, BooleanStatistics, ParquetStatistics,
+        PrimitiveStatistics, Statistics,
+    },
+};
+
+use crate::{
+    array::Array,
+    compute::statistics::{Accumulator, Bounds},
+    error::{ArrowError, Result},
+};
+
+/// Serializes the statistics of an [`Accumulator`] into parquet statistics of a column
+/// of `descriptor`.
+///
+/// The minimum and maximum are converted to the physical type of `descriptor`, in the
+/// same way that the parquet writer converts the values of the column.
+/// # Errors
+/// Errors iff the data type of the accumulator cannot be written to the physical type
+/// of `descriptor`.
+pub fn to_parquet_statistics(
+    accumulator: &Accumulator,
+    descriptor: ColumnDescriptor,
+) -> Result<ParquetStatistics> {
+    fn primitive<T, R>(
+        bounds: &Option<(T, T)>,
+        null_count: Option<i64>,
+        descriptor: ColumnDescriptor,
+    ) -> ParquetStatistics
+    where
+        T: Copy + num_traits::AsPrimitive<R>,
+        R: parquet2::types::NativeType,
+    {
+        serialize_statistics(&PrimitiveStatistics::<R> {
+            descriptor,
+            null_count,
+            distinct_count: None,
+            min_value: bounds.map(|(min, _)| min.as_()),
+            max_value: bounds.map(|(_, max)| max.as_()),
+        } as &dyn Statistics)
+    }
+
+    let null_count = Some(accumulator.null_count() as i64);
+    let physical_type = *descriptor.physical_type();
+    Ok(match (accumulator.bounds(), physical_type) {
+        (Bounds::Unsupported, _) => ParquetStatistics {
+            null_count,
+            distinct_count: None,
+            max_value: None,
+            min_value: None,
+            min: None,
+            max: None,
+        },
+        (Bounds::Boolean(bounds), PhysicalType::Boolean) => {
+            serialize_statistics(&BooleanStatistics {
+                null_count,
+                distinct_count: None,
+                min_value: bounds.map(|(min, _)| min),
+                max_value: bounds.map(|(_, max)| max),
+            } as &dyn Statistics)
+        }
+        (Bounds::Int8(bounds), PhysicalType::Int32) => {
+            primitive::<i8, i32>(bounds, null_count, descriptor)
+        }
+        (Bounds::Int16(bounds), PhysicalType::Int32) => {
+            primitive::<i16, i32>(bounds, null_count, descriptor)
+        }
+        (Bounds::Int32(bounds), PhysicalType::Int32) => {
+            primitive::<i32, i32>(bounds, null_count, descriptor)
+        }
+        (Bounds::Int64(bounds), PhysicalType::Int64) => {
+            primitive::<i64, i64>(bounds, null_count, descriptor)
+        }
+        (Bounds::UInt8(bounds), PhysicalType::Int32) => {
+            primitive::<u8, i32>(bounds, null_count, descriptor)
+        }
+        (Bounds::UInt16(bounds), PhysicalType::Int32) => {
+            primitive::<u16, i32>(bounds, null_count, descriptor)
+        }
+        (Bounds::UInt32(bounds), PhysicalType::Int32) => {
+            primitive::<u32, i32>(bounds, null_count, descriptor)
+        }
+        (Bounds::UInt64(bounds), PhysicalType::Int64) => {
+            primitive::<u64, i64>(bounds, null_count, descriptor)
+        }
+        (Bounds::Float32(bounds), PhysicalType::Float) => {
+            primitive::<f32, f32>(bounds, null_count, descriptor)
+        }
+        (Bounds::Float64(bounds), PhysicalType::Double) => {
+            primitive::<f64, f64>(bounds, null_count, descriptor)
+        }
+        (Bounds::Int128(bounds), PhysicalType::Int32) => {
+            primitive::<i128, i32>(bounds, null_count, descriptor)
+        }
+        (Bounds::Int128(bounds), PhysicalType::Int64) => {
+            primitive::<i128, i64>(bounds, null_count, descriptor)
+        }
+        (Bounds::Int128(bounds), PhysicalType::FixedLenByteArray(size)) => {
+            let size = size as usize;
+            let to_bytes = |x: i128| x.to_be_bytes()[16 - size..].to_vec();
+            ParquetStatistics {
+                null_count,
+                distinct_count: None,
+                max_value: bounds.map(|(_, max)| to_bytes(max)),
+                min_value: bounds.map(|(min, _)| to_bytes(min)),
+                min: None,
+                max: None,
+            }
+        }
+        (Bounds::Binary(bounds), PhysicalType::ByteArray) => {
+            serialize_statistics(&BinaryStatistics {
+                descriptor,
+                null_count,
+                distinct_count: None,
+                min_value: bounds.as_ref().map(|(min, _)| min.clone()),
+                max_value: bounds.as_ref().map(|(_, max)| max.clone()),
+            } as &dyn Statistics)
+        }
+        (Bounds::Binary(bounds), PhysicalType::FixedLenByteArray(_)) => ParquetStatistics {
+            null_count,
+            distinct_count: None,
+            max_value: bounds.as_ref().map(|(_, max)| max.clone()),
+            min_value: bounds.as_ref().map(|(min, _)| min.clone()),
+            min: None,
+            max: None,
+        },
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Statistics of {:?} cannot be written to the parquet physical type {:?}",
+                accumulator.data_type(),
+                physical_type
+            )))
+        }
+    })
+}
+
+/// Returns the parquet statistics of `array`, a column of `descriptor`.
+pub(super) fn build_statistics(
+    array: &dyn Array,
+    descriptor: ColumnDescriptor,
+) -> Result<ParquetStatistics> {
+    let mut accumulator = Accumulator::new(array.data_type().clone());
+    accumulator.update(array)?;
+    to_parquet_statistics(&accumulator, descriptor)
+}
+
+/// Options of the statistics of a column, applied to the statistics written when
+/// [`super::WriteOptions::write_statistics`] is true.
+///
+/// Parquet derives the statistics of a column chunk from the statistics of its pages,
+/// so these options apply to both. In particular, the statistics of the pages can't be
+/// disabled independently of the ones of the column chunk: `parquet2` computes the latter from
+/// the page headers when the column chunk is written. Since this crate writes a single data
+/// page per column chunk, the two carry the same values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StatisticsOptions {
+    /// Whether to write the statistics of the column.
+    pub enabled: bool,
+    /// The maximum number of bytes of the min and max values of binary and utf8 columns.
+    /// Larger values are truncated such that they remain a lower (min) and an upper (max) bound
+    /// of the values of the column, or dropped when no such bound exists.
+    pub max_length: Option<usize>,
+}
+
+impl Default for StatisticsOptions {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_length: None,
+        }
+    }
+}
+
+impl StatisticsOptions {
+    /// Applies these options to the statistics of `page`.
+    pub fn apply(&self, page: EncodedPage) -> EncodedPage {
+        if self.enabled && self.max_length.is_none() {
+            return page;
+        }
+        match page {
+            EncodedPage::Data(mut page) => {
+                let is_binary = *page.descriptor().physical_type() == PhysicalType::ByteArray;
+                let map = |statistics: Option<ParquetStatistics>| match self.max_length {
+                    _ if !self.enabled => None,
+                    Some(max_length) if is_binary => {
+                        statistics.map(|statistics| truncate(statistics, max_length))
+                    }
+                    _ => statistics,
+                };
+
+                let mut header = page.header().clone();
+                match &mut header {
+                    DataPageHeader::V1(header) => header.statistics = map(header.statistics.take()),
+                    DataPageHeader::V2(header) => header.statistics = map(header.statistics.take()),
+                };
+                let buffer = std::mem::take(page.buffer_mut());
+                EncodedPage::Data(DataPage::new(
+                    header,
+                    buffer,
+                    page.dictionary_page().cloned(),
+                    page.descriptor().clone(),
+                ))
+            }
+            page => page,
+        }
+    }
+}
+
+fn truncate(mut statistics: ParquetStatistics, max_length: usize) -> ParquetStatistics {
+    statistics.min_value = statistics
+        .min_value
+        .map(|value| truncate_min(value, max_length));
+    statistics.max_value = statistics
+        .max_value
+        .and_then(|value| truncate_max(value, max_length));
+    statistics
+}
+
+/// Returns the largest index `<= index` that is a char boundary of `value`
+fn floor_char_boundary(value: &str, mut index: usize) -> usize {
+    while !value.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+/// Truncates `value` to at most `max_length` bytes, preserving utf8 when `value` is utf8.
+/// The result is smaller than or equal to `value`.
+fn truncate_min(mut value: Vec<u8>, max_length: usize) -> Vec<u8> {
+    if value.len() <= max_length {
+        return value;
+    }
+    let length = match std::str::from_utf8(&value) {
+        Ok(string) => floor_char_boundary(string, max_length),
+        Err(_) => max_length,
+    };
+    value.truncate(length);
+    value
+}
+
+/// Truncates `value` to at most `max_length` bytes, preserving utf8 when `value` is utf8.
+/// The result is larger than or equal to `value`, or `None` if no such value exists.
+fn truncate_max(mut value: Vec<u8>, max_length: usize) -> Option<Vec<u8>> {
+    if value.len() <= max_length {
+        return Some(value);
+    }
+    match std::str::from_utf8(&value) {
+        Ok(string) => {
+            let length = floor_char_boundary(string, max_length);
+            let mut chars = string[..length].chars().collect::<Vec<_>>();
+            // increment the last char that can be incremented, dropping the ones after it
+            while let Some(last) = chars.pop() {
+                let next = (last as u32 + 1..=char::MAX as u32).find_map(char::from_u32);
+                if let Some(next) = next {
+                    chars.push(next);
+                    return Some(chars.into_iter().collect::<String>().into_bytes());
+                }
+            }
+            None
+        }
+        Err(_) => {
+            value.truncate(max_length);
+            // increment the last byte that can be incremented, dropping the ones after it
+            while let Some(last) = value.pop() {
+                if last < u8::MAX {
+                    value.push(last + 1);
+                    return Some(value);
+                }
+            }
+            None
+        }
+    }
+}
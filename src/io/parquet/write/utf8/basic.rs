@@ -1,12 +1,9 @@
 use parquet2::{
-    encoding::Encoding,
-    metadata::ColumnDescriptor,
-    page::DataPage,
-    statistics::{serialize_statistics, BinaryStatistics, ParquetStatistics, Statistics},
-    write::WriteOptions,
+    encoding::Encoding, metadata::ColumnDescriptor, page::DataPage, write::WriteOptions,
 };
 
-use super::super::binary::{encode_delta, ord_binary};
+use super::super::binary::encode_delta;
+use super::super::statistics::build_statistics;
 use super::super::utils;
 use crate::{
     array::{Array, Offset, Utf8Array},
@@ -77,7 +74,7 @@ pub fn array_to_page<O: Offset>(
     }
 
     let statistics = if options.write_statistics {
-        Some(build_statistics(array, descriptor.clone()))
+        Some(build_statistics(array, descriptor.clone())?)
     } else {
         None
     };
@@ -94,27 +91,3 @@ pub fn array_to_page<O: Offset>(
         encoding,
     )
 }
-
-pub(super) fn build_statistics<O: Offset>(
-    array: &Utf8Array<O>,
-    descriptor: ColumnDescriptor,
-) -> ParquetStatistics {
-    let statistics = &BinaryStatistics {
-        descriptor,
-        null_count: Some(array.null_count() as i64),
-        distinct_count: None,
-        max_value: array
-            .iter()
-            .flatten()
-            .map(|x| x.as_bytes())
-            .max_by(|x, y| ord_binary(x, y))
-            .map(|x| x.to_vec()),
-        min_value: array
-            .iter()
-            .flatten()
-            .map(|x| x.as_bytes())
-            .min_by(|x, y| ord_binary(x, y))
-            .map(|x| x.to_vec()),
-    } as &dyn Statistics;
-    serialize_statistics(statistics)
-}
@@ -61,6 +61,12 @@ impl RecordBatch {
         Ok(RecordBatch { schema, columns })
     }
 
+    /// Creates a [`RecordBatch`] from a schema and columns without checking that they are consistent.
+    #[cfg(feature = "io_ipc")]
+    pub(crate) fn new_unchecked(schema: Arc<Schema>, columns: Vec<Arc<dyn Array>>) -> Self {
+        RecordBatch { schema, columns }
+    }
+
     /// Creates a new empty [`RecordBatch`].
     pub fn new_empty(schema: Arc<Schema>) -> Self {
         let columns = schema
@@ -14,6 +14,8 @@ mod utf8;
 pub use utf8::*;
 mod binary;
 pub use binary::*;
+mod fixed_size_binary;
+pub use fixed_size_binary::*;
 mod boolean;
 pub use boolean::*;
 mod list;
@@ -120,7 +122,18 @@ pub fn new_scalar(array: &dyn Array, index: usize) -> Box<dyn Scalar> {
                 Box::new(StructScalar::new(array.data_type().clone(), None))
             }
         }
-        FixedSizeBinary => todo!(),
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            let value = if array.is_valid(index) {
+                Some(array.value(index))
+            } else {
+                None
+            };
+            Box::new(FixedSizeBinaryScalar::new(array.data_type().clone(), value))
+        }
         FixedSizeList => todo!(),
         Union | Map => todo!(),
         Dictionary(key_type) => match_integer_type!(key_type, |$T| {
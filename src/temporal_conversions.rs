@@ -1,18 +1,20 @@
 //! Conversion methods for dates and times.
 
+use std::convert::TryFrom;
+
 use chrono::{
     format::{parse, Parsed, StrftimeItems},
     Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime,
 };
 
-use crate::error::Result;
+use crate::error::{ErrorContext, Result};
 use crate::{
-    array::{Offset, PrimitiveArray, Utf8Array},
+    array::{Array, MutablePrimitiveArray, Offset, PrimitiveArray, Utf8Array},
     error::ArrowError,
 };
 use crate::{
-    datatypes::{DataType, TimeUnit},
-    types::months_days_ns,
+    datatypes::{DataType, IntervalUnit, TimeUnit},
+    types::{days_ms, months_days_ns, NativeType},
 };
 
 /// Number of seconds in a day
@@ -57,6 +59,23 @@ pub fn date64_to_date(milliseconds: i64) -> NaiveDate {
     date64_to_datetime(milliseconds).date()
 }
 
+/// floors a `i64` representing a `date64` to the midnight of its day, i.e. to a multiple of
+/// [`MILLISECONDS_IN_DAY`] as required by the Arrow specification
+#[inline]
+pub fn date64_floor(v: i64) -> i64 {
+    v.saturating_sub(v.rem_euclid(MILLISECONDS_IN_DAY))
+}
+
+/// Returns a [`DataType::Date64`] array whose values are the values of `array` floored to midnight
+/// via [`date64_floor`]. `array` is returned without copying when all its values are at midnight.
+pub fn floor_date64_array(array: &PrimitiveArray<i64>) -> PrimitiveArray<i64> {
+    if array.values().iter().all(|x| x % MILLISECONDS_IN_DAY == 0) {
+        return array.clone();
+    }
+    let values = array.values().iter().map(|x| date64_floor(*x)).collect();
+    PrimitiveArray::<i64>::from_data(array.data_type().clone(), values, array.validity().cloned())
+}
+
 /// converts a `i32` representing a `time32(s)` to [`NaiveDateTime`]
 #[inline]
 pub fn time32s_to_time(v: i32) -> NaiveTime {
@@ -327,6 +346,303 @@ pub fn utf8_to_naive_timestamp_ns<O: Offset>(
     PrimitiveArray::from_trusted_len_iter(iter).to(DataType::Timestamp(TimeUnit::Nanosecond, None))
 }
 
+/// Number of nanoseconds in a day
+const NANOSECONDS_IN_DAY: i128 = SECONDS_IN_DAY as i128 * NANOSECONDS as i128;
+
+fn invalid_interval(value: &str) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!("\"{}\" is not a valid interval", value))
+}
+
+/// Parses an unsigned decimal number, `digits[.digits]`, into its integer part and its
+/// fractional part as a (numerator, denominator) pair.
+fn parse_interval_number(number: &str) -> Option<(i128, i128, i128)> {
+    let (integer, fraction) = match number.find('.') {
+        Some(dot) => (&number[..dot], &number[dot + 1..]),
+        None => (number, ""),
+    };
+    if (integer.is_empty() && fraction.is_empty())
+        || !integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|x| x.is_ascii_digit())
+    {
+        return None;
+    }
+    let integer = if integer.is_empty() {
+        0
+    } else {
+        integer.parse::<i128>().ok()?
+    };
+    // digits beyond nanoseconds are irrelevant
+    let fraction = &fraction[..fraction.len().min(9)];
+    let denominator = 10i128.pow(fraction.len() as u32);
+    let numerator = if fraction.is_empty() {
+        0
+    } else {
+        fraction.parse::<i128>().ok()?
+    };
+    Some((integer, numerator, denominator))
+}
+
+/// Returns the component of [`months_days_ns`] (0 for months, 1 for days and 2 for nanoseconds)
+/// of a unit of a SQL interval literal and the number of them in the unit.
+fn parse_interval_unit(unit: &str) -> Option<(usize, i128)> {
+    Some(match unit.to_ascii_lowercase().as_str() {
+        "year" | "years" => (0, 12),
+        "month" | "months" | "mon" | "mons" => (0, 1),
+        "week" | "weeks" => (1, 7),
+        "day" | "days" => (1, 1),
+        "hour" | "hours" => (2, 3_600 * NANOSECONDS as i128),
+        "minute" | "minutes" | "min" | "mins" => (2, 60 * NANOSECONDS as i128),
+        "second" | "seconds" | "sec" | "secs" => (2, NANOSECONDS as i128),
+        "millisecond" | "milliseconds" | "ms" => (2, (NANOSECONDS / MILLISECONDS) as i128),
+        "microsecond" | "microseconds" | "us" => (2, (NANOSECONDS / MICROSECONDS) as i128),
+        "nanosecond" | "nanoseconds" | "ns" => (2, 1),
+        _ => return None,
+    })
+}
+
+fn parse_interval_integer(number: &str) -> Option<i128> {
+    if number.is_empty() || !number.bytes().all(|x| x.is_ascii_digit()) {
+        return None;
+    }
+    number.parse().ok()
+}
+
+/// Parses `HH:MM[:SS[.fraction]]` to nanoseconds.
+fn parse_interval_time(time: &str) -> Option<i128> {
+    let mut parts = time.split(':');
+    let hours = parts.next()?;
+    let minutes = parts.next()?;
+    let seconds = parts.next().unwrap_or("0");
+    if parts.next().is_some() {
+        return None;
+    }
+    let hours = parse_interval_integer(hours)?;
+    let minutes = parse_interval_integer(minutes).filter(|x| *x < 60)?;
+    let (seconds, numerator, denominator) = parse_interval_number(seconds).filter(|x| x.0 < 60)?;
+
+    let nanoseconds = NANOSECONDS as i128;
+    let total = hours.checked_mul(3_600 * nanoseconds)?;
+    total
+        .checked_add((minutes * 60 + seconds) * nanoseconds + numerator * nanoseconds / denominator)
+}
+
+/// Parses a SQL interval literal, such as `1 year 2 months 3 days`, `02:30:00` or
+/// `-1 day 12:00:00.5`, to a [`months_days_ns`].
+///
+/// A literal is a whitespace-separated sequence of
+/// * numbers, optionally signed and fractional, followed by a unit: `year`, `month` (`mon`),
+///   `week`, `day`, `hour`, `minute` (`min`), `second` (`sec`), `millisecond` (`ms`),
+///   `microsecond` (`us`) or `nanosecond` (`ns`), singular or plural and case-insensitive;
+/// * times of the form `HH:MM[:SS[.fraction]]`, optionally signed;
+///
+/// optionally followed by `ago`, which negates the interval. Fractions of days and weeks are
+/// converted to nanoseconds; years and months must be integers.
+/// # Errors
+/// This function errors iff `value` is not a valid interval literal or its components overflow.
+/// # Example
+/// ```
+/// use arrow2::temporal_conversions::parse_interval;
+/// use arrow2::types::months_days_ns;
+///
+/// let interval = parse_interval("1 year 2 months 3 days 02:30:00").unwrap();
+/// assert_eq!(interval, months_days_ns::new(14, 3, 9_000_000_000_000));
+/// ```
+pub fn parse_interval(value: &str) -> Result<months_days_ns> {
+    let mut components = [0i128; 3];
+    let mut is_empty = true;
+
+    let mut tokens = value.split_whitespace().peekable();
+    while let Some(token) = tokens.next() {
+        if token.eq_ignore_ascii_case("ago") && !is_empty && tokens.peek().is_none() {
+            components.iter_mut().for_each(|x| *x = -*x);
+            break;
+        }
+        is_empty = false;
+
+        let (sign, token) = match token.as_bytes()[0] {
+            b'-' => (-1, &token[1..]),
+            b'+' => (1, &token[1..]),
+            _ => (1, token),
+        };
+
+        if token.contains(':') {
+            let nanoseconds = parse_interval_time(token).ok_or_else(|| invalid_interval(value))?;
+            components[2] = components[2]
+                .checked_add(sign * nanoseconds)
+                .ok_or(ArrowError::Overflow)?;
+            continue;
+        }
+
+        // the unit is either attached to the number (`3days`) or the next token (`3 days`)
+        let split = token
+            .find(|x: char| !x.is_ascii_digit() && x != '.')
+            .unwrap_or(token.len());
+        let (number, unit) = token.split_at(split);
+        let unit = if unit.is_empty() {
+            tokens.next().ok_or_else(|| invalid_interval(value))?
+        } else {
+            unit
+        };
+        let (integer, numerator, denominator) =
+            parse_interval_number(number).ok_or_else(|| invalid_interval(value))?;
+        let (component, multiplier) =
+            parse_interval_unit(unit).ok_or_else(|| invalid_interval(value))?;
+
+        let (whole, fraction) = match component {
+            0 if numerator != 0 => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "\"{}\" is not a valid interval: years and months must be integers",
+                    value
+                )))
+            }
+            // fractions of days are carried to nanoseconds
+            1 => (
+                integer.checked_mul(multiplier),
+                numerator * multiplier * NANOSECONDS_IN_DAY / denominator,
+            ),
+            _ => (
+                integer.checked_mul(multiplier),
+                numerator * multiplier / denominator,
+            ),
+        };
+        let whole = whole.ok_or(ArrowError::Overflow)?;
+        components[component] = components[component]
+            .checked_add(sign * whole)
+            .ok_or(ArrowError::Overflow)?;
+        components[2] = components[2]
+            .checked_add(sign * fraction)
+            .ok_or(ArrowError::Overflow)?;
+    }
+    if is_empty {
+        return Err(invalid_interval(value));
+    }
+
+    let months = i32::try_from(components[0]).map_err(|_| ArrowError::Overflow)?;
+    let days = i32::try_from(components[1]).map_err(|_| ArrowError::Overflow)?;
+    let nanoseconds = i64::try_from(components[2]).map_err(|_| ArrowError::Overflow)?;
+    Ok(months_days_ns::new(months, days, nanoseconds))
+}
+
+/// Converts a [`months_days_ns`] to the number of months of an `Interval(YearMonth)`,
+/// or `None` if it has days or nanoseconds.
+#[inline]
+pub fn months_days_ns_to_months(interval: months_days_ns) -> Option<i32> {
+    (interval.days() == 0 && interval.ns() == 0).then(|| interval.months())
+}
+
+/// Converts a [`months_days_ns`] to the [`days_ms`] of an `Interval(DayTime)`, or `None` if
+/// it has months or its nanoseconds are not a number of milliseconds representable as `i32`.
+#[inline]
+pub fn months_days_ns_to_days_ms(interval: months_days_ns) -> Option<days_ms> {
+    let nanoseconds_in_ms = NANOSECONDS / MILLISECONDS;
+    if interval.months() != 0 || interval.ns() % nanoseconds_in_ms != 0 {
+        return None;
+    }
+    let milliseconds = i32::try_from(interval.ns() / nanoseconds_in_ms).ok()?;
+    Some(days_ms::new(interval.days(), milliseconds))
+}
+
+/// Converts a [`months_days_ns`] to a `Duration(time_unit)`, or `None` if it has months
+/// (whose length varies) or it is not representable in `time_unit`.
+#[inline]
+pub fn months_days_ns_to_duration(interval: months_days_ns, time_unit: TimeUnit) -> Option<i64> {
+    if interval.months() != 0 {
+        return None;
+    }
+    let total = interval.days() as i128 * NANOSECONDS_IN_DAY + interval.ns() as i128;
+    let unit = (NANOSECONDS / time_unit_multiple(time_unit)) as i128;
+    if total % unit != 0 {
+        return None;
+    }
+    i64::try_from(total / unit).ok()
+}
+
+/// Get the time unit as a multiple of a second
+pub(crate) const fn time_unit_multiple(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => MILLISECONDS,
+        TimeUnit::Microsecond => MICROSECONDS,
+        TimeUnit::Nanosecond => NANOSECONDS,
+    }
+}
+
+fn try_utf8_to_interval<O: Offset, T: NativeType, F: Fn(months_days_ns) -> Option<T>>(
+    array: &Utf8Array<O>,
+    data_type: &DataType,
+    op: F,
+) -> Result<Box<dyn Array>> {
+    let iter = array.iter().enumerate().map(|(row, x)| {
+        x.map(|x| {
+            parse_interval(x)
+                .and_then(|interval| {
+                    op(interval).ok_or_else(|| {
+                        ArrowError::InvalidArgumentError(format!(
+                            "The interval \"{}\" cannot be represented as {:?}",
+                            x, data_type
+                        ))
+                    })
+                })
+                .map_err(|e| e.with_context(ErrorContext::default().with_row(row)))
+        })
+        .transpose()
+    });
+    let array = MutablePrimitiveArray::<T>::try_from_trusted_len_iter(iter)?;
+    Ok(Box::new(
+        PrimitiveArray::<T>::from(array).to(data_type.clone()),
+    ))
+}
+
+/// Parses a [`Utf8Array`] of SQL interval literals (see [`parse_interval`]) to an array of
+/// `data_type`, which must be an `Interval` or a `Duration`.
+/// Null elements remain null.
+/// # Errors
+/// This function errors on the first element that is not a valid interval literal or that
+/// is not representable in `data_type` (e.g. `1 month` as a `Duration`), wrapped in an
+/// [`ArrowError::WithContext`] with its row in the [`ErrorContext`].
+/// # Example
+/// ```
+/// use arrow2::array::{Array, PrimitiveArray, Utf8Array};
+/// use arrow2::datatypes::{DataType, TimeUnit};
+/// use arrow2::temporal_conversions::utf8_to_interval;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("1 day 02:00:00"), None, Some("90 seconds")]);
+/// let data_type = DataType::Duration(TimeUnit::Second);
+///
+/// let result = utf8_to_interval(&array, &data_type).unwrap();
+/// let expected = PrimitiveArray::<i64>::from(&[Some(93_600), None, Some(90)]).to(data_type);
+/// assert_eq!(result.as_ref(), &expected as &dyn Array);
+///
+/// let array = Utf8Array::<i32>::from_slice(&["1 day", "1 month"]);
+/// let error = utf8_to_interval(&array, &DataType::Duration(TimeUnit::Second)).unwrap_err();
+/// assert_eq!(error.context().unwrap().row, Some(1));
+/// ```
+pub fn utf8_to_interval<O: Offset>(
+    array: &Utf8Array<O>,
+    data_type: &DataType,
+) -> Result<Box<dyn Array>> {
+    match data_type.to_logical_type() {
+        DataType::Interval(IntervalUnit::YearMonth) => {
+            try_utf8_to_interval(array, data_type, months_days_ns_to_months)
+        }
+        DataType::Interval(IntervalUnit::DayTime) => {
+            try_utf8_to_interval(array, data_type, months_days_ns_to_days_ms)
+        }
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            try_utf8_to_interval(array, data_type, Some)
+        }
+        DataType::Duration(time_unit) => try_utf8_to_interval(array, data_type, |x| {
+            months_days_ns_to_duration(x, *time_unit)
+        }),
+        other => Err(ArrowError::InvalidArgumentError(format!(
+            "Intervals can only be parsed to Interval or Duration, not {:?}",
+            other
+        ))),
+    }
+}
+
 fn add_month(year: i32, month: u32, months: i32) -> chrono::NaiveDate {
     let new_year = (year * 12 + (month - 1) as i32 + months) / 12;
     let new_month = (year * 12 + (month - 1) as i32 + months) % 12 + 1;
@@ -395,3 +711,155 @@ pub fn add_interval<T: chrono::TimeZone>(
         TimeUnit::Nanosecond => new_datetime_tz.timestamp_nanos(),
     }
 }
+
+/// Policy to add months to a date whose day does not exist in the resulting month,
+/// e.g. January 31st + 1 month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EndOfMonth {
+    /// The day is clamped to the last day of the resulting month, e.g. February 28th.
+    Clamp,
+    /// The excess days overflow to the next month, e.g. March 3rd.
+    Overflow,
+}
+
+/// Adds `months` to `date` following `end_of_month`, returning `None` on overflow.
+#[inline]
+pub fn checked_add_months(
+    date: NaiveDate,
+    months: i32,
+    end_of_month: EndOfMonth,
+) -> Option<NaiveDate> {
+    let months = date.year() as i64 * 12 + date.month0() as i64 + months as i64;
+    let year = i32::try_from(months.div_euclid(12)).ok()?;
+    let month = months.rem_euclid(12) as u32 + 1;
+    let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+    match end_of_month {
+        EndOfMonth::Clamp => {
+            let mut day = date.day();
+            loop {
+                if let Some(date) = first.with_day(day) {
+                    return Some(date);
+                }
+                day -= 1;
+            }
+        }
+        EndOfMonth::Overflow => {
+            first.checked_add_signed(chrono::Duration::days(date.day() as i64 - 1))
+        }
+    }
+}
+
+/// Adds the months and days of `interval` to `datetime` following `end_of_month`,
+/// followed by its nanoseconds, returning `None` on overflow.
+fn checked_add_interval_datetime(
+    datetime: NaiveDateTime,
+    interval: months_days_ns,
+    end_of_month: EndOfMonth,
+) -> Option<NaiveDateTime> {
+    let date = checked_add_months(datetime.date(), interval.months(), end_of_month)?;
+    date.and_time(datetime.time())
+        .checked_add_signed(chrono::Duration::days(interval.days() as i64))?
+        .checked_add_signed(chrono::Duration::nanoseconds(interval.ns()))
+}
+
+/// Converts a timestamp in `time_unit` to [`NaiveDateTime`], returning `None` when it is
+/// out of the range of [`NaiveDateTime`].
+fn checked_timestamp_to_naive_datetime(
+    timestamp: i64,
+    time_unit: TimeUnit,
+) -> Option<NaiveDateTime> {
+    let units = match time_unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => MILLISECONDS,
+        TimeUnit::Microsecond => MICROSECONDS,
+        TimeUnit::Nanosecond => NANOSECONDS,
+    };
+    let seconds = timestamp.div_euclid(units);
+    let nanoseconds = timestamp.rem_euclid(units) * (NANOSECONDS / units);
+    NaiveDateTime::from_timestamp_opt(seconds, nanoseconds as u32)
+}
+
+/// Converts `datetime` to a timestamp in `time_unit`, returning `None` on overflow.
+fn checked_naive_datetime_to_timestamp(
+    datetime: NaiveDateTime,
+    time_unit: TimeUnit,
+) -> Option<i64> {
+    let duration =
+        datetime.signed_duration_since(NaiveDate::from_ymd_opt(1970, 1, 1)?.and_hms_opt(0, 0, 0)?);
+    // `Duration::num_*` truncate towards zero; round the sub-second part down instead, so that
+    // e.g. 1ns before the epoch is -1s and not 0s
+    let seconds = duration.num_seconds();
+    let nanoseconds = (duration - chrono::Duration::seconds(seconds)).num_nanoseconds()?;
+    let (units_per_second, nanoseconds_per_unit) = match time_unit {
+        TimeUnit::Second => (1, NANOSECONDS),
+        TimeUnit::Millisecond => (MILLISECONDS, MICROSECONDS),
+        TimeUnit::Microsecond => (MICROSECONDS, MILLISECONDS),
+        TimeUnit::Nanosecond => (NANOSECONDS, 1),
+    };
+    seconds
+        .checked_mul(units_per_second)?
+        .checked_add(nanoseconds.div_euclid(nanoseconds_per_unit))
+}
+
+/// Adds an `interval` to a `timestamp` in `time_unit` units without timezone,
+/// returning `None` on overflow.
+///
+/// Contrarily to [`add_naive_interval`], the months of the interval are added to the calendar
+/// date following `end_of_month` (e.g. January 31st + 1 month is February 28th when clamped).
+/// Months are added first, then days and then nanoseconds, and the result is rounded down
+/// to `time_unit`.
+#[inline]
+pub fn checked_add_naive_interval(
+    timestamp: i64,
+    time_unit: TimeUnit,
+    interval: months_days_ns,
+    end_of_month: EndOfMonth,
+) -> Option<i64> {
+    let datetime = checked_timestamp_to_naive_datetime(timestamp, time_unit)?;
+    let datetime = checked_add_interval_datetime(datetime, interval, end_of_month)?;
+    checked_naive_datetime_to_timestamp(datetime, time_unit)
+}
+
+/// Adds an `interval` to a `timestamp` in `time_unit` units and timezone `timezone`,
+/// returning `None` on overflow.
+///
+/// Like [`checked_add_naive_interval`], but months and days are added to the local date and time
+/// of `timezone`. Results that do not exist in `timezone` (e.g. within a daylight saving
+/// time gap) are `None`, and ambiguous results are the earliest of them.
+#[inline]
+pub fn checked_add_interval<T: chrono::TimeZone>(
+    timestamp: i64,
+    time_unit: TimeUnit,
+    interval: months_days_ns,
+    end_of_month: EndOfMonth,
+    timezone: &T,
+) -> Option<i64> {
+    let datetime = checked_timestamp_to_naive_datetime(timestamp, time_unit)?;
+    let offset = chrono::Offset::fix(&timezone.offset_from_utc_datetime(&datetime));
+    let local =
+        datetime.checked_add_signed(chrono::Duration::seconds(offset.local_minus_utc() as i64))?;
+    let date = checked_add_months(local.date(), interval.months(), end_of_month)?;
+    let local = date
+        .and_time(local.time())
+        .checked_add_signed(chrono::Duration::days(interval.days() as i64))?;
+    let datetime = timezone
+        .from_local_datetime(&local)
+        .earliest()?
+        .naive_utc()
+        .checked_add_signed(chrono::Duration::nanoseconds(interval.ns()))?;
+    checked_naive_datetime_to_timestamp(datetime, time_unit)
+}
+
+/// Adds an `interval` to a `date32`, returning `None` on overflow.
+/// The nanoseconds of the interval are added and the result is rounded down to days.
+/// See [`checked_add_naive_interval`] for how the months are added.
+#[inline]
+pub fn checked_add_date32_interval(
+    days: i32,
+    interval: months_days_ns,
+    end_of_month: EndOfMonth,
+) -> Option<i32> {
+    let datetime = (days as i64).checked_mul(SECONDS_IN_DAY)?;
+    let datetime = checked_add_naive_interval(datetime, TimeUnit::Second, interval, end_of_month)?;
+    i32::try_from(datetime.div_euclid(SECONDS_IN_DAY)).ok()
+}
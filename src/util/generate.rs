@@ -0,0 +1,406 @@
+//! Generators of deterministic random data of arbitrary [`DataType`]s, e.g. to benchmark
+//! kernels and IO paths reproducibly.
+use std::sync::Arc;
+
+use rand::distributions::Alphanumeric;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::array::*;
+use crate::bitmap::Bitmap;
+use crate::buffer::Buffer;
+use crate::datatypes::{
+    DataType, Field, IntegerType, IntervalUnit, PrimitiveType, Schema, TimeUnit,
+};
+use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
+use crate::types::{days_ms, months_days_ns, NativeType};
+
+// maximum length of generated utf8 and binary values, and of generated list elements
+const MAX_LENGTH: usize = 16;
+const MAX_LIST_LENGTH: usize = 4;
+
+/// Returns a [`RecordBatch`] of `length` rows with random values for each field of `schema`.
+///
+/// The data is fully determined by `seed`, on every platform:
+/// * nullable fields have each slot null with probability `null_density`, and non-nullable
+///   fields have no nulls;
+/// * when `cardinality` is `Some(n)`, the values of each primitive, utf8 and binary array
+///   (including the children of nested arrays) are drawn from `n` random values, and the values
+///   of dictionary arrays have (at most) `n` elements.
+/// # Example
+/// ```rust
+/// use arrow2::datatypes::{DataType, Field, Schema};
+/// use arrow2::util::generate::record_batch;
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let schema = Schema::new(vec![
+///     Field::new("a", DataType::Int32, true),
+///     Field::new("b", DataType::List(Box::new(Field::new("item", DataType::Utf8, true))), false),
+/// ]);
+/// let batch = record_batch(&schema, 100, 0, 0.1, Some(10))?;
+/// assert_eq!(batch.num_rows(), 100);
+/// assert_eq!(batch, record_batch(&schema, 100, 0, 0.1, Some(10))?);
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// Errors iff a field has a [`DataType`] whose generation is not supported (unions).
+pub fn record_batch(
+    schema: &Schema,
+    length: usize,
+    seed: u64,
+    null_density: f32,
+    cardinality: Option<usize>,
+) -> Result<RecordBatch> {
+    let mut generator = Generator::new(seed, null_density, cardinality);
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| generator.field(field, length).map(|array| array.into()))
+        .collect::<Result<Vec<_>>>()?;
+    RecordBatch::try_new(Arc::new(schema.clone()), columns)
+}
+
+/// Returns an [`Array`] of `data_type` and `length` with random values.
+/// See [`record_batch`] for the meaning of the arguments.
+/// # Errors
+/// Errors iff the generation of `data_type` is not supported (unions).
+pub fn array(
+    data_type: &DataType,
+    length: usize,
+    seed: u64,
+    null_density: f32,
+    cardinality: Option<usize>,
+) -> Result<Box<dyn Array>> {
+    Generator::new(seed, null_density, cardinality).array(data_type, length, null_density)
+}
+
+struct Generator {
+    rng: ChaCha8Rng,
+    null_density: f32,
+    cardinality: Option<usize>,
+}
+
+impl Generator {
+    fn new(seed: u64, null_density: f32, cardinality: Option<usize>) -> Self {
+        Self {
+            rng: ChaCha8Rng::seed_from_u64(seed),
+            null_density,
+            cardinality,
+        }
+    }
+
+    fn field(&mut self, field: &Field, length: usize) -> Result<Box<dyn Array>> {
+        let null_density = if field.is_nullable() {
+            self.null_density
+        } else {
+            0.0
+        };
+        self.array(field.data_type(), length, null_density)
+    }
+
+    fn validity(&mut self, length: usize, null_density: f32) -> Option<Bitmap> {
+        if null_density <= 0.0 {
+            return None;
+        }
+        let rng = &mut self.rng;
+        Some(
+            (0..length)
+                .map(|_| rng.gen::<f32>() >= null_density)
+                .collect(),
+        )
+    }
+
+    /// Returns `length` values generated by `gen`, drawn from a pool of `cardinality` values if set
+    fn values<T: Clone>(&mut self, length: usize, gen: impl Fn(&mut ChaCha8Rng) -> T) -> Vec<T> {
+        let rng = &mut self.rng;
+        match self.cardinality {
+            Some(cardinality) if cardinality > 0 => {
+                let pool = (0..cardinality).map(|_| gen(rng)).collect::<Vec<_>>();
+                (0..length)
+                    .map(|_| pool[rng.gen_range(0..cardinality)].clone())
+                    .collect()
+            }
+            _ => (0..length).map(|_| gen(rng)).collect(),
+        }
+    }
+
+    fn primitive<T: NativeType>(
+        &mut self,
+        data_type: &DataType,
+        length: usize,
+        null_density: f32,
+        gen: impl Fn(&mut ChaCha8Rng) -> T,
+    ) -> Box<dyn Array> {
+        let values = self.values(length, gen);
+        let validity = self.validity(length, null_density);
+        Box::new(PrimitiveArray::<T>::from_data(
+            data_type.clone(),
+            values.into(),
+            validity,
+        ))
+    }
+
+    fn bytes(&mut self, length: usize, utf8: bool) -> Vec<Vec<u8>> {
+        self.values(length, |rng| {
+            let size = rng.gen_range(0..=MAX_LENGTH);
+            if utf8 {
+                rng.sample_iter(&Alphanumeric).take(size).collect()
+            } else {
+                (0..size).map(|_| rng.gen()).collect()
+            }
+        })
+    }
+
+    fn offsets<O: Offset>(&mut self, length: usize) -> Vec<O> {
+        let mut offsets = Vec::with_capacity(length + 1);
+        offsets.push(O::zero());
+        let mut total = 0;
+        for _ in 0..length {
+            total += self.rng.gen_range(0..=MAX_LIST_LENGTH);
+            offsets.push(O::from_usize(total).unwrap());
+        }
+        offsets
+    }
+
+    fn array(
+        &mut self,
+        data_type: &DataType,
+        length: usize,
+        null_density: f32,
+    ) -> Result<Box<dyn Array>> {
+        use crate::datatypes::PhysicalType::*;
+        Ok(match data_type.to_physical_type() {
+            Null => Box::new(NullArray::from_data(data_type.clone(), length)),
+            Boolean => {
+                let values = self.values(length, |rng| rng.gen::<bool>());
+                let validity = self.validity(length, null_density);
+                Box::new(BooleanArray::from_data(
+                    data_type.clone(),
+                    values.into_iter().collect(),
+                    validity,
+                ))
+            }
+            Primitive(primitive) => {
+                self.primitive_array(primitive, data_type, length, null_density)
+            }
+            Utf8 => self.binary_array::<i32>(data_type, length, null_density, true),
+            LargeUtf8 => self.binary_array::<i64>(data_type, length, null_density, true),
+            Binary => self.binary_array::<i32>(data_type, length, null_density, false),
+            LargeBinary => self.binary_array::<i64>(data_type, length, null_density, false),
+            FixedSizeBinary => {
+                let size = FixedSizeBinaryArray::get_size(data_type);
+                let values = self.values(length, |rng| {
+                    (0..size).map(|_| rng.gen::<u8>()).collect::<Vec<_>>()
+                });
+                let validity = self.validity(length, null_density);
+                Box::new(FixedSizeBinaryArray::from_data(
+                    data_type.clone(),
+                    values.concat().into(),
+                    validity,
+                ))
+            }
+            List => self.list_array::<i32>(data_type, length, null_density)?,
+            LargeList => self.list_array::<i64>(data_type, length, null_density)?,
+            FixedSizeList => {
+                let (field, size) = FixedSizeListArray::get_child_and_size(data_type);
+                let values = self.field(field, length * size)?;
+                let validity = self.validity(length, null_density);
+                Box::new(FixedSizeListArray::from_data(
+                    data_type.clone(),
+                    values.into(),
+                    validity,
+                ))
+            }
+            Struct => {
+                let values = StructArray::get_fields(data_type)
+                    .iter()
+                    .map(|field| self.field(field, length).map(|array| array.into()))
+                    .collect::<Result<Vec<_>>>()?;
+                let validity = self.validity(length, null_density);
+                Box::new(StructArray::from_data(data_type.clone(), values, validity))
+            }
+            Map => {
+                let offsets = self.offsets::<i32>(length);
+                let entries =
+                    self.field(MapArray::get_field(data_type), offsets[length] as usize)?;
+                let validity = self.validity(length, null_density);
+                Box::new(MapArray::from_data(
+                    data_type.clone(),
+                    offsets.into(),
+                    entries.into(),
+                    validity,
+                ))
+            }
+            Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+                self.dictionary_array::<$T>(key_type, data_type, length, null_density)?
+            }),
+            Union => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Generating arrays of {:?}",
+                    data_type
+                )))
+            }
+        })
+    }
+
+    fn primitive_array(
+        &mut self,
+        primitive: PrimitiveType,
+        data_type: &DataType,
+        length: usize,
+        null_density: f32,
+    ) -> Box<dyn Array> {
+        // temporal values are kept within 1970-2069, so that they are valid dates and times
+        const DAYS: i64 = 36525;
+        const SECONDS: i64 = 86400;
+        let range = match data_type.to_logical_type() {
+            DataType::Date32 => Some(DAYS),
+            DataType::Date64 => Some(DAYS * SECONDS * 1_000),
+            DataType::Time32(TimeUnit::Second) => Some(SECONDS),
+            DataType::Time32(TimeUnit::Millisecond) => Some(SECONDS * 1_000),
+            DataType::Time64(TimeUnit::Microsecond) => Some(SECONDS * 1_000_000),
+            DataType::Time64(TimeUnit::Nanosecond) => Some(SECONDS * 1_000_000_000),
+            DataType::Timestamp(unit, _) | DataType::Duration(unit) => {
+                let factor = match unit {
+                    TimeUnit::Second => 1,
+                    TimeUnit::Millisecond => 1_000,
+                    TimeUnit::Microsecond => 1_000_000,
+                    TimeUnit::Nanosecond => 1_000_000_000,
+                };
+                Some(DAYS * SECONDS * factor)
+            }
+            DataType::Interval(IntervalUnit::YearMonth) => Some(1200),
+            _ => None,
+        };
+
+        use PrimitiveType::*;
+        match primitive {
+            Int8 => self.primitive(data_type, length, null_density, |rng| rng.gen::<i8>()),
+            Int16 => self.primitive(data_type, length, null_density, |rng| rng.gen::<i16>()),
+            Int32 => match range {
+                Some(range) => self.primitive(data_type, length, null_density, |rng| {
+                    rng.gen_range(0..range as i32)
+                }),
+                None => self.primitive(data_type, length, null_density, |rng| rng.gen::<i32>()),
+            },
+            Int64 => match range {
+                Some(range) => self.primitive(data_type, length, null_density, |rng| {
+                    rng.gen_range(0..range)
+                }),
+                None => self.primitive(data_type, length, null_density, |rng| rng.gen::<i64>()),
+            },
+            Int128 => match data_type.to_logical_type() {
+                DataType::Decimal(precision, _) => {
+                    let max = 10i128.pow(*precision as u32);
+                    self.primitive(data_type, length, null_density, |rng| {
+                        rng.gen_range(-max + 1..max)
+                    })
+                }
+                _ => self.primitive(data_type, length, null_density, |rng| rng.gen::<i128>()),
+            },
+            DaysMs => self.primitive(data_type, length, null_density, |rng| {
+                days_ms::new(rng.gen_range(0..DAYS as i32), rng.gen_range(0..86_400_000))
+            }),
+            MonthDayNano => self.primitive(data_type, length, null_density, |rng| {
+                months_days_ns::new(
+                    rng.gen_range(0..1200),
+                    rng.gen_range(0..31),
+                    rng.gen_range(0..SECONDS * 1_000_000_000),
+                )
+            }),
+            UInt8 => self.primitive(data_type, length, null_density, |rng| rng.gen::<u8>()),
+            UInt16 => self.primitive(data_type, length, null_density, |rng| rng.gen::<u16>()),
+            UInt32 => self.primitive(data_type, length, null_density, |rng| rng.gen::<u32>()),
+            UInt64 => self.primitive(data_type, length, null_density, |rng| rng.gen::<u64>()),
+            Float32 => self.primitive(data_type, length, null_density, |rng| rng.gen::<f32>()),
+            Float64 => self.primitive(data_type, length, null_density, |rng| rng.gen::<f64>()),
+        }
+    }
+
+    fn binary_array<O: Offset>(
+        &mut self,
+        data_type: &DataType,
+        length: usize,
+        null_density: f32,
+        utf8: bool,
+    ) -> Box<dyn Array> {
+        let values = self.bytes(length, utf8);
+        let validity = self.validity(length, null_density);
+
+        let mut offsets = Vec::with_capacity(length + 1);
+        offsets.push(O::zero());
+        let mut total = 0;
+        for value in &values {
+            total += value.len();
+            offsets.push(O::from_usize(total).unwrap());
+        }
+        let offsets: Buffer<O> = offsets.into();
+        let values: Buffer<u8> = values.concat().into();
+        if utf8 {
+            Box::new(Utf8Array::<O>::from_data(
+                data_type.clone(),
+                offsets,
+                values,
+                validity,
+            ))
+        } else {
+            Box::new(BinaryArray::<O>::from_data(
+                data_type.clone(),
+                offsets,
+                values,
+                validity,
+            ))
+        }
+    }
+
+    fn list_array<O: Offset>(
+        &mut self,
+        data_type: &DataType,
+        length: usize,
+        null_density: f32,
+    ) -> Result<Box<dyn Array>> {
+        let offsets = self.offsets::<O>(length);
+        let values = self.field(
+            ListArray::<O>::get_child_field(data_type),
+            offsets[length].to_usize(),
+        )?;
+        let validity = self.validity(length, null_density);
+        Ok(Box::new(ListArray::<O>::from_data(
+            data_type.clone(),
+            offsets.into(),
+            values.into(),
+            validity,
+        )))
+    }
+
+    fn dictionary_array<K: DictionaryKey>(
+        &mut self,
+        key_type: IntegerType,
+        data_type: &DataType,
+        length: usize,
+        null_density: f32,
+    ) -> Result<Box<dyn Array>> {
+        let max_keys = match key_type {
+            IntegerType::Int8 => i8::MAX as usize + 1,
+            IntegerType::Int16 => i16::MAX as usize + 1,
+            IntegerType::UInt8 => u8::MAX as usize + 1,
+            IntegerType::UInt16 => u16::MAX as usize + 1,
+            _ => usize::MAX,
+        };
+        let num_values = self.cardinality.unwrap_or(length).min(max_keys).max(1);
+        let values = self.array(DictionaryArray::<K>::get_child(data_type), num_values, 0.0)?;
+
+        let rng = &mut self.rng;
+        let keys = (0..length)
+            .map(|_| K::from_usize(rng.gen_range(0..num_values)).unwrap())
+            .collect::<Vec<_>>();
+        let validity = self.validity(length, null_density);
+        let keys = PrimitiveArray::<K>::from_data(K::DATA_TYPE, keys.into(), validity);
+        Ok(Box::new(
+            DictionaryArray::<K>::from_data(keys, values.into())
+                .with_ordered(DictionaryArray::<K>::get_is_ordered(data_type)),
+        ))
+    }
+}
@@ -18,3 +18,7 @@ pub use lexical::*;
 #[cfg(feature = "benchmarks")]
 #[cfg_attr(docsrs, doc(cfg(feature = "benchmarks")))]
 pub mod bench_util;
+
+#[cfg(feature = "benchmarks")]
+#[cfg_attr(docsrs, doc(cfg(feature = "benchmarks")))]
+pub mod generate;
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::datatypes::DataType;
+
+#[test]
+fn downcast() {
+    let array: ArrayRef = Arc::new(Int32Array::from_slice(&[1, 2]));
+    assert_eq!(array.as_primitive::<i32>().unwrap().value(0), 1);
+    assert!(array.as_primitive::<u32>().is_none());
+    assert!(array.as_boolean().is_none());
+
+    let array: Box<dyn Array> = Box::new(Utf8Array::<i64>::from_slice(&["a"]));
+    assert_eq!(array.as_utf8::<i64>().unwrap().value(0), "a");
+    assert!(array.as_utf8::<i32>().is_none());
+    assert!(array.as_binary::<i64>().is_none());
+}
+
+#[test]
+fn downcast_across_threads() {
+    let array: ArrayRef = Arc::new(Int64Array::from_slice(&[1, 2, 3]));
+    let handles = (0..2)
+        .map(|_| {
+            let array = array.clone();
+            std::thread::spawn(move || {
+                array
+                    .as_primitive::<i64>()
+                    .unwrap()
+                    .values()
+                    .iter()
+                    .sum::<i64>()
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), 6);
+    }
+}
+
+#[test]
+fn any_array() {
+    let array = Int32Array::from_slice(&[1, 2]).to(DataType::Date32);
+    match AnyArray::from(&array as &dyn Array) {
+        AnyArray::Primitive(AnyPrimitiveArray::Int32(a)) => assert_eq!(a, &array),
+        other => panic!("unexpected {:?}", other),
+    }
+
+    let array = BooleanArray::from_slice(&[true]);
+    let any = AnyArray::from(&array as &dyn Array);
+    assert!(matches!(any, AnyArray::Boolean(_)));
+    assert_eq!(any.as_array().len(), 1);
+
+    let values = Arc::new(Utf8Array::<i32>::from_slice(&["a"]));
+    let array = DictionaryArray::<u8>::from_data(UInt8Array::from_slice(&[0, 0]), values);
+    assert!(matches!(
+        AnyArray::from(&array as &dyn Array),
+        AnyArray::Dictionary(AnyDictionaryArray::UInt8(_))
+    ));
+}
+
+#[test]
+fn any_array_all_nulls() {
+    use arrow2::datatypes::*;
+    let datatypes = vec![
+        DataType::Null,
+        DataType::Int8,
+        DataType::Float64,
+        DataType::Interval(IntervalUnit::DayTime),
+        DataType::Decimal(10, 2),
+        DataType::Utf8,
+        DataType::LargeBinary,
+        DataType::FixedSizeBinary(2),
+        DataType::List(Box::new(Field::new("a", DataType::Int32, true))),
+        DataType::Struct(vec![Field::new("a", DataType::Int32, true)]),
+    ];
+    for data_type in datatypes {
+        let array = new_null_array(data_type, 2);
+        assert_eq!(AnyArray::from(array.as_ref()).as_array(), array.as_ref());
+    }
+}
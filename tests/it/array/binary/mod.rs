@@ -135,3 +135,15 @@ fn value_unchecked_with_wrong_offsets_panics() {
     // even if `0` is in bounds
     unsafe { array.value_unchecked(0) };
 }
+
+#[test]
+fn offsets_windows_and_values_bytes_iter() {
+    let array = BinaryArray::<i64>::from(&[Some(b"hello".as_ref()), None, Some(b"hello2")]);
+    let array = array.slice(1, 2);
+
+    let windows = array.offsets_windows().collect::<Vec<_>>();
+    assert_eq!(windows, vec![(5, 5), (5, 11)]);
+
+    let values = array.values_bytes_iter().collect::<Vec<_>>();
+    assert_eq!(values, vec![b"".as_ref(), b"hello2"]);
+}
@@ -107,3 +107,24 @@ fn extend_trusted_len() {
         Some(&Bitmap::from_u8_slice(&[0b00001011], 4))
     );
 }
+
+#[test]
+fn extend_constant() {
+    let mut a = MutableBinaryArray::<i32>::new();
+    a.extend_constant(2, Some(b"ab"));
+    a.extend_constant(1, None::<&[u8]>);
+    let a: BinaryArray<i32> = a.into();
+    assert_eq!(
+        a,
+        BinaryArray::<i32>::from([Some(b"ab"), Some(b"ab"), None])
+    );
+}
+
+#[test]
+fn extend_from_slice() {
+    let mut a = MutableBinaryArray::<i32>::new();
+    a.extend_from_slice(&[b"a", b"b"]);
+    assert_eq!(a.validity(), None);
+    let a: BinaryArray<i32> = a.into();
+    assert_eq!(a, BinaryArray::<i32>::from([Some(b"a"), Some(b"b")]));
+}
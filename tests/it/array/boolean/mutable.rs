@@ -1,4 +1,4 @@
-use arrow2::array::{MutableArray, MutableBooleanArray};
+use arrow2::array::{BooleanArray, MutableArray, MutableBooleanArray};
 use arrow2::bitmap::MutableBitmap;
 use arrow2::datatypes::DataType;
 use arrow2::error::Result;
@@ -97,3 +97,26 @@ fn extend_trusted_len_values() {
     );
     assert_eq!(a.values(), &MutableBitmap::from([false, true, false]));
 }
+
+#[test]
+fn extend_constant() {
+    let mut a = MutableBooleanArray::new();
+    a.extend_constant(2, Some(true));
+    assert_eq!(a.validity(), None);
+    a.extend_constant(1, None);
+    a.extend_constant(1, Some(false));
+    let a: BooleanArray = a.into();
+    assert_eq!(
+        a,
+        BooleanArray::from(&[Some(true), Some(true), None, Some(false)])
+    );
+}
+
+#[test]
+fn extend_from_slice() {
+    let mut a = MutableBooleanArray::new();
+    a.push(None);
+    a.extend_from_slice(&[true, false]);
+    let a: BooleanArray = a.into();
+    assert_eq!(a, BooleanArray::from(&[None, Some(true), Some(false)]));
+}
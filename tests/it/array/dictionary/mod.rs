@@ -1 +1,100 @@
 mod mutable;
+
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::error::Result;
+
+#[test]
+fn try_from_strings() -> Result<()> {
+    let array = DictionaryArray::<i32>::try_from_strings(
+        [Some("low"), None, Some("high"), Some("low")],
+        &["low", "medium", "high"],
+        true,
+    )?;
+
+    assert!(array.is_ordered());
+    assert_eq!(
+        array.codes(),
+        &Int32Array::from([Some(0), None, Some(2), Some(0)])
+    );
+    let expected =
+        Arc::new(Utf8Array::<i32>::from_slice(["low", "medium", "high"])) as Arc<dyn Array>;
+    assert_eq!(array.categories(), &expected);
+
+    // slicing preserves the ordering
+    assert!(array.slice(1, 2).is_ordered());
+    Ok(())
+}
+
+#[test]
+fn try_from_strings_errors() {
+    assert!(DictionaryArray::<i32>::try_from_strings([Some("a")], &["b"], false).is_err());
+    assert!(DictionaryArray::<i32>::try_from_strings([Some("a")], &["a", "a"], false).is_err());
+    let categories = (0..200).map(|x| x.to_string()).collect::<Vec<_>>();
+    assert!(DictionaryArray::<i8>::try_from_strings([Some("1")], &categories, false).is_err());
+}
+
+#[test]
+fn ordered_compare() -> Result<()> {
+    let categories = ["low", "medium", "high"];
+    let lhs =
+        DictionaryArray::<i32>::try_from_strings([Some("low"), Some("high")], &categories, true)?;
+    let rhs = DictionaryArray::<i32>::try_from_strings(
+        [Some("medium"), Some("medium")],
+        &categories,
+        true,
+    )?;
+
+    let cmp = ord::build_compare(&lhs, &rhs)?;
+    assert_eq!(cmp(0, 0), std::cmp::Ordering::Less);
+    assert_eq!(cmp(1, 1), std::cmp::Ordering::Greater);
+
+    // lexicographically, "high" < "medium"
+    let cmp = ord::build_compare(&lhs.clone().with_ordered(false), &rhs.with_ordered(false))?;
+    assert_eq!(cmp(1, 1), std::cmp::Ordering::Less);
+
+    // ordered dictionaries with different categories can't be compared
+    let other = DictionaryArray::<i32>::try_from_strings([Some("low")], &["low"], true)?;
+    assert!(ord::build_compare(&lhs, &other).is_err());
+    Ok(())
+}
+
+#[test]
+fn ordered_propagation() -> Result<()> {
+    use arrow2::array::growable::{Growable, GrowableDictionary};
+    use arrow2::compute::{concatenate::concatenate, filter::filter};
+
+    let categories = ["low", "medium", "high"];
+    let array =
+        DictionaryArray::<i32>::try_from_strings([Some("low"), Some("high")], &categories, true)?;
+    assert_ne!(array, array.clone().with_ordered(false));
+
+    let result = filter(&array, &BooleanArray::from_slice([false, true]))?;
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    assert!(result.is_ordered());
+    assert_eq!(result.codes(), &Int32Array::from_slice([2]));
+
+    // equal categories are shared, preserving the order of the codes
+    let result = concatenate(&[&array, &array])?;
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    assert!(result.is_ordered());
+    assert_eq!(result.categories(), array.categories());
+    assert_eq!(result.codes(), &Int32Array::from_slice([0, 2, 0, 2]));
+
+    // different categories are concatenated and are no longer ordered
+    let other = DictionaryArray::<i32>::try_from_strings([Some("low")], &["low"], true)?;
+    let mut growable = GrowableDictionary::new(&[&array, &other], false, 3);
+    growable.extend(0, 0, 2);
+    growable.extend(1, 0, 1);
+    let result: DictionaryArray<i32> = growable.into();
+    assert!(!result.is_ordered());
+    assert_eq!(result.codes(), &Int32Array::from_slice([0, 2, 3]));
+    Ok(())
+}
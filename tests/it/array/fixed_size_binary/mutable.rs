@@ -67,3 +67,41 @@ fn push_null() {
     let array: FixedSizeBinaryArray = array.into();
     assert_eq!(array.validity(), Some(&Bitmap::from([false])));
 }
+
+#[test]
+fn extend_constant() {
+    let mut a = MutableFixedSizeBinaryArray::new(2);
+    a.extend_constant(2, Some(b"ab"));
+    assert_eq!(a.validity(), None);
+    a.extend_constant(1, None::<&[u8]>);
+    assert_eq!(
+        a.validity(),
+        Some(&MutableBitmap::from([true, true, false]))
+    );
+    assert_eq!(
+        a.values(),
+        &MutableBuffer::from([b'a', b'b', b'a', b'b', 0, 0])
+    );
+    assert!(a.try_extend_constant(1, Some(b"abc")).is_err());
+}
+
+#[test]
+fn extend_from_slice() {
+    let mut a = MutableFixedSizeBinaryArray::new(2);
+    a.push(None::<&[u8]>);
+    a.extend_from_slice(b"abcd");
+    assert_eq!(
+        a.validity(),
+        Some(&MutableBitmap::from([false, true, true]))
+    );
+    assert_eq!(
+        a.values(),
+        &MutableBuffer::from([0, 0, b'a', b'b', b'c', b'd'])
+    );
+}
+
+#[test]
+#[should_panic]
+fn new_zero_size() {
+    MutableFixedSizeBinaryArray::new(0);
+}
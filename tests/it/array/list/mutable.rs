@@ -50,3 +50,59 @@ fn push() {
     assert_eq!(array.offsets().as_ref(), [0, 3]);
     assert_eq!(array.validity(), None);
 }
+
+#[test]
+fn try_from_iter() {
+    let data = vec![
+        Some(vec![Some(1i32), Some(2), Some(3)]),
+        None,
+        Some(vec![Some(4), None, Some(6)]),
+    ];
+
+    let array =
+        ListArray::<i32>::try_from_iter::<MutablePrimitiveArray<i32>, _, _, _>(data.clone())
+            .unwrap();
+
+    let mut expected = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    expected.try_extend(data).unwrap();
+    let expected: ListArray<i32> = expected.into();
+    assert_eq!(array, expected);
+}
+
+#[test]
+fn extend_from_lengths() {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_push(Some(vec![Some(0i32)])).unwrap();
+    array
+        .try_extend_from_lengths(
+            vec![Some(1), Some(2), Some(3), Some(4), None, Some(6)],
+            vec![Some(3), None, Some(3), Some(0)],
+        )
+        .unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let values =
+        PrimitiveArray::<i32>::from([Some(0), Some(1), Some(2), Some(3), Some(4), None, Some(6)]);
+    let data_type = ListArray::<i32>::default_datatype(DataType::Int32);
+    let expected = ListArray::<i32>::from_data(
+        data_type,
+        Buffer::from([0, 1, 4, 4, 7, 7]),
+        Arc::new(values),
+        Some(Bitmap::from([true, true, false, true, true])),
+    );
+    assert_eq!(expected, array);
+}
+
+#[test]
+fn extend_from_lengths_mismatch() {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_push(Some(vec![Some(0i32)])).unwrap();
+
+    let result =
+        array.try_extend_from_lengths(vec![Some(1), Some(2)], vec![Some(1), None, Some(2)]);
+    assert!(result.is_err());
+    // no list was added
+    assert_eq!(array.len(), 1);
+    assert_eq!(array.offsets().as_ref(), [0, 1]);
+    assert_eq!(array.validity(), None);
+}
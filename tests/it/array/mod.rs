@@ -1,3 +1,4 @@
+mod any;
 mod binary;
 mod boolean;
 mod dictionary;
@@ -12,9 +13,12 @@ mod struct_;
 mod union;
 mod utf8;
 
-use arrow2::array::{clone, new_empty_array, new_null_array, Array, PrimitiveArray};
+use std::sync::Arc;
+
+use arrow2::array::*;
 use arrow2::bitmap::Bitmap;
-use arrow2::datatypes::{DataType, Field, UnionMode};
+use arrow2::datatypes::{DataType, Field, IntegerType, UnionMode};
+use arrow2::scalar::*;
 
 #[test]
 fn nulls() {
@@ -24,6 +28,12 @@ fn nulls() {
         DataType::Utf8,
         DataType::Binary,
         DataType::List(Box::new(Field::new("a", DataType::Binary, true))),
+        DataType::Null,
+        DataType::Boolean,
+        DataType::LargeUtf8,
+        DataType::FixedSizeBinary(3),
+        DataType::Struct(vec![Field::new("a", DataType::Int8, true)]),
+        DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false),
     ];
     let a = datatypes
         .into_iter()
@@ -57,6 +67,12 @@ fn empty() {
         DataType::Utf8,
         DataType::Binary,
         DataType::List(Box::new(Field::new("a", DataType::Binary, true))),
+        DataType::Null,
+        DataType::Boolean,
+        DataType::LargeUtf8,
+        DataType::FixedSizeBinary(3),
+        DataType::Struct(vec![Field::new("a", DataType::Int8, true)]),
+        DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false),
         DataType::Union(
             vec![Field::new("a", DataType::Binary, true)],
             None,
@@ -72,6 +88,101 @@ fn empty() {
     assert!(a);
 }
 
+#[test]
+fn full() {
+    let scalar = PrimitiveScalar::new(DataType::Int32, Some(1i32));
+    let result = new_full_array(&scalar, 3).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &Int32Array::from_slice(&[1, 1, 1]) as &dyn Array
+    );
+
+    let scalar = Utf8Scalar::<i32>::new(Some("ab"));
+    let result = new_full_array(&scalar, 2).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &Utf8Array::<i32>::from_slice(&["ab", "ab"]) as &dyn Array
+    );
+
+    let scalar = BooleanScalar::new(Some(true));
+    let result = new_full_array(&scalar, 2).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &BooleanArray::from_slice(&[true, true]) as &dyn Array
+    );
+
+    let values: Arc<dyn Array> = Arc::new(Int32Array::from_slice(&[1, 2]));
+    let scalar = ListScalar::<i32>::new(
+        DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+        Some(values),
+    );
+    let result = new_full_array(&scalar, 2).unwrap();
+    let result = result.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+    assert_eq!(result.offsets().as_slice(), &[0, 2, 4]);
+    assert_eq!(
+        result.values().as_ref(),
+        &Int32Array::from_slice(&[1, 2, 1, 2]) as &dyn Array
+    );
+}
+
+#[test]
+fn full_fixed_size_binary() {
+    let scalar = FixedSizeBinaryScalar::new(DataType::FixedSizeBinary(2), Some(vec![1u8, 2]));
+    let result = new_full_array(&scalar, 3).unwrap();
+    let expected = FixedSizeBinaryArray::from_data(
+        DataType::FixedSizeBinary(2),
+        vec![1, 2, 1, 2, 1, 2].into(),
+        None,
+    );
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[derive(Debug)]
+struct MapScalar(DataType);
+
+impl Scalar for MapScalar {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn is_valid(&self) -> bool {
+        true
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.0
+    }
+}
+
+#[test]
+fn full_unsupported() {
+    let field = Field::new(
+        "entries",
+        DataType::Struct(vec![
+            Field::new("key", DataType::Utf8, false),
+            Field::new("value", DataType::Int32, true),
+        ]),
+        false,
+    );
+    let scalar = MapScalar(DataType::Map(Box::new(field), false));
+    assert!(new_full_array(&scalar, 2).is_err());
+}
+
+#[test]
+fn full_null() {
+    let datatypes = vec![
+        DataType::Int32,
+        DataType::Utf8,
+        DataType::Binary,
+        DataType::Boolean,
+    ];
+    let a = datatypes.into_iter().all(|x| {
+        let scalar = new_scalar(new_null_array(x.clone(), 1).as_ref(), 0);
+        new_full_array(scalar.as_ref(), 10).unwrap() == new_null_array(x, 10)
+    });
+    assert!(a);
+}
+
 #[test]
 fn test_clone() {
     let datatypes = vec![
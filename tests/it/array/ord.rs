@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 
-use arrow2::array::ord::build_compare;
+use arrow2::array::ord::{build_compare, fixed_size_cmp};
 use arrow2::array::*;
 use arrow2::error::Result;
 
@@ -101,3 +101,39 @@ fn dict_i32() -> Result<()> {
     assert_eq!(Ordering::Greater, (cmp)(2, 3));
     Ok(())
 }
+
+#[test]
+fn fixed_size_binary() -> Result<()> {
+    // sizes compared as words of 128, 64 and 32 bits, and byte per byte
+    for size in [32, 24, 4, 3] {
+        let mut a = vec![0u8; size];
+        let mut b = vec![0u8; size];
+        a[size - 1] = 1;
+        b[0] = 1;
+        let array = FixedSizeBinaryArray::from_iter(vec![Some(a), Some(b)], size);
+
+        let cmp = build_compare(&array, &array)?;
+
+        assert_eq!(Ordering::Less, (cmp)(0, 1));
+        assert_eq!(Ordering::Greater, (cmp)(1, 0));
+        assert_eq!(Ordering::Equal, (cmp)(1, 1));
+    }
+    Ok(())
+}
+
+#[test]
+fn fixed_size_binary_different_sizes() {
+    let left = FixedSizeBinaryArray::from_iter(vec![Some([1u8; 8])], 8);
+    let right = FixedSizeBinaryArray::from_iter(vec![Some([2u8; 4])], 4);
+    assert!(build_compare(&left, &right).is_err());
+
+    // byte strings of other lengths than `size` are compared lexicographically
+    let cmp = fixed_size_cmp(8);
+    assert_eq!(cmp(&[1; 8], &[2; 4]), Ordering::Less);
+    assert_eq!(cmp(&[1; 8], &[1; 4]), Ordering::Greater);
+    assert_eq!(
+        cmp(&[1; 12], &[1, 1, 1, 1, 1, 1, 1, 1, 0]),
+        Ordering::Greater
+    );
+    assert_eq!(cmp(&[1; 8], &[1; 8]), Ordering::Equal);
+}
@@ -187,3 +187,21 @@ fn wrong_data_type() {
     let values = MutableBuffer::from(b"abbb");
     MutablePrimitiveArray::from_data(DataType::Utf8, values, None);
 }
+
+#[test]
+fn extend_constant() {
+    let mut a = MutablePrimitiveArray::<i32>::new();
+    a.extend_constant(2, Some(1));
+    assert_eq!(a.validity(), None);
+    a.extend_constant(2, None);
+    assert_eq!(
+        a.validity(),
+        Some(&MutableBitmap::from([true, true, false, false]))
+    );
+    a.extend_constant(1, Some(2));
+    assert_eq!(
+        a.validity(),
+        Some(&MutableBitmap::from([true, true, false, false, true]))
+    );
+    assert_eq!(a.values(), &MutableBuffer::<i32>::from([1, 1, 0, 0, 2]));
+}
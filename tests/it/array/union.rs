@@ -40,8 +40,93 @@ fn slice() -> Result<()> {
     let result = array.slice(1, 2);
 
     let types = Buffer::from(&[0, 1]);
+    let fields = fields.iter().map(|x| x.slice(1, 2).into()).collect();
     let expected = UnionArray::from_data(data_type, types, fields, None);
 
     assert_eq!(expected, result);
     Ok(())
 }
+
+fn dense() -> UnionArray {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+    let types = Buffer::from(&[0, 1, 0, 1, 0]);
+    let fields = vec![
+        Arc::new(Int32Array::from(&[Some(1), None, Some(2)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b")])) as Arc<dyn Array>,
+    ];
+    let offsets = Some(Buffer::from(&[0, 0, 1, 1, 2]));
+    UnionArray::from_data(data_type, types, fields, offsets)
+}
+
+#[test]
+fn slice_dense() -> Result<()> {
+    let array = dense();
+    let result = array.slice(2, 2);
+
+    assert_eq!(format!("{}", result), "UnionArray[, b]");
+    assert_eq!(result.offsets().as_ref().unwrap().as_slice(), &[1, 1]);
+    Ok(())
+}
+
+#[test]
+fn child_slices() -> Result<()> {
+    let array = dense().slice(1, 3);
+    let slices = array.child_slices();
+    assert_eq!(slices[0].as_ref(), &Int32Array::from(&[None]) as &dyn Array);
+    assert_eq!(
+        slices[1].as_ref(),
+        &Utf8Array::<i32>::from(&[Some("a"), Some("b")]) as &dyn Array
+    );
+
+    // fields not selected by any slot have empty slices
+    let slices = dense().slice(1, 1).child_slices();
+    assert_eq!(slices[0].len(), 0);
+    assert_eq!(slices[1].len(), 1);
+    Ok(())
+}
+
+#[test]
+fn child_slices_sparse() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Sparse);
+    let types = Buffer::from(&[0, 0, 1]);
+    let fields = vec![
+        Arc::new(Int32Array::from(&[Some(1), None, Some(2)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b"), Some("c")])) as Arc<dyn Array>,
+    ];
+    let array = UnionArray::from_data(data_type, types, fields, None).slice(1, 2);
+
+    let slices = array.child_slices();
+    assert_eq!(
+        slices[0].as_ref(),
+        &Int32Array::from(&[None, Some(2)]) as &dyn Array
+    );
+    assert_eq!(
+        slices[1].as_ref(),
+        &Utf8Array::<i32>::from(&[Some("b"), Some("c")]) as &dyn Array
+    );
+
+    let result = array.with_child_slices();
+    assert_eq!(result, array);
+    assert_eq!(result.fields()[0].len(), 2);
+    Ok(())
+}
+
+#[test]
+fn with_child_slices() -> Result<()> {
+    let array = dense().slice(2, 3);
+    let result = array.with_child_slices();
+
+    assert_eq!(result, array);
+    assert_eq!(result.offsets().as_ref().unwrap().as_slice(), &[0, 0, 1]);
+    assert_eq!(result.fields()[0].len(), 2);
+    assert_eq!(result.fields()[1].len(), 1);
+    Ok(())
+}
@@ -174,3 +174,36 @@ fn index_out_of_bounds_panics() {
 
     array.value(3);
 }
+
+#[test]
+fn offsets_windows() {
+    let array = Utf8Array::<i32>::from(&[Some("hello"), None, Some("hello2")]);
+    let windows = array.offsets_windows().collect::<Vec<_>>();
+    assert_eq!(windows, vec![(0, 5), (5, 5), (5, 11)]);
+
+    // the positions are in the (unsliced) values
+    let array = array.slice(1, 2);
+    let windows = array.offsets_windows().collect::<Vec<_>>();
+    assert_eq!(windows, vec![(5, 5), (5, 11)]);
+    assert_eq!(array.offsets_windows().next_back(), Some((5, 11)));
+
+    let array = Utf8Array::<i32>::new_empty(DataType::Utf8);
+    assert_eq!(array.offsets_windows().next(), None);
+}
+
+#[test]
+fn values_bytes_iter() {
+    let array = Utf8Array::<i32>::from(&[Some("hello"), None, Some("hello2")]);
+    let iter = array.values_bytes_iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(
+        iter.collect::<Vec<_>>(),
+        vec![b"hello".as_ref(), b"", b"hello2"]
+    );
+
+    let array = array.slice(2, 1);
+    assert_eq!(
+        array.values_bytes_iter().rev().collect::<Vec<_>>(),
+        vec![b"hello2".as_ref()]
+    );
+}
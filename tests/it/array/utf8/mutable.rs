@@ -81,3 +81,27 @@ fn test_extend_trusted_len() {
         Some(&Bitmap::from_u8_slice(&[0b00011011], 5))
     );
 }
+
+#[test]
+fn extend_constant() {
+    let mut a = MutableUtf8Array::<i32>::new();
+    a.extend_constant(2, Some("ab"));
+    a.extend_constant(0, None::<&str>);
+    a.extend_constant(1, None::<&str>);
+    a.extend_constant(1, Some(""));
+    let a: Utf8Array<i32> = a.into();
+    assert_eq!(
+        a,
+        Utf8Array::<i32>::from([Some("ab"), Some("ab"), None, Some("")])
+    );
+    assert_eq!(a.offsets().as_slice(), &[0, 2, 4, 4, 4]);
+}
+
+#[test]
+fn extend_from_slice() {
+    let mut a = MutableUtf8Array::<i32>::new();
+    a.push(None::<&str>);
+    a.extend_from_slice(&["a", "bc"]);
+    let a: Utf8Array<i32> = a.into();
+    assert_eq!(a, Utf8Array::<i32>::from([None, Some("a"), Some("bc")]));
+}
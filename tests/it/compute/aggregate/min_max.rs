@@ -1,8 +1,9 @@
 use arrow2::compute::aggregate::{
-    max_binary, max_boolean, max_primitive, max_string, min_binary, min_boolean, min_primitive,
-    min_string,
+    max, max_binary, max_boolean, max_fixed_size_binary, max_primitive, max_string, min,
+    min_binary, min_boolean, min_fixed_size_binary, min_primitive, min_string,
 };
-use arrow2::{array::*, datatypes::DataType};
+use arrow2::scalar::FixedSizeBinaryScalar;
+use arrow2::{array::*, datatypes::DataType, error::Result};
 
 #[test]
 fn test_primitive_array_min_max() {
@@ -56,6 +57,28 @@ fn min_max_f64_large() {
     assert_eq!(Some(8.0), max_primitive(&a));
 }
 
+#[test]
+fn min_max_primitive_many_lanes() {
+    // covers several simd chunks, a remainder and a sliced validity
+    let a = (0..101)
+        .map(|x| {
+            if x % 10 == 0 {
+                None
+            } else {
+                Some((x * 37) % 101 - 50)
+            }
+        })
+        .collect::<Vec<_>>();
+    let a = Int32Array::from(a);
+    assert_eq!(Some(-49), min_primitive(&a));
+    assert_eq!(Some(47), max_primitive(&a));
+
+    let a = a.slice(3, 90);
+    let values = a.iter().flatten().copied().collect::<Vec<_>>();
+    assert_eq!(values.iter().min().copied(), min_primitive(&a));
+    assert_eq!(values.iter().max().copied(), max_primitive(&a));
+}
+
 #[test]
 fn min_max_f64_nan_only() {
     let a = Float64Array::from(&[None, Some(f64::NAN)]);
@@ -186,3 +209,20 @@ fn test_binary_min_max_1() {
     assert_eq!(Some("a".as_bytes()), min_binary(&a));
     assert_eq!(Some("b".as_bytes()), max_binary(&a));
 }
+
+#[test]
+fn test_fixed_size_binary_min_max() -> Result<()> {
+    let a =
+        FixedSizeBinaryArray::from_iter(vec![Some(b"bb"), None, Some(b"ab"), Some(b"ba"), None], 2);
+    assert_eq!(Some(b"ab".as_ref()), min_fixed_size_binary(&a));
+    assert_eq!(Some(b"bb".as_ref()), max_fixed_size_binary(&a));
+
+    let expected = FixedSizeBinaryScalar::new(DataType::FixedSizeBinary(2), Some(b"bb"));
+    assert_eq!(max(&a)?.as_ref(), &expected as &dyn arrow2::scalar::Scalar);
+
+    let a = FixedSizeBinaryArray::from_iter(vec![None::<&[u8]>, None], 16);
+    assert_eq!(None, min_fixed_size_binary(&a));
+    let expected = FixedSizeBinaryScalar::new(DataType::FixedSizeBinary(16), None::<Vec<u8>>);
+    assert_eq!(min(&a)?.as_ref(), &expected as &dyn arrow2::scalar::Scalar);
+    Ok(())
+}
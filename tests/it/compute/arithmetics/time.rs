@@ -317,3 +317,148 @@ fn test_date64() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn checked_add_interval_date32_end_of_month() {
+    use arrow2::types::months_days_ns;
+
+    // 2021-01-31, 2020-01-31, 2021-03-31, null
+    let date =
+        PrimitiveArray::from([Some(18658), Some(18292), Some(18717), None]).to(DataType::Date32);
+    let interval = PrimitiveArray::from_slice([
+        months_days_ns::new(1, 0, 0),
+        months_days_ns::new(1, 0, 0),
+        months_days_ns::new(-1, 0, 0),
+        months_days_ns::new(1, 0, 0),
+    ]);
+
+    let result = checked_add_interval_date32(&date, &interval, EndOfMonth::Clamp).unwrap();
+    // 2021-02-28, 2020-02-29, 2021-02-28, null
+    let expected =
+        PrimitiveArray::from([Some(18686), Some(18321), Some(18686), None]).to(DataType::Date32);
+    assert_eq!(result, expected);
+
+    let result = checked_add_interval_date32(&date, &interval, EndOfMonth::Overflow).unwrap();
+    // 2021-03-03, 2020-03-02, 2021-03-03, null
+    let expected =
+        PrimitiveArray::from([Some(18689), Some(18323), Some(18689), None]).to(DataType::Date32);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn checked_add_interval_days_and_overflow() {
+    use arrow2::types::months_days_ns;
+
+    let date = PrimitiveArray::from_slice([18658, 18658]).to(DataType::Date32);
+    let interval = PrimitiveArray::from_slice([
+        // 1 month, 1 day and -1ns
+        months_days_ns::new(1, 1, -1),
+        months_days_ns::new(i32::MAX, 0, 0),
+    ]);
+
+    let result = checked_add_interval_date32(&date, &interval, EndOfMonth::Clamp).unwrap();
+    // 2021-02-28, null
+    let expected = PrimitiveArray::from([Some(18686), None]).to(DataType::Date32);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn checked_add_interval_rounds_down() {
+    use arrow2::types::months_days_ns;
+
+    // 1970-01-01, 1970-01-02 and 1969-12-31
+    let date = PrimitiveArray::from_slice([0, 1, -1]).to(DataType::Date32);
+    let interval = PrimitiveArray::from_slice([
+        months_days_ns::new(0, 0, -1),
+        months_days_ns::new(0, 0, -1),
+        months_days_ns::new(0, 0, 1),
+    ]);
+    let result = checked_add_interval_date32(&date, &interval, EndOfMonth::Clamp).unwrap();
+    // 1969-12-31, 1970-01-01 and 1969-12-31
+    let expected = PrimitiveArray::from_slice([-1, 0, -1]).to(DataType::Date32);
+    assert_eq!(result, expected);
+
+    // 1ns before the epoch
+    let interval = PrimitiveArray::from_slice([months_days_ns::new(0, 0, -1)]);
+    for time_unit in [
+        TimeUnit::Second,
+        TimeUnit::Millisecond,
+        TimeUnit::Microsecond,
+    ] {
+        let data_type = DataType::Timestamp(time_unit, None);
+        let timestamp = PrimitiveArray::from_slice([0i64]).to(data_type.clone());
+        let result = checked_add_interval(&timestamp, &interval, EndOfMonth::Clamp).unwrap();
+        let expected = PrimitiveArray::from_slice([-1i64]).to(data_type);
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+fn checked_add_interval_timestamp() {
+    use arrow2::types::months_days_ns;
+
+    let interval = PrimitiveArray::from_slice([months_days_ns::new(1, 0, 0)]);
+
+    // 2021-01-31T00:30:00Z
+    let timestamp = PrimitiveArray::from_slice([1612053000i64]);
+
+    let naive = timestamp
+        .clone()
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    let result = checked_add_interval(&naive, &interval, EndOfMonth::Clamp).unwrap();
+    // 2021-02-28T00:30:00
+    let expected = PrimitiveArray::from_slice([1614472200i64]).to(naive.data_type().clone());
+    assert_eq!(result, expected);
+
+    // in -01:00, the date is 2021-01-30 and the result is 2021-02-28T23:30:00-01:00
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("-01:00".to_string()));
+    let tz = timestamp.to(data_type.clone());
+    let result = checked_add_interval(&tz, &interval, EndOfMonth::Clamp).unwrap();
+    let expected = PrimitiveArray::from_slice([1614558600i64]).to(data_type);
+    assert_eq!(result, expected);
+
+    let date = PrimitiveArray::from_slice([0i32]).to(DataType::Date32);
+    assert!(
+        checked_add_interval(&naive.to(DataType::Int64), &interval, EndOfMonth::Clamp).is_err()
+    );
+    assert!(
+        checked_add_interval_date32(&date.to(DataType::Int32), &interval, EndOfMonth::Clamp)
+            .is_err()
+    );
+}
+
+#[test]
+fn checked_add_interval_bounds() {
+    use arrow2::types::months_days_ns;
+
+    let interval = PrimitiveArray::from_slice([months_days_ns::new(1, 0, 0); 3]);
+
+    // 1969-12-31T23:59:59.999 + 1 month is 1970-01-31T23:59:59.999
+    let date = PrimitiveArray::from_slice([-1, i64::MAX, i64::MIN]).to(DataType::Date64);
+    let result = checked_add_interval(&date, &interval, EndOfMonth::Clamp).unwrap();
+    let expected = PrimitiveArray::from([Some(2678399999), None, None]).to(DataType::Date64);
+    assert_eq!(result, expected);
+
+    let date = PrimitiveArray::from_slice([-1, i32::MAX, i32::MIN]).to(DataType::Date32);
+    let result = checked_add_interval_date32(&date, &interval, EndOfMonth::Clamp).unwrap();
+    let expected = PrimitiveArray::from([Some(30), None, None]).to(DataType::Date32);
+    assert_eq!(result, expected);
+
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string()));
+    let timestamp = PrimitiveArray::from_slice([-1, i64::MAX, i64::MIN]).to(data_type.clone());
+    let result = checked_add_interval(&timestamp, &interval, EndOfMonth::Clamp).unwrap();
+    let expected = PrimitiveArray::from([Some(2678399), None, None]).to(data_type);
+    assert_eq!(result, expected);
+
+    // the maximum overflows nanoseconds; the minimum is 1677-09-21 and September has 30 days
+    let data_type = DataType::Timestamp(TimeUnit::Nanosecond, None);
+    let timestamp = PrimitiveArray::from_slice([-1, i64::MAX, i64::MIN]).to(data_type.clone());
+    let result = checked_add_interval(&timestamp, &interval, EndOfMonth::Clamp).unwrap();
+    let expected = PrimitiveArray::from([
+        Some(2678399999999999),
+        None,
+        Some(i64::MIN + 2592000000000000),
+    ])
+    .to(data_type);
+    assert_eq!(result, expected);
+}
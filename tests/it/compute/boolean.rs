@@ -1,5 +1,6 @@
 use arrow2::array::*;
 use arrow2::compute::boolean::*;
+use arrow2::scalar::BooleanScalar;
 
 #[test]
 fn array_and() {
@@ -336,3 +337,47 @@ fn test_nullable_array_with_offset_is_not_null() {
 
     assert_eq!(expected, res);
 }
+
+#[test]
+fn array_and_scalar() {
+    let array = BooleanArray::from(&[Some(false), Some(true), None, Some(true)]);
+
+    let result = and_scalar(&array, &BooleanScalar::new(Some(true)));
+    assert_eq!(result, array);
+
+    let result = and_scalar(&array, &BooleanScalar::new(Some(false)));
+    let expected = BooleanArray::from(&[Some(false), Some(false), None, Some(false)]);
+    assert_eq!(result, expected);
+
+    let result = and_scalar(&array, &BooleanScalar::new(None));
+    let expected = BooleanArray::from(&[None, None, None, None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn array_or_scalar() {
+    let array = BooleanArray::from(&[Some(false), Some(true), None, Some(false)]);
+
+    let result = or_scalar(&array, &BooleanScalar::new(Some(true)));
+    let expected = BooleanArray::from(&[Some(true), Some(true), None, Some(true)]);
+    assert_eq!(result, expected);
+
+    let result = or_scalar(&array, &BooleanScalar::new(Some(false)));
+    assert_eq!(result, array);
+
+    let result = or_scalar(&array, &BooleanScalar::new(None));
+    let expected = BooleanArray::from(&[None, None, None, None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn array_set_validity_as_false_values() {
+    let array = BooleanArray::from(&[None, Some(true), None, Some(true), Some(false)]);
+    let array = array.slice(1, 4);
+
+    let result = set_validity_as_false_values(&array);
+
+    let expected = BooleanArray::from_slice([true, false, true, false]);
+    assert_eq!(result, expected);
+    assert_eq!(result.validity(), None);
+}
@@ -1,7 +1,9 @@
 use arrow2::array::*;
-use arrow2::compute::cast::{can_cast_types, cast, CastOptions};
+use arrow2::compute::cast::{
+    can_cast_types, cast, normalize_date64, utf8_to_interval_lossy, CastOptions, Date64Policy,
+};
 use arrow2::datatypes::*;
-use arrow2::types::NativeType;
+use arrow2::types::{months_days_ns, NativeType};
 
 #[test]
 fn i32_to_f64() {
@@ -347,6 +349,7 @@ fn consistency() {
         Duration(TimeUnit::Nanosecond),
         List(Box::new(Field::new("a", Utf8, true))),
         LargeList(Box::new(Field::new("a", Utf8, true))),
+        Decimal(10, 2),
     ];
     for d1 in &datatypes {
         for d2 in &datatypes {
@@ -398,6 +401,64 @@ fn date64_to_date32() {
     );
 }
 
+#[test]
+fn normalize_date64_floor() {
+    let array = Int64Array::from(&[Some(864000000005), None, Some(-1), Some(1545696000000)])
+        .to(DataType::Date64);
+    let result = normalize_date64(&array, Date64Policy::Floor).unwrap();
+    let expected = Int64Array::from(&[
+        Some(864000000000),
+        None,
+        Some(-86400000),
+        Some(1545696000000),
+    ])
+    .to(DataType::Date64);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn normalize_date64_error() {
+    let array = Int64Array::from(&[Some(864000000000), None, Some(-86400000)]).to(DataType::Date64);
+    assert_eq!(
+        normalize_date64(&array, Date64Policy::Error).unwrap(),
+        array
+    );
+
+    let array = Int64Array::from(&[Some(864000000000), Some(1)]).to(DataType::Date64);
+    assert!(normalize_date64(&array, Date64Policy::Error).is_err());
+}
+
+#[test]
+fn utf8_to_interval() {
+    let array = Utf8Array::<i32>::from(&[Some("1 year 2 months"), None, Some("3 days"), Some("x")]);
+
+    let data_type = DataType::Interval(IntervalUnit::YearMonth);
+    let result = cast(&array, &data_type, CastOptions::default()).unwrap();
+    let expected = Int32Array::from(&[Some(14), None, None, None]).to(data_type);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let data_type = DataType::Interval(IntervalUnit::MonthDayNano);
+    let array = array.iter().collect::<Utf8Array<i64>>();
+    let result = cast(&array, &data_type, CastOptions::default()).unwrap();
+    let expected = PrimitiveArray::<months_days_ns>::from(&[
+        Some(months_days_ns::new(14, 0, 0)),
+        None,
+        Some(months_days_ns::new(0, 3, 0)),
+        None,
+    ])
+    .to(data_type);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let data_type = DataType::Duration(TimeUnit::Second);
+    let result = cast(&array, &data_type, CastOptions::default()).unwrap();
+    let expected = Int64Array::from(&[None, None, Some(259_200), None]).to(data_type.clone());
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = utf8_to_interval_lossy(&array, &data_type).unwrap();
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    assert!(utf8_to_interval_lossy(&array, &DataType::Int64).is_err());
+}
+
 #[test]
 fn date32_to_int32() {
     test_primitive_to_primitive(
@@ -483,7 +544,7 @@ fn utf8_to_dict() {
     let array = Utf8Array::<i32>::from(&[Some("one"), None, Some("three"), Some("one")]);
 
     // Cast to a dictionary (same value type, Utf8)
-    let cast_type = DataType::Dictionary(u8::KEY_TYPE, Box::new(DataType::Utf8));
+    let cast_type = DataType::Dictionary(u8::KEY_TYPE, Box::new(DataType::Utf8), false);
     let result = cast(&array, &cast_type, CastOptions::default()).expect("cast failed");
 
     let mut expected = MutableDictionaryArray::<u8, MutableUtf8Array<i32>>::new();
@@ -514,7 +575,7 @@ fn i32_to_dict() {
     let array = Int32Array::from(&[Some(1), None, Some(3), Some(1)]);
 
     // Cast to a dictionary (same value type, Utf8)
-    let cast_type = DataType::Dictionary(u8::KEY_TYPE, Box::new(DataType::Int32));
+    let cast_type = DataType::Dictionary(u8::KEY_TYPE, Box::new(DataType::Int32), false);
     let result = cast(&array, &cast_type, CastOptions::default()).expect("cast failed");
 
     let mut expected = MutableDictionaryArray::<u8, MutablePrimitiveArray<i32>>::new();
@@ -525,6 +586,97 @@ fn i32_to_dict() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn large_utf8_to_dict_and_back() {
+    let array = Utf8Array::<i64>::from(&[Some("one"), None, Some("three"), Some("one")]);
+
+    let cast_type = DataType::Dictionary(u8::KEY_TYPE, Box::new(DataType::LargeUtf8), false);
+    assert!(can_cast_types(array.data_type(), &cast_type));
+    let result = cast(&array, &cast_type, CastOptions::default()).expect("cast failed");
+
+    let mut expected = MutableDictionaryArray::<u8, MutableUtf8Array<i64>>::new();
+    expected
+        .try_extend([Some("one"), None, Some("three"), Some("one")])
+        .unwrap();
+    let expected: DictionaryArray<u8> = expected.into();
+    assert_eq!(expected, result.as_ref());
+
+    let result = cast(
+        result.as_ref(),
+        &DataType::LargeUtf8,
+        CastOptions::default(),
+    )
+    .expect("cast failed");
+    assert_eq!(array, result.as_ref());
+}
+
+#[test]
+fn large_utf8_to_large_binary_dict_and_back() {
+    let array = Utf8Array::<i64>::from(&[Some("one"), None, Some("one")]);
+
+    let cast_type = DataType::Dictionary(i32::KEY_TYPE, Box::new(DataType::LargeBinary), false);
+    assert!(can_cast_types(array.data_type(), &cast_type));
+    let result = cast(&array, &cast_type, CastOptions::default()).expect("cast failed");
+
+    let mut expected = MutableDictionaryArray::<i32, MutableBinaryArray<i64>>::new();
+    expected
+        .try_extend([Some(b"one"), None, Some(b"one")])
+        .unwrap();
+    let expected: DictionaryArray<i32> = expected.into();
+    assert_eq!(expected, result.as_ref());
+
+    assert!(can_cast_types(&cast_type, &DataType::LargeUtf8));
+    let result = cast(
+        result.as_ref(),
+        &DataType::LargeUtf8,
+        CastOptions::default(),
+    )
+    .expect("cast failed");
+    assert_eq!(array, result.as_ref());
+}
+
+#[test]
+fn utf8_to_binary() {
+    let array = Utf8Array::<i32>::from(&[Some("one"), None]);
+
+    let result = cast(&array, &DataType::Binary, CastOptions::default()).expect("cast failed");
+
+    let expected = BinaryArray::<i32>::from(&[Some(b"one"), None]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn to_dict_max_size() {
+    let array = Utf8Array::<i64>::from(&[Some("one"), None, Some("two"), Some("one")]);
+    let cast_type = DataType::Dictionary(u8::KEY_TYPE, Box::new(DataType::LargeUtf8), false);
+
+    // within the limit: dictionary-encoded
+    let options = CastOptions {
+        max_dictionary_size: Some(2),
+        ..Default::default()
+    };
+    let result = cast(&array, &cast_type, options).expect("cast failed");
+    assert_eq!(result.data_type(), &cast_type);
+
+    // above the limit: a plain copy
+    let options = CastOptions {
+        max_dictionary_size: Some(1),
+        ..Default::default()
+    };
+    let result = cast(&array, &cast_type, options).expect("cast failed");
+    assert_eq!(array, result.as_ref());
+
+    let array = BinaryArray::<i64>::from(&[Some(b"a"), Some(b"b")]);
+    let cast_type = DataType::Dictionary(u8::KEY_TYPE, Box::new(DataType::LargeBinary), false);
+    let result = cast(&array, &cast_type, options).expect("cast failed");
+    assert_eq!(array, result.as_ref());
+
+    let array = Int32Array::from_slice(&[1, 2, 1]);
+    let cast_type = DataType::Dictionary(u8::KEY_TYPE, Box::new(DataType::Int64), false);
+    let result = cast(&array, &cast_type, options).expect("cast failed");
+    assert_eq!(Int64Array::from_slice(&[1, 2, 1]), result.as_ref());
+}
+
 #[test]
 fn list_to_list() {
     let data = vec![
@@ -769,3 +921,60 @@ fn make_union_array() -> UnionArray {
     builder.build().unwrap()
 }
 */
+
+#[test]
+fn decimal_to_float_basics() {
+    use arrow2::compute::cast::decimal_to_float;
+
+    let array = Int128Array::from([Some(-12345), None, Some(1)]).to(DataType::Decimal(10, 3));
+    let result = decimal_to_float::<f64>(&array).unwrap();
+    assert_eq!(
+        result,
+        Float64Array::from([Some(-12.345), None, Some(0.001)])
+    );
+
+    let result = decimal_to_float::<f32>(&array).unwrap();
+    assert_eq!(
+        result,
+        Float32Array::from([Some(-12.345), None, Some(0.001)])
+    );
+}
+
+#[test]
+fn float_to_decimal_rounding() {
+    use arrow2::compute::cast::{float_to_decimal, RoundingMode};
+
+    let array = Float64Array::from_slice([2.5, -2.5, 3.5, 2.4, -2.6]);
+    let cases = [
+        (RoundingMode::HalfAwayFromZero, [3, -3, 4, 2, -3]),
+        (RoundingMode::HalfEven, [2, -2, 4, 2, -3]),
+        (RoundingMode::TowardZero, [2, -2, 3, 2, -2]),
+        (RoundingMode::Floor, [2, -3, 3, 2, -3]),
+        (RoundingMode::Ceil, [3, -2, 4, 3, -2]),
+    ];
+    for (rounding, expected) in cases {
+        let result = float_to_decimal(&array, 3, 0, rounding).unwrap();
+        let expected = Int128Array::from_slice(expected).to(DataType::Decimal(3, 0));
+        assert_eq!(result, expected, "{:?}", rounding);
+    }
+}
+
+#[test]
+fn float_to_decimal_overflow() {
+    use arrow2::compute::cast::{float_to_decimal, RoundingMode};
+
+    let array = Float32Array::from([
+        Some(9.994),
+        Some(9.996),
+        Some(f32::INFINITY),
+        Some(f32::NAN),
+        None,
+    ]);
+    let result = float_to_decimal(&array, 3, 2, RoundingMode::HalfAwayFromZero).unwrap();
+    let expected =
+        Int128Array::from([Some(999), None, None, None, None]).to(DataType::Decimal(3, 2));
+    assert_eq!(result, expected);
+
+    assert!(float_to_decimal(&array, 39, 2, RoundingMode::Floor).is_err());
+    assert!(float_to_decimal(&array, 2, 3, RoundingMode::Floor).is_err());
+}
@@ -0,0 +1,203 @@
+use arrow2::array::new_null_array;
+use arrow2::compute::comparison::{can_eq, eq, eq_scalar};
+use arrow2::datatypes::DataType::*;
+use arrow2::datatypes::TimeUnit;
+use arrow2::scalar::new_scalar;
+
+mod selection;
+
+#[test]
+fn consistency() {
+    let datatypes = vec![
+        Null,
+        Boolean,
+        UInt8,
+        UInt16,
+        UInt32,
+        UInt64,
+        Int8,
+        Int16,
+        Int32,
+        Int64,
+        Float32,
+        Float64,
+        Timestamp(TimeUnit::Second, None),
+        Timestamp(TimeUnit::Millisecond, None),
+        Timestamp(TimeUnit::Microsecond, None),
+        Timestamp(TimeUnit::Nanosecond, None),
+        Time64(TimeUnit::Microsecond),
+        Time64(TimeUnit::Nanosecond),
+        Date32,
+        Time32(TimeUnit::Second),
+        Time32(TimeUnit::Millisecond),
+        Date64,
+        Utf8,
+        LargeUtf8,
+        Binary,
+        LargeBinary,
+        FixedSizeBinary(16),
+        Duration(TimeUnit::Second),
+        Duration(TimeUnit::Millisecond),
+        Duration(TimeUnit::Microsecond),
+        Duration(TimeUnit::Nanosecond),
+    ];
+
+    // array <> array
+    datatypes.clone().into_iter().for_each(|d1| {
+        let array = new_null_array(d1.clone(), 10);
+        if can_eq(&d1) {
+            eq(array.as_ref(), array.as_ref());
+        }
+    });
+
+    // array <> scalar
+    datatypes.into_iter().for_each(|d1| {
+        let array = new_null_array(d1.clone(), 10);
+        let scalar = new_scalar(array.as_ref(), 0);
+        if can_eq(&d1) {
+            eq_scalar(array.as_ref(), scalar.as_ref());
+        }
+    });
+}
+
+#[test]
+fn decimal_int() {
+    use arrow2::array::*;
+    use arrow2::compute::comparison::decimal::*;
+
+    // 99.99, 100.00, 100.01, null
+    let array = Int128Array::from([Some(9999), Some(10000), Some(10001), None]).to(Decimal(5, 2));
+
+    let expected = BooleanArray::from([Some(false), Some(false), Some(true), None]);
+    assert_eq!(gt_int_scalar(&array, 100), expected);
+    let expected = BooleanArray::from([Some(false), Some(true), Some(true), None]);
+    assert_eq!(gt_eq_int_scalar(&array, 100), expected);
+    let expected = BooleanArray::from([Some(false), Some(true), Some(false), None]);
+    assert_eq!(eq_int_scalar(&array, 100), expected);
+    let expected = BooleanArray::from([Some(true), Some(false), Some(false), None]);
+    assert_eq!(lt_int_scalar(&array, 100), expected);
+
+    // integers beyond the range of the decimal
+    let expected = BooleanArray::from([Some(true), Some(true), Some(true), None]);
+    assert_eq!(lt_int_scalar(&array, i128::MAX), expected);
+    assert_eq!(gt_int_scalar(&array, i128::MIN), expected);
+
+    let rhs = Int64Array::from([Some(99), Some(100), None, Some(1)]);
+    let expected = BooleanArray::from([Some(true), Some(false), None, None]);
+    assert_eq!(gt_int(&array, &rhs), expected);
+    let expected = BooleanArray::from([Some(true), Some(true), None, None]);
+    assert_eq!(gt_eq_int(&array, &rhs), expected);
+}
+
+#[test]
+fn decimal_float() {
+    use arrow2::array::*;
+    use arrow2::compute::comparison::decimal::*;
+
+    // 0.1, -0.1, 0.5, null
+    let array = Int128Array::from([Some(1), Some(-1), Some(5), None]).to(Decimal(5, 1));
+
+    // 0.1_f64 is slightly above 0.1
+    let expected = BooleanArray::from([Some(false), Some(false), Some(false), None]);
+    assert_eq!(eq_float_scalar(&array, 0.1), expected);
+    let expected = BooleanArray::from([Some(true), Some(true), Some(false), None]);
+    assert_eq!(lt_float_scalar(&array, 0.1), expected);
+    // -0.1_f64 is slightly below -0.1
+    let expected = BooleanArray::from([Some(true), Some(true), Some(true), None]);
+    assert_eq!(gt_float_scalar(&array, -0.1), expected);
+    // 0.5 is exactly representable
+    let expected = BooleanArray::from([Some(false), Some(false), Some(true), None]);
+    assert_eq!(eq_float_scalar(&array, 0.5), expected);
+    let expected = BooleanArray::from([Some(true), Some(true), Some(true), None]);
+    assert_eq!(lt_eq_float_scalar(&array, 0.5), expected);
+
+    // NaN and infinities
+    let expected = BooleanArray::from([Some(false), Some(false), Some(false), None]);
+    assert_eq!(eq_float_scalar(&array, f64::NAN), expected);
+    assert_eq!(lt_float_scalar(&array, f64::NAN), expected);
+    let expected = BooleanArray::from([Some(true), Some(true), Some(true), None]);
+    assert_eq!(neq_float_scalar(&array, f64::NAN), expected);
+    assert_eq!(lt_float_scalar(&array, f64::INFINITY), expected);
+    assert_eq!(gt_float_scalar(&array, f64::NEG_INFINITY), expected);
+    assert_eq!(lt_float_scalar(&array, 1e300), expected);
+
+    let rhs = Float32Array::from([Some(0.5), Some(-0.1), Some(0.5), Some(0.0)]);
+    let expected = BooleanArray::from([Some(false), Some(false), Some(true), None]);
+    assert_eq!(eq_float(&array, &rhs), expected);
+    let expected = BooleanArray::from([Some(true), Some(false), Some(false), None]);
+    assert_eq!(lt_float(&array, &rhs), expected);
+}
+
+#[test]
+fn decimal_float_bounds() {
+    use arrow2::array::*;
+    use arrow2::compute::comparison::decimal::*;
+
+    // 2^100 is exact and larger than the products of 128 bits at scale 10
+    let array = Int128Array::from_slice([1i128 << 100, i128::MAX]).to(Decimal(38, 0));
+    let expected = BooleanArray::from_slice([true, false]);
+    assert_eq!(eq_float_scalar(&array, 2f64.powi(100)), expected);
+    let array = Int128Array::from_slice([i128::MAX, 0]).to(Decimal(38, 10));
+    let expected = BooleanArray::from_slice([true, true]);
+    assert_eq!(lt_float_scalar(&array, 2f64.powi(100)), expected);
+
+    // the smallest subnormal is not zero at any scale
+    let array = Int128Array::from_slice([0, -1]).to(Decimal(38, 37));
+    let expected = BooleanArray::from_slice([false, false]);
+    assert_eq!(eq_float_scalar(&array, f64::from_bits(1)), expected);
+    let expected = BooleanArray::from_slice([true, true]);
+    assert_eq!(lt_float_scalar(&array, f64::from_bits(1)), expected);
+    let expected = BooleanArray::from_slice([true, false]);
+    assert_eq!(gt_float_scalar(&array, -f64::from_bits(1)), expected);
+
+    // 1.5 is exact at every scale, and 2 * 10^38 does not fit in a decimal of scale 38
+    let array = Int128Array::from_slice([15 * 10i128.pow(35), 0]).to(Decimal(38, 36));
+    let expected = BooleanArray::from_slice([true, false]);
+    assert_eq!(eq_float_scalar(&array, 1.5), expected);
+    let array = Int128Array::from_slice([i128::MAX, i128::MIN]).to(Decimal(38, 38));
+    let expected = BooleanArray::from_slice([true, true]);
+    assert_eq!(lt_float_scalar(&array, 2.0), expected);
+}
+
+#[test]
+fn fixed_size_binary() {
+    use arrow2::array::{BooleanArray, FixedSizeBinaryArray};
+    use arrow2::compute::comparison::{fixed_size_binary, gt, lt_eq_scalar};
+    use arrow2::scalar::FixedSizeBinaryScalar;
+
+    let value = |x: u128| Some(x.to_be_bytes());
+    let lhs = FixedSizeBinaryArray::from_iter(vec![value(1), value(1 << 64), None, value(3)], 16);
+    let rhs = FixedSizeBinaryArray::from_iter(vec![value(2), value(2), value(2), value(3)], 16);
+
+    let expected = BooleanArray::from([Some(false), Some(true), None, Some(false)]);
+    assert_eq!(gt(&lhs, &rhs), expected);
+    let expected = BooleanArray::from([Some(false), Some(false), None, Some(true)]);
+    assert_eq!(fixed_size_binary::eq(&lhs, &rhs), expected);
+
+    let scalar = FixedSizeBinaryScalar::new(FixedSizeBinary(16), value(3));
+    let expected = BooleanArray::from([Some(true), Some(false), None, Some(true)]);
+    assert_eq!(lt_eq_scalar(&lhs, &scalar), expected);
+}
+
+#[test]
+fn primitive_many_lanes() {
+    use arrow2::array::{BooleanArray, Int16Array};
+    use arrow2::compute::comparison::primitive::{gt_eq_scalar, lt};
+
+    // covers several simd chunks and a remainder
+    let lhs = Int16Array::from_values((0..100).map(|x| (x * 37) % 101));
+    let rhs = Int16Array::from_values((0..100).map(|x| 100 - x));
+
+    let expected = BooleanArray::from_slice(
+        lhs.values()
+            .iter()
+            .zip(rhs.values().iter())
+            .map(|(l, r)| l < r)
+            .collect::<Vec<_>>(),
+    );
+    assert_eq!(lt(&lhs, &rhs), expected);
+
+    let expected =
+        BooleanArray::from_slice(lhs.values().iter().map(|x| *x >= 50).collect::<Vec<_>>());
+    assert_eq!(gt_eq_scalar(&lhs, 50), expected);
+}
@@ -0,0 +1,144 @@
+use arrow2::array::*;
+use arrow2::bitmap::MutableBitmap;
+use arrow2::compute::comparison;
+use arrow2::compute::comparison::selection::*;
+use arrow2::datatypes::DataType;
+use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
+
+fn to_vec(mask: &MutableBitmap) -> Vec<bool> {
+    mask.iter().collect()
+}
+
+#[test]
+fn consistent_with_full_kernels() {
+    let pairs: Vec<(Box<dyn Array>, Box<dyn Array>)> = vec![
+        (
+            Box::new(Int32Array::from(&[Some(1), None, Some(3), Some(4)])),
+            Box::new(Int32Array::from(&[Some(1), Some(2), Some(2), Some(5)])),
+        ),
+        (
+            Box::new(Float64Array::from(&[
+                Some(1.0),
+                Some(f64::NAN),
+                None,
+                Some(-1.0),
+            ])),
+            Box::new(Float64Array::from(&[
+                Some(1.0),
+                Some(1.0),
+                Some(2.0),
+                Some(1.0),
+            ])),
+        ),
+        (
+            Box::new(Utf8Array::<i32>::from(&[
+                Some("a"),
+                Some("b"),
+                None,
+                Some("d"),
+            ])),
+            Box::new(Utf8Array::<i32>::from(&[
+                Some("a"),
+                Some("a"),
+                Some("c"),
+                Some("e"),
+            ])),
+        ),
+        (
+            Box::new(BooleanArray::from(&[
+                Some(true),
+                Some(false),
+                None,
+                Some(false),
+            ])),
+            Box::new(BooleanArray::from(&[
+                Some(true),
+                Some(true),
+                Some(true),
+                Some(false),
+            ])),
+        ),
+    ];
+    type Selected = fn(&dyn Array, &dyn Array, Option<&Selection>, &mut MutableBitmap);
+    type Full = fn(&dyn Array, &dyn Array) -> BooleanArray;
+    let kernels: Vec<(Selected, Full)> = vec![
+        (eq, comparison::eq),
+        (neq, comparison::neq),
+        (lt, comparison::lt),
+        (lt_eq, comparison::lt_eq),
+        (gt, comparison::gt),
+        (gt_eq, comparison::gt_eq),
+    ];
+    for (lhs, rhs) in &pairs {
+        for (selected, full) in &kernels {
+            let expected = full(lhs.as_ref(), rhs.as_ref())
+                .iter()
+                .map(|x| x == Some(true))
+                .collect::<Vec<_>>();
+
+            let mut mask = MutableBitmap::from_len_zeroed(lhs.len());
+            selected(lhs.as_ref(), rhs.as_ref(), None, &mut mask);
+            assert_eq!(to_vec(&mask), expected);
+
+            let mut mask = MutableBitmap::from_len_zeroed(lhs.len());
+            let ranges = [0..1, 1..lhs.len()];
+            selected(
+                lhs.as_ref(),
+                rhs.as_ref(),
+                Some(&Selection::Ranges(&ranges)),
+                &mut mask,
+            );
+            assert_eq!(to_vec(&mask), expected);
+        }
+    }
+}
+
+#[test]
+fn unselected_rows_are_untouched() {
+    let lhs = Int32Array::from_slice(&[1, 2, 3, 4]);
+    let rhs = Int32Array::from_slice(&[1, 0, 0, 4]);
+
+    let mut mask = MutableBitmap::from_len_zeroed(4);
+    mask.set(1, true);
+    mask.set(2, true);
+    eq(&lhs, &rhs, Some(&Selection::Indices(&[0, 2])), &mut mask);
+    assert_eq!(to_vec(&mask), vec![true, true, false, false]);
+}
+
+#[test]
+fn scalar() {
+    let array = Int32Array::from(&[Some(1), Some(5), None, Some(7)]);
+    let selection = Selection::Indices(&[1, 2, 3]);
+
+    let mut mask = MutableBitmap::from_len_zeroed(4);
+    mask.set(0, true);
+    let five = PrimitiveScalar::new(DataType::Int32, Some(5));
+    gt_eq_scalar(&array, &five, Some(&selection), &mut mask);
+    assert_eq!(to_vec(&mask), vec![true, true, false, true]);
+
+    let null = PrimitiveScalar::<i32>::new(DataType::Int32, None);
+    lt_scalar(&array, &null, Some(&selection), &mut mask);
+    assert_eq!(to_vec(&mask), vec![true, false, false, false]);
+
+    let strings = Utf8Array::<i32>::from_slice(&["a", "b", "c"]);
+    let mut mask = MutableBitmap::from_len_zeroed(3);
+    let b = Utf8Scalar::<i32>::new(Some("b"));
+    neq_scalar(&strings, &b, None, &mut mask);
+    assert_eq!(to_vec(&mask), vec![true, false, true]);
+}
+
+#[test]
+fn indices_from_mask() {
+    let mut mask = MutableBitmap::from_len_zeroed(5);
+    mask.set(1, true);
+    mask.set(4, true);
+    assert_eq!(selected_indices(&mask), vec![1, 4]);
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds() {
+    let array = Int32Array::from_slice(&[1, 2]);
+    let mut mask = MutableBitmap::from_len_zeroed(2);
+    eq(&array, &array, Some(&Selection::Indices(&[2])), &mut mask);
+}
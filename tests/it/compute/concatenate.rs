@@ -1,5 +1,5 @@
 use arrow2::array::*;
-use arrow2::compute::concatenate::concatenate;
+use arrow2::compute::concatenate::{concatenate, concatenate_with_offsets};
 use arrow2::error::Result;
 
 #[test]
@@ -115,3 +115,17 @@ fn boolean_primitive_arrays() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn with_offsets() -> Result<()> {
+    let (arr, offsets) = concatenate_with_offsets(&[
+        &Int32Array::from(&[Some(1), None]),
+        &Int32Array::from_slice(&[]),
+        &Int32Array::from_slice(&[3, 4, 5]).slice(1, 2),
+    ])?;
+
+    let expected = Int32Array::from(&[Some(1), None, Some(4), Some(5)]);
+    assert_eq!(arr.as_ref(), &expected as &dyn Array);
+    assert_eq!(offsets, vec![0, 2, 2, 4]);
+    Ok(())
+}
@@ -80,6 +80,15 @@ fn primative_array_with_null() {
     assert!(d.is_null(0));
 }
 
+#[test]
+fn primitive_array_many_values() {
+    let a = Int64Array::from_values(0..100);
+    let b = BooleanArray::from_slice((0..100).map(|x| x % 3 == 0).collect::<Vec<_>>());
+    let c = filter(&a, &b).unwrap();
+    let expected = Int64Array::from_values((0..100).filter(|x| x % 3 == 0));
+    assert_eq!(c.as_ref(), &expected as &dyn Array);
+}
+
 #[test]
 fn string_array_with_null() {
     let a = Utf8Array::<i32>::from(&vec![Some("hello"), None, Some("world"), None]);
@@ -193,3 +202,16 @@ fn list_array() {
     assert_eq!(&make_array(expected), &result);
 }
 */
+
+#[test]
+fn with_indices() {
+    let a = Utf8Array::<i32>::from(&[Some("a"), None, Some("c"), Some("d"), Some("e")]).slice(1, 4);
+    let b = BooleanArray::from(&[Some(true), Some(true), None, Some(true)]);
+
+    let (c, indices) = filter_with_indices::<i64>(&a, &b).unwrap();
+
+    let expected = Utf8Array::<i32>::from(&[None, Some("c"), Some("e")]);
+    assert_eq!(expected, c.as_ref());
+    assert_eq!(indices, Int64Array::from_slice(&[0, 1, 3]));
+    assert_eq!(indices.validity(), None);
+}
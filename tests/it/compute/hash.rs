@@ -47,3 +47,74 @@ fn consistency() {
         }
     });
 }
+
+#[test]
+fn hash_with_state() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{BuildHasherDefault, Hasher};
+
+    use arrow2::array::*;
+
+    let state = BuildHasherDefault::<DefaultHasher>::default();
+    let expected = |value: &str| {
+        let mut hasher = DefaultHasher::new();
+        hasher.write(&(value.len() as u64).to_le_bytes());
+        hasher.write(value.as_bytes());
+        hasher.finish()
+    };
+
+    let array = Utf8Array::<i64>::from(&[Some("a"), None, Some("b")]);
+    let result = hash_with(&array, &state).unwrap();
+    assert_eq!(result.validity(), array.validity());
+    assert_eq!(result.value(0), expected("a"));
+    assert_eq!(result.value(2), expected("b"));
+
+    let array = Int64Array::from_slice([1, 2, 1]);
+    let result = hash_with(&array, &StableState::with_keys(0, 1)).unwrap();
+    assert_eq!(result.value(0), result.value(2));
+    assert_ne!(result.value(0), result.value(1));
+    assert_eq!(
+        result,
+        hash_with(&array, &StableState::with_keys(0, 1)).unwrap()
+    );
+    assert_ne!(
+        result,
+        hash_with(&array, &StableState::with_keys(2, 3)).unwrap()
+    );
+
+    let array = new_null_array(Float32, 1);
+    assert!(hash_with(array.as_ref(), &state).is_err());
+}
+
+#[test]
+fn siphash_vectors() {
+    use std::hash::{BuildHasher, Hasher};
+
+    // reference vectors of SipHash-2-4, with key 00 01 .. 0f and messages 00 01 .. (n - 1)
+    let state = StableState::with_keys(0x0706050403020100, 0x0f0e0d0c0b0a0908);
+    let message = (0..16u8).collect::<Vec<_>>();
+    let hash = |length: usize| {
+        let mut hasher = state.build_hasher();
+        hasher.write(&message[..length]);
+        hasher.finish()
+    };
+    assert_eq!(hash(0), 0x726fdb47dd0e0e31);
+    assert_eq!(hash(1), 0x74f839c593dc67fd);
+    assert_eq!(hash(15), 0xa129ca6149be45e5);
+
+    // the result does not depend on how the bytes are split across writes
+    let mut hasher = state.build_hasher();
+    hasher.write(&message[..3]);
+    hasher.write(&message[3..12]);
+    hasher.write(&message[12..15]);
+    assert_eq!(hasher.finish(), hash(15));
+
+    // hashes are pinned across versions and platforms
+    let array = arrow2::array::Int32Array::from_slice([1]);
+    let expected = {
+        let mut hasher = state.build_hasher();
+        hasher.write(&[1, 0, 0, 0]);
+        hasher.finish()
+    };
+    assert_eq!(hash_with(&array, &state).unwrap().value(0), expected);
+}
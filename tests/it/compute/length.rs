@@ -81,8 +81,46 @@ fn consistency() {
         let array = new_null_array(d1.clone(), 10);
         if can_length(&d1) {
             assert!(length(array.as_ref()).is_ok());
+            assert!(char_length(array.as_ref()).is_ok());
+            assert!(bit_length(array.as_ref()).is_ok());
         } else {
             assert!(length(array.as_ref()).is_err());
+            assert!(char_length(array.as_ref()).is_err());
+            assert!(bit_length(array.as_ref()).is_err());
         }
     });
 }
+
+fn char_and_bit_length_test_string<O: Offset>() {
+    let array = Utf8Array::<O>::from(&[Some("hello"), Some("💖a"), Some(""), None]);
+
+    let data_type = if O::is_large() {
+        DataType::Int64
+    } else {
+        DataType::Int32
+    };
+    let to_array = |x: Vec<Option<usize>>| {
+        x.into_iter()
+            .map(|x| x.map(|x| O::from_usize(x).unwrap()))
+            .collect::<PrimitiveArray<O>>()
+            .to(data_type.clone())
+    };
+
+    let result = char_length(&array).unwrap();
+    let expected = to_array(vec![Some(5), Some(2), Some(0), None]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = bit_length(&array).unwrap();
+    let expected = Int64Array::from(&[Some(40), Some(40), Some(0), None]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn char_and_bit_length_utf8() {
+    char_and_bit_length_test_string::<i32>()
+}
+
+#[test]
+fn char_and_bit_length_large_utf8() {
+    char_and_bit_length_test_string::<i64>()
+}
@@ -44,3 +44,94 @@ fn test_nlike_binary_scalar() -> Result<()> {
     );
     Ok(())
 }
+
+#[test]
+fn test_like_utf8_scalar() -> Result<()> {
+    let array = Utf8Array::<i32>::from(&[Some("Arrow"), Some("Ar"), None, Some("rowA"), Some("")]);
+
+    let result = like_utf8_scalar(&array, "Ar%")?;
+    let expected = BooleanArray::from(&[Some(true), Some(true), None, Some(false), Some(false)]);
+    assert_eq!(result, expected);
+
+    let result = like_utf8_scalar(&array, "%A")?;
+    let expected = BooleanArray::from(&[Some(false), Some(false), None, Some(true), Some(false)]);
+    assert_eq!(result, expected);
+
+    let result = like_utf8_scalar(&array, "Ar")?;
+    let expected = BooleanArray::from(&[Some(false), Some(true), None, Some(false), Some(false)]);
+    assert_eq!(result, expected);
+
+    let result = like_utf8_scalar(&array, "%")?;
+    let expected = BooleanArray::from(&[Some(true), Some(true), None, Some(true), Some(true)]);
+    assert_eq!(result, expected);
+
+    let result = like_utf8_scalar(&array, "%ro%")?;
+    let expected = BooleanArray::from(&[Some(true), Some(false), None, Some(true), Some(false)]);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_nlike_utf8_scalar() -> Result<()> {
+    let array = Utf8Array::<i32>::from_slice(&["Arrow", "Ar", "rowA"]);
+
+    let result = nlike_utf8_scalar(&array, "Ar")?;
+    assert_eq!(result, BooleanArray::from_slice(&[true, false, true]));
+
+    let result = nlike_utf8_scalar(&array, "%A")?;
+    assert_eq!(result, BooleanArray::from_slice(&[true, true, false]));
+
+    let result = nlike_utf8_scalar(&array, "A_r%")?;
+    assert_eq!(result, BooleanArray::from_slice(&[false, true, true]));
+    Ok(())
+}
+
+#[test]
+fn test_like_utf8_scalar_escaped() -> Result<()> {
+    let array = Utf8Array::<i32>::from_slice(&["100%", "1000", "a.c", "abc", "a_c", "a\nc"]);
+
+    let result = like_utf8_scalar(&array, "%\\%")?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[true, false, false, false, false, false])
+    );
+
+    // regex metacharacters are matched literally
+    let result = like_utf8_scalar(&array, "a.%")?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[false, false, true, false, false, false])
+    );
+
+    let result = like_utf8_scalar(&array, "a\\_c")?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[false, false, false, false, true, false])
+    );
+
+    // `_` matches any character, including new lines
+    let result = like_utf8_scalar(&array, "a_c")?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[false, false, true, true, true, true])
+    );
+    Ok(())
+}
+
+#[test]
+fn test_like_utf8() -> Result<()> {
+    let strings = Utf8Array::<i32>::from_slice(&["Arrow", "Arrow", "Arrow", "a.c", "abc", "50%"]);
+    let patterns = Utf8Array::<i32>::from_slice(&["Arrow", "%row", "Arr%", "a.c", "a.c", "50\\%"]);
+    let result = like_utf8(&strings, &patterns)?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[true, true, true, true, false, true])
+    );
+
+    let result = nlike_utf8(&strings, &patterns)?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[false, false, false, false, true, false])
+    );
+    Ok(())
+}
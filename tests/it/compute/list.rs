@@ -0,0 +1,101 @@
+use arrow2::array::*;
+use arrow2::compute::list::*;
+use arrow2::compute::sort::SortOptions;
+use arrow2::error::Result;
+
+fn new_list<O: Offset>(data: Vec<Option<Vec<Option<i32>>>>) -> ListArray<O> {
+    let mut array = MutableListArray::<O, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    array.into()
+}
+
+#[test]
+fn sort_values_basics() -> Result<()> {
+    let array = new_list::<i32>(vec![
+        Some(vec![Some(3), None, Some(1), Some(3)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(2), Some(0)]),
+    ]);
+
+    let result = sort_values(&array, &SortOptions::default())?;
+    let expected = new_list::<i32>(vec![
+        Some(vec![None, Some(1), Some(3), Some(3)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(0), Some(2)]),
+    ]);
+    assert_eq!(result, expected);
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let result = sort_values(&array, &options)?;
+    let expected = new_list::<i32>(vec![
+        Some(vec![Some(3), Some(3), Some(1), None]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(2), Some(0)]),
+    ]);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn sort_values_sliced() -> Result<()> {
+    let array = new_list::<i64>(vec![
+        Some(vec![Some(3), Some(1)]),
+        Some(vec![Some(5), Some(4)]),
+        Some(vec![Some(2), Some(0)]),
+    ]);
+    let array = array.slice(1, 2);
+
+    let result = sort_values(&array, &SortOptions::default())?;
+    let expected = new_list::<i64>(vec![
+        Some(vec![Some(4), Some(5)]),
+        Some(vec![Some(0), Some(2)]),
+    ]);
+    assert_eq!(result, expected);
+    assert_eq!(result.offsets().as_slice(), &[0, 2, 4]);
+    Ok(())
+}
+
+#[test]
+fn unique() -> Result<()> {
+    let array = new_list::<i32>(vec![
+        Some(vec![Some(3), None, Some(1), Some(3), None]),
+        None,
+        Some(vec![Some(2), Some(2)]),
+    ]);
+
+    let result = list_unique(&array)?;
+    let expected = new_list::<i32>(vec![
+        Some(vec![None, Some(1), Some(3)]),
+        None,
+        Some(vec![Some(2)]),
+    ]);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn unique_utf8() -> Result<()> {
+    let mut array = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend(vec![
+        Some(vec![Some("b"), Some("a"), Some("b")]),
+        Some(vec![Some("c")]),
+    ])?;
+    let array: ListArray<i32> = array.into();
+
+    let result = list_unique(&array)?;
+
+    let mut expected = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    expected.try_extend(vec![
+        Some(vec![Some("a"), Some("b")]),
+        Some(vec![Some("c")]),
+    ])?;
+    let expected: ListArray<i32> = expected.into();
+    assert_eq!(result, expected);
+    Ok(())
+}
@@ -184,3 +184,64 @@ fn consistency() {
         }
     });
 }
+
+#[test]
+fn ascii_sliced() {
+    let array =
+        Utf8Array::<i32>::from(&[Some("Straße"), Some("HeLLo"), None, Some("WoRld")]).slice(1, 3);
+    let result = lower(&array).unwrap();
+    let expected = Utf8Array::<i32>::from(&[Some("hello"), None, Some("world")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn owned_in_place() {
+    let array = Utf8Array::<i64>::from(&[Some("HeLLo"), None, Some("WoRld")]);
+    let ptr = array.values().as_ptr();
+
+    let result = lower_owned(array);
+    assert_eq!(result.values().as_ptr(), ptr);
+    assert_eq!(
+        result,
+        Utf8Array::<i64>::from(&[Some("hello"), None, Some("world")])
+    );
+}
+
+#[test]
+fn owned_shared() {
+    let array = Utf8Array::<i32>::from(&[Some("HeLLo"), Some("WoRld")]);
+    let result = lower_owned(array.clone());
+    assert_eq!(
+        result,
+        Utf8Array::<i32>::from(&[Some("hello"), Some("world")])
+    );
+    // the original array is not modified
+    assert_eq!(
+        array,
+        Utf8Array::<i32>::from(&[Some("HeLLo"), Some("WoRld")])
+    );
+}
+
+#[test]
+fn owned_non_ascii() {
+    let array = Utf8Array::<i32>::from(&[Some("HeLLo"), Some("Straße")]);
+    let result = lower_owned(array);
+    assert_eq!(
+        result,
+        Utf8Array::<i32>::from(&[Some("hello"), Some("straße")])
+    );
+}
+
+#[test]
+fn owned_non_ascii_outside_slice() {
+    let array = Utf8Array::<i32>::from(&[Some("HeLLo"), Some("WoRld"), Some("Straße")]).slice(0, 2);
+    let ptr = array.values().as_ptr();
+
+    // only the values of the slice are checked to be ASCII
+    let result = lower_owned(array);
+    assert_eq!(result.values().as_ptr(), ptr);
+    assert_eq!(
+        result,
+        Utf8Array::<i32>::from(&[Some("hello"), Some("world")])
+    );
+}
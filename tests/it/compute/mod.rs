@@ -28,16 +28,22 @@ mod length;
 mod like;
 #[cfg(feature = "compute_limit")]
 mod limit;
+#[cfg(feature = "compute_list")]
+mod list;
 #[cfg(feature = "compute_lower")]
 mod lower;
 #[cfg(feature = "compute_merge_sort")]
 mod merge_sort;
 #[cfg(feature = "compute_partition")]
 mod partition;
+#[cfg(feature = "compute_rechunk")]
+mod rechunk;
 #[cfg(feature = "compute_regex_match")]
 mod regex_match;
 #[cfg(feature = "compute_sort")]
 mod sort;
+#[cfg(feature = "compute_statistics")]
+mod statistics;
 #[cfg(feature = "compute_substring")]
 mod substring;
 #[cfg(feature = "compute_take")]
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::compute::rechunk::rechunk;
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::error::{ArrowError, Result};
+use arrow2::record_batch::RecordBatch;
+
+fn schema() -> Arc<Schema> {
+    Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]))
+}
+
+fn batch(start: i32, len: i32) -> Result<RecordBatch> {
+    let values = (start..start + len).collect::<Vec<_>>();
+    let a = Int32Array::from_slice(&values);
+    let b = values.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+    let b = Utf8Array::<i32>::from_slice(b);
+    RecordBatch::try_new(schema(), vec![Arc::new(a), Arc::new(b)])
+}
+
+fn flatten(batches: &[RecordBatch]) -> Vec<i32> {
+    batches
+        .iter()
+        .flat_map(|batch| {
+            let a = batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .unwrap();
+            a.values().iter().copied().collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[test]
+fn split_and_coalesce() -> Result<()> {
+    let batches = vec![
+        batch(0, 3),
+        batch(3, 10),
+        batch(13, 0),
+        batch(13, 1),
+        batch(14, 2),
+    ];
+
+    let result = rechunk(batches.into_iter(), 4).collect::<Result<Vec<_>>>()?;
+
+    let rows = result.iter().map(|b| b.num_rows()).collect::<Vec<_>>();
+    assert_eq!(rows, vec![4, 4, 4, 4]);
+    assert_eq!(flatten(&result), (0..16).collect::<Vec<_>>());
+
+    let b = result[1]
+        .column(1)
+        .as_any()
+        .downcast_ref::<Utf8Array<i32>>()
+        .unwrap();
+    assert_eq!(b, &Utf8Array::<i32>::from_slice(&["4", "5", "6", "7"]));
+    Ok(())
+}
+
+#[test]
+fn remainder() -> Result<()> {
+    let batches = vec![batch(0, 2), batch(2, 3)];
+
+    let result = rechunk(batches.into_iter(), 3).collect::<Result<Vec<_>>>()?;
+
+    let rows = result.iter().map(|b| b.num_rows()).collect::<Vec<_>>();
+    assert_eq!(rows, vec![3, 2]);
+    assert_eq!(flatten(&result), (0..5).collect::<Vec<_>>());
+    Ok(())
+}
+
+#[test]
+fn slices_are_not_copied() -> Result<()> {
+    let original = batch(0, 6)?;
+    let values = original
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap()
+        .values()
+        .as_ptr();
+
+    let result = rechunk(vec![Ok(original)].into_iter(), 3).collect::<Result<Vec<_>>>()?;
+
+    assert_eq!(result.len(), 2);
+    let a = result[1]
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!(a.values().as_ptr(), unsafe { values.add(3) });
+    Ok(())
+}
+
+#[test]
+fn empty() {
+    let result = rechunk(vec![batch(0, 0)].into_iter(), 3).collect::<Vec<_>>();
+    assert!(result.is_empty());
+}
+
+#[test]
+fn forwards_errors() {
+    let batches = vec![
+        batch(0, 2),
+        Err(ArrowError::InvalidArgumentError("error".to_string())),
+        batch(2, 2),
+    ];
+
+    let mut iter = rechunk(batches.into_iter(), 3);
+
+    assert!(iter.next().unwrap().is_err());
+    let rest = iter.collect::<Result<Vec<_>>>().unwrap();
+    assert_eq!(flatten(&rest), vec![0, 1, 2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn zero_target() {
+    rechunk(vec![batch(0, 1)].into_iter(), 0);
+}
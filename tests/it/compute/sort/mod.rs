@@ -443,6 +443,39 @@ fn string_dicts() {
     );
 }
 
+#[test]
+fn ordered_dict() {
+    let array = DictionaryArray::<i32>::try_from_strings(
+        [Some("high"), None, Some("low"), Some("medium")],
+        &["low", "medium", "high"],
+        true,
+    )
+    .unwrap();
+
+    let options = SortOptions {
+        descending: false,
+        nulls_first: true,
+    };
+    let output = sort_to_indices::<i32>(&array, &options, None).unwrap();
+    assert_eq!(output, Int32Array::from_slice([1, 2, 3, 0]));
+
+    let output = sort(&array, &options, None).unwrap();
+    let output = output
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    assert!(output.is_ordered());
+    assert_eq!(
+        output.codes(),
+        &Int32Array::from([None, Some(0), Some(1), Some(2)])
+    );
+
+    // unordered dictionaries sort by value
+    let array = array.with_ordered(false);
+    let output = sort_to_indices::<i32>(&array, &options, None).unwrap();
+    assert_eq!(output, Int32Array::from_slice([1, 0, 2, 3]));
+}
+
 /*
 #[test]
 fn list() {
@@ -603,3 +636,63 @@ fn consistency() {
         }
     });
 }
+
+#[test]
+fn to_indices_index_types() {
+    let options = SortOptions::default();
+    let input = Utf8Array::<i32>::from(&[Some("b"), None, Some("a"), Some("c")]);
+
+    let output = sort_to_indices::<u32>(&input, &options, None).unwrap();
+    assert_eq!(output, UInt32Array::from_slice([1, 2, 0, 3]));
+    let output = sort_to_indices::<i64>(&input, &options, None).unwrap();
+    assert_eq!(output, Int64Array::from_slice([1, 2, 0, 3]));
+    let output = sort_to_indices::<u64>(&input, &options, None).unwrap();
+    assert_eq!(output, UInt64Array::from_slice([1, 2, 0, 3]));
+
+    let input = Int16Array::from(&[Some(2), None, Some(1)]);
+    let output = sort_to_indices::<u64>(&input, &options, Some(2)).unwrap();
+    assert_eq!(output, UInt64Array::from_slice([1, 2]));
+}
+
+#[test]
+fn to_indices_too_long() {
+    use arrow2::error::ArrowError;
+
+    // a null array does not allocate its values; its largest index, `i32::MAX + 1`, does not fit
+    let input = NullArray::from_data(DataType::Null, i32::MAX as usize + 2);
+    let error = sort_to_indices::<i32>(&input, &SortOptions::default(), None).unwrap_err();
+    assert!(matches!(error, ArrowError::InvalidArgumentError(_)));
+}
+
+#[test]
+fn fixed_size_binary() {
+    let array =
+        FixedSizeBinaryArray::from_iter(vec![Some(b"ba"), None, Some(b"ab"), Some(b"bb")], 2);
+    let options = SortOptions {
+        descending: false,
+        nulls_first: true,
+    };
+
+    let indices = sort_to_indices::<i32>(&array, &options, None).unwrap();
+    assert_eq!(indices, Int32Array::from_slice([1, 2, 0, 3]));
+
+    let sorted = sort(&array, &options, None).unwrap();
+    let expected =
+        FixedSizeBinaryArray::from_iter(vec![None, Some(b"ab"), Some(b"ba"), Some(b"bb")], 2);
+    assert_eq!(sorted.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn with_indices() {
+    let input = Utf8Array::<i32>::from(&[Some("b"), None, Some("a"), Some("c")]);
+    let options = SortOptions {
+        descending: false,
+        nulls_first: true,
+    };
+
+    let (sorted, indices) = sort_with_indices::<u32>(&input, &options, None).unwrap();
+
+    let expected = Utf8Array::<i32>::from(&[None, Some("a"), Some("b"), Some("c")]);
+    assert_eq!(sorted.as_ref(), &expected as &dyn Array);
+    assert_eq!(indices, UInt32Array::from_slice(&[1, 2, 0, 3]));
+}
@@ -0,0 +1,126 @@
+use arrow2::array::*;
+use arrow2::compute::statistics::Accumulator;
+use arrow2::datatypes::DataType;
+use arrow2::error::Result;
+use arrow2::scalar::*;
+
+#[test]
+fn primitive() -> Result<()> {
+    let mut accumulator = Accumulator::new(DataType::Int32);
+    assert!(accumulator.is_empty());
+
+    accumulator.update(&Int32Array::from([Some(2), None, Some(-1)]))?;
+    accumulator.update(&Int32Array::from([None, None]))?;
+    accumulator.update(&Int32Array::from_slice([5]))?;
+
+    assert_eq!(accumulator.len(), 6);
+    assert_eq!(accumulator.null_count(), 3);
+    assert_eq!(
+        accumulator.min().unwrap().as_ref(),
+        &PrimitiveScalar::new(DataType::Int32, Some(-1)) as &dyn Scalar
+    );
+    assert_eq!(
+        accumulator.max().unwrap().as_ref(),
+        &PrimitiveScalar::new(DataType::Int32, Some(5)) as &dyn Scalar
+    );
+    Ok(())
+}
+
+#[test]
+fn all_null() -> Result<()> {
+    let mut accumulator = Accumulator::new(DataType::Float64);
+    accumulator.update(&Float64Array::from([None, None]))?;
+    assert_eq!(accumulator.null_count(), 2);
+    assert!(!accumulator.min().unwrap().is_valid());
+    assert!(!accumulator.max().unwrap().is_valid());
+    Ok(())
+}
+
+#[test]
+fn float_ignores_nan() -> Result<()> {
+    let mut accumulator = Accumulator::new(DataType::Float32);
+    accumulator.update(&Float32Array::from_slice([f32::NAN, 1.0, -2.0, f32::NAN]))?;
+    assert_eq!(
+        accumulator.min().unwrap().as_ref(),
+        &PrimitiveScalar::new(DataType::Float32, Some(-2.0f32)) as &dyn Scalar
+    );
+    assert_eq!(
+        accumulator.max().unwrap().as_ref(),
+        &PrimitiveScalar::new(DataType::Float32, Some(1.0f32)) as &dyn Scalar
+    );
+    Ok(())
+}
+
+#[test]
+fn utf8() -> Result<()> {
+    let mut accumulator = Accumulator::new(DataType::Utf8);
+    accumulator.update(&Utf8Array::<i32>::from(&[Some("ab"), None, Some("b")]))?;
+    accumulator.update(&Utf8Array::<i32>::from(&[Some("abc"), Some("a")]))?;
+
+    assert_eq!(accumulator.null_count(), 1);
+    assert_eq!(
+        accumulator.min().unwrap().as_ref(),
+        &Utf8Scalar::<i32>::new(Some("a")) as &dyn Scalar
+    );
+    assert_eq!(
+        accumulator.max().unwrap().as_ref(),
+        &Utf8Scalar::<i32>::new(Some("b")) as &dyn Scalar
+    );
+    Ok(())
+}
+
+#[test]
+fn boolean() -> Result<()> {
+    let mut accumulator = Accumulator::new(DataType::Boolean);
+    accumulator.update(&BooleanArray::from(&[Some(true), None]))?;
+    assert_eq!(
+        accumulator.min().unwrap().as_ref(),
+        &BooleanScalar::new(Some(true)) as &dyn Scalar
+    );
+    accumulator.update(&BooleanArray::from_slice([false]))?;
+    assert_eq!(
+        accumulator.min().unwrap().as_ref(),
+        &BooleanScalar::new(Some(false)) as &dyn Scalar
+    );
+    Ok(())
+}
+
+#[test]
+fn merge() -> Result<()> {
+    let mut lhs = Accumulator::new(DataType::Int64);
+    lhs.update(&Int64Array::from([Some(1), None]))?;
+    let mut rhs = Accumulator::new(DataType::Int64);
+    rhs.update(&Int64Array::from([Some(3), Some(-3)]))?;
+
+    let mut expected = Accumulator::new(DataType::Int64);
+    expected.update(&Int64Array::from([Some(1), None]))?;
+    expected.update(&Int64Array::from([Some(3), Some(-3)]))?;
+
+    lhs.merge(&rhs)?;
+    assert_eq!(lhs, expected);
+
+    assert!(lhs.merge(&Accumulator::new(DataType::Int32)).is_err());
+    Ok(())
+}
+
+#[test]
+fn unordered() -> Result<()> {
+    let data_type = DataType::List(Box::new(arrow2::datatypes::Field::new(
+        "item",
+        DataType::Int32,
+        true,
+    )));
+    let mut accumulator = Accumulator::new(data_type.clone());
+    accumulator.update(new_null_array(data_type, 2).as_ref())?;
+
+    assert_eq!(accumulator.null_count(), 2);
+    assert!(accumulator.min().is_none());
+    assert!(accumulator.max().is_none());
+    Ok(())
+}
+
+#[test]
+fn wrong_data_type() {
+    let mut accumulator = Accumulator::new(DataType::Int32);
+    assert!(accumulator.update(&Int64Array::from_slice([1])).is_err());
+}
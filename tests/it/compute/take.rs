@@ -174,3 +174,148 @@ fn unsigned_take() {
     let a = take(&values, &indices).unwrap();
     assert_eq!(a.len(), 0)
 }
+
+#[test]
+fn take_record_batch_basics() -> Result<()> {
+    use arrow2::compute::take::take_record_batch;
+    use arrow2::datatypes::Schema;
+    use arrow2::record_batch::RecordBatch;
+
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]));
+    let a = Int32Array::from(&[Some(1), None, Some(3), Some(4)]);
+    let b = Utf8Array::<i32>::from(&[Some("a"), Some("b"), None, Some("d")]);
+    let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(a), Arc::new(b)])?;
+
+    let indices = Int32Array::from(&[Some(2), Some(3), None, Some(0), Some(1)]);
+    let result = take_record_batch(&batch, &indices)?;
+
+    let a = Int32Array::from(&[Some(3), Some(4), None, Some(1), None]);
+    let b = Utf8Array::<i32>::from(&[None, Some("d"), None, Some("a"), Some("b")]);
+    let expected = RecordBatch::try_new(schema, vec![Arc::new(a), Arc::new(b)])?;
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn take_arrays_out_of_bounds() {
+    use arrow2::compute::take::take_arrays;
+
+    let a = Int32Array::from_slice([1, 2]);
+    let indices = Int32Array::from_slice([0, 2]);
+    assert!(take_arrays(&[&a], &indices).is_err());
+
+    let b = Int32Array::from_slice([1, 2, 3]);
+    let indices = Int32Array::from_slice([0]);
+    assert!(take_arrays(&[&a, &b], &indices).is_err());
+}
+
+#[test]
+fn take_arrays_null_indices_and_fallback() -> Result<()> {
+    use arrow2::bitmap::Bitmap;
+    use arrow2::compute::take::take_arrays;
+
+    let a = Int32Array::from_slice([1, 2]);
+    let b = FixedSizeListArray::from_data(
+        DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 2),
+        Arc::new(Int32Array::from_slice([1, 2, 3, 4])),
+        None,
+    );
+    // the null index's value is out of bounds
+    let indices = Int32Array::from_data(
+        DataType::Int32,
+        vec![1, 100].into(),
+        Some(Bitmap::from([true, false])),
+    );
+    let result = take_arrays(&[&a, &b], &indices)?;
+
+    assert_eq!(
+        result[0].as_ref(),
+        &Int32Array::from([Some(2), None]) as &dyn Array
+    );
+    let expected = FixedSizeListArray::from_data(
+        b.data_type().clone(),
+        Arc::new(Int32Array::from_slice([3, 4, 0, 0])),
+        Some(Bitmap::from([true, false])),
+    );
+    assert_eq!(result[1].as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn take_indices_into_growable() -> Result<()> {
+    use arrow2::array::growable::{Growable, GrowablePrimitive};
+    use arrow2::compute::take::TakeIndices;
+
+    let a = Int32Array::from_slice([1, 2, 3]);
+    let b = Int32Array::from_slice([4, 5, 6]);
+    let indices = TakeIndices::try_new(&Int32Array::from(&[Some(2), None, Some(0)]), 3)?;
+    assert_eq!(indices.len(), 3);
+    assert!(indices.has_nulls());
+
+    let mut growable = GrowablePrimitive::new(vec![&a, &b], true, 6);
+    indices.extend(&mut growable, 0);
+    indices.extend(&mut growable, 1);
+    let result = growable.as_box();
+
+    let expected = Int32Array::from(&[Some(3), None, Some(1), Some(6), None, Some(4)]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn fixed_size_binary() -> Result<()> {
+    let values = FixedSizeBinaryArray::from_iter(vec![Some(b"ab"), None, Some(b"cd")], 2);
+    let indices = Int32Array::from([Some(2), None, Some(1), Some(0)]);
+
+    let result = take(&values, &indices)?;
+
+    let expected = FixedSizeBinaryArray::from_iter(vec![Some(b"cd"), None, None, Some(b"ab")], 2);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    assert!(can_take(values.data_type()));
+    Ok(())
+}
+
+#[test]
+fn gather_with_misses() -> Result<()> {
+    use arrow2::compute::take::gather;
+
+    let values = create_test_struct();
+    let indices = UInt32Array::from(&[Some(3), None, Some(0), Some(1)]);
+
+    let result = gather(&values, &indices)?;
+
+    let expected = take(&values, &indices)?;
+    assert_eq!(result.as_ref(), expected.as_ref());
+    assert!(result.is_null(1));
+
+    let indices = UInt32Array::from_slice([4]);
+    assert!(gather(&values, &indices).is_err());
+    Ok(())
+}
+
+#[test]
+fn scatter_basics() -> Result<()> {
+    use arrow2::compute::take::scatter;
+
+    let values = Utf8Array::<i32>::from([Some("a"), None, Some("c"), Some("d")]);
+    let positions = UInt32Array::from(&[Some(4), Some(0), None, Some(1)]);
+
+    let result = scatter(&values, &positions, 5)?;
+
+    let expected = Utf8Array::<i32>::from([None, Some("d"), None, None, Some("a")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn scatter_errors() {
+    use arrow2::compute::take::scatter;
+
+    let values = Int32Array::from_slice([1, 2]);
+    assert!(scatter(&values, &UInt32Array::from_slice([0]), 2).is_err());
+    assert!(scatter(&values, &UInt32Array::from_slice([0, 2]), 2).is_err());
+    assert!(scatter(&values, &UInt32Array::from_slice([1, 1]), 2).is_err());
+}
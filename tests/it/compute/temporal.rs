@@ -372,3 +372,140 @@ fn consistency_check<O: arrow2::types::NativeType>(
         }
     });
 }
+
+#[test]
+fn date_diff_date32() {
+    // 2020-01-31, 2021-03-15, null
+    let lhs = PrimitiveArray::<i32>::from([Some(18292), Some(18701), None]).to(DataType::Date32);
+    // 2020-01-01, 2021-01-20, 2021-01-20
+    let rhs =
+        PrimitiveArray::<i32>::from([Some(18262), Some(18647), Some(18647)]).to(DataType::Date32);
+
+    let cases = [
+        (DiffUnit::Days, [30, 54]),
+        (DiffUnit::Weeks, [4, 7]),
+        (DiffUnit::Months, [0, 1]),
+        (DiffUnit::Years, [0, 0]),
+        (DiffUnit::Hours, [720, 1296]),
+    ];
+    for (unit, expected) in cases {
+        let result = date_diff(&lhs, &rhs, unit).unwrap();
+        let expected = Int64Array::from([Some(expected[0]), Some(expected[1]), None]);
+        assert_eq!(result, expected, "{:?}", unit);
+    }
+
+    // negative differences are truncated towards zero
+    let result = date_diff(&rhs, &lhs, DiffUnit::Months).unwrap();
+    assert_eq!(result, Int64Array::from([Some(0), Some(-1), None]));
+}
+
+#[test]
+fn date_diff_months_calendar() {
+    // 2021-01-31T00:00:00 and 2021-02-28T00:00:00 and 2022-02-28T00:00:00
+    let lhs = PrimitiveArray::<i64>::from_slice([1614470400, 1646006400])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    let rhs = PrimitiveArray::<i64>::from_slice([1612051200, 1612051200])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+
+    let result = date_diff(&lhs, &rhs, DiffUnit::Months).unwrap();
+    assert_eq!(result, Int64Array::from_slice([0, 12]));
+    let result = date_diff(&lhs, &rhs, DiffUnit::Years).unwrap();
+    assert_eq!(result, Int64Array::from_slice([0, 1]));
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn date_diff_dst() {
+    // 2021-03-29T00:00:00 and 2021-03-28T00:00:00 at Europe/Lisbon; the latter is
+    // 23 hours before the former due to the daylight saving time transition
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("Europe/Lisbon".to_string()));
+    let lhs = PrimitiveArray::<i64>::from_slice([1616972400]).to(data_type.clone());
+    let rhs = PrimitiveArray::<i64>::from_slice([1616889600]).to(data_type);
+
+    let result = date_diff(&lhs, &rhs, DiffUnit::Days).unwrap();
+    assert_eq!(result, Int64Array::from_slice([1]));
+    let result = date_diff(&lhs, &rhs, DiffUnit::Hours).unwrap();
+    assert_eq!(result, Int64Array::from_slice([23]));
+}
+
+#[test]
+fn date_diff_errors() {
+    let lhs = PrimitiveArray::<i32>::from_slice([1]).to(DataType::Date32);
+    let rhs = PrimitiveArray::<i64>::from_slice([1]).to(DataType::Date64);
+    assert!(date_diff(&lhs, &rhs, DiffUnit::Days).is_err());
+
+    let lhs = Int32Array::from_slice([1]);
+    assert!(date_diff(&lhs, &lhs, DiffUnit::Days).is_err());
+}
+
+#[test]
+fn consistency_date_diff() {
+    consistency_check(can_date_diff, |array| {
+        date_diff(array, array, DiffUnit::Days)
+    });
+}
+
+#[test]
+fn consistency_is_leap_year() {
+    use arrow2::datatypes::DataType::*;
+
+    for data_type in [Date32, Date64, Int32, Utf8, Time32(TimeUnit::Second)] {
+        let array = new_null_array(data_type.clone(), 10);
+        assert_eq!(
+            is_leap_year(array.as_ref()).is_ok(),
+            can_is_leap_year(&data_type)
+        );
+    }
+}
+
+#[test]
+fn consistency_days_in_month() {
+    consistency_check(can_days_in_month, days_in_month);
+}
+
+#[test]
+fn is_leap_year_date32() {
+    // 2000-02-01, 1900-02-01, 2024-07-01, 2023-01-01
+    let array =
+        PrimitiveArray::<i32>::from([Some(10988), Some(-25536), None, Some(19905), Some(19358)])
+            .to(DataType::Date32);
+    let result = is_leap_year(&array).unwrap();
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), Some(false), None, Some(true), Some(false)])
+    );
+}
+
+#[test]
+fn days_in_month_date32() {
+    // 2000-02-01, 1900-02-01, 2024-07-01, 2023-04-01
+    let array =
+        PrimitiveArray::<i32>::from([Some(10988), Some(-25536), None, Some(19905), Some(19448)])
+            .to(DataType::Date32);
+    let result = days_in_month(&array).unwrap();
+    assert_eq!(
+        result,
+        UInt32Array::from([Some(29), Some(28), None, Some(31), Some(30)])
+    );
+}
+
+#[test]
+fn weekday_and_month_names() {
+    // 2021-12-19T23:00:00 UTC is Monday 2021-12-20 at +02:00
+    let array = PrimitiveArray::<i64>::from([Some(1639954800), None]).to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("+02:00".to_string()),
+    ));
+
+    let result = weekday_name(&array, Locale::English).unwrap();
+    assert_eq!(result, Utf8Array::<i32>::from([Some("Monday"), None]));
+    let result = weekday_name(&array, Locale::German).unwrap();
+    assert_eq!(result, Utf8Array::<i32>::from([Some("Montag"), None]));
+
+    let result = month_name(&array, Locale::default()).unwrap();
+    assert_eq!(result, Utf8Array::<i32>::from([Some("December"), None]));
+    let result = month_name(&array, Locale::Spanish).unwrap();
+    assert_eq!(result, Utf8Array::<i32>::from([Some("diciembre"), None]));
+
+    assert!(weekday_name(&Int32Array::from_slice([1]), Locale::English).is_err());
+}
@@ -1,6 +1,8 @@
-use arrow2::array::{new_null_array, Int32Array};
+use arrow2::array::*;
+use arrow2::compute::sort::{lexsort_to_indices, sort_to_indices, SortColumn, SortOptions};
 use arrow2::compute::window::*;
 use arrow2::datatypes::DataType;
+use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
 
 #[test]
 fn shift_pos() {
@@ -27,3 +29,88 @@ fn shift_max() {
 
     assert_eq!(expected.as_ref(), result.as_ref());
 }
+
+#[test]
+fn shift_fill_utf8() {
+    let array = Utf8Array::<i32>::from(&[Some("a"), None, Some("c")]);
+    let fill = Utf8Scalar::<i32>::new(Some("z"));
+
+    let result = shift_fill(&array, -2, &fill).unwrap();
+    let expected = Utf8Array::<i32>::from(&[Some("c"), Some("z"), Some("z")]);
+    assert_eq!(expected, result.as_ref());
+
+    let fill = PrimitiveScalar::<i32>::new(DataType::Int32, Some(1));
+    assert!(shift_fill(&array, 1, &fill).is_err());
+}
+
+#[test]
+fn lead_lag() {
+    let array = Int32Array::from_slice(&[1, 2, 3]);
+    let fill = PrimitiveScalar::<i32>::new(DataType::Int32, Some(0));
+
+    let result = lag(&array, 1, None).unwrap();
+    assert_eq!(Int32Array::from(&[None, Some(1), Some(2)]), result.as_ref());
+
+    let result = lead(&array, 1, Some(&fill)).unwrap();
+    assert_eq!(Int32Array::from_slice(&[2, 3, 0]), result.as_ref());
+
+    // offsets beyond the length fill the whole array
+    let result = lag(&array, 10, Some(&fill)).unwrap();
+    assert_eq!(Int32Array::from_slice(&[0, 0, 0]), result.as_ref());
+}
+
+#[test]
+fn ranks() {
+    let array = Int32Array::from(&[Some(2), None, Some(1), Some(2), None, Some(3)]);
+    let options = SortOptions {
+        descending: false,
+        nulls_first: true,
+    };
+    let indices = sort_to_indices::<u32>(&array, &options, None).unwrap();
+
+    // the order of peers depends on the sort
+    let result = row_number(&indices);
+    for (position, index) in indices.values().iter().enumerate() {
+        assert_eq!(result.value(*index as usize), position as u64 + 1);
+    }
+
+    let result = rank(&[&array], &indices).unwrap();
+    assert_eq!(result, UInt64Array::from_slice(&[4, 1, 3, 4, 1, 6]));
+
+    let result = dense_rank(&[&array], &indices).unwrap();
+    assert_eq!(result, UInt64Array::from_slice(&[3, 1, 2, 3, 1, 4]));
+}
+
+#[test]
+fn ranks_multiple_columns() {
+    let a = Int32Array::from_slice(&[1, 1, 1, 2]);
+    let b = Utf8Array::<i32>::from_slice(&["x", "y", "x", "x"]);
+    let columns = vec![
+        SortColumn {
+            values: &a,
+            options: None,
+        },
+        SortColumn {
+            values: &b,
+            options: None,
+        },
+    ];
+    let indices = lexsort_to_indices::<u32>(&columns, None).unwrap();
+
+    let result = rank(&[&a, &b], &indices).unwrap();
+    assert_eq!(result, UInt64Array::from_slice(&[1, 3, 1, 4]));
+
+    let result = dense_rank(&[&a, &b], &indices).unwrap();
+    assert_eq!(result, UInt64Array::from_slice(&[1, 2, 1, 3]));
+
+    assert!(rank(&[&a.slice(0, 2)], &indices).is_err());
+}
+
+#[test]
+fn ranks_out_of_bounds() {
+    let array = Int32Array::from_slice(&[1, 2, 3]);
+    let indices = UInt32Array::from_slice(&[0, 3, 1]);
+
+    assert!(rank(&[&array], &indices).is_err());
+    assert!(dense_rank(&[&array], &indices).is_err());
+}
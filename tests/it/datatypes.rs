@@ -0,0 +1,125 @@
+use arrow2::datatypes::*;
+
+#[test]
+fn predicates() {
+    let list = DataType::List(Box::new(Field::new("item", DataType::Int32, true)));
+    let extension = DataType::Extension("ext".to_string(), Box::new(DataType::Int32), None);
+
+    assert!(DataType::UInt8.is_integer());
+    assert!(!DataType::Float32.is_integer());
+    assert!(DataType::Float16.is_floating());
+    assert!(DataType::Decimal(10, 2).is_numeric());
+    assert!(extension.is_numeric());
+    assert!(!DataType::Boolean.is_numeric());
+    assert!(!DataType::Date32.is_numeric());
+
+    assert!(DataType::Date32.is_temporal());
+    assert!(DataType::Interval(IntervalUnit::DayTime).is_temporal());
+    assert!(DataType::Timestamp(TimeUnit::Second, None).is_temporal());
+    assert!(!DataType::Int64.is_temporal());
+
+    assert!(list.is_nested());
+    assert!(DataType::Struct(vec![]).is_nested());
+    assert!(!DataType::Utf8.is_nested());
+}
+
+#[test]
+fn supertype_numeric() {
+    use DataType::*;
+    let cases = [
+        (Int8, Int8, Some(Int8)),
+        (Null, Int8, Some(Int8)),
+        (Int8, Int32, Some(Int32)),
+        (UInt16, UInt8, Some(UInt16)),
+        (Int8, UInt8, Some(Int16)),
+        (Int64, UInt32, Some(Int64)),
+        (UInt32, Int16, Some(Int64)),
+        (Int32, UInt64, Some(Int64)),
+        (Int64, Float32, Some(Float32)),
+        (Float16, Float64, Some(Float64)),
+        (Decimal(5, 2), Decimal(10, 4), Some(Decimal(10, 4))),
+        (Decimal(5, 2), Int32, Some(Decimal(12, 2))),
+        (Decimal(5, 2), Float32, Some(Float64)),
+        (Decimal(38, 0), Decimal(38, 10), None),
+        (Int8, Utf8, None),
+        (Boolean, Int8, None),
+    ];
+    for (lhs, rhs, expected) in cases {
+        assert_eq!(
+            common_supertype(&lhs, &rhs),
+            expected,
+            "{:?} {:?}",
+            lhs,
+            rhs
+        );
+        assert_eq!(
+            common_supertype(&rhs, &lhs),
+            expected,
+            "{:?} {:?}",
+            rhs,
+            lhs
+        );
+    }
+}
+
+#[test]
+fn supertype_temporal() {
+    use DataType::*;
+    use TimeUnit::*;
+    let utc = Some("+00:00".to_string());
+    let cases = [
+        (Date32, Date64, Some(Date64)),
+        (
+            Date32,
+            Timestamp(Millisecond, None),
+            Some(Timestamp(Millisecond, None)),
+        ),
+        (
+            Timestamp(Second, utc.clone()),
+            Timestamp(Nanosecond, utc.clone()),
+            Some(Timestamp(Nanosecond, utc.clone())),
+        ),
+        (Timestamp(Second, utc), Timestamp(Second, None), None),
+        (
+            Time32(Second),
+            Time32(Millisecond),
+            Some(Time32(Millisecond)),
+        ),
+        (
+            Time32(Second),
+            Time64(Microsecond),
+            Some(Time64(Microsecond)),
+        ),
+        (
+            Duration(Second),
+            Duration(Microsecond),
+            Some(Duration(Microsecond)),
+        ),
+        (Duration(Second), Int64, None),
+    ];
+    for (lhs, rhs, expected) in cases {
+        assert_eq!(
+            common_supertype(&lhs, &rhs),
+            expected,
+            "{:?} {:?}",
+            lhs,
+            rhs
+        );
+        assert_eq!(
+            common_supertype(&rhs, &lhs),
+            expected,
+            "{:?} {:?}",
+            rhs,
+            lhs
+        );
+    }
+}
+
+#[test]
+fn supertype_dictionary() {
+    let dictionary = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Int16), false);
+    assert_eq!(
+        common_supertype(&dictionary, &DataType::Int32),
+        Some(DataType::Int32)
+    );
+}
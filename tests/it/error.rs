@@ -0,0 +1,60 @@
+use arrow2::error::{ArrowError, ErrorCode, ErrorContext, Location};
+
+#[test]
+fn with_context() {
+    let error = ArrowError::OutOfSpec("invalid".to_string());
+    assert_eq!(error.code(), ErrorCode::OutOfSpec);
+    assert!(error.context().is_none());
+
+    let error = error
+        .with_context(ErrorContext::default().with_column("a").with_location(
+            Location::ParquetPage {
+                row_group: None,
+                column_chunk: Some(0),
+                page: Some(2),
+            },
+        ))
+        .with_context(ErrorContext::default().with_column("b").with_location(
+            Location::ParquetPage {
+                row_group: Some(1),
+                column_chunk: None,
+                page: None,
+            },
+        ));
+
+    // the innermost context takes precedence
+    assert_eq!(
+        error.context(),
+        Some(&ErrorContext {
+            column: Some("a".to_string()),
+            row: None,
+            byte_offset: None,
+            location: Some(Location::ParquetPage {
+                row_group: Some(1),
+                column_chunk: Some(0),
+                page: Some(2),
+            }),
+        })
+    );
+    assert_eq!(error.code(), ErrorCode::OutOfSpec);
+    assert!(matches!(error.without_context(), ArrowError::OutOfSpec(_)));
+    assert_eq!(
+        error.to_string(),
+        "invalid (at column \"a\", parquet row group 1 column chunk 0 page 2)"
+    );
+}
+
+#[test]
+fn source() {
+    use std::error::Error;
+
+    let error = ArrowError::Overflow.with_context(ErrorContext::default().with_row(1));
+    assert_eq!(
+        error.source().unwrap().to_string(),
+        ArrowError::Overflow.to_string()
+    );
+    assert_eq!(
+        error.to_string(),
+        "Operation overflew the backing container. (at row 1)"
+    );
+}
@@ -0,0 +1,71 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::error::Result;
+use arrow2::ffi::arrow_rs::*;
+use arrow2::record_batch::RecordBatch;
+
+fn round_trip(array: Arc<dyn Array>) -> Result<()> {
+    let result = from_arrow_rs(to_arrow_rs(array.clone())?.as_ref())?;
+    assert_eq!(result.as_ref(), array.as_ref());
+    Ok(())
+}
+
+#[test]
+fn primitive() -> Result<()> {
+    round_trip(Arc::new(Int32Array::from(&[Some(1), None, Some(3)])))
+}
+
+#[test]
+fn sliced_utf8() -> Result<()> {
+    let array = Utf8Array::<i32>::from(&[Some("a"), None, Some("ccc"), Some("dd")]).slice(1, 3);
+    round_trip(Arc::new(array))
+}
+
+#[test]
+fn list() -> Result<()> {
+    let data = vec![
+        Some(vec![Some(1i64), Some(2)]),
+        None,
+        Some(vec![None, Some(3)]),
+    ];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i64>>::new();
+    array.try_extend(data).unwrap();
+    round_trip(array.into_arc())
+}
+
+#[test]
+fn from_arrow_array() -> Result<()> {
+    let array = arrow::array::Int64Array::from(vec![Some(1), None, Some(3)]);
+
+    let result = from_arrow_rs(&array)?;
+
+    let expected = Int64Array::from(&[Some(1), None, Some(3)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn batch() -> Result<()> {
+    let mut metadata = BTreeMap::new();
+    metadata.insert("key".to_string(), "value".to_string());
+    let schema = Schema::new(vec![
+        Field::new("a", DataType::Int32, false).with_metadata(metadata),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let columns = vec![
+        Arc::new(Int32Array::from_slice(&[1, 2])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("a"), None])) as Arc<dyn Array>,
+    ];
+    let batch = RecordBatch::try_new(Arc::new(schema), columns)?;
+
+    let arrow_batch = batch_to_arrow_rs(&batch)?;
+    assert_eq!(arrow_batch.num_rows(), 2);
+    assert_eq!(arrow_batch.schema().field(1).name(), "b");
+
+    let result = batch_from_arrow_rs(&arrow_batch)?;
+    assert_eq!(result, batch);
+    Ok(())
+}
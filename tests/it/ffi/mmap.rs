@@ -0,0 +1,143 @@
+use std::io::Cursor;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::error::Result;
+use arrow2::ffi::mmap::mmap_record_batch;
+use arrow2::io::ipc::read::read_stream_metadata;
+use arrow2::io::ipc::write::{StreamWriter, WriteOptions};
+use arrow2::record_batch::RecordBatch;
+
+/// A region aligned to 8 bytes that records when it is dropped
+struct Region {
+    data: Vec<u64>,
+    length: usize,
+    dropped: Arc<AtomicBool>,
+}
+
+impl Region {
+    fn new(bytes: &[u8], dropped: Arc<AtomicBool>) -> Self {
+        let mut data = vec![0u64; (bytes.len() + 7) / 8];
+        let region =
+            unsafe { std::slice::from_raw_parts_mut(data.as_mut_ptr() as *mut u8, bytes.len()) };
+        region.copy_from_slice(bytes);
+        Self {
+            data,
+            length: bytes.len(),
+            dropped,
+        }
+    }
+}
+
+impl AsRef<[u8]> for Region {
+    fn as_ref(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.data.as_ptr() as *const u8, self.length) }
+    }
+}
+
+impl Drop for Region {
+    fn drop(&mut self) {
+        self.dropped.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Returns the record batch message of a stream with `batch`
+fn batch_message(batch: &RecordBatch) -> Result<Vec<u8>> {
+    let mut stream = vec![];
+    let options = WriteOptions { compression: None };
+    let mut writer = StreamWriter::try_new(&mut stream, batch.schema(), options)?;
+    writer.write(batch)?;
+    writer.finish()?;
+
+    let mut reader = Cursor::new(&stream);
+    read_stream_metadata(&mut reader)?;
+    let start = reader.position() as usize;
+    Ok(stream[start..].to_vec())
+}
+
+fn round_trip(columns: Vec<Arc<dyn Array>>) -> Result<()> {
+    let fields = columns
+        .iter()
+        .enumerate()
+        .map(|(i, array)| Field::new(format!("c{}", i), array.data_type().clone(), true))
+        .collect();
+    let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?;
+    let message = batch_message(&batch)?;
+
+    let dropped = Arc::new(AtomicBool::new(false));
+    let region = Arc::new(Region::new(&message, dropped.clone()));
+    let start = region.as_ref().as_ref().as_ptr() as usize;
+    let end = start + message.len();
+
+    let result = unsafe { mmap_record_batch(region, batch.schema().clone())? };
+    assert_eq!(result, batch);
+
+    // the values are not copied
+    for column in result.columns() {
+        if let Some(array) = column.as_any().downcast_ref::<Int32Array>() {
+            let ptr = array.values().as_ptr() as usize;
+            assert!(ptr >= start && ptr < end);
+        }
+    }
+
+    assert!(!dropped.load(Ordering::SeqCst));
+    drop(result);
+    assert!(dropped.load(Ordering::SeqCst));
+    Ok(())
+}
+
+#[test]
+fn primitive_and_utf8() -> Result<()> {
+    round_trip(vec![
+        Arc::new(Int32Array::from(&[Some(1), None, Some(3)])),
+        Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("bb"), None])),
+        Arc::new(BooleanArray::from_slice(&[true, false, true])),
+        Arc::new(NullArray::from_data(DataType::Null, 3)),
+    ])
+}
+
+#[test]
+fn nested() -> Result<()> {
+    let data = vec![
+        Some(vec![Some(1i32), Some(2)]),
+        None,
+        Some(vec![None, Some(3)]),
+    ];
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(data).unwrap();
+    let list: ListArray<i32> = list.into();
+
+    let fields = vec![Field::new("a", DataType::LargeBinary, true)];
+    let values =
+        vec![Arc::new(BinaryArray::<i64>::from(&[Some(b"a"), None, Some(b"c")])) as Arc<dyn Array>];
+    let r#struct = StructArray::from_data(DataType::Struct(fields), values, None);
+
+    round_trip(vec![Arc::new(list), Arc::new(r#struct)])
+}
+
+#[test]
+fn unaligned() -> Result<()> {
+    let batch = RecordBatch::try_from_iter(vec![(
+        "a",
+        Arc::new(Int32Array::from_slice(&[1, 2])) as Arc<dyn Array>,
+    )])?;
+    let message = batch_message(&batch)?;
+
+    let mut bytes = vec![0u8];
+    bytes.extend_from_slice(&message);
+    let region = Region::new(&bytes, Arc::new(AtomicBool::new(false)));
+
+    // the message starts at an offset of 1 byte of an aligned region
+    struct Offset(Region);
+    impl AsRef<[u8]> for Offset {
+        fn as_ref(&self) -> &[u8] {
+            &self.0.as_ref()[1..]
+        }
+    }
+
+    let result = unsafe { mmap_record_batch(Arc::new(Offset(region)), batch.schema().clone()) };
+    assert!(result.is_err());
+    Ok(())
+}
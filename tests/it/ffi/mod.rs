@@ -1,13 +1,26 @@
 use arrow2::array::*;
 use arrow2::bitmap::Bitmap;
-use arrow2::datatypes::{DataType, Field, TimeUnit};
+use arrow2::buffer::Buffer;
+use arrow2::datatypes::{DataType, Field, TimeUnit, UnionMode};
 use arrow2::{error::Result, ffi};
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
+#[cfg(feature = "ffi_arrow_rs")]
+mod arrow_rs;
+#[cfg(feature = "io_ipc")]
+mod mmap;
+
 fn _test_round_trip(array: Arc<dyn Array>, expected: Box<dyn Array>) -> Result<()> {
     let field = Field::new("a", array.data_type().clone(), true);
+    _test_round_trip_field(field, array, expected)
+}
 
+fn _test_round_trip_field(
+    field: Field,
+    array: Arc<dyn Array>,
+    expected: Box<dyn Array>,
+) -> Result<()> {
     let array_ptr = Box::new(ffi::Ffi_ArrowArray::empty());
     let schema_ptr = Box::new(ffi::Ffi_ArrowSchema::empty());
 
@@ -93,6 +106,46 @@ fn binary() -> Result<()> {
     test_round_trip(data)
 }
 
+#[test]
+fn boolean() -> Result<()> {
+    let data = BooleanArray::from(&[Some(true), None, Some(false), None]);
+    test_round_trip(data)
+}
+
+#[test]
+fn fixed_size_binary() -> Result<()> {
+    let data = FixedSizeBinaryArray::from_data(
+        DataType::FixedSizeBinary(2),
+        vec![1, 2, 3, 4, 5, 6, 7, 8].into(),
+        Some(Bitmap::from([true, false, true, true])),
+    );
+    test_round_trip(data)
+}
+
+#[test]
+fn sliced_beyond_first_byte() -> Result<()> {
+    let data = (0..20)
+        .map(|x| if x % 3 == 0 { None } else { Some(x) })
+        .collect::<Int32Array>();
+    let array: Arc<dyn Array> = Arc::new(data.slice(10, 7));
+    _test_round_trip(array, Box::new(data.slice(10, 7)))?;
+
+    let data = (0..20)
+        .map(|x| if x % 3 == 0 { None } else { Some(x % 2 == 0) })
+        .collect::<BooleanArray>();
+    let array: Arc<dyn Array> = Arc::new(data.slice(10, 7));
+    _test_round_trip(array, Box::new(data.slice(10, 7)))
+}
+
+#[test]
+fn misaligned_validity() -> Result<()> {
+    // the validity's offset is larger than the values' offset, which requires re-aligning it
+    let validity = Bitmap::from([true, false, true, true, false, true]).slice(3, 3);
+    let data = Int32Array::from_slice(&[1, 2, 3, 4]).slice(1, 3);
+    let data = data.with_validity(Some(validity));
+    test_round_trip(data)
+}
+
 #[test]
 fn timestamp_tz() -> Result<()> {
     let data = Int64Array::from(&vec![Some(2), None, None]).to(DataType::Timestamp(
@@ -173,6 +226,56 @@ fn struct_() -> Result<()> {
     test_round_trip(array)
 }
 
+#[test]
+fn struct_nested_sliced() -> Result<()> {
+    let inner_type = DataType::Struct(vec![Field::new("a", DataType::Int32, true)]);
+    let values = (0..20)
+        .map(|x| if x % 3 == 0 { None } else { Some(x) })
+        .collect::<Int32Array>();
+    let validity = (0..20).map(|x| x % 4 != 0).collect::<Bitmap>();
+    let inner = StructArray::from_data(
+        inner_type.clone(),
+        vec![Arc::new(values) as Arc<dyn Array>],
+        Some(validity),
+    );
+
+    let data_type = DataType::Struct(vec![Field::new("b", inner_type, true)]);
+    let validity = (0..20).map(|x| x % 5 != 0).collect::<Bitmap>();
+    let array = StructArray::from_data(
+        data_type,
+        vec![Arc::new(inner) as Arc<dyn Array>],
+        Some(validity),
+    );
+
+    let sliced: Arc<dyn Array> = Arc::new(array.slice(3, 13));
+    _test_round_trip(sliced, Box::new(array.slice(3, 13)))?;
+    test_round_trip(array)
+}
+
+#[test]
+fn union() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields.clone(), Some(vec![0, 1]), UnionMode::Sparse);
+    let types = Buffer::from(&[0, 1, 0, 1]);
+    let children = vec![
+        Arc::new(Int32Array::from(&[Some(1), None, None, None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[None, Some("a"), None, Some("b")])) as Arc<dyn Array>,
+    ];
+    test_round_trip(UnionArray::from_data(data_type, types, children, None))?;
+
+    let data_type = DataType::Union(fields, Some(vec![0, 1]), UnionMode::Dense);
+    let types = Buffer::from(&[0, 1, 0, 1]);
+    let children = vec![
+        Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b")])) as Arc<dyn Array>,
+    ];
+    let offsets = Some(Buffer::from(&[0, 0, 1, 1]));
+    test_round_trip(UnionArray::from_data(data_type, types, children, offsets))
+}
+
 #[test]
 fn dict() -> Result<()> {
     let data = vec![Some("a"), Some("a"), None, Some("b")];
@@ -185,6 +288,19 @@ fn dict() -> Result<()> {
     test_round_trip(array)
 }
 
+#[test]
+fn dict_ordered() -> Result<()> {
+    let array = DictionaryArray::<i32>::try_from_strings(
+        [Some("high"), None, Some("low")],
+        &["low", "high"],
+        true,
+    )?;
+    assert!(array.is_ordered());
+
+    // the flag is part of the data type, and thus of the exported schema
+    test_round_trip(array)
+}
+
 #[test]
 fn schema() -> Result<()> {
     let field = Field::new(
@@ -196,7 +312,7 @@ fn schema() -> Result<()> {
 
     let field = Field::new(
         "a",
-        DataType::Dictionary(u32::KEY_TYPE, Box::new(DataType::Utf8)),
+        DataType::Dictionary(u32::KEY_TYPE, Box::new(DataType::Utf8), false),
         true,
     );
     test_round_trip_schema(field)?;
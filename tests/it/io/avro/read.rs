@@ -6,11 +6,13 @@ use avro_rs::{Days, Duration, Millis, Months, Schema as AvroSchema};
 
 use arrow2::array::*;
 use arrow2::datatypes::*;
-use arrow2::error::Result;
+use arrow2::error::{ArrowError, ErrorCode, Result};
 use arrow2::io::avro::read;
 use arrow2::record_batch::RecordBatch;
 use arrow2::types::months_days_ns;
 
+use crate::io::hooks::CancelAfter;
+
 fn schema() -> (AvroSchema, Schema) {
     let raw_schema = r#"
     {
@@ -69,7 +71,7 @@ fn schema() -> (AvroSchema, Schema) {
         ),
         Field::new(
             "enum",
-            DataType::Dictionary(i32::KEY_TYPE, Box::new(DataType::Utf8)),
+            DataType::Dictionary(i32::KEY_TYPE, Box::new(DataType::Utf8), false),
             false,
         ),
         Field::new(
@@ -200,3 +202,127 @@ fn read_deflate() -> Result<()> {
 fn read_snappy() -> Result<()> {
     test(Codec::Snappy)
 }
+
+fn read_batch(data: &[u8], checksum: bool, strict: bool) -> Result<RecordBatch> {
+    let file = &mut &data[..];
+
+    let (avro_schema, schema, codec, file_marker) = read::read_metadata(file)?;
+
+    let mut reader = read::Reader::new(
+        read::Decompressor::new(
+            read::BlockStreamIterator::new(file, file_marker).with_strict(strict),
+            codec,
+        )
+        .with_checksum(checksum),
+        avro_schema,
+        Arc::new(schema),
+    );
+
+    let batch = reader.next().unwrap()?;
+    assert!(reader.next().transpose()?.is_none());
+    Ok(batch)
+}
+
+#[test]
+fn read_snappy_checksum() -> Result<()> {
+    let (mut data, expected) = write(Codec::Snappy).unwrap();
+    assert_eq!(read_batch(&data, true, true)?, expected);
+
+    // the checksum is the last 4 bytes of the block, before the sync marker
+    let index = data.len() - 16 - 1;
+    data[index] ^= 1;
+    assert_eq!(read_batch(&data, false, true)?, expected);
+    let error = read_batch(&data, true, true).unwrap_err();
+    assert!(matches!(
+        error.without_context(),
+        ArrowError::ExternalFormat(_)
+    ));
+    Ok(())
+}
+
+#[test]
+fn read_strict() -> Result<()> {
+    let (mut data, expected) = write(Codec::Null).unwrap();
+    // garbage after the last sync marker
+    data.extend_from_slice(&[0, 0, 0]);
+
+    assert_eq!(read_batch(&data, false, false)?, expected);
+    let error = read_batch(&data, false, true).unwrap_err();
+    assert!(matches!(
+        error.without_context(),
+        ArrowError::ExternalFormat(_)
+    ));
+    Ok(())
+}
+
+#[test]
+fn read_cancelled() -> Result<()> {
+    let (data, expected) = write(Codec::Deflate).unwrap();
+    let file = &mut &data[..];
+
+    let (avro_schema, schema, codec, file_marker) = read::read_metadata(file)?;
+
+    let mut reader = read::Reader::new(
+        read::Decompressor::new(read::BlockStreamIterator::new(file, file_marker), codec),
+        avro_schema,
+        Arc::new(schema),
+    );
+    let hooks = Arc::new(CancelAfter::new(0));
+    reader.set_hooks(hooks.clone());
+
+    let error = reader.next().unwrap().unwrap_err();
+    assert_eq!(error.code(), ErrorCode::Cancelled);
+    assert!(reader.next().is_none());
+    assert!(hooks.progress.lock().unwrap().is_empty());
+
+    let file = &mut &data[..];
+    let (avro_schema, schema, codec, file_marker) = read::read_metadata(file)?;
+    let mut reader = read::Reader::new(
+        read::Decompressor::new(read::BlockStreamIterator::new(file, file_marker), codec),
+        avro_schema,
+        Arc::new(schema),
+    );
+    let hooks = Arc::new(CancelAfter::new(2));
+    reader.set_hooks(hooks.clone());
+
+    assert_eq!(reader.next().unwrap()?, expected);
+    assert!(reader.next().is_none());
+    let progress = hooks.progress.lock().unwrap();
+    assert_eq!(progress.len(), 1);
+    assert_eq!(progress[0].rows, expected.num_rows());
+    assert!(progress[0].bytes > 0);
+    Ok(())
+}
+
+fn assert_out_of_spec(result: Result<RecordBatch>) {
+    let error = result.unwrap_err();
+    assert!(matches!(error.without_context(), ArrowError::OutOfSpec(_)));
+}
+
+#[test]
+fn read_corrupt() -> Result<()> {
+    let (data, _) = write(Codec::Null).unwrap();
+
+    // a truncated varint after the last sync marker
+    let mut truncated = data.clone();
+    truncated.push(0x80);
+    assert_out_of_spec(read_batch(&truncated, false, false));
+    assert_out_of_spec(read_batch(&truncated, false, true));
+
+    // a block with a negative number of bytes (-1 is zigzag-encoded as 1)
+    let mut negative = data.clone();
+    negative.extend_from_slice(&[2, 1]);
+    assert_out_of_spec(read_batch(&negative, false, false));
+
+    // a block declaring more bytes than the file has
+    let mut too_large = data.clone();
+    too_large.extend_from_slice(&[2, 0xfe, 0xff, 0xff, 0xff, 0x0f, 1, 2]);
+    assert_out_of_spec(read_batch(&too_large, false, false));
+
+    // a block not followed by the file's marker
+    let mut marker = data;
+    let index = marker.len() - 1;
+    marker[index] ^= 1;
+    assert_out_of_spec(read_batch(&marker, false, false));
+    Ok(())
+}
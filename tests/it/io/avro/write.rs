@@ -0,0 +1,168 @@
+use avro_rs::types::Value;
+use avro_rs::{Days, Decimal, Duration, Millis, Months, Writer};
+
+use arrow2::datatypes::*;
+use arrow2::error::Result;
+use arrow2::io::avro::{read, write};
+
+#[test]
+fn schema() -> Result<()> {
+    let list = DataType::List(Box::new(Field::new("item", DataType::Int32, true)));
+    let map = DataType::Map(
+        Box::new(Field::new(
+            "entries",
+            DataType::Struct(vec![
+                Field::new("key", DataType::Utf8, false),
+                Field::new("value", DataType::Float64, true),
+            ]),
+            false,
+        )),
+        false,
+    );
+    let nested = DataType::Struct(vec![
+        Field::new("a", DataType::Boolean, false),
+        Field::new("b", list, true),
+    ]);
+    let uuid = DataType::Extension(
+        "arrow.uuid".to_string(),
+        Box::new(DataType::FixedSizeBinary(16)),
+        None,
+    );
+    let schema = Schema::new(vec![
+        Field::new("int", DataType::Int16, false),
+        Field::new("long", DataType::UInt32, true),
+        Field::new("decimal", DataType::Decimal(10, 2), false),
+        Field::new("uuid", uuid, false),
+        Field::new("fixed", DataType::FixedSizeBinary(4), false),
+        Field::new(
+            "timestamp",
+            DataType::Timestamp(TimeUnit::Microsecond, Some("+00:00".to_string())),
+            true,
+        ),
+        Field::new("nested", nested, false),
+        Field::new("map", map, false),
+    ]);
+
+    let avro = write::to_avro_schema(&schema)?;
+
+    let expected = serde_json::json!({
+        "type": "record",
+        "name": "record",
+        "fields": [
+            {"name": "int", "type": "int"},
+            {"name": "long", "type": ["null", "long"]},
+            {"name": "decimal", "type": {
+                "type": "bytes", "logicalType": "decimal", "scale": 2, "precision": 10
+            }},
+            {"name": "uuid", "type": {"type": "fixed", "name": "record.uuid", "size": 16}},
+            {"name": "fixed", "type": {"type": "fixed", "name": "record.fixed", "size": 4}},
+            {"name": "timestamp", "type": [
+                "null", {"type": "long", "logicalType": "timestamp-micros"}
+            ]},
+            {"name": "nested", "type": {
+                "type": "record",
+                "name": "record.nested",
+                "fields": [
+                    {"name": "a", "type": "boolean"},
+                    {"name": "b", "type": ["null", {"type": "array", "items": ["null", "int"]}]}
+                ]
+            }},
+            {"name": "map", "type": {"type": "map", "values": ["null", "double"]}}
+        ]
+    });
+    assert_eq!(serde_json::to_value(&avro).unwrap(), expected);
+    Ok(())
+}
+
+#[test]
+fn schema_unique_names() -> Result<()> {
+    let item = |data_type| DataType::List(Box::new(Field::new("item", data_type, true)));
+    let record = DataType::Struct(vec![Field::new("a", DataType::Int32, true)]);
+    let fixed = DataType::FixedSizeBinary(2);
+    let schema = Schema::new(vec![
+        Field::new("a", item(record.clone()), false),
+        Field::new("b", item(record.clone()), false),
+        Field::new("c", item(fixed.clone()), false),
+        Field::new("d", item(fixed), false),
+        Field::new("item", record, false),
+    ]);
+
+    let avro = write::to_avro_schema(&schema)?;
+    let value = serde_json::to_value(&avro).unwrap();
+
+    let field_type = |index: usize| &value["fields"][index]["type"];
+    assert_eq!(field_type(0)["items"][1]["name"], "record.a.item");
+    assert_eq!(field_type(1)["items"][1]["name"], "record.b.item");
+    assert_eq!(field_type(2)["items"][1]["name"], "record.c.item");
+    assert_eq!(field_type(3)["items"][1]["name"], "record.d.item");
+    assert_eq!(field_type(4)["name"], "record.item");
+
+    // the schema is valid Avro and can be used to write
+    let avro = avro_rs::Schema::parse(&value)?;
+    let writer = Writer::new(&avro, vec![]);
+    writer.into_inner()?;
+    Ok(())
+}
+
+#[test]
+fn schema_roundtrip() -> Result<()> {
+    let schema = Schema::new(vec![
+        Field::new("a", DataType::Int64, false),
+        Field::new("b", DataType::Utf8, true),
+        Field::new("c", DataType::Date32, false),
+        Field::new("d", DataType::Time32(TimeUnit::Millisecond), false),
+        Field::new("e", DataType::Timestamp(TimeUnit::Millisecond, None), true),
+        Field::new("f", DataType::Decimal(20, 5), false),
+        Field::new("g", DataType::Interval(IntervalUnit::MonthDayNano), false),
+        Field::new(
+            "h",
+            DataType::List(Box::new(Field::new("item", DataType::Float32, true))),
+            false,
+        ),
+    ]);
+
+    let avro = write::to_avro_schema(&schema)?;
+    // the header is only written with the first record
+    let record = Value::Record(vec![
+        ("a".to_string(), Value::Long(1)),
+        ("b".to_string(), Value::Union(Box::new(Value::Null))),
+        ("c".to_string(), Value::Date(1)),
+        ("d".to_string(), Value::TimeMillis(1)),
+        ("e".to_string(), Value::Union(Box::new(Value::Null))),
+        ("f".to_string(), Value::Decimal(Decimal::from(vec![1u8]))),
+        (
+            "g".to_string(),
+            Value::Duration(Duration::new(Months::new(1), Days::new(1), Millis::new(1))),
+        ),
+        ("h".to_string(), Value::Array(vec![])),
+    ]);
+    let mut writer = Writer::new(&avro, vec![]);
+    writer.append(record)?;
+    let data = writer.into_inner()?;
+
+    let (_, result, _, _) = read::read_metadata(&mut &data[..])?;
+    assert_eq!(result, schema);
+    Ok(())
+}
+
+#[test]
+fn unsupported() {
+    for data_type in [
+        DataType::UInt64,
+        DataType::Timestamp(TimeUnit::Nanosecond, None),
+        DataType::Map(
+            Box::new(Field::new(
+                "entries",
+                DataType::Struct(vec![
+                    Field::new("key", DataType::Int32, false),
+                    Field::new("value", DataType::Int32, true),
+                ]),
+                false,
+            )),
+            false,
+        ),
+    ] {
+        let schema = Schema::new(vec![Field::new("a", data_type, false)]);
+        assert!(write::to_avro_schema(&schema).is_err());
+    }
+}
@@ -252,3 +252,111 @@ proptest! {
         assert_eq!(infer(v.as_bytes()), DataType::Timestamp(TimeUnit::Millisecond, Some("-02:00".to_string())));
     }
 }
+
+#[test]
+fn custom_parsers() -> Result<()> {
+    let data = "name,ratio,price\na,\"12,5%\",$1000.50\nb,,$-3\nc,x,\n";
+    let mut reader = ReaderBuilder::new().from_reader(Cursor::new(data));
+
+    let fields = vec![
+        Field::new("name", DataType::Utf8, true),
+        Field::new("ratio", DataType::Float64, true),
+        Field::new("price", DataType::Decimal(10, 2), true),
+    ];
+
+    let mut rows = vec![ByteRecord::default(); 10];
+    let rows_read = read_rows(&mut reader, 0, &mut rows)?;
+
+    let parsers = ColumnParsers::new()
+        .with_primitive(1, |bytes: &[u8]| {
+            std::str::from_utf8(bytes)
+                .ok()?
+                .strip_suffix('%')?
+                .replace(',', ".")
+                .parse::<f64>()
+                .ok()
+                .map(|x| x / 100.0)
+        })
+        .with_primitive(2, |bytes: &[u8]| {
+            let value = std::str::from_utf8(bytes).ok()?.strip_prefix('$')?;
+            value
+                .parse::<f64>()
+                .ok()
+                .map(|x| (x * 100.0).round() as i128)
+        });
+
+    let batch = deserialize_batch(&rows[..rows_read], &fields, None, 0, |r, c, d, l| {
+        parsers.deserialize_column(r, c, d, l)
+    })?;
+
+    let expected = Utf8Array::<i32>::from_slice(&["a", "b", "c"]);
+    assert_eq!(expected, batch.column(0).as_ref());
+
+    let expected = Float64Array::from(&[Some(0.125), None, None]);
+    assert_eq!(expected, batch.column(1).as_ref());
+
+    let expected =
+        Int128Array::from(&[Some(100050), Some(-300), None]).to(DataType::Decimal(10, 2));
+    assert_eq!(expected, batch.column(2).as_ref());
+    Ok(())
+}
+
+#[test]
+fn custom_parsers_invalid_type() -> Result<()> {
+    let mut rows = vec![ByteRecord::default(); 10];
+    let mut reader = ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(Cursor::new("1\n2\n"));
+    let rows_read = read_rows(&mut reader, 0, &mut rows)?;
+
+    let parsers = ColumnParsers::new().with_primitive(0, |_: &[u8]| Some(1.0f64));
+    assert!(parsers
+        .deserialize_column(&rows[..rows_read], 0, DataType::Int32, 0)
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn error_context() -> Result<()> {
+    use arrow2::error::{ArrowError, ErrorCode, Location};
+    use std::error::Error;
+
+    let data = "a,b\n1,2\n3,4,5\n";
+    let mut reader = ReaderBuilder::new().from_reader(Cursor::new(data));
+    let mut rows = vec![ByteRecord::default(); 10];
+    let error = read_rows(&mut reader, 0, &mut rows).unwrap_err();
+
+    // the csv error is wrapped in the context
+    assert!(matches!(error, ArrowError::WithContext { .. }));
+    assert!(matches!(
+        error.without_context(),
+        ArrowError::External(_, _)
+    ));
+    assert!(error.source().unwrap().source().unwrap().is::<csv::Error>());
+    assert_eq!(error.code(), ErrorCode::External);
+    let context = error.context().unwrap();
+    assert_eq!(context.row, Some(1));
+    assert_eq!(context.byte_offset, Some(8));
+    assert_eq!(context.location, Some(Location::CsvLine(3)));
+    Ok(())
+}
+
+#[test]
+fn error_context_column() -> Result<()> {
+    use arrow2::error::ErrorCode;
+
+    let data = "a,b\n1,2\n";
+    let mut reader = ReaderBuilder::new().from_reader(Cursor::new(data));
+    let mut rows = vec![ByteRecord::default(); 10];
+    let rows_read = read_rows(&mut reader, 0, &mut rows)?;
+
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Interval(IntervalUnit::DayTime), true),
+    ];
+    let error =
+        deserialize_batch(&rows[..rows_read], &fields, None, 0, deserialize_column).unwrap_err();
+    assert_eq!(error.code(), ErrorCode::NotYetImplemented);
+    assert_eq!(error.context().unwrap().column.as_deref(), Some("b"));
+    Ok(())
+}
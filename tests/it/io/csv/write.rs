@@ -5,6 +5,7 @@ use arrow2::array::*;
 use arrow2::datatypes::*;
 use arrow2::error::Result;
 use arrow2::io::csv::write::*;
+use arrow2::io::ChunkSink;
 use arrow2::record_batch::RecordBatch;
 
 fn data() -> RecordBatch {
@@ -56,6 +57,31 @@ d,-556132.25,1,,2019-04-18 02:45:55.555,23:46:03,c
     Ok(())
 }
 
+#[test]
+fn write_csv_sink() -> Result<()> {
+    let batch = data();
+
+    let writer = WriterBuilder::new().from_writer(Cursor::new(Vec::<u8>::new()));
+    let mut sink = FileWriter::try_new(
+        writer,
+        batch.schema().as_ref().clone(),
+        SerializeOptions::default(),
+    )?;
+    assert_eq!(sink.schema(), batch.schema().as_ref());
+    sink.write(&batch)?;
+    sink.write(&batch)?;
+    // batches must have the sink's schema
+    let other = RecordBatch::try_from_iter(vec![("c1", batch.column(0).clone())])?;
+    assert!(sink.write(&other).is_err());
+    sink.close()?;
+
+    let buffer = sink.into_inner().into_inner().unwrap().into_inner();
+    let buffer = String::from_utf8(buffer).unwrap();
+    assert!(buffer.starts_with("c1,c2,c3,c4,c5,c6,c7\na b,"));
+    assert_eq!(buffer.lines().count(), 7);
+    Ok(())
+}
+
 #[test]
 fn write_csv_custom_options() -> Result<()> {
     let batch = data();
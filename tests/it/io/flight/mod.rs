@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use futures::{StreamExt, TryStreamExt};
+
+use arrow2::array::*;
+use arrow2::error::Result;
+use arrow2::io::flight::*;
+use arrow2::io::ipc::write::WriteOptions;
+use arrow2::record_batch::RecordBatch;
+
+#[tokio::test]
+async fn roundtrip_stream() -> Result<()> {
+    let mut values = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    values.try_extend([Some("a"), None, Some("b"), Some("a")])?;
+    let dictionary: DictionaryArray<i32> = values.into();
+    let batch = RecordBatch::try_from_iter(vec![
+        (
+            "a",
+            Arc::new(Int32Array::from([Some(1), None, Some(3), Some(4)])) as Arc<dyn Array>,
+        ),
+        ("b", Arc::new(dictionary) as Arc<dyn Array>),
+    ])?;
+    let schema = batch.schema().clone();
+    let columns = batch
+        .columns()
+        .iter()
+        .map(|column| column.slice(1, 2).into())
+        .collect();
+    let other = RecordBatch::try_new(schema.clone(), columns)?;
+    let batches = vec![
+        Ok((batch.clone(), b"first".to_vec())),
+        Ok((other.clone(), b"second".to_vec())),
+    ];
+
+    let data = serialize_stream_with_metadata(
+        &schema,
+        futures::stream::iter(batches),
+        WriteOptions { compression: None },
+    )
+    .collect::<Vec<_>>()
+    .await;
+    // schema, dictionary, batch, batch: the dictionary is only sent once
+    assert_eq!(data.len(), 4);
+
+    let (new_schema, stream) = deserialize_stream(futures::stream::iter(data)).await?;
+    assert_eq!(new_schema, schema);
+    let result = stream.try_collect::<Vec<_>>().await?;
+    assert_eq!(
+        result,
+        vec![(batch, b"first".to_vec()), (other, b"second".to_vec())]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn deserialize_empty_stream() {
+    let data = futures::stream::iter(Vec::<Result<_>>::new());
+    assert!(deserialize_stream(data).await.is_err());
+}
@@ -0,0 +1,28 @@
+use std::sync::Mutex;
+
+use arrow2::io::{ReadHooks, ReadProgress};
+
+/// [`ReadHooks`] that record the progress and cancel the read after `batches` batches.
+pub struct CancelAfter {
+    batches: usize,
+    pub progress: Mutex<Vec<ReadProgress>>,
+}
+
+impl CancelAfter {
+    pub fn new(batches: usize) -> Self {
+        Self {
+            batches,
+            progress: Mutex::new(vec![]),
+        }
+    }
+}
+
+impl ReadHooks for CancelAfter {
+    fn should_cancel(&self) -> bool {
+        self.progress.lock().unwrap().len() >= self.batches
+    }
+
+    fn on_progress(&self, progress: ReadProgress) {
+        self.progress.lock().unwrap().push(progress)
+    }
+}
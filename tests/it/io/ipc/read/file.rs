@@ -1,7 +1,13 @@
 use std::fs::File;
+use std::io::Cursor;
+use std::sync::Arc;
 
+use arrow2::array::*;
+use arrow2::datatypes::DataType;
 use arrow2::error::Result;
 use arrow2::io::ipc::read::*;
+use arrow2::io::ipc::write::{FileWriter, WriteOptions};
+use arrow2::record_batch::RecordBatch;
 
 use super::super::common::read_gzip_json;
 
@@ -180,3 +186,81 @@ fn read_projected() -> Result<()> {
     test_projection("1.0.0-littleendian", "generated_dictionary", 2)?;
     test_projection("1.0.0-littleendian", "generated_nested", 0)
 }
+
+fn write_file(batch: &RecordBatch) -> Result<Vec<u8>> {
+    let options = WriteOptions { compression: None };
+    let mut writer = FileWriter::try_new(vec![], batch.schema(), options)?;
+    writer.write(batch)?;
+    writer.finish()?;
+    Ok(writer.into_inner())
+}
+
+#[test]
+fn read_validation() -> Result<()> {
+    let utf8 = Utf8Array::<i32>::from([Some("a"), None, Some("bcd")]);
+    let binary = BinaryArray::<i64>::from([Some(b"a".as_ref()), Some(b""), None]);
+    let data = vec![Some(vec![Some(1i32), None]), None, Some(vec![])];
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(data)?;
+    let list: ListArray<i32> = list.into();
+    let batch = RecordBatch::try_from_iter(vec![
+        ("utf8", Arc::new(utf8) as Arc<dyn Array>),
+        ("binary", Arc::new(binary) as Arc<dyn Array>),
+        ("list", Arc::new(list) as Arc<dyn Array>),
+    ])?;
+    let data = write_file(&batch)?;
+
+    let modes = unsafe {
+        [
+            ValidationMode::default(),
+            ValidationMode::shallow(),
+            ValidationMode::none(),
+        ]
+    };
+    for mode in modes {
+        let mut reader = Cursor::new(&data);
+        let metadata = read_file_metadata(&mut reader)?;
+        let mut reader = FileReader::new(reader, metadata, None);
+        reader.set_validation(mode);
+
+        let batches = reader.collect::<Result<Vec<_>>>()?;
+        assert_eq!(batches, vec![batch.clone()]);
+    }
+    Ok(())
+}
+
+fn invalid_utf8_file() -> Result<Vec<u8>> {
+    let array = unsafe {
+        Utf8Array::<i32>::from_data_unchecked(
+            DataType::Utf8,
+            vec![0, 2].into(),
+            vec![0xc3, 0x28].into(),
+            None,
+        )
+    };
+    let batch = RecordBatch::try_from_iter(vec![("a", Arc::new(array) as Arc<dyn Array>)])?;
+    write_file(&batch)
+}
+
+#[test]
+#[should_panic]
+fn read_validation_full_invalid_utf8() {
+    let data = invalid_utf8_file().unwrap();
+    let mut reader = Cursor::new(&data);
+    let metadata = read_file_metadata(&mut reader).unwrap();
+    let reader = FileReader::new(reader, metadata, None);
+    let _ = reader.collect::<Result<Vec<_>>>();
+}
+
+#[test]
+fn read_validation_shallow_skips_utf8() -> Result<()> {
+    let data = invalid_utf8_file()?;
+    let mut reader = Cursor::new(&data);
+    let metadata = read_file_metadata(&mut reader)?;
+    let mut reader = FileReader::new(reader, metadata, None);
+    reader.set_validation(unsafe { ValidationMode::shallow() });
+
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches[0].num_rows(), 1);
+    Ok(())
+}
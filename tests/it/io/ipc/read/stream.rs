@@ -1,7 +1,11 @@
 use std::fs::File;
+use std::sync::Arc;
 
+use arrow2::array::*;
 use arrow2::error::Result;
 use arrow2::io::ipc::read::*;
+use arrow2::io::ipc::write::{StreamWriter, WriteOptions};
+use arrow2::record_batch::RecordBatch;
 
 use crate::io::ipc::common::read_gzip_json;
 
@@ -99,3 +103,43 @@ fn read_generated_200_compression_lz4() -> Result<()> {
 fn read_generated_200_compression_zstd() -> Result<()> {
     test_file("2.0.0-compression", "generated_zstd")
 }
+
+#[test]
+fn read_validation() -> Result<()> {
+    let values = Utf8Array::<i32>::from_slice(["a", "bb"]);
+    let keys = PrimitiveArray::<i32>::from(&[Some(1), None, Some(0)]);
+    let dictionary = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+    let utf8 = Utf8Array::<i64>::from([Some("a"), None, Some("bcd")]);
+    let batch = RecordBatch::try_from_iter(vec![
+        ("dictionary", Arc::new(dictionary) as Arc<dyn Array>),
+        ("utf8", Arc::new(utf8) as Arc<dyn Array>),
+    ])?;
+
+    let mut data = vec![];
+    {
+        let options = WriteOptions { compression: None };
+        let mut writer = StreamWriter::try_new(&mut data, batch.schema(), options)?;
+        writer.write(&batch)?;
+        writer.finish()?;
+    }
+
+    let modes = unsafe {
+        [
+            ValidationMode::default(),
+            ValidationMode::shallow(),
+            ValidationMode::none(),
+        ]
+    };
+    for mode in modes {
+        let mut reader = data.as_slice();
+        let metadata = read_stream_metadata(&mut reader)?;
+        let mut reader = StreamReader::new(reader, metadata);
+        reader.set_validation(mode);
+
+        let batches = reader
+            .map(|x| x.map(|x| x.unwrap()))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(batches, vec![batch.clone()]);
+    }
+    Ok(())
+}
@@ -1,11 +1,14 @@
 use std::io::Cursor;
+use std::sync::Arc;
 
 use arrow2::array::*;
-use arrow2::error::Result;
+use arrow2::datatypes::DataType;
+use arrow2::error::{ErrorCode, Result};
 use arrow2::io::ipc::read::{read_file_metadata, FileReader};
 use arrow2::io::ipc::write::*;
 use arrow2::record_batch::RecordBatch;
 
+use crate::io::hooks::CancelAfter;
 use crate::io::ipc::common::read_gzip_json;
 
 fn round_trip(batch: RecordBatch) -> Result<()> {
@@ -359,3 +362,205 @@ fn write_sliced_list() -> Result<()> {
     let batch = RecordBatch::try_from_iter(vec![("a", array)]).unwrap();
     round_trip(batch)
 }
+
+#[test]
+fn write_sliced_union() -> Result<()> {
+    use arrow2::buffer::Buffer;
+    use arrow2::datatypes::{DataType, Field, UnionMode};
+
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let children = vec![
+        Arc::new(Int32Array::from(&[Some(1), None, Some(2)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b"), Some("c")])) as Arc<dyn Array>,
+    ];
+    for mode in [UnionMode::Sparse, UnionMode::Dense] {
+        let data_type = DataType::Union(fields.clone(), None, mode);
+        let offsets = if mode.is_sparse() {
+            None
+        } else {
+            Some(Buffer::from(&[0, 1, 2]))
+        };
+        let types = Buffer::from(&[0, 0, 1]);
+        let array = UnionArray::from_data(data_type, types, children.clone(), offsets);
+        let array = Arc::new(array.slice(1, 2)) as Arc<dyn Array>;
+        let batch = RecordBatch::try_from_iter(vec![("a", array)])?;
+        round_trip(batch)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn write_sink() -> Result<()> {
+    use arrow2::io::ChunkSink;
+
+    let array = Arc::new(Int32Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>;
+    let batch = RecordBatch::try_from_iter(vec![("a", array)])?;
+
+    let options = WriteOptions { compression: None };
+    let mut sink = FileWriter::try_new(vec![], batch.schema(), options)?;
+    assert_eq!(ChunkSink::schema(&sink), batch.schema().as_ref());
+    ChunkSink::write(&mut sink, &batch)?;
+    sink.close()?;
+
+    let mut reader = Cursor::new(sink.into_inner());
+    let metadata = read_file_metadata(&mut reader)?;
+    let reader = FileReader::new(reader, metadata, None);
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches, vec![batch]);
+    Ok(())
+}
+
+fn write_file(
+    batches: &[RecordBatch],
+    options: WriteOptions,
+    parallel: Option<ParallelOptions>,
+) -> Result<Vec<u8>> {
+    let mut writer = FileWriter::try_new(vec![], batches[0].schema(), options)?;
+    if let Some(parallel) = parallel {
+        writer = writer.with_parallel(parallel);
+    }
+    for batch in batches {
+        writer.write(batch)?;
+    }
+    writer.finish()?;
+    Ok(writer.into_inner())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn write_parallel() -> Result<()> {
+    let data = vec![Some(vec![Some(1i32), Some(2)]), None, Some(vec![None])];
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(data).unwrap();
+
+    let batch = RecordBatch::try_from_iter(vec![
+        (
+            "a",
+            Arc::new(Int32Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>,
+        ),
+        (
+            "b",
+            Arc::new(Utf8Array::<i32>::from([Some("a"), Some("bb"), None])) as Arc<dyn Array>,
+        ),
+        ("c", list.into_arc()),
+        (
+            "d",
+            Arc::new(DictionaryArray::<i32>::from_data(
+                Int32Array::from_slice([1, 0, 1]),
+                Arc::new(Utf8Array::<i32>::from_slice(["x", "y"])),
+            )) as Arc<dyn Array>,
+        ),
+        (
+            "e",
+            Arc::new(BooleanArray::from_slice([true, false, true])) as Arc<dyn Array>,
+        ),
+    ])?;
+    let batches = vec![batch.clone(), batch];
+
+    let options = WriteOptions {
+        compression: Some(Compression::ZSTD),
+    };
+    let expected = write_file(&batches, options, None)?;
+    for num_tasks in [1, 2, 8] {
+        let parallel = ParallelOptions {
+            num_tasks,
+            ..Default::default()
+        };
+        let result = write_file(&batches, options, Some(parallel))?;
+        assert_eq!(result, expected);
+    }
+
+    let mut reader = Cursor::new(expected);
+    let metadata = read_file_metadata(&mut reader)?;
+    let reader = FileReader::new(reader, metadata, None);
+    assert_eq!(reader.collect::<Result<Vec<_>>>()?, batches);
+    Ok(())
+}
+
+#[test]
+fn write_parallel_failed_task() {
+    let array = Arc::new(Int32Array::from_slice([1, 2])) as Arc<dyn Array>;
+    let batch = RecordBatch::try_from_iter(vec![("a", array.clone()), ("b", array)]).unwrap();
+    // a spawner that drops the tasks without running them
+    let parallel = ParallelOptions {
+        num_tasks: 2,
+        spawn: Arc::new(|_| {}),
+    };
+
+    let options = WriteOptions { compression: None };
+    assert!(write_file(&[batch], options, Some(parallel)).is_err());
+}
+
+#[test]
+fn date64_floored_on_read() -> Result<()> {
+    let array = Int64Array::from([Some(86_400_001), None, Some(-1)]).to(DataType::Date64);
+    let batch = RecordBatch::try_from_iter(vec![("a", Arc::new(array) as Arc<dyn Array>)])?;
+
+    let options = WriteOptions { compression: None };
+    let mut writer = FileWriter::try_new(vec![], batch.schema(), options)?;
+    writer.write(&batch)?;
+    writer.finish()?;
+
+    let mut reader = Cursor::new(writer.into_inner());
+    let metadata = read_file_metadata(&mut reader)?;
+    let reader = FileReader::new(reader, metadata, None);
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+
+    let expected =
+        Int64Array::from([Some(86_400_000), None, Some(-86_400_000)]).to(DataType::Date64);
+    assert_eq!(batches[0].column(0).as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn read_cancelled() -> Result<()> {
+    let array = Arc::new(Int32Array::from_slice([1, 2, 3])) as Arc<dyn Array>;
+    let batch = RecordBatch::try_from_iter(vec![("a", array)])?;
+
+    let options = WriteOptions { compression: None };
+    let mut writer = FileWriter::try_new(vec![], batch.schema(), options)?;
+    writer.write(&batch)?;
+    writer.write(&batch)?;
+    writer.write(&batch)?;
+    writer.finish()?;
+
+    let mut reader = Cursor::new(writer.into_inner());
+    let metadata = read_file_metadata(&mut reader)?;
+    let mut reader = FileReader::new(reader, metadata, None);
+    let hooks = Arc::new(CancelAfter::new(2));
+    reader.set_hooks(hooks.clone());
+
+    assert_eq!(reader.next().unwrap()?, batch);
+    assert_eq!(reader.next().unwrap()?, batch);
+    let error = reader.next().unwrap().unwrap_err();
+    assert_eq!(error.code(), ErrorCode::Cancelled);
+    assert!(reader.next().is_none());
+
+    let progress = hooks.progress.lock().unwrap();
+    assert_eq!(
+        progress.iter().map(|x| x.rows).collect::<Vec<_>>(),
+        vec![3, 6]
+    );
+    assert!(progress[0].bytes > 0);
+    assert_eq!(progress[1].bytes, 2 * progress[0].bytes);
+    Ok(())
+}
+
+#[test]
+fn write_dictionary_ordered() -> Result<()> {
+    use arrow2::datatypes::{Field, Schema};
+
+    let array = DictionaryArray::<i32>::try_from_strings(
+        [Some("high"), None, Some("low")],
+        &["low", "high"],
+        true,
+    )?;
+    let field = Field::new("a", array.data_type().clone(), true);
+    assert_eq!(field.dict_is_ordered(), Some(true));
+    let schema = Arc::new(Schema::new(vec![field]));
+    let batch = RecordBatch::try_new(schema, vec![Arc::new(array)])?;
+    round_trip(batch)
+}
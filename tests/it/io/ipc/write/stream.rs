@@ -1,10 +1,14 @@
 use std::io::Cursor;
+use std::sync::Arc;
 
-use arrow2::error::Result;
+use arrow2::array::*;
+use arrow2::error::{ErrorCode, Result};
 use arrow2::io::ipc::read::read_stream_metadata;
 use arrow2::io::ipc::read::StreamReader;
-use arrow2::io::ipc::write::{StreamWriter, WriteOptions};
+use arrow2::io::ipc::write::{ParallelOptions, StreamWriter, WriteOptions};
+use arrow2::record_batch::RecordBatch;
 
+use crate::io::hooks::CancelAfter;
 use crate::io::ipc::common::read_arrow_stream;
 use crate::io::ipc::common::read_gzip_json;
 
@@ -132,3 +136,59 @@ fn write_100_custom_metadata() {
 fn write_100_decimal() {
     test_file("1.0.0-littleendian", "generated_decimal");
 }
+
+#[test]
+fn write_parallel() -> Result<()> {
+    let batch = RecordBatch::try_from_iter(vec![
+        (
+            "a",
+            Arc::new(Int64Array::from([Some(1), None])) as Arc<dyn Array>,
+        ),
+        (
+            "b",
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "bb"])) as Arc<dyn Array>,
+        ),
+    ])?;
+
+    let write = |parallel: Option<ParallelOptions>| -> Result<Vec<u8>> {
+        let options = WriteOptions { compression: None };
+        let mut writer = StreamWriter::try_new(vec![], batch.schema(), options)?;
+        if let Some(parallel) = parallel {
+            writer = writer.with_parallel(parallel);
+        }
+        writer.write(&batch)?;
+        writer.finish()?;
+        Ok(writer.into_inner())
+    };
+    assert_eq!(write(Some(ParallelOptions::default()))?, write(None)?);
+    Ok(())
+}
+
+#[test]
+fn read_cancelled() -> Result<()> {
+    let array = Arc::new(Int32Array::from_slice([1, 2, 3])) as Arc<dyn Array>;
+    let batch = RecordBatch::try_from_iter(vec![("a", array)])?;
+
+    let options = WriteOptions { compression: None };
+    let mut writer = StreamWriter::try_new(vec![], batch.schema(), options)?;
+    writer.write(&batch)?;
+    writer.write(&batch)?;
+    writer.finish()?;
+
+    let mut reader = Cursor::new(writer.into_inner());
+    let metadata = read_stream_metadata(&mut reader)?;
+    let mut reader = StreamReader::new(reader, metadata);
+    let hooks = Arc::new(CancelAfter::new(1));
+    reader.set_hooks(hooks.clone());
+
+    assert_eq!(reader.next().unwrap()?.unwrap(), batch);
+    let error = reader.next().unwrap().map(|_| ()).unwrap_err();
+    assert_eq!(error.code(), ErrorCode::Cancelled);
+    assert!(reader.next().is_none());
+
+    let progress = hooks.progress.lock().unwrap();
+    assert_eq!(progress.len(), 1);
+    assert_eq!(progress[0].rows, 3);
+    assert!(progress[0].bytes > 0);
+    Ok(())
+}
@@ -294,3 +294,67 @@ fn infer_schema_mixed_list() -> Result<()> {
     assert_eq!(inferred_schema, schema);
     Ok(())
 }
+
+#[test]
+fn deserialize_hooks() -> Result<()> {
+    use serde_json::Value;
+
+    let b_field = Field::new("b", DataType::Int64, true);
+    let schema = Arc::new(Schema::new(vec![
+        // stringified json
+        Field::new("a", DataType::Struct(vec![b_field.clone()]), true),
+        // dates as "YYYY-MM-DD"
+        Field::new(
+            "c",
+            DataType::List(Box::new(Field::new("item", DataType::Date32, true))),
+            true,
+        ),
+    ]));
+
+    let mut hooks = DeserializeHooks::new();
+    hooks.insert(&["a"], |value: &Value| {
+        value
+            .as_str()
+            .and_then(|v| serde_json::from_str(v).ok())
+            .unwrap_or(Value::Null)
+    });
+    // the hook of "b" applies to the values parsed by the hook of "a"
+    hooks.insert(&["a", "b"], |value: &Value| match value.as_i64() {
+        Some(v) => Value::from(v * 10),
+        None => Value::Null,
+    });
+    hooks.insert(&["c", "item"], |value: &Value| {
+        let date = value
+            .as_str()
+            .and_then(|v| chrono::NaiveDate::parse_from_str(v, "%Y-%m-%d").ok());
+        match date {
+            Some(date) => Value::from(
+                date.signed_duration_since(chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap())
+                    .num_days(),
+            ),
+            None => Value::Null,
+        }
+    });
+
+    let data = r#"{"a": "{\"b\": 1}", "c": ["1970-01-02", null]}
+    {"a": null, "c": ["1971-01-01"]}"#;
+    let builder = ReaderBuilder::new().with_schema(schema).with_hooks(hooks);
+    let mut reader = builder.build(Cursor::new(data))?;
+    let batch = reader.next()?.unwrap();
+
+    let b = Int64Array::from([Some(10), None]);
+    let expected = StructArray::from_data(DataType::Struct(vec![b_field]), vec![Arc::new(b)], None);
+    assert_eq!(&expected as &dyn Array, batch.column(0).as_ref());
+
+    let list = batch
+        .column(1)
+        .as_any()
+        .downcast_ref::<ListArray<i32>>()
+        .unwrap();
+    let dates = list.values().as_any().downcast_ref::<Int32Array>().unwrap();
+    assert_eq!(
+        dates,
+        &Int32Array::from([Some(1), None, Some(365)]).to(DataType::Date32)
+    );
+    Ok(())
+}
@@ -6,6 +6,7 @@ use arrow2::{
     buffer::Buffer,
     datatypes::{DataType, Field, Schema},
     io::json::LineDelimitedWriter,
+    io::ChunkSink,
     record_batch::RecordBatch,
 };
 
@@ -38,6 +39,35 @@ fn write_simple_rows() {
     );
 }
 
+#[test]
+fn write_sink() {
+    let a = Int32Array::from([Some(1), None]);
+    let batch = RecordBatch::try_from_iter(vec![("c1", Arc::new(a) as Arc<dyn Array>)]).unwrap();
+
+    let mut buf = Vec::new();
+    {
+        let mut writer = LineDelimitedWriter::new(&mut buf);
+        writer.write(&batch).unwrap();
+        assert_eq!(writer.schema(), batch.schema().as_ref());
+        writer.write(&batch).unwrap();
+        // batches must have the schema of the first batch
+        let b = Int32Array::from([Some(1)]);
+        let other =
+            RecordBatch::try_from_iter(vec![("c2", Arc::new(b) as Arc<dyn Array>)]).unwrap();
+        assert!(writer.write(&other).is_err());
+        writer.close().unwrap();
+    }
+
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        r#"{"c1":1}
+{"c1":null}
+{"c1":1}
+{"c1":null}
+"#
+    );
+}
+
 #[test]
 fn write_nested_struct_with_validity() {
     let inner = vec![
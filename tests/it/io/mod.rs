@@ -7,6 +7,9 @@ mod json;
 #[cfg(feature = "io_ipc")]
 mod ipc;
 
+#[cfg(feature = "io_flight_async")]
+mod flight;
+
 #[cfg(feature = "io_parquet")]
 mod parquet;
 
@@ -19,3 +22,6 @@ mod avro;
     feature = "io_csv_read_async"
 ))]
 mod csv;
+
+#[cfg(any(feature = "io_ipc", feature = "io_parquet", feature = "io_avro"))]
+mod hooks;
@@ -1,16 +1,18 @@
 use std::io::{Cursor, Read, Seek};
 use std::sync::Arc;
 
-use arrow2::error::ArrowError;
+use arrow2::error::{ArrowError, ErrorCode};
 use arrow2::{
     array::*, bitmap::Bitmap, buffer::Buffer, datatypes::*, error::Result,
     io::parquet::read::statistics::*, io::parquet::read::*, io::parquet::write::*,
     record_batch::RecordBatch,
 };
 
+use crate::io::hooks::CancelAfter;
 use crate::io::ipc::read_gzip_json;
 
 mod read;
+mod read_vectored;
 mod write;
 
 type ArrayStats = (Arc<dyn Array>, Option<Box<dyn Statistics>>);
@@ -626,7 +628,7 @@ fn integration_write(schema: &Schema, batches: &[RecordBatch]) -> Result<Vec<u8>
             .iter()
             .zip(descritors.clone())
             .map(|(array, descriptor)| {
-                let encoding = if let DataType::Dictionary(_, _) = array.data_type() {
+                let encoding = if let DataType::Dictionary(_, _, _) = array.data_type() {
                     Encoding::RleDictionary
                 } else {
                     Encoding::Plain
@@ -721,3 +723,197 @@ fn arrow_type() -> Result<()> {
     assert_eq!(new_batches, vec![batch]);
     Ok(())
 }
+
+#[test]
+fn date64_floored_on_read() -> Result<()> {
+    let array = Int64Array::from([Some(86_400_001), None, Some(-1), Some(0)]).to(DataType::Date64);
+    let batch = RecordBatch::try_from_iter(vec![("a", Arc::new(array) as Arc<dyn Array>)])?;
+    let schema = batch.schema().as_ref().clone();
+
+    let data = integration_write(&schema, &[batch])?;
+    let (_, batches) = integration_read(&data)?;
+
+    let expected =
+        Int64Array::from([Some(86_400_000), None, Some(-86_400_000), Some(0)]).to(DataType::Date64);
+    assert_eq!(batches[0].column(0).as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn read_chunk_size() -> Result<()> {
+    let array = Arc::new(Int32Array::from_slice([0, 1, 2, 3, 4, 5, 6])) as Arc<dyn Array>;
+    let batch = RecordBatch::try_from_iter(vec![("a", array.clone())])?;
+    let schema = batch.schema().as_ref().clone();
+    let other = RecordBatch::try_new(batch.schema().clone(), vec![array.slice(0, 2).into()])?;
+    // two row groups
+    let data = integration_write(&schema, &[batch, other])?;
+
+    let mut reader = RecordReader::try_new(Cursor::new(&data), None, None, None, None)?;
+    reader.set_chunk_size(3);
+    let lengths = reader
+        .map(|batch| batch.map(|batch| batch.num_rows()))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(lengths, vec![3, 3, 1, 2]);
+
+    let mut reader = RecordReader::try_new(Cursor::new(&data), None, Some(5), None, None)?;
+    reader.set_chunk_size(3);
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    let expected = Int32Array::from_slice([3, 4]);
+    assert_eq!(batches.len(), 2);
+    assert_eq!(batches[1].column(0).as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+/// Writes `array` as a single row group with pages of `page_size` rows.
+fn write_pages(array: &dyn Array, page_size: usize) -> Result<Vec<u8>> {
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let schema = Schema::new(vec![Field::new("a", array.data_type().clone(), true)]);
+    let parquet_schema = to_parquet_schema(&schema)?;
+    let descriptor = parquet_schema.columns()[0].clone();
+
+    let pages = (0..array.len())
+        .step_by(page_size)
+        .map(|offset| {
+            let length = page_size.min(array.len() - offset);
+            array_to_page(
+                array.slice(offset, length).as_ref(),
+                descriptor.clone(),
+                options,
+                Encoding::Plain,
+            )
+        })
+        .collect::<Vec<_>>();
+    let compressed_pages = Compressor::new(
+        DynIter::new(pages.into_iter().map(|x| Ok(x?))),
+        options.compression,
+        vec![],
+    )
+    .map_err(ArrowError::from);
+    let columns = DynIter::new(std::iter::once(Ok(DynStreamingIterator::new(
+        compressed_pages,
+    ))));
+
+    let mut writer = Cursor::new(vec![]);
+    write_file(
+        &mut writer,
+        std::iter::once(Ok(columns)),
+        &schema,
+        parquet_schema,
+        options,
+        None,
+    )?;
+    Ok(writer.into_inner())
+}
+
+#[test]
+fn read_chunk_size_by_page() -> Result<()> {
+    let array = Int32Array::from(
+        (0..1000)
+            .map(|x| if x % 7 == 0 { None } else { Some(x) })
+            .collect::<Vec<_>>(),
+    );
+    // a single row group of 10 pages
+    let data = write_pages(&array, 100)?;
+
+    let mut reader = RecordReader::try_new(Cursor::new(&data), None, None, None, None)?;
+    reader.set_chunk_size(100);
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches.len(), 10);
+    for (i, batch) in batches.iter().enumerate() {
+        let chunk = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        // each chunk is deserialized from its own page, not sliced from the whole row group
+        assert_eq!(chunk.values().offset(), 0);
+        assert_eq!(chunk, &array.slice(i * 100, 100));
+    }
+
+    // chunks spanning pages
+    let mut reader = RecordReader::try_new(Cursor::new(&data), None, Some(900), None, None)?;
+    reader.set_chunk_size(150);
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    let lengths = batches.iter().map(|x| x.num_rows()).collect::<Vec<_>>();
+    assert_eq!(lengths, vec![150; 6]);
+    for (i, batch) in batches.iter().enumerate() {
+        let chunk = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert!(chunk.values().offset() < 100);
+        assert_eq!(chunk, &array.slice(i * 150, 150));
+    }
+    Ok(())
+}
+
+#[test]
+fn read_cancelled() -> Result<()> {
+    let array = Arc::new(Int32Array::from_slice([0, 1, 2])) as Arc<dyn Array>;
+    let batch = RecordBatch::try_from_iter(vec![("a", array)])?;
+    let schema = batch.schema().as_ref().clone();
+    // three row groups
+    let data = integration_write(&schema, &[batch.clone(), batch.clone(), batch.clone()])?;
+
+    let mut reader = RecordReader::try_new(Cursor::new(&data), None, None, None, None)?;
+    let hooks = Arc::new(CancelAfter::new(2));
+    reader.set_hooks(hooks.clone());
+
+    assert_eq!(reader.next().unwrap()?, batch);
+    assert_eq!(reader.next().unwrap()?, batch);
+    let error = reader.next().unwrap().unwrap_err();
+    assert_eq!(error.code(), ErrorCode::Cancelled);
+    assert!(reader.next().is_none());
+
+    let progress = hooks.progress.lock().unwrap();
+    assert_eq!(
+        progress.iter().map(|x| x.rows).collect::<Vec<_>>(),
+        vec![3, 6]
+    );
+    assert!(progress[0].bytes > 0);
+    assert_eq!(progress[1].bytes, 2 * progress[0].bytes);
+    Ok(())
+}
+
+// returns the position of `pattern` in `data`, which must exist
+fn find(data: &[u8], pattern: &[u8]) -> usize {
+    data.windows(pattern.len())
+        .position(|window| window == pattern)
+        .unwrap()
+}
+
+#[test]
+fn read_corrupted_validity() -> Result<()> {
+    let array = Int32Array::from([Some(0x11111111), None, Some(0x22222222), Some(0x33333333)]);
+    let batch = RecordBatch::try_from_iter(vec![("a", Arc::new(array) as Arc<dyn Array>)])?;
+    let schema = batch.schema().as_ref().clone();
+    let mut data = integration_write(&schema, &[batch])?;
+
+    // the validity, bitpacked, precedes the values; declare all of them valid
+    let position = find(&data, &[0x11, 0x11, 0x11, 0x11, 0x22, 0x22, 0x22, 0x22]);
+    assert_eq!(data[position - 1], 0b00001101);
+    data[position - 1] = 0b00001111;
+
+    assert!(integration_read(&data).is_err());
+    Ok(())
+}
+
+#[test]
+fn read_corrupted_binary() -> Result<()> {
+    let array = Utf8Array::<i32>::from_slice(["aa", "bb"]);
+    let batch = RecordBatch::try_from_iter(vec![("a", Arc::new(array) as Arc<dyn Array>)])?;
+    let schema = batch.schema().as_ref().clone();
+    let mut data = integration_write(&schema, &[batch])?;
+
+    // the length of the second value exceeds the page
+    let position = find(&data, b"\x02\x00\x00\x00aa\x02\x00\x00\x00bb");
+    data[position + 6] = 0xff;
+
+    assert!(integration_read(&data).is_err());
+    Ok(())
+}
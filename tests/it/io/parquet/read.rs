@@ -397,3 +397,101 @@ fn all_types() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn error_context() -> Result<()> {
+    use arrow2::error::{ErrorCode, Location};
+
+    let array = Int64Array::from_slice(&(0..1000).collect::<Vec<_>>());
+    let schema = Schema::new(vec![Field::new("a", DataType::Int64, false)]);
+    let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(array)])?;
+
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Snappy,
+        version: Version::V1,
+    };
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(batch)].into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain],
+    )?;
+    let mut writer = Cursor::new(vec![]);
+    let parquet_schema = row_groups.parquet_schema().clone();
+    write_file(
+        &mut writer,
+        row_groups,
+        &schema,
+        parquet_schema,
+        options,
+        None,
+    )?;
+    let mut data = writer.into_inner();
+
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    let (start, length) = metadata.row_groups[0].column(0).byte_range();
+    // corrupt the compressed values of the page
+    let end = (start + length) as usize;
+    data[end - 100..end].iter_mut().for_each(|x| *x = 255);
+
+    let error = read_column(&mut Cursor::new(data), 0, 0).unwrap_err();
+    let context = error.context().unwrap();
+    assert_eq!(context.column.as_deref(), Some("a"));
+    assert_eq!(context.byte_offset, Some(start));
+    assert_eq!(
+        context.location,
+        Some(Location::ParquetPage {
+            row_group: Some(0),
+            column_chunk: Some(0),
+            page: Some(0),
+        })
+    );
+    assert_eq!(error.code(), ErrorCode::ExternalFormat);
+    Ok(())
+}
+
+#[test]
+fn parse_metadata_from_tail() -> Result<()> {
+    let array = Int64Array::from_slice(&(0..1000).collect::<Vec<_>>());
+    let schema = Schema::new(vec![Field::new("a", DataType::Int64, false)]);
+    let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(array)])?;
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(batch)].into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain],
+    )?;
+    let mut writer = Cursor::new(vec![]);
+    let parquet_schema = row_groups.parquet_schema().clone();
+    write_file(
+        &mut writer,
+        row_groups,
+        &schema,
+        parquet_schema,
+        options,
+        None,
+    )?;
+    let data = writer.into_inner();
+
+    let expected = read_metadata(&mut Cursor::new(&data))?;
+
+    // a tail larger than the footer
+    let metadata = parse_metadata(&data[data.len() - 1000..])?;
+    assert_eq!(metadata.num_rows, expected.num_rows);
+    assert_eq!(
+        metadata.row_groups[0].column(0).byte_range(),
+        expected.row_groups[0].column(0).byte_range()
+    );
+    assert_eq!(get_schema(&metadata)?, get_schema(&expected)?);
+
+    assert!(parse_metadata(&data[data.len() - 20..]).is_err());
+    assert!(parse_metadata(&data[..data.len() - 1]).is_err());
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::error::Result;
+use arrow2::io::parquet::read::vectored::*;
+use arrow2::io::parquet::read::*;
+use arrow2::record_batch::RecordBatch;
+
+use super::integration_write;
+
+fn data() -> (RecordBatch, Vec<u8>) {
+    let batch = RecordBatch::try_from_iter(vec![
+        (
+            "a",
+            Arc::new(Int32Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>,
+        ),
+        (
+            "b",
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "bb", "ccc"])) as Arc<dyn Array>,
+        ),
+        (
+            "c",
+            Arc::new(Int64Array::from_slice([4, 5, 6])) as Arc<dyn Array>,
+        ),
+    ])
+    .unwrap();
+    let data = integration_write(batch.schema(), std::slice::from_ref(&batch)).unwrap();
+    (batch, data)
+}
+
+#[test]
+fn coalesce() {
+    let ranges = [(10, 5), (0, 5), (30, 10), (16, 4)];
+    assert_eq!(
+        coalesce_ranges(&ranges, 1),
+        vec![(0, 5), (10, 10), (30, 10)]
+    );
+    assert_eq!(coalesce_ranges(&ranges, 10), vec![(0, 40)]);
+    assert_eq!(coalesce_ranges(&[(0, 10), (2, 3)], 0), vec![(0, 10)]);
+}
+
+#[test]
+fn ranges_reader() {
+    let mut reader = RangesReader::try_new(vec![(10, vec![1, 2, 3]), (0, vec![4, 5])]).unwrap();
+    let mut buf = [0; 2];
+    reader.seek(SeekFrom::Start(11)).unwrap();
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(buf, [2, 3]);
+    reader.seek(SeekFrom::Start(2)).unwrap();
+    assert!(reader.read_exact(&mut buf).is_err());
+
+    assert!(RangesReader::try_new(vec![(0, vec![1, 2]), (1, vec![1])]).is_err());
+}
+
+#[tokio::test]
+async fn read_vectored() -> Result<()> {
+    let (batch, data) = data();
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    let schema = get_schema(&metadata)?;
+
+    let fields = [0, 2];
+    let mut requests = 0;
+    let fetch = |ranges: Vec<(u64, u64)>| {
+        requests += 1;
+        let data = &data;
+        async move {
+            Ok(ranges
+                .into_iter()
+                .map(|(start, length)| data[start as usize..(start + length) as usize].to_vec())
+                .collect())
+        }
+    };
+    let mut reader = read_ranges_vectored(fetch, &metadata, 0, &fields, 0).await?;
+    assert_eq!(requests, 1);
+    // the two column chunks are not contiguous
+    assert_eq!(reader.ranges().len(), 2);
+
+    for field in fields {
+        let columns = get_column_iterator(&mut reader, &metadata, 0, field, None, vec![]);
+        let (array, _, _) = column_iter_to_array(columns, &schema.fields()[field], vec![])?;
+        assert_eq!(batch.column(field).as_ref(), array.as_ref());
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_async_coalesced() -> Result<()> {
+    let (batch, data) = data();
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    let schema = get_schema(&metadata)?;
+
+    let mut reader = futures::io::Cursor::new(&data);
+    let mut reader = read_ranges_async(&mut reader, &metadata, 0, &[0, 2], u64::MAX).await?;
+    assert_eq!(reader.ranges().len(), 1);
+
+    let columns = get_column_iterator(&mut reader, &metadata, 0, 2, None, vec![]);
+    let (array, _, _) = column_iter_to_array(columns, &schema.fields()[2], vec![])?;
+    assert_eq!(batch.column(2).as_ref(), array.as_ref());
+    Ok(())
+}
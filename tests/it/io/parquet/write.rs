@@ -247,6 +247,30 @@ fn bool_required_v2_compressed() -> Result<()> {
     )
 }
 
+#[test]
+fn bool_optional_v1_rle() -> Result<()> {
+    round_trip(
+        3,
+        true,
+        false,
+        Version::V1,
+        Compression::Uncompressed,
+        Encoding::Rle,
+    )
+}
+
+#[test]
+fn bool_required_v2_rle() -> Result<()> {
+    round_trip(
+        3,
+        false,
+        false,
+        Version::V2,
+        Compression::Uncompressed,
+        Encoding::Rle,
+    )
+}
+
 #[test]
 fn list_int64_optional_v2() -> Result<()> {
     round_trip(
@@ -295,6 +319,75 @@ fn list_bool_optional_v1() -> Result<()> {
     )
 }
 
+#[test]
+fn list_bool_optional_v2_rle() -> Result<()> {
+    round_trip(
+        4,
+        true,
+        true,
+        Version::V2,
+        Compression::Uncompressed,
+        Encoding::Rle,
+    )
+}
+
+#[test]
+fn list_int64_required_v1() -> Result<()> {
+    round_trip(
+        1,
+        true,
+        true,
+        Version::V1,
+        Compression::Uncompressed,
+        Encoding::Plain,
+    )
+}
+
+fn list_bool_required(version: Version, encoding: Encoding) -> Result<()> {
+    // [[true, false], None, [], [true]]
+    let array = ListArray::<i32>::from_data(
+        DataType::List(Box::new(Field::new("item", DataType::Boolean, false))),
+        Buffer::from([0, 2, 2, 2, 3]),
+        Arc::new(BooleanArray::from_slice([true, false, true])),
+        Some(Bitmap::from([true, false, true, true])),
+    );
+    let schema = Schema::new(vec![Field::new("a1", array.data_type().clone(), true)]);
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Uncompressed,
+        version,
+    };
+
+    let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(array.clone())]);
+    let row_groups =
+        RowGroupIterator::try_new(vec![batch].into_iter(), &schema, options, vec![encoding])?;
+
+    let mut writer = Cursor::new(vec![]);
+    let parquet_schema = to_parquet_schema(&schema)?;
+    write_file(
+        &mut writer,
+        row_groups,
+        &schema,
+        parquet_schema,
+        options,
+        None,
+    )?;
+
+    let (result, _) = read_column(&mut Cursor::new(writer.into_inner()), 0, 0)?;
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn list_bool_required_v1() -> Result<()> {
+    list_bool_required(Version::V1, Encoding::Plain)
+}
+
+#[test]
+fn list_bool_required_v2_rle() -> Result<()> {
+    list_bool_required(Version::V2, Encoding::Rle)
+}
+
 #[test]
 fn list_utf8_optional_v2() -> Result<()> {
     round_trip(
@@ -523,3 +616,376 @@ fn decimal_26_required_v2() -> Result<()> {
         Encoding::Plain,
     )
 }
+
+#[test]
+fn write_sink() -> Result<()> {
+    use arrow2::io::ChunkSink;
+
+    let array: Arc<dyn Array> = pyarrow_nullable(0).into();
+    let schema = Schema::new(vec![Field::new("a1", array.data_type().clone(), true)]);
+    let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![array.clone()])?;
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+
+    let mut sink =
+        FileWriter::try_new(Cursor::new(vec![]), schema, options, vec![Encoding::Plain])?;
+    sink.write(&batch)?;
+    sink.write(&batch)?;
+    sink.close()?;
+    assert!(sink.write(&batch).is_err());
+
+    let data = sink.into_inner().into_inner();
+    let (result, _) = read_column(&mut Cursor::new(data.clone()), 0, 0)?;
+    assert_eq!(array.as_ref(), result.as_ref());
+    let (result, _) = read_column(&mut Cursor::new(data), 1, 0)?;
+    assert_eq!(array.as_ref(), result.as_ref());
+    Ok(())
+}
+
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<std::sync::Mutex<Vec<u8>>>);
+
+impl std::io::Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn write_sink_incremental() -> Result<()> {
+    use arrow2::io::ChunkSink;
+
+    let array: Arc<dyn Array> = pyarrow_nullable(0).into();
+    let schema = Schema::new(vec![Field::new("a1", array.data_type().clone(), true)]);
+    let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![array])?;
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+
+    let buffer = SharedBuffer::default();
+    let mut sink =
+        BackgroundFileWriter::try_new(buffer.clone(), schema, options, vec![Encoding::Plain])?;
+    sink.write(&batch)?;
+    // the second batch is only received once the first row group was written
+    sink.write(&batch)?;
+    let written = buffer.0.lock().unwrap().len();
+    // more than the file's magic number
+    assert!(written > 4);
+    sink.close()?;
+    assert!(buffer.0.lock().unwrap().len() > written);
+    Ok(())
+}
+
+#[test]
+fn write_sink_background_drop() -> Result<()> {
+    use arrow2::io::ChunkSink;
+
+    let array: Arc<dyn Array> = pyarrow_nullable(0).into();
+    let schema = Schema::new(vec![Field::new("a1", array.data_type().clone(), true)]);
+    let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![array.clone()])?;
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+
+    let buffer = SharedBuffer::default();
+    let mut sink =
+        BackgroundFileWriter::try_new(buffer.clone(), schema, options, vec![Encoding::Plain])?;
+    sink.write(&batch)?;
+    // dropping the writer finishes the file and waits for its thread
+    drop(sink);
+
+    let data = buffer.0.lock().unwrap().clone();
+    let (result, _) = read_column(&mut Cursor::new(data), 0, 0)?;
+    assert_eq!(array.as_ref(), result.as_ref());
+    Ok(())
+}
+
+fn write_batches<I>(
+    row_groups: I,
+    schema: &Schema,
+    parquet_schema: SchemaDescriptor,
+) -> Result<Vec<u8>>
+where
+    I: Iterator<Item = Result<RowGroupIter<'static, arrow2::error::ArrowError>>>,
+{
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Snappy,
+        version: Version::V2,
+    };
+    let mut writer = Cursor::new(vec![]);
+    write_file(
+        &mut writer,
+        row_groups,
+        schema,
+        parquet_schema,
+        options,
+        None,
+    )?;
+    Ok(writer.into_inner())
+}
+
+/// Returns the bytes of the pages of every column chunk of a parquet file.
+fn column_chunks(data: &[u8]) -> Result<Vec<&[u8]>> {
+    let metadata = arrow2::io::parquet::read::read_metadata(&mut Cursor::new(data))?;
+    Ok(metadata
+        .row_groups
+        .iter()
+        .flat_map(|row_group| row_group.columns())
+        .map(|column| {
+            let (start, length) = column.byte_range();
+            &data[start as usize..(start + length) as usize]
+        })
+        .collect())
+}
+
+#[test]
+fn write_parallel() -> Result<()> {
+    // all columns but the dictionary, which has a different length
+    let columns = [0, 1, 2, 3, 4, 5, 7, 8, 9]
+        .iter()
+        .map(|i| pyarrow_nullable(*i).into())
+        .collect::<Vec<Arc<dyn Array>>>();
+    let schema = Schema::new(
+        columns
+            .iter()
+            .enumerate()
+            .map(|(i, array)| Field::new(&format!("c{}", i), array.data_type().clone(), true))
+            .collect(),
+    );
+    let batch = RecordBatch::try_new(Arc::new(schema.clone()), columns.clone())?;
+    let batches = vec![batch.clone(), batch];
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Snappy,
+        version: Version::V2,
+    };
+    let encodings = vec![Encoding::Plain; columns.len()];
+
+    let row_groups = RowGroupIterator::try_new(
+        batches.clone().into_iter().map(Ok),
+        &schema,
+        options,
+        encodings.clone(),
+    )?;
+    let parquet_schema = row_groups.parquet_schema().clone();
+    let expected = write_batches(row_groups, &schema, parquet_schema)?;
+
+    // a budget smaller than any page forces the tasks to wait for the writer
+    for max_in_flight in [1, usize::MAX] {
+        let parallel = ParallelOptions {
+            num_tasks: 3,
+            max_in_flight,
+            ..Default::default()
+        };
+        let row_groups = ParallelRowGroupIterator::try_new(
+            batches.clone().into_iter().map(Ok),
+            &schema,
+            options,
+            encodings.clone(),
+            parallel,
+        )?;
+        let parquet_schema = row_groups.parquet_schema().clone();
+        let data = write_batches(row_groups, &schema, parquet_schema)?;
+        // the pages are the same; the metadata's order of encodings is not deterministic
+        assert_eq!(column_chunks(&data)?, column_chunks(&expected)?);
+
+        let reader = arrow2::io::parquet::read::RecordReader::try_new(
+            Cursor::new(data),
+            None,
+            None,
+            None,
+            None,
+        )?;
+        let result = reader.collect::<Result<Vec<_>>>()?;
+        assert_eq!(result.len(), batches.len());
+        for (result, batch) in result.iter().zip(batches.iter()) {
+            assert_eq!(result.columns(), batch.columns());
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn write_parallel_error() -> Result<()> {
+    let array: Arc<dyn Array> = Arc::new(Int32Array::from_slice(&[1, 2]));
+    let schema = Schema::new(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Int32, false),
+    ]);
+    let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![array.clone(), array])?;
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Snappy,
+        version: Version::V2,
+    };
+    // integers cannot be encoded with `DeltaLengthByteArray`
+    let row_groups = ParallelRowGroupIterator::try_new(
+        vec![Ok(batch)].into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain, Encoding::DeltaLengthByteArray],
+        ParallelOptions::default(),
+    )?;
+    let parquet_schema = row_groups.parquet_schema().clone();
+    assert!(write_batches(row_groups, &schema, parquet_schema).is_err());
+    Ok(())
+}
+
+fn write_with_statistics(
+    columns: Vec<Arc<dyn Array>>,
+    statistics: Vec<StatisticsOptions>,
+) -> Result<Vec<u8>> {
+    let schema = Schema::new(
+        columns
+            .iter()
+            .enumerate()
+            .map(|(i, array)| Field::new(&format!("c{}", i), array.data_type().clone(), true))
+            .collect(),
+    );
+    let batch = RecordBatch::try_new(Arc::new(schema.clone()), columns)?;
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Snappy,
+        version: Version::V1,
+    };
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(batch)].into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain; statistics.len()],
+    )?
+    .with_statistics(statistics);
+    let parquet_schema = row_groups.parquet_schema().clone();
+    write_batches(row_groups, &schema, parquet_schema)
+}
+
+#[test]
+fn statistics_truncated() -> Result<()> {
+    let columns: Vec<Arc<dyn Array>> = vec![
+        Arc::new(Utf8Array::<i32>::from(&[
+            Some("aaaaaa"),
+            None,
+            Some("zzzzzz"),
+        ])),
+        Arc::new(Utf8Array::<i32>::from(&[
+            Some("aé"),
+            Some("a\u{10FFFF}é"),
+            None,
+        ])),
+        Arc::new(BinaryArray::<i32>::from(&[
+            Some(b"\x00\x01\x02".as_ref()),
+            Some(b"\x01\xff\xff"),
+            None,
+        ])),
+        Arc::new(BinaryArray::<i32>::from(&[
+            Some(b"\xff\xff\xff".as_ref()),
+            None,
+            None,
+        ])),
+    ];
+    let options = StatisticsOptions {
+        enabled: true,
+        max_length: Some(2),
+    };
+    let data = write_with_statistics(columns, vec![options; 4])?;
+
+    let (_, statistics) = read_column(&mut Cursor::new(&data), 0, 0)?;
+    let expected = Utf8Statistics {
+        null_count: Some(1),
+        distinct_count: None,
+        min_value: Some("aa".to_string()),
+        max_value: Some("z{".to_string()),
+    };
+    assert_eq!(statistics.unwrap().as_ref(), &expected as &dyn Statistics);
+
+    // truncated at char boundaries, and the max skips the chars that cannot be incremented
+    let (_, statistics) = read_column(&mut Cursor::new(&data), 0, 1)?;
+    let expected = Utf8Statistics {
+        null_count: Some(1),
+        distinct_count: None,
+        min_value: Some("a".to_string()),
+        max_value: Some("b".to_string()),
+    };
+    assert_eq!(statistics.unwrap().as_ref(), &expected as &dyn Statistics);
+
+    let (_, statistics) = read_column(&mut Cursor::new(&data), 0, 2)?;
+    let expected = BinaryStatistics {
+        null_count: Some(1),
+        distinct_count: None,
+        min_value: Some(vec![0, 1]),
+        max_value: Some(vec![2]),
+    };
+    assert_eq!(statistics.unwrap().as_ref(), &expected as &dyn Statistics);
+
+    // there is no upper bound of at most 2 bytes
+    let (_, statistics) = read_column(&mut Cursor::new(&data), 0, 3)?;
+    let expected = BinaryStatistics {
+        null_count: Some(2),
+        distinct_count: None,
+        min_value: Some(vec![255, 255]),
+        max_value: None,
+    };
+    assert_eq!(statistics.unwrap().as_ref(), &expected as &dyn Statistics);
+    Ok(())
+}
+
+#[test]
+fn statistics_disabled() -> Result<()> {
+    let columns: Vec<Arc<dyn Array>> = vec![
+        Arc::new(Int32Array::from_slice(&[1, 2])),
+        Arc::new(Int32Array::from_slice(&[1, 2])),
+    ];
+    let disabled = StatisticsOptions {
+        enabled: false,
+        ..Default::default()
+    };
+    let data = write_with_statistics(columns, vec![StatisticsOptions::default(), disabled])?;
+
+    let (array, statistics) = read_column(&mut Cursor::new(&data), 0, 0)?;
+    assert_eq!(array.len(), 2);
+    assert!(statistics.is_some());
+    let (array, statistics) = read_column(&mut Cursor::new(&data), 0, 1)?;
+    assert_eq!(array.len(), 2);
+    assert!(statistics.is_none());
+    Ok(())
+}
+
+#[test]
+fn accumulator_statistics() -> Result<()> {
+    use arrow2::compute::statistics::Accumulator;
+    use arrow2::datatypes::{DataType, Field, Schema};
+
+    let schema = Schema::new(vec![Field::new("a", DataType::UInt32, true)]);
+    let descriptor = to_parquet_schema(&schema)?.columns()[0].clone();
+
+    let mut accumulator = Accumulator::new(DataType::UInt32);
+    accumulator.update(&UInt32Array::from([Some(3), None]))?;
+    accumulator.update(&UInt32Array::from([Some(1), Some(2)]))?;
+
+    let statistics = to_parquet_statistics(&accumulator, descriptor.clone())?;
+    assert_eq!(statistics.null_count, Some(1));
+    assert_eq!(statistics.min_value, Some(1i32.to_le_bytes().to_vec()));
+    assert_eq!(statistics.max_value, Some(3i32.to_le_bytes().to_vec()));
+
+    let accumulator = Accumulator::new(DataType::Utf8);
+    assert!(to_parquet_statistics(&accumulator, descriptor).is_err());
+    Ok(())
+}
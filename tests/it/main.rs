@@ -1,6 +1,8 @@
 mod array;
 mod bitmap;
 mod buffer;
+mod datatypes;
+mod error;
 mod ffi;
 mod scalar;
 mod temporal_conversions;
@@ -9,3 +11,6 @@ mod io;
 mod test_util;
 
 mod compute;
+
+#[cfg(feature = "benchmarks")]
+mod util;
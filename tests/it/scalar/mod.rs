@@ -1,5 +1,6 @@
 mod binary;
 mod boolean;
+mod fixed_size_binary;
 mod list;
 mod null;
 mod primitive;
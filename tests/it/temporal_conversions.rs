@@ -1,7 +1,7 @@
 use arrow2::array::*;
-use arrow2::datatypes::TimeUnit;
+use arrow2::datatypes::{DataType, IntervalUnit, TimeUnit};
 use arrow2::temporal_conversions;
-use arrow2::types::months_days_ns;
+use arrow2::types::{days_ms, months_days_ns};
 
 #[test]
 fn naive() {
@@ -139,3 +139,86 @@ fn add_interval_timezone() {
     let r = temporal_conversions::timestamp_to_datetime(r, timeunit, &timezone);
     assert_eq!("2020-10-29 01:00:00 WET", format!("{}", r));
 }
+
+#[test]
+fn parse_interval() {
+    let hour = 3_600_000_000_000i64;
+    let cases = [
+        ("1 year 2 months 3 days", months_days_ns::new(14, 3, 0)),
+        ("02:30:00", months_days_ns::new(0, 0, 5 * hour / 2)),
+        ("02:30", months_days_ns::new(0, 0, 5 * hour / 2)),
+        ("00:00:01.5", months_days_ns::new(0, 0, 1_500_000_000)),
+        ("-1 day 12:00:00", months_days_ns::new(0, -1, 12 * hour)),
+        ("-12:00:00", months_days_ns::new(0, 0, -12 * hour)),
+        ("1 Mon 2 WEEKS", months_days_ns::new(1, 14, 0)),
+        ("1.5 days", months_days_ns::new(0, 1, 12 * hour)),
+        ("3days 4hours", months_days_ns::new(0, 3, 4 * hour)),
+        ("10 ms 5 us 1 ns", months_days_ns::new(0, 0, 10_005_001)),
+        ("  1   minute  ", months_days_ns::new(0, 0, 60_000_000_000)),
+        ("1 year 1 day ago", months_days_ns::new(-12, -1, 0)),
+        ("+2 secs", months_days_ns::new(0, 0, 2_000_000_000)),
+    ];
+    for (value, expected) in cases {
+        assert_eq!(
+            temporal_conversions::parse_interval(value).unwrap(),
+            expected,
+            "{}",
+            value
+        );
+    }
+
+    for value in [
+        "",
+        "ago",
+        "1",
+        "1 fortnight",
+        "1.5 years",
+        "one day",
+        "1:2:3:4",
+        "00:60:00",
+        "1.2.3 days",
+        "300000000 years",
+    ] {
+        assert!(
+            temporal_conversions::parse_interval(value).is_err(),
+            "{}",
+            value
+        );
+    }
+}
+
+#[test]
+fn utf8_to_interval() {
+    let array = Utf8Array::<i32>::from(&[Some("1 day 1 ms"), None, Some("-2 days")]);
+
+    let data_type = DataType::Interval(IntervalUnit::DayTime);
+    let result = temporal_conversions::utf8_to_interval(&array, &data_type).unwrap();
+    let expected = PrimitiveArray::<days_ms>::from(&[
+        Some(days_ms::new(1, 1)),
+        None,
+        Some(days_ms::new(-2, 0)),
+    ])
+    .to(data_type);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let data_type = DataType::Duration(TimeUnit::Millisecond);
+    let result = temporal_conversions::utf8_to_interval(&array, &data_type).unwrap();
+    let expected =
+        PrimitiveArray::<i64>::from(&[Some(86_400_001), None, Some(-172_800_000)]).to(data_type);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    // not representable in seconds or in months
+    let data_type = DataType::Duration(TimeUnit::Second);
+    let error = temporal_conversions::utf8_to_interval(&array, &data_type).unwrap_err();
+    assert_eq!(error.context().unwrap().row, Some(0));
+
+    let data_type = DataType::Interval(IntervalUnit::YearMonth);
+    assert!(temporal_conversions::utf8_to_interval(&array, &data_type).is_err());
+
+    let array = Utf8Array::<i32>::from_slice(&["1 day", "1 dya"]);
+    let data_type = DataType::Interval(IntervalUnit::MonthDayNano);
+    let error = temporal_conversions::utf8_to_interval(&array, &data_type).unwrap_err();
+    assert_eq!(error.context().unwrap().row, Some(1));
+
+    assert!(temporal_conversions::utf8_to_interval(&array, &DataType::Int64).is_err());
+}
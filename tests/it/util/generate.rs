@@ -0,0 +1,88 @@
+use std::collections::HashSet;
+
+use arrow2::array::*;
+use arrow2::datatypes::*;
+use arrow2::error::Result;
+use arrow2::util::generate::*;
+
+fn schema() -> Schema {
+    let item = Box::new(Field::new("item", DataType::Int64, true));
+    Schema::new(vec![
+        Field::new("null", DataType::Null, true),
+        Field::new("bool", DataType::Boolean, true),
+        Field::new("int", DataType::Int32, false),
+        Field::new("decimal", DataType::Decimal(10, 2), true),
+        Field::new(
+            "timestamp",
+            DataType::Timestamp(TimeUnit::Millisecond, None),
+            true,
+        ),
+        Field::new("utf8", DataType::LargeUtf8, true),
+        Field::new("binary", DataType::FixedSizeBinary(3), true),
+        Field::new("list", DataType::List(item.clone()), true),
+        Field::new("fixed_list", DataType::FixedSizeList(item, 2), true),
+        Field::new(
+            "struct",
+            DataType::Struct(vec![
+                Field::new("a", DataType::Float64, true),
+                Field::new("b", DataType::Binary, false),
+            ]),
+            true,
+        ),
+        Field::new(
+            "dict",
+            DataType::Dictionary(IntegerType::UInt8, Box::new(DataType::Utf8), false),
+            true,
+        ),
+    ])
+}
+
+#[test]
+fn deterministic() -> Result<()> {
+    let schema = schema();
+    let batch = record_batch(&schema, 100, 0, 0.2, None)?;
+    assert_eq!(batch.num_rows(), 100);
+    assert_eq!(batch.schema().as_ref(), &schema);
+
+    assert_eq!(batch, record_batch(&schema, 100, 0, 0.2, None)?);
+    assert_ne!(batch, record_batch(&schema, 100, 1, 0.2, None)?);
+    Ok(())
+}
+
+#[test]
+fn nulls() -> Result<()> {
+    let batch = record_batch(&schema(), 1000, 0, 0.5, None)?;
+    // non-nullable fields have no nulls
+    assert_eq!(batch.column(2).null_count(), 0);
+    assert!(batch.column(1).null_count() > 0);
+
+    let batch = record_batch(&schema(), 1000, 0, 0.0, None)?;
+    assert!(batch.columns()[1..].iter().all(|c| c.null_count() == 0));
+    Ok(())
+}
+
+#[test]
+fn cardinality() -> Result<()> {
+    let utf8 = array(&DataType::Utf8, 1000, 0, 0.0, Some(5))?;
+    let utf8 = utf8.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+    assert!(utf8.values_iter().collect::<HashSet<_>>().len() <= 5);
+
+    let data_type = DataType::Dictionary(IntegerType::Int8, Box::new(DataType::Int32), false);
+    let array = array(&data_type, 1000, 0, 0.0, Some(1000))?;
+    let array = array
+        .as_any()
+        .downcast_ref::<DictionaryArray<i8>>()
+        .unwrap();
+    assert_eq!(array.values().len(), 128);
+    Ok(())
+}
+
+#[test]
+fn union_unsupported() {
+    let data_type = DataType::Union(
+        vec![Field::new("a", DataType::Int32, true)],
+        None,
+        UnionMode::Sparse,
+    );
+    assert!(array(&data_type, 10, 0, 0.0, None).is_err());
+}